@@ -56,12 +56,7 @@ pub struct PkiHeader<'a> {
     pub pvno: Pvno,
     pub sender: GeneralName,
     pub recipient: GeneralName,
-    #[asn1(
-        context_specific = "0",
-        tag_mode = "EXPLICIT",
-        constructed = "false",
-        optional = "true"
-    )]
+    #[asn1(context_specific = "0", tag_mode = "EXPLICIT", optional = "true")]
     pub message_time: Option<GeneralizedTime>,
     #[asn1(
         context_specific = "1",
@@ -70,40 +65,15 @@ pub struct PkiHeader<'a> {
         optional = "true"
     )]
     pub protection_alg: Option<AlgorithmIdentifierOwned>,
-    #[asn1(
-        context_specific = "2",
-        tag_mode = "EXPLICIT",
-        constructed = "false",
-        optional = "true"
-    )]
+    #[asn1(context_specific = "2", tag_mode = "EXPLICIT", optional = "true")]
     pub sender_kid: Option<OctetString>,
-    #[asn1(
-        context_specific = "3",
-        tag_mode = "EXPLICIT",
-        constructed = "false",
-        optional = "true"
-    )]
+    #[asn1(context_specific = "3", tag_mode = "EXPLICIT", optional = "true")]
     pub recip_kid: Option<OctetString>,
-    #[asn1(
-        context_specific = "4",
-        tag_mode = "EXPLICIT",
-        constructed = "false",
-        optional = "true"
-    )]
+    #[asn1(context_specific = "4", tag_mode = "EXPLICIT", optional = "true")]
     pub trans_id: Option<OctetString>,
-    #[asn1(
-        context_specific = "5",
-        tag_mode = "EXPLICIT",
-        constructed = "false",
-        optional = "true"
-    )]
+    #[asn1(context_specific = "5", tag_mode = "EXPLICIT", optional = "true")]
     pub sender_nonce: Option<OctetString>,
-    #[asn1(
-        context_specific = "6",
-        tag_mode = "EXPLICIT",
-        constructed = "false",
-        optional = "true"
-    )]
+    #[asn1(context_specific = "6", tag_mode = "EXPLICIT", optional = "true")]
     pub recip_nonce: Option<OctetString>,
     #[asn1(
         context_specific = "7",
@@ -25,12 +25,7 @@ use crate::header::{CmpCertificate, PkiHeader};
 pub struct PkiMessage<'a> {
     pub header: PkiHeader<'a>,
     pub body: PkiBody<'a>,
-    #[asn1(
-        context_specific = "0",
-        tag_mode = "EXPLICIT",
-        constructed = "false",
-        optional = "true"
-    )]
+    #[asn1(context_specific = "0", tag_mode = "EXPLICIT", optional = "true")]
     pub protection: Option<PkiProtection>,
     #[asn1(
         context_specific = "1",
@@ -99,6 +99,14 @@ mod tests {
         assert_eq!(None, super::DB.by_oid(&none));
     }
 
+    #[test]
+    fn to_name() {
+        assert_eq!(Some("cn"), CN.to_name());
+
+        let none = ObjectIdentifier::new_unwrap("0.1.2.3.4.5.6.7.8.9");
+        assert_eq!(None, none.to_name());
+    }
+
     #[test]
     fn by_name() {
         let cn = super::DB.by_name("CN").expect("cn not found");
@@ -184,6 +184,20 @@ impl ObjectIdentifier {
     }
 }
 
+#[cfg(feature = "db")]
+#[cfg_attr(docsrs, doc(cfg(feature = "db")))]
+impl ObjectIdentifier {
+    /// Look up the symbolic name of this OID (e.g. `cn`) in the OID names
+    /// [`db`], returning `None` if it isn't known.
+    ///
+    /// When an OID has several names, the first one in the database is used.
+    /// Note that the [`Display`][`fmt::Display`] impl always renders OIDs in
+    /// dotted decimal notation.
+    pub const fn to_name(&self) -> Option<&'static str> {
+        db::DB.by_oid(self)
+    }
+}
+
 impl<'a> ObjectIdentifier<&'a [u8]> {
     /// Initialize OID from a byte slice without validating that it contains
     /// a well-formed BER-encoded OID.
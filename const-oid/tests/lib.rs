@@ -207,3 +207,26 @@ fn push_arc() {
         ObjectIdentifier::new("1.2.3.4").unwrap()
     );
 }
+
+#[test]
+fn arcs() {
+    let arcs: Vec<_> = EXAMPLE_OID_2.arcs().collect();
+    assert_eq!(arcs, [2, 16, 840, 1, 101, 3, 4, 1, 42]);
+
+    let arcs: Vec<_> = EXAMPLE_OID_LARGE_ARC.arcs().collect();
+    assert_eq!(arcs, [0, 9, 2342, 19200300, 100, 1, 1]);
+}
+
+#[test]
+fn parent_of_child() {
+    let oid = EXAMPLE_OID_1.push_arc(7).unwrap();
+    assert_eq!(oid.to_string(), "1.2.840.10045.2.1.7");
+    assert_eq!(oid.parent(), Some(EXAMPLE_OID_1));
+    assert_eq!(
+        oid.parent()
+            .and_then(|oid| oid.parent())
+            .unwrap()
+            .to_string(),
+        "1.2.840.10045.2"
+    );
+}
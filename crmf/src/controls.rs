@@ -130,7 +130,7 @@ pub enum PkiArchiveOptions {
     EncryptedPrivKey(EncryptedKey),
     #[asn1(context_specific = "1", tag_mode = "EXPLICIT", constructed = "true")]
     KeyGenParameters(KeyGenParameters),
-    #[asn1(context_specific = "2", tag_mode = "EXPLICIT", constructed = "false")]
+    #[asn1(context_specific = "2", tag_mode = "EXPLICIT")]
     ArchiveRemGenPrivKey(bool),
 }
 
@@ -191,12 +191,7 @@ pub struct EncryptedValue {
         optional = "true"
     )]
     pub sym_alg: Option<AlgorithmIdentifierOwned>,
-    #[asn1(
-        context_specific = "2",
-        tag_mode = "EXPLICIT",
-        constructed = "false",
-        optional = "true"
-    )]
+    #[asn1(context_specific = "2", tag_mode = "EXPLICIT", optional = "true")]
     pub enc_sym_key: Option<BitString>,
     #[asn1(
         context_specific = "3",
@@ -205,12 +200,7 @@ pub struct EncryptedValue {
         optional = "true"
     )]
     pub key_alg: Option<AlgorithmIdentifierOwned>,
-    #[asn1(
-        context_specific = "4",
-        tag_mode = "EXPLICIT",
-        constructed = "false",
-        optional = "true"
-    )]
+    #[asn1(context_specific = "4", tag_mode = "EXPLICIT", optional = "true")]
     pub value_hint: Option<OctetString>,
     pub enc_value: BitString,
 }
@@ -154,11 +154,11 @@ pub struct PbmParameter {
 #[derive(Clone, Debug, PartialEq, Eq, Choice)]
 #[allow(missing_docs)]
 pub enum POPOPrivKey {
-    #[asn1(context_specific = "0", tag_mode = "EXPLICIT", constructed = "false")]
+    #[asn1(context_specific = "0", tag_mode = "EXPLICIT")]
     ThisMessage(BitString),
     #[asn1(context_specific = "1", tag_mode = "EXPLICIT", constructed = "true")]
     SubsequentMessage(SubsequentMessage),
-    #[asn1(context_specific = "2", tag_mode = "EXPLICIT", constructed = "false")]
+    #[asn1(context_specific = "2", tag_mode = "EXPLICIT")]
     DhMac(BitString),
     #[asn1(context_specific = "3", tag_mode = "EXPLICIT", constructed = "true")]
     AgreeMac(PkMacValue),
@@ -128,18 +128,8 @@ pub struct CertTemplate {
 #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
 #[allow(missing_docs)]
 pub struct OptionalValidity {
-    #[asn1(
-        context_specific = "0",
-        tag_mode = "EXPLICIT",
-        constructed = "false",
-        optional = "true"
-    )]
+    #[asn1(context_specific = "0", tag_mode = "EXPLICIT", optional = "true")]
     pub not_before: Option<Time>,
-    #[asn1(
-        context_specific = "1",
-        tag_mode = "EXPLICIT",
-        constructed = "false",
-        optional = "true"
-    )]
+    #[asn1(context_specific = "1", tag_mode = "EXPLICIT", optional = "true")]
     pub not_after: Option<Time>,
 }
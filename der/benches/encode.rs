@@ -0,0 +1,119 @@
+//! `der` encoding benchmarks
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use der::{
+    asn1::Any, Decode, DecodeValue, Encode, EncodeValue, Header, Length, Reader, Result,
+    SliceReader, Tag, Tagged, Writer,
+};
+
+/// Certificate chain used by the benchmarks, leaf first.
+const CHAIN: &[&[u8]] = &[
+    include_bytes!("../../x509-cert/tests/examples/amazon.der"),
+    include_bytes!("../../x509-cert/tests/examples/eca.der"),
+    include_bytes!("../../x509-cert/tests/examples/entrust.der"),
+    include_bytes!("../../x509-cert/tests/examples/exostar.der"),
+    include_bytes!("../../x509-cert/tests/examples/raytheon.der"),
+    include_bytes!("../../x509-cert/tests/examples/rsa2048-crt.der"),
+    include_bytes!("../../x509-cert/tests/examples/GoodCACert.crt"),
+];
+
+/// Owned tree of TLVs, so that encoding a certificate recurses through every
+/// nested value the same way as encoding its structured representation.
+enum Tlv {
+    Primitive(Any),
+    Constructed(Tag, Vec<Tlv>),
+}
+
+impl<'a> DecodeValue<'a> for Tlv {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        let any = Any::decode_value(reader, header)?;
+
+        if !header.tag.is_constructed() {
+            return Ok(Tlv::Primitive(any));
+        }
+
+        let mut reader = SliceReader::new(any.value())?;
+        let mut children = Vec::new();
+
+        while !reader.is_finished() {
+            children.push(reader.decode()?);
+        }
+
+        Ok(Tlv::Constructed(header.tag, children))
+    }
+}
+
+impl<'a> Decode<'a> for Tlv {
+    fn decode<R: Reader<'a>>(reader: &mut R) -> Result<Self> {
+        let header = Header::decode(reader)?;
+        Self::decode_value(reader, header)
+    }
+}
+
+impl EncodeValue for Tlv {
+    fn value_len(&self) -> Result<Length> {
+        match self {
+            Tlv::Primitive(any) => any.value_len(),
+            Tlv::Constructed(_, children) => children
+                .iter()
+                .try_fold(Length::ZERO, |len, child| len + child.encoded_len()?),
+        }
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        match self {
+            Tlv::Primitive(any) => any.encode_value(writer),
+            Tlv::Constructed(_, children) => children.iter().try_for_each(|c| c.encode(writer)),
+        }
+    }
+}
+
+impl Tagged for Tlv {
+    fn tag(&self) -> Tag {
+        match self {
+            Tlv::Primitive(any) => any.tag(),
+            Tlv::Constructed(tag, _) => *tag,
+        }
+    }
+}
+
+fn chain() -> Vec<Tlv> {
+    CHAIN
+        .iter()
+        .map(|der| Tlv::from_der(der).unwrap())
+        .collect()
+}
+
+fn chain_len() -> usize {
+    CHAIN.iter().map(|der| der.len()).sum()
+}
+
+fn encode(c: &mut Criterion) {
+    let chain = chain();
+    let mut group = c.benchmark_group("certificate chain");
+    group.throughput(Throughput::Bytes(chain_len() as u64));
+
+    group.bench_function("to_vec", |b| {
+        b.iter(|| {
+            for cert in &chain {
+                black_box(cert.to_vec().unwrap());
+            }
+        })
+    });
+
+    group.bench_function("to_der_into", |b| {
+        let mut buf = vec![0u8; chain_len()];
+        b.iter(|| {
+            let mut pos = 0;
+            for cert in &chain {
+                pos += cert.to_der_into(&mut buf[pos..]).unwrap();
+            }
+            black_box(&buf[..pos]);
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, encode);
+criterion_main!(benches);
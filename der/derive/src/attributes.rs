@@ -80,6 +80,9 @@ pub(crate) struct FieldAttrs {
     pub tag_mode: TagMode,
 
     /// Is the inner type constructed?
+    ///
+    /// Defaults to `true` for `EXPLICIT`ly tagged context-specific fields,
+    /// and `false` otherwise.
     pub constructed: bool,
 }
 
@@ -165,14 +168,26 @@ impl FieldAttrs {
             }
         }
 
+        let tag_mode = tag_mode.unwrap_or(type_attrs.tag_mode);
+
+        // `EXPLICIT` context-specific tags always wrap a constructed encoding
+        // (X.690 Section 8.14)
+        let explicit = context_specific.is_some() && tag_mode == TagMode::Explicit;
+
+        if explicit && constructed == Some(false) {
+            abort_call_site!("`EXPLICIT` context-specific fields must be constructed");
+        }
+
+        let constructed = constructed.unwrap_or(explicit);
+
         Self {
             asn1_type,
             context_specific,
             default,
             extensible: extensible.unwrap_or_default(),
             optional: optional.unwrap_or_default(),
-            tag_mode: tag_mode.unwrap_or(type_attrs.tag_mode),
-            constructed: constructed.unwrap_or_default(),
+            tag_mode,
+            constructed,
         }
     }
 
@@ -0,0 +1,179 @@
+//! Support for deriving the `Decode` and `Encode` traits on structs for the
+//! purposes of decoding/encoding ASN.1 `BIT STRING` types with named bits.
+
+use crate::{default_lifetime, ATTR_NAME};
+use proc_macro2::{Literal, TokenStream};
+use proc_macro_error::abort;
+use quote::quote;
+use syn::{DeriveInput, Field, Fields, Ident, Type};
+
+/// Derive `Decode` and `Encode` for a struct of named bits.
+pub(crate) struct DeriveBitStringFlags {
+    /// Name of the struct type.
+    ident: Ident,
+
+    /// Named bits of this struct, in bit position order.
+    bits: Vec<Ident>,
+}
+
+impl DeriveBitStringFlags {
+    /// Parse [`DeriveInput`].
+    pub fn new(input: DeriveInput) -> Self {
+        let data = match input.data {
+            syn::Data::Struct(data) => data,
+            _ => abort!(
+                input.ident,
+                "can't derive `BitStringFlags` on this type: only `struct` types are allowed",
+            ),
+        };
+
+        if !input.generics.params.is_empty() {
+            abort!(
+                input.generics,
+                "`BitStringFlags` can't be derived on generic types"
+            );
+        }
+
+        let fields = match data.fields {
+            Fields::Named(fields) => fields.named,
+            _ => abort!(
+                input.ident,
+                "`BitStringFlags` can only be derived on structs with named fields"
+            ),
+        };
+
+        if fields.is_empty() {
+            abort!(input.ident, "`BitStringFlags` requires at least one field");
+        }
+
+        Self {
+            ident: input.ident,
+            bits: fields.iter().map(parse_field).collect(),
+        }
+    }
+
+    /// Lower the derived output into a [`TokenStream`].
+    pub fn to_tokens(&self) -> TokenStream {
+        let default_lifetime = default_lifetime();
+        let ident = &self.ident;
+        let bits = &self.bits;
+        let num_bits = Literal::usize_unsuffixed(bits.len());
+        let num_bytes = Literal::usize_unsuffixed((bits.len() + 7) / 8);
+
+        let mut set_bits = Vec::new();
+        for (position, bit) in bits.iter().enumerate() {
+            let index = Literal::usize_unsuffixed(position / 8);
+            let mask = Literal::u8_suffixed(0x80 >> (position % 8));
+            let bit_len = Literal::usize_unsuffixed(position + 1);
+
+            set_bits.push(quote! {
+                if self.#bit {
+                    bytes[#index] |= #mask;
+                    bit_len = #bit_len;
+                }
+            });
+        }
+
+        // Trailing zero bits are trimmed as required by X.690 Section 11.2.2
+        let encode_body = quote! {
+            let mut bytes = [0u8; #num_bytes];
+            let mut bit_len = 0usize;
+            #(#set_bits)*
+            let bit_string = ::der::asn1::BitStringRef::new(
+                ((8 - bit_len % 8) % 8) as u8,
+                &bytes[..(bit_len + 7) / 8]
+            )?;
+        };
+
+        quote! {
+            impl<#default_lifetime> ::der::DecodeValue<#default_lifetime> for #ident {
+                fn decode_value<R: ::der::Reader<#default_lifetime>>(
+                    reader: &mut R,
+                    header: ::der::Header
+                ) -> ::der::Result<Self> {
+                    let bit_string = <::der::asn1::BitStringRef<#default_lifetime> as ::der::DecodeValue<#default_lifetime>>
+                        ::decode_value(reader, header)?;
+
+                    if bit_string.bit_len() > #num_bits {
+                        return Err(::der::Tag::BitString.value_error());
+                    }
+
+                    if bit_string.bits().last() == Some(false) {
+                        return Err(::der::Tag::BitString.non_canonical_error());
+                    }
+
+                    let mut bits = bit_string.bits();
+
+                    Ok(Self {
+                        #(#bits: bits.next().unwrap_or(false),)*
+                    })
+                }
+            }
+
+            impl ::der::EncodeValue for #ident {
+                fn value_len(&self) -> ::der::Result<::der::Length> {
+                    #encode_body
+                    ::der::EncodeValue::value_len(&bit_string)
+                }
+
+                fn encode_value(&self, writer: &mut impl ::der::Writer) -> ::der::Result<()> {
+                    #encode_body
+                    ::der::EncodeValue::encode_value(&bit_string, writer)
+                }
+            }
+
+            impl ::der::FixedTag for #ident {
+                const TAG: ::der::Tag = ::der::Tag::BitString;
+            }
+        }
+    }
+}
+
+/// Parse a named bit from a field of a derived `BitStringFlags`.
+fn parse_field(field: &Field) -> Ident {
+    for attr in &field.attrs {
+        if attr.path.is_ident(ATTR_NAME) {
+            abort!(
+                attr,
+                "`asn1` attribute is not allowed on fields of `BitStringFlags` types"
+            );
+        }
+    }
+
+    match &field.ty {
+        Type::Path(ty) if ty.qself.is_none() && ty.path.is_ident("bool") => (),
+        other => abort!(other, "fields of `BitStringFlags` types must be `bool`"),
+    }
+
+    field.ident.clone().expect("named field")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DeriveBitStringFlags;
+    use syn::parse_quote;
+
+    /// X.509 `KeyUsage`.
+    #[test]
+    fn key_usage_example() {
+        let input = parse_quote! {
+            pub struct KeyUsage {
+                pub digital_signature: bool,
+                pub non_repudiation: bool,
+                pub key_encipherment: bool,
+                pub data_encipherment: bool,
+                pub key_agreement: bool,
+                pub key_cert_sign: bool,
+                pub crl_sign: bool,
+                pub encipher_only: bool,
+                pub decipher_only: bool,
+            }
+        };
+
+        let ir = DeriveBitStringFlags::new(input);
+        assert_eq!(ir.ident, "KeyUsage");
+        assert_eq!(ir.bits.len(), 9);
+        assert_eq!(ir.bits[0], "digital_signature");
+        assert_eq!(ir.bits[8], "decipher_only");
+    }
+}
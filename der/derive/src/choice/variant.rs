@@ -153,10 +153,12 @@ impl ChoiceVariant {
 #[cfg(test)]
 mod tests {
     use super::ChoiceVariant;
-    use crate::{choice::variant::TagOrPath, Asn1Type, FieldAttrs, Tag, TagMode, TagNumber};
+    use crate::{
+        choice::variant::TagOrPath, Asn1Type, FieldAttrs, Tag, TagMode, TagNumber, TypeAttrs,
+    };
     use proc_macro2::Span;
     use quote::quote;
-    use syn::Ident;
+    use syn::{parse_quote, Ident, Variant};
 
     #[test]
     fn simple() {
@@ -414,4 +416,47 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn explicit_implies_constructed() {
+        let type_attrs = TypeAttrs {
+            tag_mode: TagMode::Implicit,
+        };
+
+        let implicit: Variant = parse_quote! {
+            #[asn1(context_specific = "1", type = "IA5String")]
+            Rfc822Name(Ia5StringRef<'a>)
+        };
+
+        let explicit: Variant = parse_quote! {
+            #[asn1(context_specific = "4", tag_mode = "EXPLICIT")]
+            DirectoryName(Name)
+        };
+
+        for (variant, constructed, number) in [(implicit, false, 1), (explicit, true, 4)] {
+            let variant = ChoiceVariant::new(&variant, &type_attrs);
+            assert_eq!(
+                variant.tag,
+                Tag::ContextSpecific {
+                    constructed,
+                    number: TagNumber(number),
+                }
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn explicit_primitive() {
+        let type_attrs = TypeAttrs {
+            tag_mode: TagMode::Implicit,
+        };
+
+        let variant: Variant = parse_quote! {
+            #[asn1(context_specific = "4", tag_mode = "EXPLICIT", constructed = "false")]
+            DirectoryName(Name)
+        };
+
+        ChoiceVariant::new(&variant, &type_attrs);
+    }
 }
@@ -6,6 +6,7 @@
 //! This crate contains custom derive macros intended to be used in the
 //! following way:
 //!
+//! - [`BitStringFlags`][`derive@BitStringFlags`]: map ASN.1 `BIT STRING` with named bits to a Rust struct.
 //! - [`Choice`][`derive@Choice`]: map ASN.1 `CHOICE` to a Rust enum.
 //! - [`Enumerated`][`derive@Enumerated`]: map ASN.1 `ENUMERATED` to a C-like Rust enum.
 //! - [`Sequence`][`derive@Sequence`]: map ASN.1 `SEQUENCE` to a Rust struct.
@@ -62,6 +63,12 @@
 //! This behaves like `serde_derive`'s `default` attribute, allowing you to
 //! specify the path to a function which returns a default value.
 //!
+//! When encoding, a field which is equal to its default value is omitted, as
+//! DER requires. When decoding, the default value is substituted if the field
+//! is absent. It can be combined with `context_specific` (e.g. for the
+//! `[0] EXPLICIT Version DEFAULT v1` field of an X.509 certificate), but not
+//! with `optional` or `type`.
+//!
 //! ### `#[asn1(extensible = "true")]` attribute: support for `...` extensibility operator
 //!
 //! This attribute can be applied to the fields of `struct` types, and will
@@ -98,6 +105,12 @@
 //! This attribute can be used to specify that an "inner" type is constructed. It is most
 //! commonly used when a `CHOICE` has a constructed inner type.
 //!
+//! Context-specific fields with `EXPLICIT` tagging are always constructed (X.690 Section
+//! 8.14), so this attribute defaults to `true` for them and setting it to `false` is an
+//! error. This makes it possible to mix `IMPLICIT` and `EXPLICIT` tagging among the
+//! variants of a `CHOICE` by annotating individual variants with
+//! `#[asn1(tag_mode = "...")]`.
+//!
 //! Note: please open a GitHub Issue if you would like to request support
 //! for additional ASN.1 types.
 //!
@@ -123,6 +136,7 @@
 
 mod asn1_type;
 mod attributes;
+mod bit_string_flags;
 mod choice;
 mod enumerated;
 mod sequence;
@@ -132,6 +146,7 @@ mod value_ord;
 use crate::{
     asn1_type::Asn1Type,
     attributes::{FieldAttrs, TypeAttrs, ATTR_NAME},
+    bit_string_flags::DeriveBitStringFlags,
     choice::DeriveChoice,
     enumerated::DeriveEnumerated,
     sequence::DeriveSequence,
@@ -150,6 +165,43 @@ fn default_lifetime() -> proc_macro2::TokenStream {
     quote!(#lifetime)
 }
 
+/// Derive decoders and encoders for ASN.1 `BIT STRING` types with named
+/// bits on a `struct` of `bool` fields.
+///
+/// # Usage
+///
+/// Each field corresponds to a named bit, numbered in declaration order
+/// starting from bit 0:
+///
+/// ```ignore
+/// use der::BitStringFlags;
+///
+/// /// X.509 `KeyUsage`
+/// #[derive(BitStringFlags, Copy, Clone, Debug, Default, Eq, PartialEq)]
+/// pub struct KeyUsage {
+///     pub digital_signature: bool,
+///     pub non_repudiation: bool,
+///     pub key_encipherment: bool,
+///     pub data_encipherment: bool,
+///     pub key_agreement: bool,
+///     pub key_cert_sign: bool,
+///     pub crl_sign: bool,
+///     pub encipher_only: bool,
+///     pub decipher_only: bool,
+/// }
+/// ```
+///
+/// As required by DER for named bit lists, trailing zero bits are omitted
+/// when encoding, so a value with no bits set is encoded as an empty
+/// `BIT STRING`. Decoding rejects encodings with trailing zero bits as well
+/// as bits beyond the last named bit.
+#[proc_macro_derive(BitStringFlags)]
+#[proc_macro_error]
+pub fn derive_bit_string_flags(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    DeriveBitStringFlags::new(input).to_tokens().into()
+}
+
 /// Derive the [`Choice`][1] trait on an `enum`.
 ///
 /// This custom derive macro can be used to automatically impl the
@@ -5,25 +5,36 @@
 mod internal_macros;
 
 mod any;
+mod application;
 mod bit_string;
+mod bmp_string;
 mod boolean;
 mod choice;
 mod context_specific;
+#[cfg(feature = "oid")]
+mod external;
+mod general_string;
 mod generalized_time;
+mod graphic_string;
 mod ia5_string;
 mod integer;
 mod null;
+mod numeric_string;
+mod object_descriptor;
 mod octet_string;
 #[cfg(feature = "oid")]
 mod oid;
 mod optional;
 mod printable_string;
+mod private;
 #[cfg(feature = "real")]
 mod real;
+mod relative_oid;
 mod sequence;
 mod sequence_of;
 mod set_of;
 mod teletex_string;
+mod universal_string;
 mod utc_time;
 mod utf8_string;
 mod videotex_string;
@@ -32,29 +43,43 @@ mod videotex_string;
 #[cfg_attr(docsrs, doc(cfg(feature = "oid")))]
 pub use const_oid::ObjectIdentifier;
 
+#[cfg(feature = "oid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "oid")))]
+pub use self::external::{External, ExternalEncoding};
+
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 pub use self::{
-    any::Any, bit_string::BitString, ia5_string::Ia5String, integer::bigint::Int,
-    integer::bigint::Uint, octet_string::OctetString, printable_string::PrintableString,
-    set_of::SetOfVec, teletex_string::TeletexString,
+    any::Any, bit_string::BitString, bmp_string::BmpString, ia5_string::Ia5String,
+    integer::bigint::Int, integer::bigint::Uint, object_descriptor::ObjectDescriptor,
+    octet_string::OctetString, printable_string::PrintableString, set_of::SetOfVec,
+    teletex_string::TeletexString, universal_string::UniversalString,
 };
 pub use self::{
-    any::AnyRef,
+    any::{AnyRef, SequenceFieldsIter},
+    application::{Application, ApplicationRef},
     bit_string::{BitStringIter, BitStringRef},
+    bmp_string::BmpStringRef,
     choice::Choice,
     context_specific::{ContextSpecific, ContextSpecificRef},
+    general_string::GeneralStringRef,
     generalized_time::GeneralizedTime,
+    graphic_string::GraphicStringRef,
     ia5_string::Ia5StringRef,
     integer::bigint::IntRef,
     integer::bigint::UintRef,
     null::Null,
+    numeric_string::NumericStringRef,
+    object_descriptor::ObjectDescriptorRef,
     octet_string::OctetStringRef,
     printable_string::PrintableStringRef,
+    private::{Private, PrivateRef},
+    relative_oid::{RelativeOid, RelativeOidArcs},
     sequence::{Sequence, SequenceRef},
     sequence_of::{SequenceOf, SequenceOfIter},
     set_of::{SetOf, SetOfIter},
     teletex_string::TeletexStringRef,
+    universal_string::UniversalStringRef,
     utc_time::UtcTime,
     utf8_string::Utf8StringRef,
     videotex_string::VideotexStringRef,
@@ -6,7 +6,7 @@ use crate::{
     BytesRef, Choice, Decode, DecodeValue, DerOrd, EncodeValue, Error, ErrorKind, Header, Length,
     Reader, Result, SliceReader, Tag, Tagged, ValueOrd, Writer,
 };
-use core::cmp::Ordering;
+use core::{cmp::Ordering, iter::FusedIterator};
 
 /// ASN.1 `ANY`: represents any explicitly tagged ASN.1 value.
 ///
@@ -61,16 +61,30 @@ impl<'a> AnyRef<'a> {
             return Err(self.tag.unexpected_error(None));
         }
 
-        let header = Header {
-            tag: self.tag,
-            length: self.value.len(),
-        };
+        let header = Header::new(self.tag, self.value.len())?;
 
         let mut decoder = SliceReader::new(self.value())?;
         let result = T::decode_value(&mut decoder, header)?;
         decoder.finish(result)
     }
 
+    /// Decode this [`AnyRef`] as the given type on demand.
+    ///
+    /// This is intended for values whose type isn't known until after they
+    /// have been read, e.g. an `ANY DEFINED BY` field whose type is selected
+    /// by a sibling `OBJECT IDENTIFIER`. Types which borrow from the input
+    /// retain the original `'a` lifetime, so no copy is made.
+    ///
+    /// Unlike [`AnyRef::decode_as`], this accepts any type which can be
+    /// converted from an [`AnyRef`], including tagged wrappers like
+    /// [`ContextSpecific`][`crate::asn1::ContextSpecific`].
+    pub fn decode_into<T>(self) -> Result<T>
+    where
+        T: TryFrom<AnyRef<'a>, Error = Error>,
+    {
+        T::try_from(self)
+    }
+
     /// Is this value an ASN.1 `NULL` value?
     pub fn is_null(self) -> bool {
         self == Self::NULL
@@ -87,6 +101,16 @@ impl<'a> AnyRef<'a> {
         let result = f(&mut reader)?;
         reader.finish(result)
     }
+
+    /// Iterate over the fields of this value as an ASN.1 `SEQUENCE` without a
+    /// concrete schema, yielding each field as an [`AnyRef`].
+    pub fn sequence_fields(self) -> Result<SequenceFieldsIter<'a>> {
+        self.tag.assert_eq(Tag::Sequence)?;
+
+        Ok(SequenceFieldsIter {
+            reader: SliceReader::new(self.value.as_slice())?,
+        })
+    }
 }
 
 impl<'a> Choice<'a> for AnyRef<'a> {
@@ -97,13 +121,17 @@ impl<'a> Choice<'a> for AnyRef<'a> {
 
 impl<'a> Decode<'a> for AnyRef<'a> {
     fn decode<R: Reader<'a>>(reader: &mut R) -> Result<AnyRef<'a>> {
-        let header = Header::decode(reader)?;
-        Self::decode_value(reader, header)
+        Header::decode_tlv(reader, Self::decode_value)
     }
 }
 
 impl<'a> DecodeValue<'a> for AnyRef<'a> {
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        // BER constructed `OCTET STRING`s can't be represented without a copy
+        if header.is_constructed() && !header.tag.is_constructed() {
+            return Err(header.tag.value_error());
+        }
+
         Ok(Self {
             tag: header.tag,
             value: BytesRef::decode_value(reader, header)?,
@@ -147,13 +175,35 @@ impl<'a> TryFrom<&'a [u8]> for AnyRef<'a> {
     }
 }
 
+/// Iterator over the fields of an ASN.1 `SEQUENCE` captured as an [`AnyRef`].
+///
+/// Iteration stops after the first error is returned.
+pub struct SequenceFieldsIter<'a> {
+    /// Reader over the body of the `SEQUENCE`.
+    reader: SliceReader<'a>,
+}
+
+impl<'a> Iterator for SequenceFieldsIter<'a> {
+    type Item = Result<AnyRef<'a>>;
+
+    fn next(&mut self) -> Option<Result<AnyRef<'a>>> {
+        if self.reader.is_failed() || self.reader.is_finished() {
+            None
+        } else {
+            Some(self.reader.decode())
+        }
+    }
+}
+
+impl<'a> FusedIterator for SequenceFieldsIter<'a> {}
+
 #[cfg(feature = "alloc")]
 pub use self::allocating::Any;
 
 #[cfg(feature = "alloc")]
 mod allocating {
     use super::*;
-    use crate::{referenced::*, BytesOwned};
+    use crate::{asn1::OctetString, referenced::*, BytesOwned};
     use alloc::boxed::Box;
 
     /// ASN.1 `ANY`: represents any explicitly tagged ASN.1 value.
@@ -194,6 +244,16 @@ mod allocating {
             AnyRef::from(self).decode_as()
         }
 
+        /// Decode this [`Any`] as the given type on demand.
+        ///
+        /// See [`AnyRef::decode_into`] for more information.
+        pub fn decode_into<'a, T>(&'a self) -> Result<T>
+        where
+            T: TryFrom<AnyRef<'a>, Error = Error>,
+        {
+            AnyRef::from(self).decode_into()
+        }
+
         /// Attempt to decode this value an ASN.1 `SEQUENCE`, creating a new
         /// nested reader and calling the provided argument with it.
         pub fn sequence<'a, F, T>(&'a self, f: F) -> Result<T>
@@ -202,6 +262,13 @@ mod allocating {
         {
             AnyRef::from(self).sequence(f)
         }
+
+        /// Iterate over the fields of this value as an ASN.1 `SEQUENCE`.
+        ///
+        /// See [`AnyRef::sequence_fields`] for more information.
+        pub fn sequence_fields(&self) -> Result<SequenceFieldsIter<'_>> {
+            AnyRef::from(self).sequence_fields()
+        }
     }
 
     impl Choice<'_> for Any {
@@ -212,13 +279,18 @@ mod allocating {
 
     impl<'a> Decode<'a> for Any {
         fn decode<R: Reader<'a>>(reader: &mut R) -> Result<Self> {
-            let header = Header::decode(reader)?;
-            Self::decode_value(reader, header)
+            Header::decode_tlv(reader, Self::decode_value)
         }
     }
 
     impl<'a> DecodeValue<'a> for Any {
         fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+            // Canonicalize BER constructed `OCTET STRING`s to primitive form
+            if header.is_constructed() && !header.tag.is_constructed() {
+                let octet_string = OctetString::decode_value(reader, header)?;
+                return Self::new(header.tag, octet_string.into_bytes());
+            }
+
             let value = reader.read_vec(header.length)?;
             Self::new(header.tag, value)
         }
@@ -290,3 +362,56 @@ mod allocating {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::AnyRef;
+    use crate::{
+        asn1::{ContextSpecific, OctetStringRef},
+        Decode, Tag,
+    };
+    use hex_literal::hex;
+
+    /// `SEQUENCE { INTEGER 1, [0] EXPLICIT OCTET STRING, NULL }`
+    const EXAMPLE_SEQUENCE: &[u8] = &hex!("300D 020101 A006 0404DEADBEEF 0500");
+
+    #[test]
+    fn sequence_fields() {
+        let any = AnyRef::from_der(EXAMPLE_SEQUENCE).unwrap();
+        let mut fields = any.sequence_fields().unwrap();
+
+        let field = fields.next().unwrap().unwrap();
+        assert_eq!(field.decode_into::<u8>().unwrap(), 1);
+
+        let field = fields
+            .next()
+            .unwrap()
+            .unwrap()
+            .decode_into::<ContextSpecific<OctetStringRef<'_>>>()
+            .unwrap();
+        assert_eq!(field.value.as_bytes(), &hex!("DEADBEEF"));
+        assert!(core::ptr::eq(
+            field.value.as_bytes(),
+            &EXAMPLE_SEQUENCE[9..13]
+        ));
+
+        assert!(fields.next().unwrap().unwrap().is_null());
+        assert!(fields.next().is_none());
+    }
+
+    #[test]
+    fn sequence_fields_stops_on_error() {
+        // Second field is truncated
+        let any = AnyRef::new(Tag::Sequence, &hex!("0500 0404DEAD")).unwrap();
+        let mut fields = any.sequence_fields().unwrap();
+        assert!(fields.next().unwrap().unwrap().is_null());
+        assert!(fields.next().unwrap().is_err());
+        assert!(fields.next().is_none());
+    }
+
+    #[test]
+    fn sequence_fields_wrong_tag() {
+        let any = AnyRef::new(Tag::Set, &[]).unwrap();
+        assert!(any.sequence_fields().is_err());
+    }
+}
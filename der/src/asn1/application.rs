@@ -0,0 +1,68 @@
+//! Application class field.
+
+impl_custom_class!(Application, Application, "APPLICATION", "0b01000000");
+impl_custom_class_ref!(ApplicationRef, Application, "APPLICATION", "0b01000000");
+
+#[cfg(test)]
+mod tests {
+    use super::{Application, ApplicationRef};
+    use crate::{Decode, Encode, SliceReader, TagMode, TagNumber, Tagged};
+    use hex_literal::hex;
+
+    /// `[APPLICATION 0] EXPLICIT INTEGER` containing `5`.
+    const EXPLICIT_BYTES: &[u8] = &hex!("6003020105");
+
+    /// `[APPLICATION 0] IMPLICIT INTEGER` containing `5`.
+    const IMPLICIT_BYTES: &[u8] = &hex!("400105");
+
+    #[test]
+    fn round_trip_explicit() {
+        let field = Application::<u8>::from_der(EXPLICIT_BYTES).unwrap();
+        assert_eq!(field.tag_number, TagNumber::N0);
+        assert_eq!(field.tag_mode, TagMode::Explicit);
+        assert_eq!(field.value, 5);
+        assert_eq!(field.tag().octet(), 0x60);
+
+        let mut buf = [0u8; 16];
+        assert_eq!(field.encode_to_slice(&mut buf).unwrap(), EXPLICIT_BYTES);
+    }
+
+    #[test]
+    fn round_trip_implicit() {
+        let mut reader = SliceReader::new(IMPLICIT_BYTES).unwrap();
+        let field = Application::<u8>::decode_implicit(&mut reader, TagNumber::N0)
+            .unwrap()
+            .unwrap();
+        assert_eq!(field.tag_mode, TagMode::Implicit);
+        assert_eq!(field.value, 5);
+        assert_eq!(field.tag().octet(), 0x40);
+
+        let mut buf = [0u8; 16];
+        assert_eq!(field.encode_to_slice(&mut buf).unwrap(), IMPLICIT_BYTES);
+    }
+
+    #[test]
+    fn encode_ref() {
+        let value = 5u8;
+        let field = ApplicationRef {
+            tag_number: TagNumber::N0,
+            tag_mode: TagMode::Explicit,
+            value: &value,
+        };
+
+        let mut buf = [0u8; 16];
+        assert_eq!(field.encode_to_slice(&mut buf).unwrap(), EXPLICIT_BYTES);
+    }
+
+    #[test]
+    fn reject_other_classes() {
+        // `[0] EXPLICIT INTEGER`
+        assert!(Application::<u8>::from_der(&hex!("A003020105")).is_err());
+
+        let mut reader = SliceReader::new(&hex!("A003020105")).unwrap();
+        assert_eq!(
+            Application::<u8>::decode_explicit(&mut reader, TagNumber::N0).unwrap(),
+            None
+        );
+    }
+}
@@ -122,8 +122,8 @@ impl_type!(BitStringRef<'a>, 'a);
 impl<'a> DecodeValue<'a> for BitStringRef<'a> {
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
         let header = Header {
-            tag: header.tag,
             length: (header.length - Length::ONE)?,
+            ..header
         };
 
         let unused_bits = reader.read_byte()?;
@@ -0,0 +1,379 @@
+//! ASN.1 `BMPString` support.
+
+use crate::{
+    asn1::AnyRef, ord::OrdIsValueOrd, BytesRef, DecodeValue, EncodeValue, FixedTag, Header, Length,
+    Reader, Result, Tag, Writer,
+};
+use core::{char, fmt};
+
+/// ASN.1 `BMPString` type: borrowed form.
+///
+/// Encodes Unicode characters from the Basic Multilingual Plane (BMP) as
+/// UCS-2, i.e. two bytes per character in big endian order.
+///
+/// This is a zero-copy reference type which borrows from the input data.
+///
+/// # Surrogates
+///
+/// UCS-2 has no surrogate pairs, but characters outside of the BMP are
+/// commonly encoded as UTF-16 surrogate pairs in practice, so these are
+/// accepted and decoded as UTF-16. Unpaired surrogates are rejected rather
+/// than replaced with `U+FFFD`.
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct BmpStringRef<'a> {
+    /// Inner value
+    inner: BytesRef<'a>,
+}
+
+impl<'a> BmpStringRef<'a> {
+    /// Create a new ASN.1 `BMPString` from its UCS-2 (or UTF-16) encoding.
+    pub fn new(bytes: &'a [u8]) -> Result<Self> {
+        validate_utf16(bytes)?;
+
+        BytesRef::new(bytes)
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.length_error())
+    }
+
+    /// Borrow the UCS-2 encoded bytes.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_slice()
+    }
+
+    /// Get the length of the encoded bytes.
+    pub fn len(&self) -> Length {
+        self.inner.len()
+    }
+
+    /// Is this string empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Iterate over the characters of this string.
+    pub fn chars(&self) -> impl Iterator<Item = char> + 'a {
+        decode_utf16(self.as_bytes())
+    }
+}
+
+impl_type!(BmpStringRef<'a>, 'a);
+
+impl AsRef<[u8]> for BmpStringRef<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for BmpStringRef<'a> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        Self::new(BytesRef::decode_value(reader, header)?.as_slice())
+    }
+}
+
+impl EncodeValue for BmpStringRef<'_> {
+    fn value_len(&self) -> Result<Length> {
+        self.inner.value_len()
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        self.inner.encode_value(writer)
+    }
+}
+
+impl FixedTag for BmpStringRef<'_> {
+    const TAG: Tag = Tag::BmpString;
+}
+
+impl OrdIsValueOrd for BmpStringRef<'_> {}
+
+impl fmt::Display for BmpStringRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.chars() {
+            fmt::Write::write_char(f, c)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for BmpStringRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BmpString(\"{}\")", self)
+    }
+}
+
+impl<'a> From<&BmpStringRef<'a>> for BmpStringRef<'a> {
+    fn from(value: &BmpStringRef<'a>) -> BmpStringRef<'a> {
+        *value
+    }
+}
+
+impl<'a> From<BmpStringRef<'a>> for AnyRef<'a> {
+    fn from(bmp_string: BmpStringRef<'a>) -> AnyRef<'a> {
+        AnyRef::from_tag_and_value(Tag::BmpString, bmp_string.inner)
+    }
+}
+
+/// Ensure the given bytes are valid big endian UTF-16, i.e. UCS-2 with
+/// optional surrogate pairs.
+fn validate_utf16(bytes: &[u8]) -> Result<()> {
+    if bytes.len() % 2 != 0 {
+        return Err(Tag::BmpString.length_error());
+    }
+
+    if char::decode_utf16(code_units(bytes)).any(|c| c.is_err()) {
+        return Err(Tag::BmpString.value_error());
+    }
+
+    Ok(())
+}
+
+/// Decode bytes previously checked with [`validate_utf16`].
+fn decode_utf16(bytes: &[u8]) -> impl Iterator<Item = char> + '_ {
+    char::decode_utf16(code_units(bytes)).map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+}
+
+/// Iterate over the big endian UTF-16 code units in the given bytes.
+fn code_units(bytes: &[u8]) -> impl Iterator<Item = u16> + '_ {
+    bytes
+        .chunks_exact(2)
+        .map(|code_unit| u16::from_be_bytes([code_unit[0], code_unit[1]]))
+}
+
+#[cfg(feature = "alloc")]
+pub use self::allocation::BmpString;
+
+#[cfg(feature = "alloc")]
+mod allocation {
+    use super::{decode_utf16, validate_utf16, BmpStringRef};
+    use crate::{
+        asn1::AnyRef,
+        ord::OrdIsValueOrd,
+        referenced::{OwnedToRef, RefToOwned},
+        BytesOwned, BytesRef, DecodeValue, EncodeValue, FixedTag, Header, Length, Reader, Result,
+        Tag, Writer,
+    };
+    use alloc::{string::String, vec::Vec};
+    use core::{fmt, str::FromStr};
+
+    /// ASN.1 `BMPString` type: owned form.
+    ///
+    /// Encodes Unicode characters from the Basic Multilingual Plane (BMP) as
+    /// UCS-2, i.e. two bytes per character in big endian order.
+    ///
+    /// See [`BmpStringRef`] for how surrogates are handled.
+    #[derive(Clone, Eq, PartialEq, PartialOrd, Ord)]
+    pub struct BmpString {
+        /// Inner value
+        inner: BytesOwned,
+    }
+
+    impl BmpString {
+        /// Create a new ASN.1 `BMPString` from its UCS-2 (or UTF-16) encoding.
+        pub fn from_ucs2(bytes: impl Into<Vec<u8>>) -> Result<Self> {
+            let bytes = bytes.into();
+            validate_utf16(&bytes)?;
+
+            BytesOwned::new(bytes)
+                .map(|inner| Self { inner })
+                .map_err(|_| Self::TAG.length_error())
+        }
+
+        /// Create a new ASN.1 `BMPString` from a Rust string.
+        ///
+        /// Characters outside of the Basic Multilingual Plane are encoded as
+        /// UTF-16 surrogate pairs.
+        pub fn from_utf8(s: &str) -> Result<Self> {
+            let mut bytes = Vec::new();
+
+            for code_unit in s.encode_utf16() {
+                bytes.extend_from_slice(&code_unit.to_be_bytes());
+            }
+
+            Self::from_ucs2(bytes)
+        }
+
+        /// Borrow the UCS-2 encoded bytes.
+        pub fn as_bytes(&self) -> &[u8] {
+            self.inner.as_slice()
+        }
+
+        /// Get the length of the encoded bytes.
+        pub fn len(&self) -> Length {
+            self.inner.len()
+        }
+
+        /// Is this string empty?
+        pub fn is_empty(&self) -> bool {
+            self.inner.is_empty()
+        }
+
+        /// Iterate over the characters of this string.
+        pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+            decode_utf16(self.as_bytes())
+        }
+    }
+
+    impl_type!(BmpString);
+
+    impl AsRef<[u8]> for BmpString {
+        fn as_ref(&self) -> &[u8] {
+            self.as_bytes()
+        }
+    }
+
+    impl<'a> DecodeValue<'a> for BmpString {
+        fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+            Self::from_ucs2(BytesRef::decode_value(reader, header)?.as_slice())
+        }
+    }
+
+    impl EncodeValue for BmpString {
+        fn value_len(&self) -> Result<Length> {
+            self.inner.value_len()
+        }
+
+        fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+            self.inner.encode_value(writer)
+        }
+    }
+
+    impl FixedTag for BmpString {
+        const TAG: Tag = Tag::BmpString;
+    }
+
+    impl OrdIsValueOrd for BmpString {}
+
+    impl FromStr for BmpString {
+        type Err = crate::Error;
+
+        fn from_str(s: &str) -> Result<Self> {
+            Self::from_utf8(s)
+        }
+    }
+
+    impl fmt::Display for BmpString {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Display::fmt(&self.owned_to_ref(), f)
+        }
+    }
+
+    impl fmt::Debug for BmpString {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Debug::fmt(&self.owned_to_ref(), f)
+        }
+    }
+
+    impl From<BmpString> for String {
+        fn from(bmp_string: BmpString) -> String {
+            bmp_string.chars().collect()
+        }
+    }
+
+    impl<'a> From<&'a BmpString> for AnyRef<'a> {
+        fn from(bmp_string: &'a BmpString) -> AnyRef<'a> {
+            bmp_string.owned_to_ref().into()
+        }
+    }
+
+    impl<'a> From<BmpStringRef<'a>> for BmpString {
+        fn from(bmp_string: BmpStringRef<'a>) -> BmpString {
+            bmp_string.ref_to_owned()
+        }
+    }
+
+    impl<'a> RefToOwned<'a> for BmpStringRef<'a> {
+        type Owned = BmpString;
+        fn ref_to_owned(&self) -> Self::Owned {
+            BmpString {
+                inner: self.inner.ref_to_owned(),
+            }
+        }
+    }
+
+    impl OwnedToRef for BmpString {
+        type Borrowed<'a> = BmpStringRef<'a>;
+        fn owned_to_ref(&self) -> Self::Borrowed<'_> {
+            BmpStringRef {
+                inner: self.inner.owned_to_ref(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BmpStringRef;
+    use crate::{Decode, Encode, SliceWriter, Tag};
+
+    #[test]
+    fn decode_and_reencode() {
+        // "Test €"
+        let example_bytes = &[
+            0x1e, 0x0c, 0x00, 0x54, 0x00, 0x65, 0x00, 0x73, 0x00, 0x74, 0x00, 0x20, 0x20, 0xac,
+        ];
+
+        let bmp_string = BmpStringRef::from_der(example_bytes).unwrap();
+        assert!(bmp_string.chars().eq("Test €".chars()));
+
+        let mut out = [0u8; 16];
+        let mut writer = SliceWriter::new(&mut out);
+        writer.encode(&bmp_string).unwrap();
+        assert_eq!(writer.finish().unwrap(), example_bytes);
+    }
+
+    #[test]
+    fn reject_odd_length() {
+        let err = BmpStringRef::from_der(&[0x1e, 0x03, 0x00, 0x41, 0x00]).unwrap_err();
+        assert_eq!(err.kind(), Tag::BmpString.length_error().kind());
+    }
+
+    #[test]
+    fn surrogate_pair() {
+        // U+1F600 encoded as a UTF-16 surrogate pair
+        let example_bytes = &[0x1e, 0x06, 0x00, 0x41, 0xd8, 0x3d, 0xde, 0x00];
+
+        let bmp_string = BmpStringRef::from_der(example_bytes).unwrap();
+        assert!(bmp_string.chars().eq("A\u{1F600}".chars()));
+
+        let mut out = [0u8; 8];
+        let mut writer = SliceWriter::new(&mut out);
+        writer.encode(&bmp_string).unwrap();
+        assert_eq!(writer.finish().unwrap(), example_bytes);
+    }
+
+    #[test]
+    fn reject_unpaired_surrogates() {
+        // unpaired high surrogate
+        let err = BmpStringRef::new(&[0xd8, 0x3d]).unwrap_err();
+        assert_eq!(err.kind(), Tag::BmpString.value_error().kind());
+        // high surrogate followed by a non-surrogate
+        assert!(BmpStringRef::new(&[0xd8, 0x3d, 0x00, 0x41]).is_err());
+        // unpaired low surrogate
+        assert!(BmpStringRef::new(&[0xdc, 0x00]).is_err());
+    }
+
+    #[test]
+    fn empty() {
+        let bmp_string = BmpStringRef::from_der(&[0x1e, 0x00]).unwrap();
+        assert!(bmp_string.is_empty());
+        assert_eq!(bmp_string.encoded_len().unwrap(), 2u8.into());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn owned_from_utf8() {
+        use super::BmpString;
+        use alloc::string::ToString;
+
+        let bmp_string = BmpString::from_utf8("friendlyName €").unwrap();
+        assert_eq!(bmp_string.to_string(), "friendlyName €");
+
+        let der = bmp_string.to_vec().unwrap();
+        assert_eq!(&der[..4], &[0x1e, 0x1c, 0x00, 0x66]);
+        assert_eq!(BmpString::from_der(&der).unwrap(), bmp_string);
+
+        let bmp_string = BmpString::from_utf8("emoji \u{1F600}").unwrap();
+        assert_eq!(bmp_string.as_bytes()[12..], [0xd8, 0x3d, 0xde, 0x00]);
+        assert_eq!(bmp_string.to_string(), "emoji \u{1F600}");
+    }
+}
@@ -23,6 +23,8 @@ impl<'a> DecodeValue<'a> for bool {
         match reader.read_byte()? {
             FALSE_OCTET => Ok(false),
             TRUE_OCTET => Ok(true),
+            // X.690 Section 8.2.2: BER permits any non-zero octet for `true`
+            _ if reader.encoding_rules().is_ber() => Ok(true),
             _ => Err(Self::TAG.non_canonical_error()),
         }
     }
@@ -79,4 +81,19 @@ mod tests {
     fn reject_non_canonical() {
         assert!(bool::from_der(&[0x01, 0x01, 0x01]).is_err());
     }
+
+    #[test]
+    fn decode_ber() {
+        assert_eq!(true, bool::from_ber(&[0x01, 0x01, 0x01]).unwrap());
+        assert_eq!(true, bool::from_ber(&[0x01, 0x01, 0xFF]).unwrap());
+        assert_eq!(false, bool::from_ber(&[0x01, 0x01, 0x00]).unwrap());
+
+        // Re-encoding canonicalizes to DER
+        let mut buffer = [0u8; 3];
+        let value = bool::from_ber(&[0x01, 0x01, 0x01]).unwrap();
+        assert_eq!(
+            &[0x01, 0x01, 0xFF],
+            value.encode_to_slice(&mut buffer).unwrap()
+        );
+    }
 }
@@ -1,262 +1,26 @@
 //! Context-specific field.
 
-use crate::{
-    asn1::AnyRef, Choice, Decode, DecodeValue, DerOrd, Encode, EncodeValue, EncodeValueRef, Error,
-    Header, Length, Reader, Result, Tag, TagMode, TagNumber, Tagged, ValueOrd, Writer,
-};
-use core::cmp::Ordering;
-
-/// Context-specific field which wraps an owned inner value.
-///
-/// This type decodes/encodes a field which is specific to a particular context
-/// and is identified by a [`TagNumber`].
-#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
-pub struct ContextSpecific<T> {
-    /// Context-specific tag number sans the leading `0b10000000` class
-    /// identifier bit and `0b100000` constructed flag.
-    pub tag_number: TagNumber,
-
-    /// Tag mode: `EXPLICIT` VS `IMPLICIT`.
-    pub tag_mode: TagMode,
-
-    /// Value of the field.
-    pub value: T,
-}
-
-impl<T> ContextSpecific<T> {
-    /// Attempt to decode an `EXPLICIT` ASN.1 `CONTEXT-SPECIFIC` field with the
-    /// provided [`TagNumber`].
-    ///
-    /// This method has the following behavior which is designed to simplify
-    /// handling of extension fields, which are denoted in an ASN.1 schema
-    /// using the `...` ellipsis extension marker:
-    ///
-    /// - Skips over [`ContextSpecific`] fields with a tag number lower than
-    ///   the current one, consuming and ignoring them.
-    /// - Returns `Ok(None)` if a [`ContextSpecific`] field with a higher tag
-    ///   number is encountered. These fields are not consumed in this case,
-    ///   allowing a field with a lower tag number to be omitted, then the
-    ///   higher numbered field consumed as a follow-up.
-    /// - Returns `Ok(None)` if anything other than a [`ContextSpecific`] field
-    ///   is encountered.
-    pub fn decode_explicit<'a, R: Reader<'a>>(
-        reader: &mut R,
-        tag_number: TagNumber,
-    ) -> Result<Option<Self>>
-    where
-        T: Decode<'a>,
-    {
-        Self::decode_with(reader, tag_number, |reader| Self::decode(reader))
-    }
-
-    /// Attempt to decode an `IMPLICIT` ASN.1 `CONTEXT-SPECIFIC` field with the
-    /// provided [`TagNumber`].
-    ///
-    /// This method otherwise behaves the same as `decode_explicit`,
-    /// but should be used in cases where the particular fields are `IMPLICIT`
-    /// as opposed to `EXPLICIT`.
-    pub fn decode_implicit<'a, R: Reader<'a>>(
-        reader: &mut R,
-        tag_number: TagNumber,
-    ) -> Result<Option<Self>>
-    where
-        T: DecodeValue<'a> + Tagged,
-    {
-        Self::decode_with(reader, tag_number, |reader| {
-            let header = Header::decode(reader)?;
-            let value = T::decode_value(reader, header)?;
-
-            if header.tag.is_constructed() != value.tag().is_constructed() {
-                return Err(header.tag.non_canonical_error());
-            }
-
-            Ok(Self {
-                tag_number,
-                tag_mode: TagMode::Implicit,
-                value,
-            })
-        })
-    }
-
-    /// Attempt to decode a context-specific field with the given
-    /// helper callback.
-    fn decode_with<'a, F, R: Reader<'a>>(
-        reader: &mut R,
-        tag_number: TagNumber,
-        f: F,
-    ) -> Result<Option<Self>>
-    where
-        F: FnOnce(&mut R) -> Result<Self>,
-    {
-        while let Some(octet) = reader.peek_byte() {
-            let tag = Tag::try_from(octet)?;
-
-            if !tag.is_context_specific() || (tag.number() > tag_number) {
-                break;
-            } else if tag.number() == tag_number {
-                return Some(f(reader)).transpose();
-            } else {
-                AnyRef::decode(reader)?;
-            }
-        }
-
-        Ok(None)
-    }
-}
-
-impl<'a, T> Choice<'a> for ContextSpecific<T>
-where
-    T: Decode<'a> + Tagged,
-{
-    fn can_decode(tag: Tag) -> bool {
-        tag.is_context_specific()
-    }
-}
-
-impl<'a, T> Decode<'a> for ContextSpecific<T>
-where
-    T: Decode<'a>,
-{
-    fn decode<R: Reader<'a>>(reader: &mut R) -> Result<Self> {
-        let header = Header::decode(reader)?;
-
-        match header.tag {
-            Tag::ContextSpecific {
-                number,
-                constructed: true,
-            } => Ok(Self {
-                tag_number: number,
-                tag_mode: TagMode::default(),
-                value: reader.read_nested(header.length, |reader| T::decode(reader))?,
-            }),
-            tag => Err(tag.unexpected_error(None)),
-        }
-    }
-}
-
-impl<T> EncodeValue for ContextSpecific<T>
-where
-    T: EncodeValue + Tagged,
-{
-    fn value_len(&self) -> Result<Length> {
-        match self.tag_mode {
-            TagMode::Explicit => self.value.encoded_len(),
-            TagMode::Implicit => self.value.value_len(),
-        }
-    }
-
-    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
-        match self.tag_mode {
-            TagMode::Explicit => self.value.encode(writer),
-            TagMode::Implicit => self.value.encode_value(writer),
-        }
-    }
-}
-
-impl<T> Tagged for ContextSpecific<T>
-where
-    T: Tagged,
-{
-    fn tag(&self) -> Tag {
-        let constructed = match self.tag_mode {
-            TagMode::Explicit => true,
-            TagMode::Implicit => self.value.tag().is_constructed(),
-        };
-
-        Tag::ContextSpecific {
-            number: self.tag_number,
-            constructed,
-        }
-    }
-}
-
-impl<'a, T> TryFrom<AnyRef<'a>> for ContextSpecific<T>
-where
-    T: Decode<'a>,
-{
-    type Error = Error;
-
-    fn try_from(any: AnyRef<'a>) -> Result<ContextSpecific<T>> {
-        match any.tag() {
-            Tag::ContextSpecific {
-                number,
-                constructed: true,
-            } => Ok(Self {
-                tag_number: number,
-                tag_mode: TagMode::default(),
-                value: T::from_der(any.value())?,
-            }),
-            tag => Err(tag.unexpected_error(None)),
-        }
-    }
-}
-
-impl<T> ValueOrd for ContextSpecific<T>
-where
-    T: EncodeValue + ValueOrd + Tagged,
-{
-    fn value_cmp(&self, other: &Self) -> Result<Ordering> {
-        match self.tag_mode {
-            TagMode::Explicit => self.der_cmp(other),
-            TagMode::Implicit => self.value_cmp(other),
-        }
-    }
-}
-
-/// Context-specific field reference.
-///
-/// This type encodes a field which is specific to a particular context
-/// and is identified by a [`TagNumber`].
-#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
-pub struct ContextSpecificRef<'a, T> {
-    /// Context-specific tag number sans the leading `0b10000000` class
-    /// identifier bit and `0b100000` constructed flag.
-    pub tag_number: TagNumber,
-
-    /// Tag mode: `EXPLICIT` VS `IMPLICIT`.
-    pub tag_mode: TagMode,
-
-    /// Value of the field.
-    pub value: &'a T,
-}
-
-impl<'a, T> ContextSpecificRef<'a, T> {
-    /// Convert to a [`ContextSpecific`].
-    fn encoder(&self) -> ContextSpecific<EncodeValueRef<'a, T>> {
-        ContextSpecific {
-            tag_number: self.tag_number,
-            tag_mode: self.tag_mode,
-            value: EncodeValueRef(self.value),
-        }
-    }
-}
-
-impl<'a, T> EncodeValue for ContextSpecificRef<'a, T>
-where
-    T: EncodeValue + Tagged,
-{
-    fn value_len(&self) -> Result<Length> {
-        self.encoder().value_len()
-    }
-
-    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
-        self.encoder().encode_value(writer)
-    }
-}
-
-impl<'a, T> Tagged for ContextSpecificRef<'a, T>
-where
-    T: Tagged,
-{
-    fn tag(&self) -> Tag {
-        self.encoder().tag()
-    }
-}
+impl_custom_class!(
+    ContextSpecific,
+    ContextSpecific,
+    "CONTEXT-SPECIFIC",
+    "0b10000000"
+);
+impl_custom_class_ref!(
+    ContextSpecificRef,
+    ContextSpecific,
+    "CONTEXT-SPECIFIC",
+    "0b10000000"
+);
 
 #[cfg(test)]
 mod tests {
     use super::ContextSpecific;
-    use crate::{asn1::BitStringRef, Decode, Encode, SliceReader, TagMode, TagNumber};
+    #[cfg(feature = "alloc")]
+    use crate::asn1::OctetString;
+    use crate::{
+        asn1::BitStringRef, Decode, Encode, EncodingRules, Reader, SliceReader, TagMode, TagNumber,
+    };
     use hex_literal::hex;
 
     // Public key data from `pkcs8` crate's `ed25519-pkcs8-v2.der`
@@ -332,6 +96,32 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn context_specific_with_implicit_ber_constructed_field() {
+        // CMS `encryptedContent [0] IMPLICIT OCTET STRING` in BER constructed form
+        let ber = hex!("A080 0402DEAD 0402BEEF 0000");
+        let tag_number = TagNumber::new(0);
+
+        let mut reader = SliceReader::new_with_encoding_rules(&ber, EncodingRules::Ber).unwrap();
+        let field = ContextSpecific::<OctetString>::decode_implicit(&mut reader, tag_number)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(field.tag_mode, TagMode::Implicit);
+        assert_eq!(field.value.as_bytes(), hex!("DEADBEEF"));
+        assert!(reader.is_finished());
+
+        // Only permitted when decoding BER
+        let mut reader = SliceReader::new(&ber).unwrap();
+        assert!(ContextSpecific::<OctetString>::decode_implicit(&mut reader, tag_number).is_err());
+
+        // Other types must still match the constructed bit of the tag
+        let mut reader =
+            SliceReader::new_with_encoding_rules(&hex!("A00100"), EncodingRules::Ber).unwrap();
+        assert!(ContextSpecific::<u8>::decode_implicit(&mut reader, tag_number).is_err());
+    }
+
     #[test]
     fn context_specific_skipping_unknown_field() {
         let tag = TagNumber::new(1);
@@ -0,0 +1,290 @@
+//! ASN.1 `EXTERNAL` support.
+
+use crate::{
+    asn1::{
+        AnyRef, BitStringRef, Choice, ContextSpecificRef, IntRef, ObjectDescriptorRef,
+        ObjectIdentifier, OctetStringRef,
+    },
+    Decode, DecodeValue, Encode, EncodeValue, FixedTag, Header, Length, Reader, Result, Tag,
+    TagMode, TagNumber, Tagged, Writer,
+};
+
+/// ASN.1 `EXTERNAL` type.
+///
+/// Carries a value whose type is specified outside of the enclosing ASN.1
+/// module, as defined in X.690 Section 8.18:
+///
+/// ```text
+/// EXTERNAL ::= [UNIVERSAL 8] IMPLICIT SEQUENCE {
+///     direct-reference      OBJECT IDENTIFIER OPTIONAL,
+///     indirect-reference    INTEGER OPTIONAL,
+///     data-value-descriptor ObjectDescriptor OPTIONAL,
+///     encoding              CHOICE {
+///         single-ASN1-type  [0] ABSTRACT-SYNTAX.&Type,
+///         octet-aligned     [1] IMPLICIT OCTET STRING,
+///         arbitrary         [2] IMPLICIT BIT STRING } }
+/// ```
+///
+/// This is a zero-copy reference type which borrows from the input data.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct External<'a> {
+    /// Object identifier of the abstract and transfer syntax of the value.
+    pub direct_reference: Option<ObjectIdentifier>,
+
+    /// Presentation context identifier of the value.
+    pub indirect_reference: Option<IntRef<'a>>,
+
+    /// Human-readable description of the value.
+    pub data_value_descriptor: Option<ObjectDescriptorRef<'a>>,
+
+    /// The value itself.
+    pub encoding: ExternalEncoding<'a>,
+}
+
+impl<'a> DecodeValue<'a> for External<'a> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        reader.read_nested(header.length, |reader| {
+            Ok(Self {
+                direct_reference: reader.decode()?,
+                indirect_reference: reader.decode()?,
+                data_value_descriptor: reader.decode()?,
+                encoding: reader.decode()?,
+            })
+        })
+    }
+}
+
+impl EncodeValue for External<'_> {
+    fn value_len(&self) -> Result<Length> {
+        self.direct_reference.encoded_len()?
+            + self.indirect_reference.encoded_len()?
+            + self.data_value_descriptor.encoded_len()?
+            + self.encoding.encoded_len()?
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        self.direct_reference.encode(writer)?;
+        self.indirect_reference.encode(writer)?;
+        self.data_value_descriptor.encode(writer)?;
+        self.encoding.encode(writer)
+    }
+}
+
+impl FixedTag for External<'_> {
+    const TAG: Tag = Tag::External;
+}
+
+impl<'a> TryFrom<AnyRef<'a>> for External<'a> {
+    type Error = crate::Error;
+
+    fn try_from(any: AnyRef<'a>) -> Result<External<'a>> {
+        any.decode_as()
+    }
+}
+
+/// The `encoding` field of an ASN.1 [`External`] value.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ExternalEncoding<'a> {
+    /// `single-ASN1-type [0] ABSTRACT-SYNTAX.&Type`: a single ASN.1 value,
+    /// explicitly tagged.
+    SingleAsn1Type(AnyRef<'a>),
+
+    /// `octet-aligned [1] IMPLICIT OCTET STRING`: an encoding which is an
+    /// integral number of octets.
+    OctetAligned(OctetStringRef<'a>),
+
+    /// `arbitrary [2] IMPLICIT BIT STRING`: any other encoding.
+    Arbitrary(BitStringRef<'a>),
+}
+
+impl ExternalEncoding<'_> {
+    /// Context-specific tag number of `single-ASN1-type`.
+    const SINGLE_ASN1_TYPE: TagNumber = TagNumber::N0;
+
+    /// Context-specific tag number of `octet-aligned`.
+    const OCTET_ALIGNED: TagNumber = TagNumber::N1;
+
+    /// Context-specific tag number of `arbitrary`.
+    const ARBITRARY: TagNumber = TagNumber::N2;
+}
+
+impl<'a> Choice<'a> for ExternalEncoding<'a> {
+    fn can_decode(tag: Tag) -> bool {
+        matches!(
+            tag,
+            Tag::ContextSpecific {
+                constructed: true,
+                number: Self::SINGLE_ASN1_TYPE,
+            } | Tag::ContextSpecific {
+                constructed: false,
+                number: Self::OCTET_ALIGNED | Self::ARBITRARY,
+            }
+        )
+    }
+}
+
+impl<'a> Decode<'a> for ExternalEncoding<'a> {
+    fn decode<R: Reader<'a>>(reader: &mut R) -> Result<Self> {
+        Header::decode_tlv(reader, |reader, header| match header.tag {
+            Tag::ContextSpecific {
+                constructed: true,
+                number: Self::SINGLE_ASN1_TYPE,
+            } => reader
+                .read_nested(header.length, |reader| reader.decode())
+                .map(Self::SingleAsn1Type),
+            Tag::ContextSpecific {
+                constructed: false,
+                number: Self::OCTET_ALIGNED,
+            } => OctetStringRef::decode_value(reader, header).map(Self::OctetAligned),
+            Tag::ContextSpecific {
+                constructed: false,
+                number: Self::ARBITRARY,
+            } => BitStringRef::decode_value(reader, header).map(Self::Arbitrary),
+            tag => Err(tag.unexpected_error(None)),
+        })
+    }
+}
+
+impl Encode for ExternalEncoding<'_> {
+    fn encoded_len(&self) -> Result<Length> {
+        match self {
+            Self::SingleAsn1Type(value) => explicit(Self::SINGLE_ASN1_TYPE, value).encoded_len(),
+            Self::OctetAligned(value) => implicit(Self::OCTET_ALIGNED, value).encoded_len(),
+            Self::Arbitrary(value) => implicit(Self::ARBITRARY, value).encoded_len(),
+        }
+    }
+
+    fn encode(&self, writer: &mut impl Writer) -> Result<()> {
+        match self {
+            Self::SingleAsn1Type(value) => explicit(Self::SINGLE_ASN1_TYPE, value).encode(writer),
+            Self::OctetAligned(value) => implicit(Self::OCTET_ALIGNED, value).encode(writer),
+            Self::Arbitrary(value) => implicit(Self::ARBITRARY, value).encode(writer),
+        }
+    }
+}
+
+impl Tagged for ExternalEncoding<'_> {
+    fn tag(&self) -> Tag {
+        match self {
+            Self::SingleAsn1Type(_) => Tag::ContextSpecific {
+                constructed: true,
+                number: Self::SINGLE_ASN1_TYPE,
+            },
+            Self::OctetAligned(_) => Tag::ContextSpecific {
+                constructed: false,
+                number: Self::OCTET_ALIGNED,
+            },
+            Self::Arbitrary(_) => Tag::ContextSpecific {
+                constructed: false,
+                number: Self::ARBITRARY,
+            },
+        }
+    }
+}
+
+/// Wrap `value` in an `EXPLICIT` context-specific tag.
+fn explicit<T>(tag_number: TagNumber, value: &T) -> ContextSpecificRef<'_, T> {
+    ContextSpecificRef {
+        tag_number,
+        tag_mode: TagMode::Explicit,
+        value,
+    }
+}
+
+/// Wrap `value` in an `IMPLICIT` context-specific tag.
+fn implicit<T>(tag_number: TagNumber, value: &T) -> ContextSpecificRef<'_, T> {
+    ContextSpecificRef {
+        tag_number,
+        tag_mode: TagMode::Implicit,
+        value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{External, ExternalEncoding};
+    use crate::{asn1::ObjectIdentifier, Decode, SliceWriter, Tag, Tagged};
+    use hex_literal::hex;
+
+    /// EXTERNAL {
+    ///   direct-reference 1.2.840.113549,
+    ///   data-value-descriptor "foo",
+    ///   encoding single-ASN1-type : UTF8String "hi" }
+    const SINGLE_ASN1_TYPE: &[u8] = &hex!("2813 06062a864886f70d 0703666f6f a004 0c026869");
+
+    /// EXTERNAL {
+    ///   indirect-reference 1,
+    ///   encoding octet-aligned : 010203 }
+    const OCTET_ALIGNED: &[u8] = &hex!("2808 020101 8103010203");
+
+    /// EXTERNAL {
+    ///   direct-reference 1.2.840.113549,
+    ///   encoding arbitrary : '11111111'B }
+    const ARBITRARY: &[u8] = &hex!("280c 06062a864886f70d 820200ff");
+
+    fn reencode(external: &External<'_>, expected: &[u8]) {
+        let mut buf = [0u8; 32];
+        let mut writer = SliceWriter::new(&mut buf);
+        writer.encode(external).unwrap();
+        assert_eq!(writer.finish().unwrap(), expected);
+    }
+
+    #[test]
+    fn decode_single_asn1_type() {
+        let external = External::from_der(SINGLE_ASN1_TYPE).unwrap();
+        assert_eq!(
+            external.direct_reference,
+            Some(ObjectIdentifier::new_unwrap("1.2.840.113549"))
+        );
+        assert!(external.indirect_reference.is_none());
+        assert_eq!(
+            external.data_value_descriptor.unwrap().as_str().unwrap(),
+            "foo"
+        );
+
+        match external.encoding {
+            ExternalEncoding::SingleAsn1Type(any) => {
+                assert_eq!(any.tag(), Tag::Utf8String);
+                assert_eq!(any.value(), b"hi");
+            }
+            other => panic!("unexpected encoding: {:?}", other),
+        }
+
+        reencode(&external, SINGLE_ASN1_TYPE);
+    }
+
+    #[test]
+    fn decode_octet_aligned() {
+        let external = External::from_der(OCTET_ALIGNED).unwrap();
+        assert!(external.direct_reference.is_none());
+        assert_eq!(external.indirect_reference.unwrap().as_bytes(), &[1]);
+        assert!(external.data_value_descriptor.is_none());
+
+        match external.encoding {
+            ExternalEncoding::OctetAligned(octets) => assert_eq!(octets.as_bytes(), &[1, 2, 3]),
+            other => panic!("unexpected encoding: {:?}", other),
+        }
+
+        reencode(&external, OCTET_ALIGNED);
+    }
+
+    #[test]
+    fn decode_arbitrary() {
+        let external = External::from_der(ARBITRARY).unwrap();
+
+        match external.encoding {
+            ExternalEncoding::Arbitrary(bits) => {
+                assert_eq!(bits.raw_bytes(), &[0xff]);
+                assert_eq!(bits.bit_len(), 8);
+            }
+            other => panic!("unexpected encoding: {:?}", other),
+        }
+
+        reencode(&external, ARBITRARY);
+    }
+
+    #[test]
+    fn reject_missing_encoding() {
+        assert!(External::from_der(&hex!("2803 020101")).is_err());
+    }
+}
@@ -0,0 +1,169 @@
+//! ASN.1 `GeneralString` support.
+
+use crate::{
+    asn1::AnyRef, ord::OrdIsValueOrd, BytesRef, DecodeValue, EncodeValue, FixedTag, Header, Length,
+    Reader, Result, Tag, Writer,
+};
+use core::{fmt, str};
+
+/// ASN.1 `GeneralString` type.
+///
+/// Stores the raw bytes of the string without validating them.
+///
+/// This is a zero-copy reference type which borrows from the input data.
+///
+/// # Supported characters
+///
+/// `GeneralString` can switch between any registered character set using
+/// ISO 2022 escape sequences, which this type doesn't interpret. In practice
+/// its contents are usually ASCII (e.g. `KerberosString` as defined in
+/// RFC 4120), so [`GeneralStringRef::as_str`] offers the contents as a `str`
+/// if they're valid UTF-8, which includes ASCII.
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct GeneralStringRef<'a> {
+    /// Inner value
+    inner: BytesRef<'a>,
+}
+
+impl<'a> GeneralStringRef<'a> {
+    /// Create a new ASN.1 `GeneralString`.
+    pub fn new<T>(input: &'a T) -> Result<Self>
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        BytesRef::new(input.as_ref())
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.length_error())
+    }
+
+    /// Borrow the raw bytes of this string.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_slice()
+    }
+
+    /// Borrow the contents of this string as a `str`.
+    ///
+    /// Returns an error if they aren't valid UTF-8.
+    pub fn as_str(&self) -> Result<&'a str> {
+        str::from_utf8(self.as_bytes()).map_err(|_| Self::TAG.value_error())
+    }
+
+    /// Are the contents of this string entirely ASCII?
+    pub fn is_ascii(&self) -> bool {
+        self.as_bytes().is_ascii()
+    }
+
+    /// Get the length of the encoded bytes.
+    pub fn len(&self) -> Length {
+        self.inner.len()
+    }
+
+    /// Is this string empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl_type!(GeneralStringRef<'a>, 'a);
+
+impl AsRef<[u8]> for GeneralStringRef<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for GeneralStringRef<'a> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        Self::new(BytesRef::decode_value(reader, header)?.as_slice())
+    }
+}
+
+impl EncodeValue for GeneralStringRef<'_> {
+    fn value_len(&self) -> Result<Length> {
+        self.inner.value_len()
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        self.inner.encode_value(writer)
+    }
+}
+
+impl FixedTag for GeneralStringRef<'_> {
+    const TAG: Tag = Tag::GeneralString;
+}
+
+impl OrdIsValueOrd for GeneralStringRef<'_> {}
+
+impl<'a> From<&GeneralStringRef<'a>> for GeneralStringRef<'a> {
+    fn from(value: &GeneralStringRef<'a>) -> GeneralStringRef<'a> {
+        *value
+    }
+}
+
+impl<'a> From<GeneralStringRef<'a>> for AnyRef<'a> {
+    fn from(general_string: GeneralStringRef<'a>) -> AnyRef<'a> {
+        AnyRef::from_tag_and_value(Tag::GeneralString, general_string.inner)
+    }
+}
+
+impl<'a> fmt::Debug for GeneralStringRef<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.as_str() {
+            Ok(s) => write!(f, "GeneralString({:?})", s),
+            Err(_) => write!(f, "GeneralString({:?})", self.as_bytes()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GeneralStringRef;
+    use crate::{asn1::AnyRef, Decode, SliceWriter, Tag, Tagged};
+
+    #[test]
+    fn parse_bytes() {
+        // KerberosString "EXAMPLE.COM"
+        let example_bytes = &[
+            0x1b, 0x0b, 0x45, 0x58, 0x41, 0x4d, 0x50, 0x4c, 0x45, 0x2e, 0x43, 0x4f, 0x4d,
+        ];
+
+        let general_string = GeneralStringRef::from_der(example_bytes).unwrap();
+        assert_eq!(general_string.as_str().unwrap(), "EXAMPLE.COM");
+        assert!(general_string.is_ascii());
+
+        let mut out = [0_u8; 30];
+        let mut writer = SliceWriter::new(&mut out);
+        writer.encode(&general_string).unwrap();
+        assert_eq!(writer.finish().unwrap(), example_bytes);
+    }
+
+    #[test]
+    fn preserve_non_utf8_bytes() {
+        let example_bytes = &[0x1b, 0x03, 0x41, 0xff, 0x42];
+
+        let general_string = GeneralStringRef::from_der(example_bytes).unwrap();
+        assert_eq!(general_string.as_bytes(), &[0x41, 0xff, 0x42]);
+        assert!(general_string.as_str().is_err());
+        assert!(!general_string.is_ascii());
+
+        let mut out = [0_u8; 5];
+        let mut writer = SliceWriter::new(&mut out);
+        writer.encode(&general_string).unwrap();
+        assert_eq!(writer.finish().unwrap(), example_bytes);
+    }
+
+    #[test]
+    fn decode_as_any() {
+        // SEQUENCE { GeneralString "krbtgt" }
+        let example_bytes = &[0x30, 0x08, 0x1b, 0x06, 0x6b, 0x72, 0x62, 0x74, 0x67, 0x74];
+
+        let any = AnyRef::from_der(&example_bytes[2..]).unwrap();
+        assert_eq!(any.tag(), Tag::GeneralString);
+
+        let general_string = GeneralStringRef::try_from(any).unwrap();
+        assert_eq!(general_string.as_str().unwrap(), "krbtgt");
+
+        let seq: [GeneralStringRef<'_>; 1] = Decode::from_der(example_bytes).unwrap();
+        assert_eq!(seq[0], general_string);
+    }
+}
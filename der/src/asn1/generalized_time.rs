@@ -15,7 +15,7 @@ use {
 };
 
 #[cfg(feature = "time")]
-use time::PrimitiveDateTime;
+use time::{OffsetDateTime, PrimitiveDateTime};
 
 /// ASN.1 `GeneralizedTime` type.
 ///
@@ -27,7 +27,19 @@ use time::PrimitiveDateTime;
 /// > (i.e., times are `YYYYMMDDHHMMSSZ`), even where the number of seconds
 /// > is zero.  GeneralizedTime values MUST NOT include fractional seconds.
 ///
+/// Fractional seconds in the canonical form described in
+/// [X.690 Section 11.7][2] are also accepted: a decimal fraction with no
+/// trailing zeros, omitted entirely when zero. They're retained with
+/// nanosecond precision as [`DateTime::nanoseconds`]. Non-canonical
+/// fractions, such as `.120Z` or `.Z`, are rejected.
+///
+/// Values with fractional seconds are encoded in the same canonical form,
+/// so they round trip, but aren't valid under RFC 5280. Values constructed
+/// from a [`Duration`] or [`SystemTime`] have any fractional seconds
+/// truncated, and so still comply with RFC 5280.
+///
 /// [1]: https://tools.ietf.org/html/rfc5280#section-4.1.2.5.2
+/// [2]: https://www.itu.int/rec/T-REC-X.690
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub struct GeneralizedTime(DateTime);
@@ -36,6 +48,13 @@ impl GeneralizedTime {
     /// Length of an RFC 5280-flavored ASN.1 DER-encoded [`GeneralizedTime`].
     const LENGTH: usize = 15;
 
+    /// Maximum number of digits of fractional seconds (i.e. nanoseconds).
+    const MAX_FRACTION_DIGITS: usize = 9;
+
+    /// Maximum length of an ASN.1 DER-encoded [`GeneralizedTime`] including
+    /// fractional seconds.
+    const MAX_LENGTH: usize = Self::LENGTH + 1 + Self::MAX_FRACTION_DIGITS;
+
     /// Create a [`GeneralizedTime`] from a [`DateTime`].
     pub fn from_date_time(datetime: DateTime) -> Self {
         Self(datetime)
@@ -80,16 +99,25 @@ impl_type!(GeneralizedTime);
 
 impl<'a> DecodeValue<'a> for GeneralizedTime {
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
-        if Self::LENGTH != usize::try_from(header.length)? {
+        let len = usize::try_from(header.length)?;
+
+        if !(Self::LENGTH..=Self::MAX_LENGTH).contains(&len) {
             return Err(Self::TAG.value_error());
         }
 
-        let mut bytes = [0u8; Self::LENGTH];
-        reader.read_into(&mut bytes)?;
+        let mut buf = [0u8; Self::MAX_LENGTH];
+        let bytes = reader.read_into(&mut buf[..len])?;
 
-        match bytes {
+        match *bytes {
             // RFC 5280 requires mandatory seconds and Z-normalized time zone
-            [y1, y2, y3, y4, mon1, mon2, day1, day2, hour1, hour2, min1, min2, sec1, sec2, b'Z'] => {
+            [y1, y2, y3, y4, mon1, mon2, day1, day2, hour1, hour2, min1, min2, sec1, sec2, ref rest @ ..] =>
+            {
+                let nanoseconds = match rest {
+                    [b'Z'] => 0,
+                    [b'.', fraction @ .., b'Z'] => decode_fraction(fraction)?,
+                    _ => return Err(Self::TAG.value_error()),
+                };
+
                 let year = u16::from(datetime::decode_decimal(Self::TAG, y1, y2)?)
                     .checked_mul(100)
                     .and_then(|y| {
@@ -103,8 +131,9 @@ impl<'a> DecodeValue<'a> for GeneralizedTime {
                 let second = datetime::decode_decimal(Self::TAG, sec1, sec2)?;
 
                 DateTime::new(year, month, day, hour, minute, second)
+                    .and_then(|dt| dt.with_nanoseconds(nanoseconds))
+                    .map(Into::into)
                     .map_err(|_| Self::TAG.value_error())
-                    .and_then(|dt| Self::from_unix_duration(dt.unix_duration()))
             }
             _ => Err(Self::TAG.value_error()),
         }
@@ -113,7 +142,10 @@ impl<'a> DecodeValue<'a> for GeneralizedTime {
 
 impl EncodeValue for GeneralizedTime {
     fn value_len(&self) -> Result<Length> {
-        Self::LENGTH.try_into()
+        match encode_fraction(self.0.nanoseconds()) {
+            (_, 0) => Self::LENGTH.try_into(),
+            (_, digits) => (Self::LENGTH + 1 + digits).try_into(),
+        }
     }
 
     fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
@@ -127,10 +159,68 @@ impl EncodeValue for GeneralizedTime {
         datetime::encode_decimal(writer, Self::TAG, self.0.hour())?;
         datetime::encode_decimal(writer, Self::TAG, self.0.minutes())?;
         datetime::encode_decimal(writer, Self::TAG, self.0.seconds())?;
+
+        let (fraction, digits) = encode_fraction(self.0.nanoseconds());
+
+        if digits != 0 {
+            writer.write_byte(b'.')?;
+            writer.write(&fraction[..digits])?;
+        }
+
         writer.write_byte(b'Z')
     }
 }
 
+/// Decode the decimal digits of a fraction of a second as nanoseconds.
+///
+/// X.690 Section 11.7.3 requires that the fraction have no trailing zeros.
+// Arithmetic can't overflow as there are at most 9 digits
+#[allow(clippy::integer_arithmetic)]
+fn decode_fraction(fraction: &[u8]) -> Result<u32> {
+    if fraction.is_empty()
+        || fraction.len() > GeneralizedTime::MAX_FRACTION_DIGITS
+        || fraction.last() == Some(&b'0')
+    {
+        return Err(GeneralizedTime::TAG.value_error());
+    }
+
+    let mut nanoseconds = 0u32;
+
+    for n in 0..GeneralizedTime::MAX_FRACTION_DIGITS {
+        let digit = match fraction.get(n) {
+            Some(&digit) if digit.is_ascii_digit() => digit - b'0',
+            Some(_) => return Err(GeneralizedTime::TAG.value_error()),
+            None => 0,
+        };
+
+        nanoseconds = nanoseconds * 10 + u32::from(digit);
+    }
+
+    Ok(nanoseconds)
+}
+
+/// Encode nanoseconds as the decimal digits of a fraction of a second,
+/// returning them along with the number of digits after trimming trailing
+/// zeros (which is zero if `nanoseconds` is zero).
+// Arithmetic can't overflow as `nanoseconds` is less than 10^9
+#[allow(clippy::integer_arithmetic, clippy::cast_possible_truncation)]
+fn encode_fraction(mut nanoseconds: u32) -> ([u8; GeneralizedTime::MAX_FRACTION_DIGITS], usize) {
+    let mut fraction = [b'0'; GeneralizedTime::MAX_FRACTION_DIGITS];
+    let mut digits = 0;
+
+    for (n, byte) in fraction.iter_mut().enumerate().rev() {
+        let digit = (nanoseconds % 10) as u8;
+        nanoseconds /= 10;
+        *byte = b'0' + digit;
+
+        if digits == 0 && digit != 0 {
+            digits = n + 1;
+        }
+    }
+
+    (fraction, digits)
+}
+
 impl FixedTag for GeneralizedTime {
     const TAG: Tag = Tag::GeneralizedTime;
 }
@@ -325,10 +415,41 @@ impl TryFrom<GeneralizedTime> for PrimitiveDateTime {
     }
 }
 
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<OffsetDateTime> for GeneralizedTime {
+    type Error = Error;
+
+    fn try_from(time: OffsetDateTime) -> Result<GeneralizedTime> {
+        Ok(GeneralizedTime::from_date_time(DateTime::try_from(time)?))
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<&OffsetDateTime> for GeneralizedTime {
+    type Error = Error;
+
+    fn try_from(time: &OffsetDateTime) -> Result<GeneralizedTime> {
+        Self::try_from(*time)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<GeneralizedTime> for OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(time: GeneralizedTime) -> Result<OffsetDateTime> {
+        time.to_date_time().try_into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::GeneralizedTime;
-    use crate::{Decode, Encode, SliceWriter};
+    use crate::{DateTime, Decode, Encode, ErrorKind, SliceWriter, Tag};
+    use core::time::Duration;
     use hex_literal::hex;
 
     #[test]
@@ -342,4 +463,101 @@ mod tests {
         utc_time.encode(&mut encoder).unwrap();
         assert_eq!(example_bytes, encoder.finish().unwrap());
     }
+
+    #[test]
+    fn round_trip_milliseconds() {
+        // 19910506234540.123Z
+        let example_bytes = hex!("18 13 31 39 39 31 30 35 30 36 32 33 34 35 34 30 2e 31 32 33 5a");
+        let time = GeneralizedTime::from_ber(&example_bytes).unwrap();
+        let mut buf = [0u8; 128];
+        assert_eq!(time.to_date_time().seconds(), 40);
+        assert_eq!(time.to_date_time().nanoseconds(), 123_000_000);
+        assert_eq!(
+            time.to_unix_duration(),
+            Duration::new(673573540, 123_000_000)
+        );
+        assert_eq!(time.encode_to_slice(&mut buf).unwrap(), example_bytes);
+    }
+
+    #[test]
+    fn round_trip_microseconds() {
+        // 19910506234540.000456Z
+        let example_bytes =
+            hex!("18 16 31 39 39 31 30 35 30 36 32 33 34 35 34 30 2e 30 30 30 34 35 36 5a");
+        let time = GeneralizedTime::from_ber(&example_bytes).unwrap();
+        let mut buf = [0u8; 128];
+        assert_eq!(time.to_date_time().nanoseconds(), 456_000);
+        assert_eq!(time.encode_to_slice(&mut buf).unwrap(), example_bytes);
+    }
+
+    #[test]
+    fn encode_fraction_canonical() {
+        let datetime = DateTime::new(1991, 5, 6, 23, 45, 40).unwrap();
+        let mut buf = [0u8; 128];
+
+        let time = GeneralizedTime::from(datetime.with_nanoseconds(500_000_000).unwrap());
+        assert_eq!(
+            time.encode_to_slice(&mut buf).unwrap(),
+            b"\x18\x1119910506234540.5Z"
+        );
+
+        let time = GeneralizedTime::from(datetime.with_nanoseconds(1).unwrap());
+        assert_eq!(
+            time.encode_to_slice(&mut buf).unwrap(),
+            b"\x18\x1919910506234540.000000001Z"
+        );
+    }
+
+    #[test]
+    fn round_trip_fraction_der() {
+        let datetime = DateTime::new(1991, 5, 6, 23, 45, 40).unwrap();
+        let time = GeneralizedTime::from(datetime.with_nanoseconds(123_000_000).unwrap());
+
+        let mut buf = [0u8; 128];
+        let der = time.encode_to_slice(&mut buf).unwrap();
+        assert_eq!(der, b"\x18\x1319910506234540.123Z");
+        assert_eq!(GeneralizedTime::from_der(der).unwrap(), time);
+    }
+
+    #[test]
+    fn reject_non_canonical_fraction() {
+        // Trailing zeros
+        let err = GeneralizedTime::from_der(b"\x18\x1319910506234540.120Z").unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Value {
+                tag: Tag::GeneralizedTime
+            }
+        );
+        assert!(GeneralizedTime::from_ber(b"\x18\x1319910506234540.120Z").is_err());
+        // Zero fraction
+        assert!(GeneralizedTime::from_der(b"\x18\x1119910506234540.0Z").is_err());
+        // Empty fraction
+        assert!(GeneralizedTime::from_der(b"\x18\x1019910506234540.Z").is_err());
+        // Comma as the decimal separator
+        assert!(GeneralizedTime::from_ber(b"\x18\x1119910506234540,5Z").is_err());
+        // Finer than nanosecond precision
+        assert!(GeneralizedTime::from_ber(b"\x18\x1a19910506234540.1234567891Z").is_err());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn round_trip_offset_date_time() {
+        use time::{OffsetDateTime, UtcOffset};
+
+        let example_bytes = hex!("18 0f 31 39 39 31 30 35 30 36 32 33 34 35 34 30 5a");
+        let time = OffsetDateTime::from_unix_timestamp(673573540)
+            .unwrap()
+            .to_offset(UtcOffset::from_hms(2, 0, 0).unwrap());
+
+        let mut buf = [0u8; 128];
+        let generalized_time = GeneralizedTime::try_from(time).unwrap();
+        assert_eq!(
+            generalized_time.encode_to_slice(&mut buf).unwrap(),
+            example_bytes
+        );
+
+        let decoded = GeneralizedTime::from_der(&example_bytes).unwrap();
+        assert_eq!(OffsetDateTime::try_from(decoded).unwrap(), time);
+    }
 }
@@ -0,0 +1,152 @@
+//! ASN.1 `GraphicString` support.
+
+use crate::{
+    asn1::AnyRef, ord::OrdIsValueOrd, BytesRef, DecodeValue, EncodeValue, FixedTag, Header, Length,
+    Reader, Result, Tag, Writer,
+};
+use core::{fmt, str};
+
+/// ASN.1 `GraphicString` type.
+///
+/// Stores the raw bytes of the string without validating them.
+///
+/// This is a zero-copy reference type which borrows from the input data.
+///
+/// # Supported characters
+///
+/// `GraphicString` can switch between the graphic characters of any
+/// registered character set using ISO 2022 escape sequences, which this type
+/// doesn't interpret. [`GraphicStringRef::as_str`] offers the contents as a
+/// `str` if they're valid UTF-8, which includes ASCII.
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct GraphicStringRef<'a> {
+    /// Inner value
+    inner: BytesRef<'a>,
+}
+
+impl<'a> GraphicStringRef<'a> {
+    /// Create a new ASN.1 `GraphicString`.
+    pub fn new<T>(input: &'a T) -> Result<Self>
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        BytesRef::new(input.as_ref())
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.length_error())
+    }
+
+    /// Borrow the raw bytes of this string.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_slice()
+    }
+
+    /// Borrow the contents of this string as a `str`.
+    ///
+    /// Returns an error if they aren't valid UTF-8.
+    pub fn as_str(&self) -> Result<&'a str> {
+        str::from_utf8(self.as_bytes()).map_err(|_| Self::TAG.value_error())
+    }
+
+    /// Are the contents of this string entirely ASCII?
+    pub fn is_ascii(&self) -> bool {
+        self.as_bytes().is_ascii()
+    }
+
+    /// Get the length of the encoded bytes.
+    pub fn len(&self) -> Length {
+        self.inner.len()
+    }
+
+    /// Is this string empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl_type!(GraphicStringRef<'a>, 'a);
+
+impl AsRef<[u8]> for GraphicStringRef<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for GraphicStringRef<'a> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        Self::new(BytesRef::decode_value(reader, header)?.as_slice())
+    }
+}
+
+impl EncodeValue for GraphicStringRef<'_> {
+    fn value_len(&self) -> Result<Length> {
+        self.inner.value_len()
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        self.inner.encode_value(writer)
+    }
+}
+
+impl FixedTag for GraphicStringRef<'_> {
+    const TAG: Tag = Tag::GraphicString;
+}
+
+impl OrdIsValueOrd for GraphicStringRef<'_> {}
+
+impl<'a> From<&GraphicStringRef<'a>> for GraphicStringRef<'a> {
+    fn from(value: &GraphicStringRef<'a>) -> GraphicStringRef<'a> {
+        *value
+    }
+}
+
+impl<'a> From<GraphicStringRef<'a>> for AnyRef<'a> {
+    fn from(graphic_string: GraphicStringRef<'a>) -> AnyRef<'a> {
+        AnyRef::from_tag_and_value(Tag::GraphicString, graphic_string.inner)
+    }
+}
+
+impl<'a> fmt::Debug for GraphicStringRef<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.as_str() {
+            Ok(s) => write!(f, "GraphicString({:?})", s),
+            Err(_) => write!(f, "GraphicString({:?})", self.as_bytes()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GraphicStringRef;
+    use crate::{Decode, SliceWriter};
+
+    #[test]
+    fn parse_bytes() {
+        let example_bytes = &[
+            0x19, 0x0b, 0x54, 0x65, 0x73, 0x74, 0x20, 0x55, 0x73, 0x65, 0x72, 0x20, 0x31,
+        ];
+
+        let graphic_string = GraphicStringRef::from_der(example_bytes).unwrap();
+        assert_eq!(graphic_string.as_str().unwrap(), "Test User 1");
+        assert!(graphic_string.is_ascii());
+
+        let mut out = [0_u8; 30];
+        let mut writer = SliceWriter::new(&mut out);
+        writer.encode(&graphic_string).unwrap();
+        assert_eq!(writer.finish().unwrap(), example_bytes);
+    }
+
+    #[test]
+    fn preserve_non_utf8_bytes() {
+        let example_bytes = &[0x19, 0x03, 0x41, 0xff, 0x42];
+
+        let graphic_string = GraphicStringRef::from_der(example_bytes).unwrap();
+        assert_eq!(graphic_string.as_bytes(), &[0x41, 0xff, 0x42]);
+        assert!(graphic_string.as_str().is_err());
+        assert!(!graphic_string.is_ascii());
+
+        let mut out = [0_u8; 5];
+        let mut writer = SliceWriter::new(&mut out);
+        writer.encode(&graphic_string).unwrap();
+        assert_eq!(writer.finish().unwrap(), example_bytes);
+    }
+}
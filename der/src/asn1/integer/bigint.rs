@@ -5,6 +5,7 @@ use crate::{
     ord::OrdIsValueOrd, BytesRef, DecodeValue, EncodeValue, ErrorKind, FixedTag, Header, Length,
     Reader, Result, Tag, Writer,
 };
+use core::cmp::Ordering;
 
 /// "Big" signed ASN.1 `INTEGER` type.
 ///
@@ -94,7 +95,10 @@ impl<'a> OrdIsValueOrd for IntRef<'a> {}
 ///
 /// Intended for use cases like very large integers that are used in
 /// cryptographic applications (e.g. keys, signatures).
-#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+///
+/// Values are ordered numerically, which is also the order of their DER
+/// encodings.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct UintRef<'a> {
     /// Inner value
     inner: BytesRef<'a>,
@@ -170,6 +174,18 @@ impl<'a> FixedTag for UintRef<'a> {
     const TAG: Tag = Tag::Integer;
 }
 
+impl<'a> Ord for UintRef<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        uint::value_cmp(self.as_bytes(), other.as_bytes())
+    }
+}
+
+impl<'a> PartialOrd for UintRef<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl<'a> OrdIsValueOrd for UintRef<'a> {}
 
 #[cfg(feature = "alloc")]
@@ -186,6 +202,7 @@ mod allocating {
         BytesOwned, DecodeValue, EncodeValue, ErrorKind, FixedTag, Header, Length, Reader, Result,
         Tag, Writer,
     };
+    use core::cmp::Ordering;
 
     /// "Big" signed ASN.1 `INTEGER` type.
     ///
@@ -310,7 +327,10 @@ mod allocating {
     ///
     /// Intended for use cases like very large integers that are used in
     /// cryptographic applications (e.g. keys, signatures).
-    #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+    ///
+    /// Values are ordered numerically, which is also the order of their DER
+    /// encodings.
+    #[derive(Clone, Debug, Eq, PartialEq)]
     pub struct Uint {
         /// Inner value
         inner: BytesOwned,
@@ -387,6 +407,18 @@ mod allocating {
         const TAG: Tag = Tag::Integer;
     }
 
+    impl Ord for Uint {
+        fn cmp(&self, other: &Self) -> Ordering {
+            uint::value_cmp(self.as_bytes(), other.as_bytes())
+        }
+    }
+
+    impl PartialOrd for Uint {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
     impl OrdIsValueOrd for Uint {}
 
     impl<'a> RefToOwned<'a> for UintRef<'a> {
@@ -408,13 +440,72 @@ mod allocating {
     }
 }
 
+#[cfg(feature = "bigint")]
+mod bigint_impl {
+    use super::UintRef;
+    use crate::{
+        bigint::{Encoding, Uint as BigUint},
+        Error, FixedTag, Result,
+    };
+
+    #[cfg(feature = "alloc")]
+    use super::Uint;
+
+    impl<'a, const LIMBS: usize> TryFrom<UintRef<'a>> for BigUint<LIMBS>
+    where
+        BigUint<LIMBS>: Encoding,
+    {
+        type Error = Error;
+
+        fn try_from(uint: UintRef<'a>) -> Result<BigUint<LIMBS>> {
+            let bytes = uint.as_bytes();
+            let mut repr = BigUint::<LIMBS>::ZERO.to_be_bytes();
+
+            // Compute number of leading zeroes to add
+            let num_zeroes = repr
+                .as_ref()
+                .len()
+                .checked_sub(bytes.len())
+                .ok_or_else(|| UintRef::TAG.length_error())?;
+
+            repr.as_mut()[num_zeroes..].copy_from_slice(bytes);
+            Ok(BigUint::from_be_bytes(repr))
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<const LIMBS: usize> TryFrom<&Uint> for BigUint<LIMBS>
+    where
+        BigUint<LIMBS>: Encoding,
+    {
+        type Error = Error;
+
+        fn try_from(uint: &Uint) -> Result<BigUint<LIMBS>> {
+            UintRef::new(uint.as_bytes())?.try_into()
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<const LIMBS: usize> TryFrom<&BigUint<LIMBS>> for Uint
+    where
+        BigUint<LIMBS>: Encoding,
+    {
+        type Error = Error;
+
+        fn try_from(uint: &BigUint<LIMBS>) -> Result<Uint> {
+            Uint::new(uint.to_be_bytes().as_ref())
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::UintRef;
     use crate::{
         asn1::{integer::tests::*, AnyRef, IntRef},
-        Decode, Encode, ErrorKind, SliceWriter, Tag,
+        Decode, DerOrd, Encode, ErrorKind, Length, SliceWriter, Tag,
     };
+    use core::cmp::Ordering;
 
     #[test]
     fn decode_int_bytes() {
@@ -526,4 +617,70 @@ mod tests {
 
         assert_eq!(err.kind(), ErrorKind::Value { tag: Tag::Integer });
     }
+
+    /// 2048-bit modulus: `0x00` followed by 256 bytes of `0xA5`
+    fn modulus_2048() -> [u8; 261] {
+        let mut der = [0xA5u8; 261];
+        der[..5].copy_from_slice(&[0x02, 0x82, 0x01, 0x01, 0x00]);
+        der
+    }
+
+    #[test]
+    fn round_trip_2048_bit_modulus() {
+        let der = modulus_2048();
+        let uint = UintRef::from_der(&der).unwrap();
+        assert_eq!(uint.as_bytes(), &[0xA5; 256]);
+        assert_eq!(uint.len(), Length::new(256));
+
+        let mut buf = [0u8; 512];
+        assert_eq!(uint.encode_to_slice(&mut buf).unwrap(), der);
+    }
+
+    #[test]
+    fn reject_negative_2048_bit_modulus() {
+        // Omitting the leading `0x00` makes the value negative
+        let mut der = modulus_2048();
+        der[1..5].copy_from_slice(&[0x82, 0x01, 0x00, 0xA5]);
+        let err = UintRef::from_der(&der[..260]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Value { tag: Tag::Integer });
+    }
+
+    #[test]
+    fn uint_numeric_ordering() {
+        let a = UintRef::from_der(I255_BYTES).unwrap();
+        let b = UintRef::from_der(I256_BYTES).unwrap();
+        let c = UintRef::from_der(I32767_BYTES).unwrap();
+        assert!(a < b && b < c);
+
+        let modulus = modulus_2048();
+        let modulus = UintRef::from_der(&modulus).unwrap();
+        assert!(c < modulus);
+        assert_eq!(modulus.cmp(&modulus), Ordering::Equal);
+        assert_eq!(a.der_cmp(&c).unwrap(), Ordering::Less);
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn crypto_bigint_conversions() {
+        use crate::bigint::{Encoding, U1024, U2048, U4096};
+
+        let modulus = modulus_2048();
+        let uint = UintRef::from_der(&modulus).unwrap();
+        let big = U2048::try_from(uint).unwrap();
+        assert_eq!(big.to_be_bytes(), [0xA5; 256]);
+        assert_eq!(
+            U4096::try_from(uint).unwrap().to_be_bytes()[256..],
+            [0xA5; 256]
+        );
+
+        let err = U1024::try_from(uint).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Length { tag: Tag::Integer });
+
+        #[cfg(feature = "alloc")]
+        {
+            let owned = super::Uint::try_from(&U4096::try_from(uint).unwrap()).unwrap();
+            assert_eq!(owned.as_bytes(), uint.as_bytes());
+            assert_eq!(U2048::try_from(&owned).unwrap(), big);
+        }
+    }
 }
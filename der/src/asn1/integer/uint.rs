@@ -1,6 +1,7 @@
 //! Unsigned integer decoders/encoders.
 
 use crate::{Length, Result, Tag, Writer};
+use core::cmp::Ordering;
 
 /// Decode an unsigned integer into a big endian byte slice with all leading
 /// zeroes removed.
@@ -73,6 +74,13 @@ pub(crate) fn strip_leading_zeroes(mut bytes: &[u8]) -> &[u8] {
     bytes
 }
 
+/// Compare the numeric values of two big endian unsigned integers.
+pub(crate) fn value_cmp(a: &[u8], b: &[u8]) -> Ordering {
+    let a = strip_leading_zeroes(a);
+    let b = strip_leading_zeroes(b);
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
 /// Does the given integer need a leading zero?
 fn needs_leading_zero(bytes: &[u8]) -> bool {
     matches!(bytes.first(), Some(byte) if *byte >= 0x80)
@@ -80,8 +88,9 @@ fn needs_leading_zero(bytes: &[u8]) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use super::decode_to_array;
+    use super::{decode_to_array, value_cmp};
     use crate::{ErrorKind, Tag};
+    use core::cmp::Ordering;
 
     #[test]
     fn decode_to_array_no_leading_zero() {
@@ -113,4 +122,12 @@ mod tests {
         let err = decode_to_array::<1>(&[1, 2, 3]).err().unwrap();
         assert_eq!(err.kind(), ErrorKind::Length { tag: Tag::Integer });
     }
+
+    #[test]
+    fn value_cmp_numeric() {
+        assert_eq!(value_cmp(&[0x80], &[0x7F, 0xFF]), Ordering::Less);
+        assert_eq!(value_cmp(&[0x01, 0x00], &[0xFF]), Ordering::Greater);
+        assert_eq!(value_cmp(&[0x00, 0x00, 0x01], &[0x01]), Ordering::Equal);
+        assert_eq!(value_cmp(&[0x01, 0x02], &[0x01, 0x03]), Ordering::Less);
+    }
 }
@@ -34,15 +34,27 @@ macro_rules! impl_type {
 
 macro_rules! impl_string_type {
     ($type: ty, $($li: lifetime)?) => {
+        impl_string_type!(@common $type, $($li),*);
+
+        mod __impl_string_decode {
+            use super::*;
+
+            use crate::{BytesRef, DecodeValue, Header, Reader, Result};
+
+            impl<'__der: $($li),*, $($li),*> DecodeValue<'__der> for $type {
+                fn decode_value<R: Reader<'__der>>(reader: &mut R, header: Header) -> Result<Self> {
+                    Self::new(BytesRef::decode_value(reader, header)?.as_slice())
+                }
+            }
+        }
+    };
+    (@common $type: ty, $($li: lifetime)?) => {
         impl_type!($type, $($li),*);
 
         mod __impl_string {
             use super::*;
 
-            use crate::{
-                ord::OrdIsValueOrd, BytesRef, DecodeValue, EncodeValue, Header, Length, Reader,
-                Result, Writer,
-            };
+            use crate::{ord::OrdIsValueOrd, EncodeValue, Length, Result, Writer};
             use core::{fmt, str};
 
             impl<$($li),*> AsRef<str> for $type {
@@ -57,12 +69,6 @@ macro_rules! impl_string_type {
                 }
             }
 
-            impl<'__der: $($li),*, $($li),*> DecodeValue<'__der> for $type {
-                fn decode_value<R: Reader<'__der>>(reader: &mut R, header: Header) -> Result<Self> {
-                    Self::new(BytesRef::decode_value(reader, header)?.as_slice())
-                }
-            }
-
             impl<$($li),*> EncodeValue for $type {
                 fn value_len(&self) -> Result<Length> {
                     self.inner.value_len()
@@ -83,3 +89,271 @@ macro_rules! impl_string_type {
         }
     };
 }
+
+/// Define an owned wrapper type for values tagged with a custom tag class
+/// (i.e. `APPLICATION`, `CONTEXT-SPECIFIC`, or `PRIVATE`).
+macro_rules! impl_custom_class {
+    ($class_type: ident, $class: ident, $asn1_class_name: literal, $class_bits: literal) => {
+        #[doc = concat!("`", $asn1_class_name, "` field which wraps an owned inner value.")]
+        ///
+        /// This type decodes/encodes a field which is identified by a
+        /// [`TagNumber`][crate::TagNumber] within its tag class.
+        #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+        pub struct $class_type<T> {
+            #[doc = concat!("`", $asn1_class_name, "` tag number sans the leading `", $class_bits, "`")]
+            /// class identifier bits and `0b100000` constructed flag.
+            pub tag_number: crate::TagNumber,
+
+            /// Tag mode: `EXPLICIT` VS `IMPLICIT`.
+            pub tag_mode: crate::TagMode,
+
+            /// Value of the field.
+            pub value: T,
+        }
+
+        impl<T> $class_type<T> {
+            #[doc = concat!("Attempt to decode an `EXPLICIT` ASN.1 `", $asn1_class_name, "` field with the")]
+            /// provided [`TagNumber`][crate::TagNumber].
+            ///
+            /// This method has the following behavior which is designed to simplify
+            /// handling of extension fields, which are denoted in an ASN.1 schema
+            /// using the `...` ellipsis extension marker:
+            ///
+            /// - Skips over fields of the same class with a tag number lower than
+            ///   the current one, consuming and ignoring them.
+            /// - Returns `Ok(None)` if a field of the same class with a higher tag
+            ///   number is encountered. These fields are not consumed in this case,
+            ///   allowing a field with a lower tag number to be omitted, then the
+            ///   higher numbered field consumed as a follow-up.
+            /// - Returns `Ok(None)` if anything other than a field of the same
+            ///   class is encountered.
+            pub fn decode_explicit<'a, R: crate::Reader<'a>>(
+                reader: &mut R,
+                tag_number: crate::TagNumber,
+            ) -> crate::Result<Option<Self>>
+            where
+                T: crate::Decode<'a>,
+            {
+                Self::decode_with(reader, tag_number, |reader| {
+                    <Self as crate::Decode<'a>>::decode(reader)
+                })
+            }
+
+            #[doc = concat!("Attempt to decode an `IMPLICIT` ASN.1 `", $asn1_class_name, "` field with the")]
+            /// provided [`TagNumber`][crate::TagNumber].
+            ///
+            /// This method otherwise behaves the same as `decode_explicit`,
+            /// but should be used in cases where the particular fields are `IMPLICIT`
+            /// as opposed to `EXPLICIT`.
+            pub fn decode_implicit<'a, R: crate::Reader<'a>>(
+                reader: &mut R,
+                tag_number: crate::TagNumber,
+            ) -> crate::Result<Option<Self>>
+            where
+                T: crate::DecodeValue<'a> + crate::Tagged,
+            {
+                Self::decode_with(reader, tag_number, |reader| {
+                    crate::Header::decode_tlv(reader, |reader, header| {
+                        let value = T::decode_value(reader, header)?;
+
+                        // BER permits `OCTET STRING`s to be encoded in constructed
+                        // form, in which case an `IMPLICIT` tag is also constructed
+                        let ber_constructed = reader.encoding_rules().is_ber()
+                            && header.tag.is_constructed()
+                            && value.tag() == crate::Tag::OctetString;
+
+                        if !ber_constructed
+                            && header.tag.is_constructed() != value.tag().is_constructed()
+                        {
+                            return Err(header.tag.non_canonical_error());
+                        }
+
+                        Ok(Self {
+                            tag_number,
+                            tag_mode: crate::TagMode::Implicit,
+                            value,
+                        })
+                    })
+                })
+            }
+
+            /// Attempt to decode a field of this class with the given
+            /// helper callback.
+            fn decode_with<'a, F, R: crate::Reader<'a>>(
+                reader: &mut R,
+                tag_number: crate::TagNumber,
+                f: F,
+            ) -> crate::Result<Option<Self>>
+            where
+                F: FnOnce(&mut R) -> crate::Result<Self>,
+            {
+                while let Some(octet) = reader.peek_byte() {
+                    let tag = crate::Tag::try_from(octet)?;
+
+                    if tag.class() != crate::Class::$class || (tag.number() > tag_number) {
+                        break;
+                    } else if tag.number() == tag_number {
+                        return Some(f(reader)).transpose();
+                    } else {
+                        <crate::asn1::AnyRef<'a> as crate::Decode<'a>>::decode(reader)?;
+                    }
+                }
+
+                Ok(None)
+            }
+        }
+
+        impl<'a, T> crate::Choice<'a> for $class_type<T>
+        where
+            T: crate::Decode<'a> + crate::Tagged,
+        {
+            fn can_decode(tag: crate::Tag) -> bool {
+                tag.class() == crate::Class::$class
+            }
+        }
+
+        impl<'a, T> crate::Decode<'a> for $class_type<T>
+        where
+            T: crate::Decode<'a>,
+        {
+            fn decode<R: crate::Reader<'a>>(reader: &mut R) -> crate::Result<Self> {
+                crate::Header::decode_tlv(reader, |reader, header| match header.tag {
+                    crate::Tag::$class {
+                        number,
+                        constructed: true,
+                    } => Ok(Self {
+                        tag_number: number,
+                        tag_mode: crate::TagMode::default(),
+                        value: reader.read_nested(header.length, |reader| T::decode(reader))?,
+                    }),
+                    tag => Err(tag.unexpected_error(None)),
+                })
+            }
+        }
+
+        impl<T> crate::EncodeValue for $class_type<T>
+        where
+            T: crate::EncodeValue + crate::Tagged,
+        {
+            fn value_len(&self) -> crate::Result<crate::Length> {
+                match self.tag_mode {
+                    crate::TagMode::Explicit => crate::Encode::encoded_len(&self.value),
+                    crate::TagMode::Implicit => self.value.value_len(),
+                }
+            }
+
+            fn encode_value(&self, writer: &mut impl crate::Writer) -> crate::Result<()> {
+                match self.tag_mode {
+                    crate::TagMode::Explicit => crate::Encode::encode(&self.value, writer),
+                    crate::TagMode::Implicit => self.value.encode_value(writer),
+                }
+            }
+        }
+
+        impl<T> crate::Tagged for $class_type<T>
+        where
+            T: crate::Tagged,
+        {
+            fn tag(&self) -> crate::Tag {
+                let constructed = match self.tag_mode {
+                    crate::TagMode::Explicit => true,
+                    crate::TagMode::Implicit => self.value.tag().is_constructed(),
+                };
+
+                crate::Tag::$class {
+                    number: self.tag_number,
+                    constructed,
+                }
+            }
+        }
+
+        impl<'a, T> TryFrom<crate::asn1::AnyRef<'a>> for $class_type<T>
+        where
+            T: crate::Decode<'a>,
+        {
+            type Error = crate::Error;
+
+            fn try_from(any: crate::asn1::AnyRef<'a>) -> crate::Result<$class_type<T>> {
+                match crate::Tagged::tag(&any) {
+                    crate::Tag::$class {
+                        number,
+                        constructed: true,
+                    } => Ok(Self {
+                        tag_number: number,
+                        tag_mode: crate::TagMode::default(),
+                        value: T::from_der(any.value())?,
+                    }),
+                    tag => Err(tag.unexpected_error(None)),
+                }
+            }
+        }
+
+        impl<T> crate::ValueOrd for $class_type<T>
+        where
+            T: crate::EncodeValue + crate::ValueOrd + crate::Tagged,
+        {
+            fn value_cmp(&self, other: &Self) -> crate::Result<core::cmp::Ordering> {
+                match self.tag_mode {
+                    crate::TagMode::Explicit => crate::DerOrd::der_cmp(self, other),
+                    crate::TagMode::Implicit => crate::ValueOrd::value_cmp(&self.value, &other.value),
+                }
+            }
+        }
+    };
+}
+
+/// Define an encode-only reference wrapper type for values tagged with a
+/// custom tag class, converting to the given owned wrapper type.
+macro_rules! impl_custom_class_ref {
+    ($ref_class_type: ident, $class_type: ident, $asn1_class_name: literal, $class_bits: literal) => {
+        #[doc = concat!("`", $asn1_class_name, "` field reference.")]
+        ///
+        /// This type encodes a field which is identified by a [`TagNumber`][crate::TagNumber]
+        /// within its tag class.
+        #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+        pub struct $ref_class_type<'a, T> {
+            #[doc = concat!("`", $asn1_class_name, "` tag number sans the leading `", $class_bits, "`")]
+            /// class identifier bits and `0b100000` constructed flag.
+            pub tag_number: crate::TagNumber,
+
+            /// Tag mode: `EXPLICIT` VS `IMPLICIT`.
+            pub tag_mode: crate::TagMode,
+
+            /// Value of the field.
+            pub value: &'a T,
+        }
+
+        impl<'a, T> $ref_class_type<'a, T> {
+            #[doc = concat!("Convert to a [`", stringify!($class_type), "`].")]
+            fn encoder(&self) -> $class_type<crate::EncodeValueRef<'a, T>> {
+                $class_type {
+                    tag_number: self.tag_number,
+                    tag_mode: self.tag_mode,
+                    value: crate::EncodeValueRef(self.value),
+                }
+            }
+        }
+
+        impl<'a, T> crate::EncodeValue for $ref_class_type<'a, T>
+        where
+            T: crate::EncodeValue + crate::Tagged,
+        {
+            fn value_len(&self) -> crate::Result<crate::Length> {
+                crate::EncodeValue::value_len(&self.encoder())
+            }
+
+            fn encode_value(&self, writer: &mut impl crate::Writer) -> crate::Result<()> {
+                crate::EncodeValue::encode_value(&self.encoder(), writer)
+            }
+        }
+
+        impl<'a, T> crate::Tagged for $ref_class_type<'a, T>
+        where
+            T: crate::Tagged,
+        {
+            fn tag(&self) -> crate::Tag {
+                crate::Tagged::tag(&self.encoder())
+            }
+        }
+    };
+}
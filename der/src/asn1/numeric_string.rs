@@ -0,0 +1,103 @@
+//! ASN.1 `NumericString` support.
+
+use crate::{asn1::AnyRef, Error, FixedTag, Result, StrRef, Tag};
+use core::{fmt, ops::Deref};
+
+/// ASN.1 `NumericString` type.
+///
+/// Supports the digits `0..9` and the space character, as specified in
+/// X.680 Section 41.2.
+///
+/// This is a zero-copy reference type which borrows from the input data.
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct NumericStringRef<'a> {
+    /// Inner value
+    inner: StrRef<'a>,
+}
+
+impl<'a> NumericStringRef<'a> {
+    /// Create a new ASN.1 `NumericString`.
+    pub fn new<T>(input: &'a T) -> Result<Self>
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        let input = input.as_ref();
+
+        // Validate all characters are within NumericString's allowed set
+        if input.iter().any(|&c| !matches!(c, b'0'..=b'9' | b' ')) {
+            return Err(Self::TAG.value_error());
+        }
+
+        StrRef::from_bytes(input)
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.value_error())
+    }
+}
+
+impl_string_type!(NumericStringRef<'a>, 'a);
+
+impl FixedTag for NumericStringRef<'_> {
+    const TAG: Tag = Tag::NumericString;
+}
+
+impl fmt::Debug for NumericStringRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "NumericString({:?})", self.as_str())
+    }
+}
+
+impl<'a> Deref for NumericStringRef<'a> {
+    type Target = StrRef<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<'a> From<&NumericStringRef<'a>> for NumericStringRef<'a> {
+    fn from(value: &NumericStringRef<'a>) -> NumericStringRef<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<&'a str> for NumericStringRef<'a> {
+    type Error = Error;
+
+    fn try_from(s: &'a str) -> Result<NumericStringRef<'a>> {
+        Self::new(s)
+    }
+}
+
+impl<'a> From<NumericStringRef<'a>> for AnyRef<'a> {
+    fn from(numeric_string: NumericStringRef<'a>) -> AnyRef<'a> {
+        AnyRef::from_tag_and_value(Tag::NumericString, numeric_string.inner.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NumericStringRef;
+    use crate::{Decode, ErrorKind, Tag};
+
+    #[test]
+    fn parse_bytes() {
+        let example_bytes = b"\x12\x08123 4567";
+        let numeric_string = NumericStringRef::from_der(example_bytes).unwrap();
+        assert_eq!(numeric_string.as_str(), "123 4567");
+    }
+
+    #[test]
+    fn reject_letters() {
+        let err = NumericStringRef::from_der(b"\x12\x0412a4").unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Value {
+                tag: Tag::NumericString
+            }
+        );
+
+        assert!(NumericStringRef::try_from("12a4").is_err());
+        assert!(NumericStringRef::new("12-4").is_err());
+        assert!(NumericStringRef::try_from("12 4").is_ok());
+    }
+}
@@ -0,0 +1,302 @@
+//! ASN.1 `ObjectDescriptor` support.
+
+use crate::{
+    asn1::AnyRef, ord::OrdIsValueOrd, BytesRef, DecodeValue, EncodeValue, FixedTag, Header, Length,
+    Reader, Result, Tag, Writer,
+};
+use core::{fmt, str};
+
+/// ASN.1 `ObjectDescriptor` type: borrowed form.
+///
+/// `ObjectDescriptor` is a human-readable description of an object, defined
+/// as `[UNIVERSAL 7] IMPLICIT GraphicString`. The contents are stored as raw
+/// bytes. See [`GraphicStringRef`][`crate::asn1::GraphicStringRef`] for how
+/// they're interpreted.
+///
+/// This is a zero-copy reference type which borrows from the input data.
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct ObjectDescriptorRef<'a> {
+    /// Inner value
+    inner: BytesRef<'a>,
+}
+
+impl<'a> ObjectDescriptorRef<'a> {
+    /// Create a new ASN.1 `ObjectDescriptor`.
+    pub fn new<T>(input: &'a T) -> Result<Self>
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        BytesRef::new(input.as_ref())
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.length_error())
+    }
+
+    /// Borrow the raw bytes of this descriptor.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_slice()
+    }
+
+    /// Borrow the contents of this descriptor as a `str`.
+    ///
+    /// Returns an error if they aren't valid UTF-8.
+    pub fn as_str(&self) -> Result<&'a str> {
+        str::from_utf8(self.as_bytes()).map_err(|_| Self::TAG.value_error())
+    }
+
+    /// Get the length of the encoded bytes.
+    pub fn len(&self) -> Length {
+        self.inner.len()
+    }
+
+    /// Is this descriptor empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl_type!(ObjectDescriptorRef<'a>, 'a);
+
+impl AsRef<[u8]> for ObjectDescriptorRef<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for ObjectDescriptorRef<'a> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        Self::new(BytesRef::decode_value(reader, header)?.as_slice())
+    }
+}
+
+impl EncodeValue for ObjectDescriptorRef<'_> {
+    fn value_len(&self) -> Result<Length> {
+        self.inner.value_len()
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        self.inner.encode_value(writer)
+    }
+}
+
+impl FixedTag for ObjectDescriptorRef<'_> {
+    const TAG: Tag = Tag::ObjectDescriptor;
+}
+
+impl OrdIsValueOrd for ObjectDescriptorRef<'_> {}
+
+/// Writes the descriptor as UTF-8, replacing each non-ASCII byte with
+/// `U+FFFD` if the contents aren't valid UTF-8.
+impl fmt::Display for ObjectDescriptorRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display_lossy(self.as_bytes(), f)
+    }
+}
+
+impl fmt::Debug for ObjectDescriptorRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ObjectDescriptor(\"{}\")", self)
+    }
+}
+
+impl<'a> From<&ObjectDescriptorRef<'a>> for ObjectDescriptorRef<'a> {
+    fn from(value: &ObjectDescriptorRef<'a>) -> ObjectDescriptorRef<'a> {
+        *value
+    }
+}
+
+impl<'a> From<ObjectDescriptorRef<'a>> for AnyRef<'a> {
+    fn from(object_descriptor: ObjectDescriptorRef<'a>) -> AnyRef<'a> {
+        AnyRef::from_tag_and_value(Tag::ObjectDescriptor, object_descriptor.inner)
+    }
+}
+
+/// Write `bytes` as UTF-8, falling back to replacing non-ASCII bytes with
+/// `U+FFFD` if they aren't valid UTF-8.
+fn display_lossy(bytes: &[u8], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if let Ok(s) = str::from_utf8(bytes) {
+        return f.write_str(s);
+    }
+
+    for &b in bytes {
+        let c = if b.is_ascii() {
+            char::from(b)
+        } else {
+            char::REPLACEMENT_CHARACTER
+        };
+        fmt::Write::write_char(f, c)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "alloc")]
+pub use self::allocation::ObjectDescriptor;
+
+#[cfg(feature = "alloc")]
+mod allocation {
+    use super::{display_lossy, ObjectDescriptorRef};
+    use crate::{
+        asn1::AnyRef,
+        ord::OrdIsValueOrd,
+        referenced::{OwnedToRef, RefToOwned},
+        BytesOwned, BytesRef, DecodeValue, EncodeValue, FixedTag, Header, Length, Reader, Result,
+        Tag, Writer,
+    };
+    use core::{fmt, str};
+
+    /// ASN.1 `ObjectDescriptor` type: owned form.
+    ///
+    /// See [`ObjectDescriptorRef`] for more information.
+    #[derive(Clone, Eq, PartialEq, PartialOrd, Ord)]
+    pub struct ObjectDescriptor {
+        /// Inner value
+        inner: BytesOwned,
+    }
+
+    impl ObjectDescriptor {
+        /// Create a new ASN.1 `ObjectDescriptor`.
+        pub fn new<T>(input: &T) -> Result<Self>
+        where
+            T: AsRef<[u8]> + ?Sized,
+        {
+            BytesOwned::new(input.as_ref())
+                .map(|inner| Self { inner })
+                .map_err(|_| Self::TAG.length_error())
+        }
+
+        /// Borrow the raw bytes of this descriptor.
+        pub fn as_bytes(&self) -> &[u8] {
+            self.inner.as_slice()
+        }
+
+        /// Borrow the contents of this descriptor as a `str`.
+        ///
+        /// Returns an error if they aren't valid UTF-8.
+        pub fn as_str(&self) -> Result<&str> {
+            str::from_utf8(self.as_bytes()).map_err(|_| Self::TAG.value_error())
+        }
+
+        /// Get the length of the encoded bytes.
+        pub fn len(&self) -> Length {
+            self.inner.len()
+        }
+
+        /// Is this descriptor empty?
+        pub fn is_empty(&self) -> bool {
+            self.inner.is_empty()
+        }
+    }
+
+    impl_type!(ObjectDescriptor);
+
+    impl AsRef<[u8]> for ObjectDescriptor {
+        fn as_ref(&self) -> &[u8] {
+            self.as_bytes()
+        }
+    }
+
+    impl<'a> DecodeValue<'a> for ObjectDescriptor {
+        fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+            Self::new(BytesRef::decode_value(reader, header)?.as_slice())
+        }
+    }
+
+    impl EncodeValue for ObjectDescriptor {
+        fn value_len(&self) -> Result<Length> {
+            self.inner.value_len()
+        }
+
+        fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+            self.inner.encode_value(writer)
+        }
+    }
+
+    impl FixedTag for ObjectDescriptor {
+        const TAG: Tag = Tag::ObjectDescriptor;
+    }
+
+    impl OrdIsValueOrd for ObjectDescriptor {}
+
+    /// Writes the descriptor as UTF-8, replacing each non-ASCII byte with
+    /// `U+FFFD` if the contents aren't valid UTF-8.
+    impl fmt::Display for ObjectDescriptor {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            display_lossy(self.as_bytes(), f)
+        }
+    }
+
+    impl fmt::Debug for ObjectDescriptor {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "ObjectDescriptor(\"{}\")", self)
+        }
+    }
+
+    impl<'a> From<&'a ObjectDescriptor> for AnyRef<'a> {
+        fn from(object_descriptor: &'a ObjectDescriptor) -> AnyRef<'a> {
+            object_descriptor.owned_to_ref().into()
+        }
+    }
+
+    impl<'a> From<ObjectDescriptorRef<'a>> for ObjectDescriptor {
+        fn from(object_descriptor: ObjectDescriptorRef<'a>) -> ObjectDescriptor {
+            object_descriptor.ref_to_owned()
+        }
+    }
+
+    impl<'a> RefToOwned<'a> for ObjectDescriptorRef<'a> {
+        type Owned = ObjectDescriptor;
+        fn ref_to_owned(&self) -> Self::Owned {
+            ObjectDescriptor {
+                inner: self.inner.ref_to_owned(),
+            }
+        }
+    }
+
+    impl OwnedToRef for ObjectDescriptor {
+        type Borrowed<'a> = ObjectDescriptorRef<'a>;
+        fn owned_to_ref(&self) -> Self::Borrowed<'_> {
+            ObjectDescriptorRef {
+                inner: self.inner.owned_to_ref(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ObjectDescriptorRef;
+    use crate::{Decode, SliceWriter};
+
+    #[test]
+    fn decode_and_reencode() {
+        let example_bytes = &[
+            0x07, 0x0e, 0x45, 0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65, 0x20, 0x6f, 0x62, 0x6a, 0x65,
+            0x63, 0x74,
+        ];
+
+        let object_descriptor = ObjectDescriptorRef::from_der(example_bytes).unwrap();
+        assert_eq!(object_descriptor.as_str().unwrap(), "Example object");
+        assert_eq!(object_descriptor.as_bytes(), &example_bytes[2..]);
+
+        let mut out = [0_u8; 16];
+        let mut writer = SliceWriter::new(&mut out);
+        writer.encode(&object_descriptor).unwrap();
+        assert_eq!(writer.finish().unwrap(), example_bytes);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_string() {
+        use super::ObjectDescriptor;
+        use crate::Encode;
+        use alloc::string::ToString;
+
+        let der = &[0x07, 0x05, 0x52, 0x53, 0x41, 0xff, 0x31];
+        let object_descriptor = ObjectDescriptor::from_der(der).unwrap();
+        assert_eq!(object_descriptor.to_string(), "RSA\u{fffd}1");
+        assert_eq!(object_descriptor.to_vec().unwrap(), der);
+
+        let object_descriptor = ObjectDescriptorRef::new("RSA encryption").unwrap();
+        assert_eq!(object_descriptor.to_string(), "RSA encryption");
+    }
+}
@@ -55,6 +55,11 @@ impl AsRef<[u8]> for OctetStringRef<'_> {
 
 impl<'a> DecodeValue<'a> for OctetStringRef<'a> {
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        // BER constructed `OCTET STRING`s can only be decoded as `OctetString`
+        if reader.encoding_rules().is_ber() && header.is_constructed() {
+            return Err(header.tag.value_error());
+        }
+
         let inner = BytesRef::decode_value(reader, header)?;
         Ok(Self { inner })
     }
@@ -146,6 +151,31 @@ mod allocating {
         pub fn is_empty(&self) -> bool {
             self.inner.is_empty()
         }
+
+        /// Decode the value of a BER constructed `OCTET STRING`, concatenating
+        /// its segments into a single logical value.
+        ///
+        /// Segments must be encoded in primitive form.
+        fn decode_constructed<'a, R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+            reader.read_nested(header.length, |reader| {
+                let mut inner = Vec::new();
+
+                while !reader.is_finished() {
+                    Header::decode_tlv(reader, |reader, segment| {
+                        segment.tag.assert_eq(Tag::OctetString)?;
+
+                        if segment.is_constructed() {
+                            return Err(segment.tag.value_error());
+                        }
+
+                        inner.append(&mut reader.read_vec(segment.length)?);
+                        Ok(())
+                    })?;
+                }
+
+                Self::new(inner)
+            })
+        }
     }
 
     impl_type!(OctetString);
@@ -158,6 +188,10 @@ mod allocating {
 
     impl<'a> DecodeValue<'a> for OctetString {
         fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+            if reader.encoding_rules().is_ber() && header.is_constructed() {
+                return Self::decode_constructed(reader, header);
+            }
+
             Self::new(reader.read_vec(header.length)?)
         }
     }
@@ -218,6 +252,10 @@ mod allocating {
 #[cfg(test)]
 mod tests {
     use crate::asn1::{OctetStringRef, PrintableStringRef};
+    #[cfg(feature = "alloc")]
+    use crate::{asn1::OctetString, Decode, Encode};
+    #[cfg(feature = "alloc")]
+    use hex_literal::hex;
 
     #[test]
     fn octet_string_decode_into() {
@@ -228,4 +266,32 @@ mod tests {
         let res = oct.decode_into::<PrintableStringRef<'_>>().unwrap();
         assert_eq!(AsRef::<str>::as_ref(&res), "hi");
     }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn decode_ber_constructed() {
+        // Constructed `OCTET STRING` with two primitive segments
+        let ber = hex!("2408 0402DEAD 0402BEEF");
+        let octet_string = OctetString::from_ber(&ber).unwrap();
+        assert_eq!(octet_string.as_bytes(), hex!("DEADBEEF"));
+
+        // Re-encoding canonicalizes to a primitive `OCTET STRING`
+        assert_eq!(octet_string.to_vec().unwrap(), hex!("0404DEADBEEF"));
+
+        // Indefinite length
+        let ber = hex!("2480 0402DEAD 0402BEEF 0000");
+        let octet_string = OctetString::from_ber(&ber).unwrap();
+        assert_eq!(octet_string.as_bytes(), hex!("DEADBEEF"));
+
+        // Not permitted in DER, nor when borrowing
+        assert!(OctetString::from_der(&ber).is_err());
+        assert!(OctetStringRef::from_ber(&ber).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn reject_nested_ber_constructed() {
+        let ber = hex!("240A 2408 0402DEAD 0402BEEF");
+        assert!(OctetString::from_ber(&ber).is_err());
+    }
 }
@@ -1,19 +1,57 @@
 //! ASN.1 `PrintableString` support.
 
-use crate::{asn1::AnyRef, FixedTag, Result, StrRef, Tag};
+use crate::{asn1::AnyRef, BytesRef, DecodeValue, FixedTag, Header, Reader, Result, StrRef, Tag};
 use core::{fmt, ops::Deref};
 
+/// Characters which aren't permitted in a `PrintableString`, but are
+/// accepted when decoding leniently.
+const LENIENT_CHARS: &[u8] = b"&*@_";
+
+/// Is the given character within `PrintableString`'s allowed set?
+fn is_printable_char(c: u8) -> bool {
+    matches!(
+        c,
+        b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b' '
+            | b'\''
+            | b'('
+            | b')'
+            | b'+'
+            | b','
+            | b'-'
+            | b'.'
+            | b'/'
+            | b':'
+            | b'='
+            | b'?'
+    )
+}
+
 macro_rules! impl_printable_string {
     ($type: ty) => {
         impl_printable_string!($type,);
     };
     ($type: ty, $($li: lifetime)?) => {
-        impl_string_type!($type, $($li),*);
+        impl_string_type!(@common $type, $($li),*);
 
         impl<$($li),*> FixedTag for $type {
             const TAG: Tag = Tag::PrintableString;
         }
 
+        impl<'__der: $($li),*, $($li),*> DecodeValue<'__der> for $type {
+            fn decode_value<R: Reader<'__der>>(reader: &mut R, header: Header) -> Result<Self> {
+                let bytes = BytesRef::decode_value(reader, header)?;
+
+                if reader.encoding_rules().is_ber() {
+                    Self::new_lenient(bytes.as_slice())
+                } else {
+                    Self::new(bytes.as_slice())
+                }
+            }
+        }
+
         impl<$($li),*> fmt::Debug for $type {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 write!(f, "PrintableString({:?})", self.as_str())
@@ -63,28 +101,50 @@ impl<'a> PrintableStringRef<'a> {
     where
         T: AsRef<[u8]> + ?Sized,
     {
-        let input = input.as_ref();
-
-        // Validate all characters are within PrintableString's allowed set
-        for &c in input.iter() {
-            match c {
-                b'A'..=b'Z'
-                | b'a'..=b'z'
-                | b'0'..=b'9'
-                | b' '
-                | b'\''
-                | b'('
-                | b')'
-                | b'+'
-                | b','
-                | b'-'
-                | b'.'
-                | b'/'
-                | b':'
-                | b'='
-                | b'?' => (),
-                _ => return Err(Self::TAG.value_error()),
-            }
+        Self::new_with_charset(input.as_ref(), is_printable_char)
+    }
+
+    /// Create a new ASN.1 `PrintableString`, leniently accepting the `&`,
+    /// `*`, `@` and `_` characters in addition to the supported characters.
+    ///
+    /// These characters aren't permitted in a `PrintableString`, but are
+    /// found in the wild in certificates issued by noncompliant CAs. Use
+    /// [`PrintableStringRef::is_compliant`] to detect them.
+    pub fn new_lenient<T>(input: &'a T) -> Result<Self>
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        Self::new_with_charset(input.as_ref(), |c| {
+            is_printable_char(c) || LENIENT_CHARS.contains(&c)
+        })
+    }
+
+    /// Decode a [`PrintableStringRef`], leniently accepting the `&`, `*`,
+    /// `@` and `_` characters.
+    ///
+    /// Decoding via [`Decode`][`crate::Decode`] is only lenient when using
+    /// [`EncodingRules::Ber`][`crate::EncodingRules::Ber`]. See
+    /// [`PrintableStringRef::new_lenient`] for more information.
+    pub fn decode_lenient<R: Reader<'a>>(reader: &mut R) -> Result<Self> {
+        Header::decode_tlv(reader, |reader, header| {
+            header.tag.assert_eq(Self::TAG)?;
+            Self::new_lenient(BytesRef::decode_value(reader, header)?.as_slice())
+        })
+    }
+
+    /// Does this string contain only characters which are permitted in a
+    /// `PrintableString`?
+    ///
+    /// This is always `true` unless the string was created leniently.
+    pub fn is_compliant(&self) -> bool {
+        self.as_bytes().iter().all(|&c| is_printable_char(c))
+    }
+
+    /// Create a new ASN.1 `PrintableString`, validating all characters are
+    /// within the given set.
+    fn new_with_charset(input: &'a [u8], is_allowed: impl Fn(u8) -> bool) -> Result<Self> {
+        if !input.iter().all(|&c| is_allowed(c)) {
+            return Err(Self::TAG.value_error());
         }
 
         StrRef::from_bytes(input)
@@ -124,7 +184,7 @@ mod allocation {
     use crate::{
         asn1::AnyRef,
         referenced::{OwnedToRef, RefToOwned},
-        BytesRef, FixedTag, Result, StrOwned, Tag,
+        BytesRef, DecodeValue, FixedTag, Header, Reader, Result, StrOwned, Tag,
     };
     use core::{fmt, ops::Deref};
 
@@ -167,12 +227,27 @@ mod allocation {
         where
             T: AsRef<[u8]> + ?Sized,
         {
-            let input = input.as_ref();
-            PrintableStringRef::new(input)?;
+            PrintableStringRef::new(input).map(Into::into)
+        }
+
+        /// Create a new ASN.1 `PrintableString`, leniently accepting the `&`,
+        /// `*`, `@` and `_` characters in addition to the supported
+        /// characters.
+        ///
+        /// See [`PrintableStringRef::new_lenient`] for more information.
+        pub fn new_lenient<T>(input: &T) -> Result<Self>
+        where
+            T: AsRef<[u8]> + ?Sized,
+        {
+            PrintableStringRef::new_lenient(input).map(Into::into)
+        }
 
-            StrOwned::from_bytes(input)
-                .map(|inner| Self { inner })
-                .map_err(|_| Self::TAG.value_error())
+        /// Does this string contain only characters which are permitted in a
+        /// `PrintableString`?
+        ///
+        /// This is always `true` unless the string was created leniently.
+        pub fn is_compliant(&self) -> bool {
+            self.owned_to_ref().is_compliant()
         }
     }
 
@@ -225,7 +300,7 @@ mod allocation {
 #[cfg(test)]
 mod tests {
     use super::PrintableStringRef;
-    use crate::Decode;
+    use crate::{Decode, SliceReader};
 
     #[test]
     fn parse_bytes() {
@@ -236,4 +311,50 @@ mod tests {
         let printable_string = PrintableStringRef::from_der(example_bytes).unwrap();
         assert_eq!(printable_string.as_str(), "Test User 1");
     }
+
+    #[test]
+    fn decode_lenient() {
+        let example_bytes = b"\x13\x0aTest*User@";
+        assert!(PrintableStringRef::from_der(example_bytes).is_err());
+
+        let mut reader = SliceReader::new(example_bytes).unwrap();
+        let printable_string = PrintableStringRef::decode_lenient(&mut reader).unwrap();
+        assert_eq!(printable_string.as_str(), "Test*User@");
+        assert!(!printable_string.is_compliant());
+
+        // Other invalid characters are still rejected
+        assert!(PrintableStringRef::new_lenient("Test!User").is_err());
+        assert!(PrintableStringRef::new_lenient("Test User")
+            .unwrap()
+            .is_compliant());
+    }
+
+    #[test]
+    fn decode_ber_lenient() {
+        let example_bytes = b"\x13\x0bTest&User_@";
+        assert!(PrintableStringRef::from_der(example_bytes).is_err());
+
+        let printable_string = PrintableStringRef::from_ber(example_bytes).unwrap();
+        assert_eq!(printable_string.as_str(), "Test&User_@");
+        assert!(!printable_string.is_compliant());
+
+        // Other invalid characters are still rejected
+        assert!(PrintableStringRef::from_ber(b"\x13\x09Test!User").is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn decode_ber_lenient_owned() {
+        use super::PrintableString;
+
+        let example_bytes = b"\x13\x0bTest&User_@";
+        assert!(PrintableString::from_der(example_bytes).is_err());
+
+        let printable_string = PrintableString::from_ber(example_bytes).unwrap();
+        assert_eq!(printable_string.as_str(), "Test&User_@");
+        assert!(!printable_string.is_compliant());
+        assert!(PrintableString::new_lenient("Test User")
+            .unwrap()
+            .is_compliant());
+    }
 }
@@ -0,0 +1,37 @@
+//! Private class field.
+
+impl_custom_class!(Private, Private, "PRIVATE", "0b11000000");
+impl_custom_class_ref!(PrivateRef, Private, "PRIVATE", "0b11000000");
+
+#[cfg(test)]
+mod tests {
+    use super::Private;
+    use crate::{Decode, Encode, SliceReader, TagMode, TagNumber, Tagged};
+    use hex_literal::hex;
+
+    #[test]
+    fn round_trip_explicit() {
+        let bytes = hex!("E103020105");
+        let field = Private::<u8>::from_der(&bytes).unwrap();
+        assert_eq!(field.tag_number, TagNumber::N1);
+        assert_eq!(field.value, 5);
+        assert_eq!(field.tag().octet(), 0xE1);
+
+        let mut buf = [0u8; 16];
+        assert_eq!(field.encode_to_slice(&mut buf).unwrap(), bytes);
+    }
+
+    #[test]
+    fn round_trip_implicit() {
+        let bytes = hex!("C10105");
+        let mut reader = SliceReader::new(&bytes).unwrap();
+        let field = Private::<u8>::decode_implicit(&mut reader, TagNumber::N1)
+            .unwrap()
+            .unwrap();
+        assert_eq!(field.tag_mode, TagMode::Implicit);
+        assert_eq!(field.value, 5);
+
+        let mut buf = [0u8; 16];
+        assert_eq!(field.encode_to_slice(&mut buf).unwrap(), bytes);
+    }
+}
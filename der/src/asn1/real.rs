@@ -1,4 +1,16 @@
 //! ASN.1 `REAL` support.
+//!
+//! `REAL` values are represented as [`f64`]. Finite non-zero values are always
+//! encoded using the binary form with base 2 (X.690 § 8.5.7) and decode back
+//! to the exact same `f64`, so encoding never rounds. Subnormal values are
+//! encoded with an exponent of -1074 and their raw mantissa. The special values
+//! `PLUS-INFINITY`, `MINUS-INFINITY`, `NOT-A-NUMBER` and minus zero are
+//! encoded as described in X.690 § 8.5.9.
+//!
+//! When decoding, values using the decimal encoding (X.690 § 8.5.8) are
+//! rounded to the nearest `f64`, as done by [`str::parse`]. Binary encodings
+//! with a base other than 2, or an exponent which doesn't fit in an `f64`, are
+//! rejected.
 
 // TODO(tarcieri): checked arithmetic
 #![allow(
@@ -87,14 +99,11 @@ impl<'a> DecodeValue<'a> for f64 {
 #[cfg_attr(docsrs, doc(cfg(feature = "real")))]
 impl EncodeValue for f64 {
     fn value_len(&self) -> Result<Length> {
-        if self.is_sign_positive() && (*self) < f64::MIN_POSITIVE {
-            // Zero: positive yet smaller than the minimum positive number
+        if self.is_sign_positive() && *self == 0.0 {
+            // Zero
             Ok(Length::ZERO)
-        } else if self.is_nan()
-            || self.is_infinite()
-            || (self.is_sign_negative() && -self < f64::MIN_POSITIVE)
-        {
-            // NaN, infinite (positive or negative), or negative zero (negative but its negative is less than the min positive number)
+        } else if self.is_nan() || self.is_infinite() || *self == 0.0 {
+            // NaN, infinite (positive or negative), or negative zero
             Ok(Length::ONE)
         } else {
             // The length is that of the first octets plus those needed for the exponent plus those needed for the mantissa
@@ -124,12 +133,8 @@ impl EncodeValue for f64 {
         // Check if special value
         // Encode zero first, if it's zero
         // Special value from section 8.5.9 if non zero
-        if self.is_nan()
-            || self.is_infinite()
-            || (self.is_sign_negative() && -self < f64::MIN_POSITIVE)
-            || (self.is_sign_positive() && (*self) < f64::MIN_POSITIVE)
-        {
-            if self.is_sign_positive() && (*self) < f64::MIN_POSITIVE {
+        if self.is_nan() || self.is_infinite() || *self == 0.0 {
+            if self.is_sign_positive() && *self == 0.0 {
                 // Zero
                 return Ok(());
             } else if self.is_nan() {
@@ -219,13 +224,24 @@ pub(crate) fn mnth_bits_to_u8<const M: usize, const N: usize>(bytes: &[u8]) -> u
     (bit_m as u8) << 1 | bit_n as u8
 }
 
+/// Exponent used to encode subnormal values, whose value is their raw
+/// mantissa multiplied by 2^-1074.
+const SUBNORMAL_EXPONENT: i16 = -1074;
+
 /// Decode an f64 as its sign, exponent, and mantissa in u64 and in that order, using bit shifts and masks.
 /// Note: this function **removes** the 1023 bias from the exponent and adds the implicit 1
+///
+/// Subnormal values are instead decoded as [`SUBNORMAL_EXPONENT`] and their raw mantissa.
 #[allow(clippy::cast_possible_truncation)]
 pub(crate) fn decode_f64(f: f64) -> (u64, u64, u64) {
     let bits = f.to_bits();
     let sign = bits >> 63;
     let exponent = bits >> 52 & 0x7ff;
+    if f.is_subnormal() {
+        let ebytes = SUBNORMAL_EXPONENT.to_be_bytes();
+        let exponent = u64::from_be_bytes([0x0, 0x0, 0x0, 0x0, 0x0, 0x0, ebytes[0], ebytes[1]]);
+        return (sign, exponent, bits & 0xfffffffffffff);
+    }
     let exponent_bytes_no_bias = (exponent as i16 - 1023).to_be_bytes();
     let exponent_no_bias = u64::from_be_bytes([
         0x0,
@@ -243,9 +259,13 @@ pub(crate) fn decode_f64(f: f64) -> (u64, u64, u64) {
 
 /// Encode an f64 from its sign, exponent (**without** the 1023 bias), and (mantissa - 1) using bit shifts as received by ASN1
 pub(crate) fn encode_f64(sign: u64, exponent: u64, mantissa: u64) -> f64 {
+    let exponent = i16::from_be_bytes([exponent.to_be_bytes()[6], exponent.to_be_bytes()[7]]);
+    if exponent == SUBNORMAL_EXPONENT {
+        return f64::from_bits(sign << 63 | mantissa);
+    }
+
     // Add the bias to the exponent
-    let exponent_with_bias =
-        (i16::from_be_bytes([exponent.to_be_bytes()[6], exponent.to_be_bytes()[7]]) + 1023) as u64;
+    let exponent_with_bias = (exponent + 1023) as u64;
     let bits = sign << 63 | exponent_with_bias << 52 | (mantissa - 1);
     f64::from_bits(bits)
 }
@@ -801,6 +821,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn roundtrip_zero_negative_and_special_values() {
+        for val in [
+            0.0,
+            -0.0,
+            -1.0,
+            -0.5,
+            -3.25,
+            -f64::MIN_POSITIVE,
+            f64::MIN,
+            f64::from_bits(1),
+            -f64::from_bits(1),
+            f64::from_bits(0x8_0000_0000_0000),
+            f64::MIN_POSITIVE - f64::from_bits(1),
+            -(f64::MIN_POSITIVE - f64::from_bits(1)),
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ] {
+            let mut buffer = [0u8; 16];
+            let encoded = val.encode_to_slice(&mut buffer).unwrap();
+            let decoded = f64::from_der(encoded).unwrap();
+            assert_eq!(
+                val.to_bits(),
+                decoded.to_bits(),
+                "wanted: {}\tgot: {}",
+                val,
+                decoded
+            );
+        }
+
+        // Subnormal values are encoded exactly as their raw mantissa times 2^-1074
+        let mut buffer = [0u8; 6];
+        let encoded = f64::from_bits(1).encode_to_slice(&mut buffer).unwrap();
+        assert_eq!(encoded, [0x09, 0x04, 0b1000_0001, 0xFB, 0xCE, 0x01]);
+
+        let mut buffer = [0u8; 3];
+        let encoded = f64::NAN.encode_to_slice(&mut buffer).unwrap();
+        assert_eq!(encoded, [0x09, 0x01, 0b0100_0010]);
+        assert!(f64::from_der(encoded).unwrap().is_nan());
+    }
+
     #[test]
     fn reject_non_canonical() {
         assert!(f64::from_der(&[0x09, 0x81, 0x00]).is_err());
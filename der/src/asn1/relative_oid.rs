@@ -0,0 +1,318 @@
+//! ASN.1 `RELATIVE-OID` support.
+
+use crate::{
+    asn1::AnyRef, ord::OrdIsValueOrd, BytesRef, DecodeValue, EncodeValue, Error, FixedTag, Header,
+    Length, Reader, Result, Tag, Writer,
+};
+use core::{fmt, str::FromStr};
+
+/// Shift amounts for each of the (up to) five 7-bit groups of a `u32` arc,
+/// most significant first.
+const ARC_SHIFTS: [u32; 5] = [28, 21, 14, 7, 0];
+
+/// ASN.1 `RELATIVE-OID` type.
+///
+/// A relative object identifier is a sequence of arcs which identifies an
+/// object relative to some known [`ObjectIdentifier`][`crate::asn1::ObjectIdentifier`].
+/// Unlike an `OBJECT IDENTIFIER`, the first two arcs aren't packed into a
+/// single subidentifier: every arc is encoded on its own in base 128.
+///
+/// Values are stored inline in a buffer of [`RelativeOid::MAX_SIZE`] bytes.
+#[derive(Copy, Clone, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct RelativeOid {
+    /// BER/DER-serialized arcs, sans the ASN.1 tag/length.
+    bytes: [u8; Self::MAX_SIZE],
+
+    /// Length of the serialized arcs in `bytes`.
+    length: u8,
+}
+
+impl RelativeOid {
+    /// Maximum size of a BER/DER-encoded relative OID in bytes.
+    pub const MAX_SIZE: usize = 39;
+
+    /// Parse a [`RelativeOid`] from its BER/DER encoding.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.is_empty() || bytes.len() > Self::MAX_SIZE {
+            return Err(Self::TAG.length_error());
+        }
+
+        let mut remaining = bytes;
+        while !remaining.is_empty() {
+            remaining = decode_arc(remaining)?.1;
+        }
+
+        let mut result = Self {
+            bytes: [0u8; Self::MAX_SIZE],
+            length: 0,
+        };
+        result.bytes[..bytes.len()].copy_from_slice(bytes);
+        result.length = u8::try_from(bytes.len()).map_err(|_| Self::TAG.length_error())?;
+        Ok(result)
+    }
+
+    /// Create a [`RelativeOid`] from a sequence of arcs.
+    pub fn from_arcs(arcs: impl IntoIterator<Item = u32>) -> Result<Self> {
+        let mut result = Self {
+            bytes: [0u8; Self::MAX_SIZE],
+            length: 0,
+        };
+
+        for arc in arcs {
+            result.push_arc(arc)?;
+        }
+
+        if result.length == 0 {
+            return Err(Self::TAG.length_error());
+        }
+
+        Ok(result)
+    }
+
+    /// Get the BER/DER serialization of this relative OID as bytes.
+    ///
+    /// Note that this encoding omits the tag/length, and only contains the
+    /// value portion of the encoded relative OID.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..usize::from(self.length)]
+    }
+
+    /// Iterate over the arcs of this relative OID.
+    ///
+    /// Collect into a `Vec<u32>` to obtain all of the arcs at once.
+    pub fn arcs(&self) -> RelativeOidArcs<'_> {
+        RelativeOidArcs {
+            remaining: self.as_bytes(),
+        }
+    }
+
+    /// Get the number of arcs in this relative OID.
+    ///
+    /// Relative OIDs always contain at least one arc.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.arcs().count()
+    }
+
+    /// Append the base 128 encoding of `arc` to the buffer.
+    fn push_arc(&mut self, arc: u32) -> Result<()> {
+        let mut started = false;
+
+        for shift in ARC_SHIFTS {
+            let group = (arc >> shift) & 0x7F;
+
+            if group == 0 && !started && shift != 0 {
+                continue;
+            }
+
+            started = true;
+            let byte = group as u8 | if shift != 0 { 0x80 } else { 0 };
+            let pos = usize::from(self.length);
+
+            *self
+                .bytes
+                .get_mut(pos)
+                .ok_or_else(|| Self::TAG.length_error())? = byte;
+
+            self.length = self
+                .length
+                .checked_add(1)
+                .ok_or_else(|| Self::TAG.length_error())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl_type!(RelativeOid);
+
+impl AsRef<[u8]> for RelativeOid {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for RelativeOid {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        Self::from_bytes(BytesRef::decode_value(reader, header)?.as_slice())
+    }
+}
+
+impl EncodeValue for RelativeOid {
+    fn value_len(&self) -> Result<Length> {
+        Length::try_from(self.as_bytes().len())
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        writer.write(self.as_bytes())
+    }
+}
+
+impl FixedTag for RelativeOid {
+    const TAG: Tag = Tag::RelativeOid;
+}
+
+impl OrdIsValueOrd for RelativeOid {}
+
+impl<'a> From<&'a RelativeOid> for AnyRef<'a> {
+    fn from(relative_oid: &'a RelativeOid) -> AnyRef<'a> {
+        // `RelativeOid::MAX_SIZE` is always a valid `Length`
+        let value = relative_oid
+            .as_bytes()
+            .try_into()
+            .expect("relative OID length invariant violated");
+
+        AnyRef::from_tag_and_value(Tag::RelativeOid, value)
+    }
+}
+
+impl FromStr for RelativeOid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut arcs = [0u32; Self::MAX_SIZE];
+        let mut len = 0usize;
+
+        for arc in s.split('.') {
+            let slot = arcs.get_mut(len).ok_or_else(|| Self::TAG.length_error())?;
+            *slot = arc.parse().map_err(|_| Self::TAG.value_error())?;
+            len = len.checked_add(1).ok_or_else(|| Self::TAG.length_error())?;
+        }
+
+        Self::from_arcs(arcs[..len].iter().copied())
+    }
+}
+
+impl fmt::Display for RelativeOid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, arc) in self.arcs().enumerate() {
+            if i != 0 {
+                f.write_str(".")?;
+            }
+
+            write!(f, "{}", arc)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for RelativeOid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RelativeOid({})", self)
+    }
+}
+
+/// Iterator over the arcs of a [`RelativeOid`].
+#[derive(Clone, Debug)]
+pub struct RelativeOidArcs<'a> {
+    /// Encoded arcs which haven't been returned yet.
+    remaining: &'a [u8],
+}
+
+impl Iterator for RelativeOidArcs<'_> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        // Arcs are validated when the `RelativeOid` is constructed
+        let (arc, remaining) = decode_arc(self.remaining).ok()?;
+        self.remaining = remaining;
+        Some(arc)
+    }
+}
+
+/// Decode the base 128 arc at the start of `bytes`, returning it along with
+/// the remaining bytes.
+fn decode_arc(bytes: &[u8]) -> Result<(u32, &[u8])> {
+    // Leading `0x80` bytes are non-minimal
+    if bytes.first() == Some(&0x80) {
+        return Err(Tag::RelativeOid.value_error());
+    }
+
+    let mut arc = 0u32;
+    let mut remaining = bytes;
+
+    while let Some((&byte, rest)) = remaining.split_first() {
+        arc = arc
+            .checked_mul(0x80)
+            .and_then(|arc| arc.checked_add(u32::from(byte & 0x7F)))
+            .ok_or_else(|| Tag::RelativeOid.value_error())?;
+
+        remaining = rest;
+
+        if byte & 0x80 == 0 {
+            return Ok((arc, remaining));
+        }
+    }
+
+    // Truncated arc which is missing its final byte
+    Err(Tag::RelativeOid.value_error())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RelativeOid;
+    use crate::{Decode, Encode, SliceWriter, Tag};
+    use hex_literal::hex;
+
+    /// RELATIVE-OID 8571.3.2
+    const EXAMPLE_BYTES: &[u8] = &hex!("0d04c27b0302");
+
+    #[test]
+    fn decode_and_reencode() {
+        let relative_oid = RelativeOid::from_der(EXAMPLE_BYTES).unwrap();
+        assert_eq!(relative_oid.as_bytes(), &EXAMPLE_BYTES[2..]);
+        assert!(relative_oid.arcs().eq([8571, 3, 2]));
+        assert_eq!(relative_oid.len(), 3);
+
+        let mut buf = [0u8; 6];
+        let mut writer = SliceWriter::new(&mut buf);
+        relative_oid.encode(&mut writer).unwrap();
+        assert_eq!(writer.finish().unwrap(), EXAMPLE_BYTES);
+    }
+
+    #[test]
+    fn from_arcs() {
+        let relative_oid = RelativeOid::from_arcs([8571, 3, 2]).unwrap();
+        assert_eq!(relative_oid, RelativeOid::from_der(EXAMPLE_BYTES).unwrap());
+
+        let relative_oid = RelativeOid::from_arcs([0, 127, 128, u32::MAX]).unwrap();
+        assert_eq!(relative_oid.as_bytes(), &hex!("00 7f 8100 8fffffff7f"));
+        assert!(relative_oid.arcs().eq([0, 127, 128, u32::MAX]));
+
+        assert!(RelativeOid::from_arcs([]).is_err());
+    }
+
+    #[test]
+    fn from_str() {
+        let relative_oid = "8571.3.2".parse::<RelativeOid>().unwrap();
+        assert_eq!(relative_oid.as_bytes(), &EXAMPLE_BYTES[2..]);
+
+        assert!("".parse::<RelativeOid>().is_err());
+        assert!("1..2".parse::<RelativeOid>().is_err());
+        assert!("1.x".parse::<RelativeOid>().is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn display_and_collect_arcs() {
+        use alloc::{string::ToString, vec::Vec};
+
+        let relative_oid = RelativeOid::from_der(EXAMPLE_BYTES).unwrap();
+        assert_eq!(relative_oid.to_string(), "8571.3.2");
+        assert_eq!(relative_oid.arcs().collect::<Vec<_>>(), [8571, 3, 2]);
+    }
+
+    #[test]
+    fn reject_malformed() {
+        // non-minimal leading 0x80
+        assert!(RelativeOid::from_bytes(&hex!("807f")).is_err());
+        // truncated arc
+        assert!(RelativeOid::from_bytes(&hex!("0281")).is_err());
+        // arc overflows u32
+        assert!(RelativeOid::from_bytes(&hex!("9080808000")).is_err());
+        // empty
+        let err = RelativeOid::from_der(&hex!("0d00")).unwrap_err();
+        assert_eq!(err.kind(), Tag::RelativeOid.length_error().kind());
+    }
+}
@@ -1,8 +1,8 @@
 //! ASN.1 `SEQUENCE OF` support.
 
 use crate::{
-    arrayvec, ord::iter_cmp, ArrayVec, Decode, DecodeValue, DerOrd, Encode, EncodeValue, FixedTag,
-    Header, Length, Reader, Result, Tag, ValueOrd, Writer,
+    arrayvec, ord::iter_cmp, ArrayVec, Decode, DecodeValue, DerOrd, Encode, EncodeValue, ErrorKind,
+    FixedTag, Header, Length, Reader, Result, Tag, ValueOrd, Writer,
 };
 use core::cmp::Ordering;
 
@@ -28,6 +28,45 @@ impl<T, const N: usize> SequenceOf<T, N> {
         }
     }
 
+    /// Decode a [`SequenceOf`] containing at most `max_len` elements.
+    ///
+    /// Returns an [`ErrorKind::Overlength`] error as soon as more than
+    /// `max_len` elements are encountered.
+    pub fn decode_bounded<'a, R: Reader<'a>>(reader: &mut R, max_len: usize) -> Result<Self>
+    where
+        T: Decode<'a>,
+    {
+        Header::decode_tlv(reader, |reader, header| {
+            header.tag.assert_eq(Tag::Sequence)?;
+            Self::decode_value_bounded(reader, header, max_len)
+        })
+    }
+
+    /// Decode the value of a [`SequenceOf`] containing at most `max_len`
+    /// elements.
+    fn decode_value_bounded<'a, R: Reader<'a>>(
+        reader: &mut R,
+        header: Header,
+        max_len: usize,
+    ) -> Result<Self>
+    where
+        T: Decode<'a>,
+    {
+        reader.read_nested(header.length, |reader| {
+            let mut sequence_of = Self::new();
+
+            while !reader.is_finished() {
+                if sequence_of.len() >= max_len {
+                    return Err(reader.error(ErrorKind::Overlength));
+                }
+
+                sequence_of.add(T::decode(reader)?)?;
+            }
+
+            Ok(sequence_of)
+        })
+    }
+
     /// Add an element to this [`SequenceOf`].
     pub fn add(&mut self, element: T) -> Result<()> {
         self.inner.add(element)
@@ -67,15 +106,7 @@ where
     T: Decode<'a>,
 {
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
-        reader.read_nested(header.length, |reader| {
-            let mut sequence_of = Self::new();
-
-            while !reader.is_finished() {
-                sequence_of.add(T::decode(reader)?)?;
-            }
-
-            Ok(sequence_of)
-        })
+        Self::decode_value_bounded(reader, header, N)
     }
 }
 
@@ -2,17 +2,20 @@
 //!
 //! # Ordering Notes
 //!
-//! Some DER serializer implementations fail to properly sort elements of a
-//! `SET OF`. This is technically non-canonical, but occurs frequently
-//! enough that most DER decoders tolerate it. Unfortunately because
-//! of that, we must also follow suit.
+//! DER requires the elements of a `SET OF` to be sorted by their encodings.
+//! When decoding DER, the types in this module return an
+//! [`ErrorKind::SetOrdering`] error if the elements are out of order or if
+//! the set contains duplicates.
 //!
-//! However, all types in this module sort elements of a set at decode-time,
-//! ensuring they'll be in the proper order if reserialized.
+//! Some DER serializer implementations fail to properly sort elements of a
+//! `SET OF`. Such inputs can be parsed leniently using the Basic Encoding
+//! Rules (e.g. with [`Decode::from_ber`]), in which case elements are sorted
+//! at decode-time, ensuring they'll be in the proper order if reserialized.
 
 use crate::{
-    arrayvec, ord::iter_cmp, ArrayVec, Decode, DecodeValue, DerOrd, Encode, EncodeValue, Error,
-    ErrorKind, FixedTag, Header, Length, Reader, Result, Tag, ValueOrd, Writer,
+    arrayvec, ord::iter_cmp, ArrayVec, Decode, DecodeValue, DerOrd, Encode, EncodeValue,
+    EncodingRules, Error, ErrorKind, FixedTag, Header, Length, Reader, Result, Tag, ValueOrd,
+    Writer,
 };
 use core::cmp::Ordering;
 
@@ -103,8 +106,7 @@ where
                 result.inner.add(T::decode(reader)?)?;
             }
 
-            der_sort(result.inner.as_mut())?;
-            validate(result.inner.as_ref())?;
+            decode_sort(result.inner.as_mut(), reader.encoding_rules())?;
             Ok(result)
         })
     }
@@ -217,6 +219,47 @@ where
         }
     }
 
+    /// Decode a [`SetOfVec`] containing at most `max_len` elements.
+    ///
+    /// Returns an [`ErrorKind::Overlength`] error as soon as more than
+    /// `max_len` elements are encountered, which bounds the memory used when
+    /// decoding untrusted input.
+    pub fn decode_bounded<'a, R: Reader<'a>>(reader: &mut R, max_len: usize) -> Result<Self>
+    where
+        T: Decode<'a>,
+    {
+        Header::decode_tlv(reader, |reader, header| {
+            header.tag.assert_eq(Tag::Set)?;
+            Self::decode_value_bounded(reader, header, max_len)
+        })
+    }
+
+    /// Decode the value of a [`SetOfVec`] containing at most `max_len`
+    /// elements.
+    fn decode_value_bounded<'a, R: Reader<'a>>(
+        reader: &mut R,
+        header: Header,
+        max_len: usize,
+    ) -> Result<Self>
+    where
+        T: Decode<'a>,
+    {
+        reader.read_nested(header.length, |reader| {
+            let mut inner = Vec::new();
+
+            while !reader.is_finished() {
+                if inner.len() >= max_len {
+                    return Err(reader.error(ErrorKind::Overlength));
+                }
+
+                inner.push(T::decode(reader)?);
+            }
+
+            decode_sort(inner.as_mut(), reader.encoding_rules())?;
+            Ok(Self { inner })
+        })
+    }
+
     /// Add an element to this [`SetOfVec`].
     ///
     /// Items MUST be added in lexicographical order according to the
@@ -282,17 +325,7 @@ where
     T: Decode<'a> + DerOrd,
 {
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
-        reader.read_nested(header.length, |reader| {
-            let mut inner = Vec::new();
-
-            while !reader.is_finished() {
-                inner.push(T::decode(reader)?);
-            }
-
-            der_sort(inner.as_mut())?;
-            validate(inner.as_ref())?;
-            Ok(Self { inner })
-        })
+        Self::decode_value_bounded(reader, header, usize::MAX)
     }
 }
 
@@ -418,6 +451,18 @@ fn der_sort<T: DerOrd>(slice: &mut [T]) -> Result<()> {
     Ok(())
 }
 
+/// Ensure the decoded elements of a `SET OF` are in canonical order.
+///
+/// DER inputs must already be sorted, whereas BER inputs are sorted in place.
+/// In either case, duplicate elements are rejected.
+fn decode_sort<T: DerOrd>(slice: &mut [T], encoding_rules: EncodingRules) -> Result<()> {
+    if encoding_rules.is_ber() {
+        der_sort(slice)?;
+    }
+
+    validate(slice)
+}
+
 /// Validate the elements of a `SET OF`, ensuring that they are all in order
 /// and that there are no duplicates.
 fn validate<T: DerOrd>(slice: &[T]) -> Result<()> {
@@ -439,11 +484,49 @@ fn validate<T: DerOrd>(slice: &[T]) -> Result<()> {
     Ok(())
 }
 
-#[cfg(all(test, feature = "alloc"))]
+#[cfg(test)]
 mod tests {
-    use super::{SetOf, SetOfVec};
-    use alloc::vec::Vec;
+    use super::SetOf;
+    use crate::{Decode, Encode, ErrorKind, SliceWriter};
+    use hex_literal::hex;
+
+    #[cfg(feature = "alloc")]
+    use {super::SetOfVec, alloc::vec::Vec};
+
+    /// SET OF INTEGER { 1, 2, 3 }
+    const EXAMPLE_SET: &[u8] = &hex!("3109020101020102020103");
+
+    #[test]
+    fn setof_decode_encode() {
+        let set = SetOf::<u8, 3>::from_der(EXAMPLE_SET).unwrap();
+        assert_eq!(set.len(), 3);
+        assert!(set.iter().copied().eq([1, 2, 3]));
+
+        let mut buf = [0u8; 16];
+        let mut writer = SliceWriter::new(&mut buf);
+        set.encode(&mut writer).unwrap();
+        assert_eq!(writer.finish().unwrap(), EXAMPLE_SET);
+    }
+
+    #[test]
+    fn setof_capacity_exceeded() {
+        let err = SetOf::<u8, 2>::from_der(EXAMPLE_SET).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Overlength);
+    }
 
+    #[test]
+    fn setof_ordering() {
+        // SET OF INTEGER { 2, 1 }
+        let err = SetOf::<u8, 2>::from_der(&hex!("3106020102020101")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SetOrdering);
+
+        let mut set = SetOf::<u8, 2>::new();
+        set.add(2).unwrap();
+        assert_eq!(set.add(1).unwrap_err().kind(), ErrorKind::SetOrdering);
+        assert_eq!(set.add(2).unwrap_err().kind(), ErrorKind::SetOrdering);
+    }
+
+    #[cfg(feature = "alloc")]
     #[test]
     fn setof_tryfrom_array() {
         let arr = [3u16, 2, 1, 65535, 0];
@@ -454,6 +537,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn setofvec_tryfrom_array() {
         let arr = [3u16, 2, 1, 65535, 0];
@@ -1,22 +1,64 @@
 //! ASN.1 `TeletexString` support.
 //!
-use crate::{asn1::AnyRef, FixedTag, Result, StrRef, Tag};
-use core::{fmt, ops::Deref};
+use crate::{asn1::AnyRef, BytesRef, FixedTag, Length, Result, Tag};
+use core::fmt;
 
 macro_rules! impl_teletex_string {
     ($type: ty) => {
         impl_teletex_string!($type,);
     };
     ($type: ty, $($li: lifetime)?) => {
-        impl_string_type!($type, $($li),*);
+        impl_type!($type, $($li),*);
+
+        mod __impl_teletex_string {
+            use super::*;
+
+            use crate::{
+                ord::OrdIsValueOrd, BytesRef, DecodeValue, EncodeValue, Header, Length, Reader,
+                Result, Writer,
+            };
+
+            impl<$($li),*> AsRef<[u8]> for $type {
+                fn as_ref(&self) -> &[u8] {
+                    self.as_bytes()
+                }
+            }
+
+            impl<'__der: $($li),*, $($li),*> DecodeValue<'__der> for $type {
+                fn decode_value<R: Reader<'__der>>(reader: &mut R, header: Header) -> Result<Self> {
+                    Self::new(BytesRef::decode_value(reader, header)?.as_slice())
+                }
+            }
+
+            impl<$($li),*> EncodeValue for $type {
+                fn value_len(&self) -> Result<Length> {
+                    self.inner.value_len()
+                }
+
+                fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+                    self.inner.encode_value(writer)
+                }
+            }
+
+            impl<$($li),*> OrdIsValueOrd for $type {}
+        }
 
         impl<$($li),*> FixedTag for $type {
             const TAG: Tag = Tag::TeletexString;
         }
 
+        impl<$($li),*> fmt::Display for $type {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                for c in self.chars() {
+                    fmt::Write::write_char(f, c)?;
+                }
+                Ok(())
+            }
+        }
+
         impl<$($li),*> fmt::Debug for $type {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                write!(f, "TeletexString({:?})", self.as_str())
+                write!(f, "TeletexString(\"{}\")", self)
             }
         }
     };
@@ -24,7 +66,7 @@ macro_rules! impl_teletex_string {
 
 /// ASN.1 `TeletexString` type.
 ///
-/// Supports a subset the ASCII character set (described below).
+/// Stores the raw bytes of the string without validating them.
 ///
 /// For UTF-8, use [`Utf8StringRef`][`crate::asn1::Utf8StringRef`] instead.
 /// For the full ASCII character set, use
@@ -38,10 +80,14 @@ macro_rules! impl_teletex_string {
 /// mailing list, "a sizable volume of software in the world treats TeletexString (T61String) as a
 /// simple 8-bit string with mostly Windows Latin 1 (superset of iso-8859-1) encoding".
 ///
+/// Accordingly, [`TeletexStringRef::chars`] and the [`Display`][`fmt::Display`] impl decode each
+/// byte as the ISO 8859-1 (Latin-1) character with the same value. This is a best-effort
+/// interpretation: callers which need strict T.61 handling should use
+/// [`TeletexStringRef::as_bytes`] and decode the raw bytes themselves.
 #[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
 pub struct TeletexStringRef<'a> {
     /// Inner value
-    inner: StrRef<'a>,
+    inner: BytesRef<'a>,
 }
 
 impl<'a> TeletexStringRef<'a> {
@@ -50,29 +96,34 @@ impl<'a> TeletexStringRef<'a> {
     where
         T: AsRef<[u8]> + ?Sized,
     {
-        let input = input.as_ref();
-
-        // FIXME: support higher part of the charset
-        if input.iter().any(|&c| c > 0x7F) {
-            return Err(Self::TAG.value_error());
-        }
-
-        StrRef::from_bytes(input)
+        BytesRef::new(input.as_ref())
             .map(|inner| Self { inner })
-            .map_err(|_| Self::TAG.value_error())
+            .map_err(|_| Self::TAG.length_error())
     }
-}
 
-impl_teletex_string!(TeletexStringRef<'a>, 'a);
+    /// Borrow the raw bytes of this string.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_slice()
+    }
+
+    /// Get the length of the encoded bytes.
+    pub fn len(&self) -> Length {
+        self.inner.len()
+    }
 
-impl<'a> Deref for TeletexStringRef<'a> {
-    type Target = StrRef<'a>;
+    /// Is this string empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
 
-    fn deref(&self) -> &Self::Target {
-        &self.inner
+    /// Iterate over the characters of this string, decoded as Latin-1.
+    pub fn chars(&self) -> impl Iterator<Item = char> + 'a {
+        self.as_bytes().iter().map(|&b| char::from(b))
     }
 }
 
+impl_teletex_string!(TeletexStringRef<'a>, 'a);
+
 impl<'a> From<&TeletexStringRef<'a>> for TeletexStringRef<'a> {
     fn from(value: &TeletexStringRef<'a>) -> TeletexStringRef<'a> {
         *value
@@ -81,7 +132,7 @@ impl<'a> From<&TeletexStringRef<'a>> for TeletexStringRef<'a> {
 
 impl<'a> From<TeletexStringRef<'a>> for AnyRef<'a> {
     fn from(teletex_string: TeletexStringRef<'a>) -> AnyRef<'a> {
-        AnyRef::from_tag_and_value(Tag::TeletexString, teletex_string.inner.into())
+        AnyRef::from_tag_and_value(Tag::TeletexString, teletex_string.inner)
     }
 }
 
@@ -95,13 +146,14 @@ mod allocation {
     use crate::{
         asn1::AnyRef,
         referenced::{OwnedToRef, RefToOwned},
-        BytesRef, FixedTag, Result, StrOwned, Tag,
+        BytesOwned, FixedTag, Length, Result, Tag,
     };
-    use core::{fmt, ops::Deref};
+    use alloc::{string::String, vec::Vec};
+    use core::fmt;
 
     /// ASN.1 `TeletexString` type.
     ///
-    /// Supports a subset the ASCII character set (described below).
+    /// Stores the raw bytes of the string without validating them.
     ///
     /// For UTF-8, use [`Utf8StringRef`][`crate::asn1::Utf8StringRef`] instead.
     /// For the full ASCII character set, use
@@ -109,14 +161,11 @@ mod allocation {
     ///
     /// # Supported characters
     ///
-    /// The standard defines a complex character set allowed in this type. However, quoting the ASN.1
-    /// mailing list, "a sizable volume of software in the world treats TeletexString (T61String) as a
-    /// simple 8-bit string with mostly Windows Latin 1 (superset of iso-8859-1) encoding".
-    ///
+    /// See [`TeletexStringRef`] for how the contents are decoded.
     #[derive(Clone, Eq, PartialEq, PartialOrd, Ord)]
     pub struct TeletexString {
         /// Inner value
-        inner: StrOwned,
+        inner: BytesOwned,
     }
 
     impl TeletexString {
@@ -125,40 +174,62 @@ mod allocation {
         where
             T: AsRef<[u8]> + ?Sized,
         {
-            let input = input.as_ref();
+            BytesOwned::new(input.as_ref())
+                .map(|inner| Self { inner })
+                .map_err(|_| Self::TAG.length_error())
+        }
 
-            TeletexStringRef::new(input)?;
+        /// Create a new ASN.1 `TeletexString` from a Rust string, encoding
+        /// it as Latin-1.
+        ///
+        /// Returns an error if the string contains characters above `U+00FF`.
+        pub fn from_utf8(s: &str) -> Result<Self> {
+            let bytes = s
+                .chars()
+                .map(|c| u8::try_from(c).map_err(|_| Self::TAG.value_error()))
+                .collect::<Result<Vec<u8>>>()?;
 
-            StrOwned::from_bytes(input)
-                .map(|inner| Self { inner })
-                .map_err(|_| Self::TAG.value_error())
+            Self::new(&bytes)
+        }
+
+        /// Borrow the raw bytes of this string.
+        pub fn as_bytes(&self) -> &[u8] {
+            self.inner.as_slice()
+        }
+
+        /// Get the length of the encoded bytes.
+        pub fn len(&self) -> Length {
+            self.inner.len()
+        }
+
+        /// Is this string empty?
+        pub fn is_empty(&self) -> bool {
+            self.inner.is_empty()
+        }
+
+        /// Iterate over the characters of this string, decoded as Latin-1.
+        pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+            self.as_bytes().iter().map(|&b| char::from(b))
         }
     }
 
     impl_teletex_string!(TeletexString);
 
-    impl Deref for TeletexString {
-        type Target = StrOwned;
-
-        fn deref(&self) -> &Self::Target {
-            &self.inner
+    impl From<TeletexString> for String {
+        fn from(teletex_string: TeletexString) -> String {
+            teletex_string.chars().collect()
         }
     }
 
     impl<'a> From<TeletexStringRef<'a>> for TeletexString {
         fn from(value: TeletexStringRef<'a>) -> TeletexString {
-            let inner =
-                StrOwned::from_bytes(value.inner.as_bytes()).expect("Invalid TeletexString");
-            Self { inner }
+            value.ref_to_owned()
         }
     }
 
     impl<'a> From<&'a TeletexString> for AnyRef<'a> {
         fn from(teletex_string: &'a TeletexString) -> AnyRef<'a> {
-            AnyRef::from_tag_and_value(
-                Tag::TeletexString,
-                BytesRef::new(teletex_string.inner.as_bytes()).expect("Invalid TeletexString"),
-            )
+            teletex_string.owned_to_ref().into()
         }
     }
 
@@ -194,11 +265,40 @@ mod tests {
         ];
 
         let teletex_string = TeletexStringRef::from_der(example_bytes).unwrap();
-        assert_eq!(teletex_string.as_str(), "Test User 1");
+        assert!(teletex_string.chars().eq("Test User 1".chars()));
         let mut out = [0_u8; 30];
         let mut writer = SliceWriter::new(&mut out);
         writer.encode(&teletex_string).unwrap();
         let encoded = writer.finish().unwrap();
         assert_eq!(encoded, example_bytes);
     }
+
+    #[test]
+    fn parse_latin1_bytes() {
+        // "Müller" with `ü` encoded as the Latin-1 byte 0xFC
+        let example_bytes = &[0x14, 0x06, 0x4d, 0xfc, 0x6c, 0x6c, 0x65, 0x72];
+
+        let teletex_string = TeletexStringRef::from_der(example_bytes).unwrap();
+        assert_eq!(teletex_string.as_bytes(), &example_bytes[2..]);
+        assert!(teletex_string.chars().eq("Müller".chars()));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn owned_from_utf8() {
+        use super::TeletexString;
+        use crate::Encode;
+        use alloc::string::{String, ToString};
+
+        let teletex_string = TeletexString::from_utf8("Müller").unwrap();
+        assert_eq!(teletex_string.as_bytes(), b"M\xfcller");
+        assert_eq!(teletex_string.to_string(), "Müller");
+        assert_eq!(
+            teletex_string.to_vec().unwrap(),
+            b"\x14\x06M\xfcller".as_slice()
+        );
+        assert_eq!(String::from(teletex_string), "Müller");
+
+        assert!(TeletexString::from_utf8("€").is_err());
+    }
 }
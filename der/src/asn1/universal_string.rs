@@ -0,0 +1,348 @@
+//! ASN.1 `UniversalString` support.
+
+use crate::{
+    asn1::AnyRef, ord::OrdIsValueOrd, BytesRef, DecodeValue, EncodeValue, FixedTag, Header, Length,
+    Reader, Result, Tag, Writer,
+};
+use core::{char, fmt};
+
+/// ASN.1 `UniversalString` type: borrowed form.
+///
+/// Encodes Unicode characters as UCS-4, i.e. four bytes per character in big
+/// endian order (equivalent to UTF-32BE).
+///
+/// Code points above `U+10FFFF` and surrogates (`U+D800` to `U+DFFF`) are
+/// rejected.
+///
+/// This is a zero-copy reference type which borrows from the input data.
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct UniversalStringRef<'a> {
+    /// Inner value
+    inner: BytesRef<'a>,
+}
+
+impl<'a> UniversalStringRef<'a> {
+    /// Create a new ASN.1 `UniversalString` from its UCS-4 encoding.
+    pub fn new(bytes: &'a [u8]) -> Result<Self> {
+        validate_ucs4(bytes)?;
+
+        BytesRef::new(bytes)
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.length_error())
+    }
+
+    /// Borrow the UCS-4 encoded bytes.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_slice()
+    }
+
+    /// Get the length of the encoded bytes.
+    pub fn len(&self) -> Length {
+        self.inner.len()
+    }
+
+    /// Is this string empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Iterate over the characters of this string.
+    pub fn chars(&self) -> impl Iterator<Item = char> + 'a {
+        decode_ucs4(self.as_bytes())
+    }
+}
+
+impl_type!(UniversalStringRef<'a>, 'a);
+
+impl AsRef<[u8]> for UniversalStringRef<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for UniversalStringRef<'a> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        Self::new(BytesRef::decode_value(reader, header)?.as_slice())
+    }
+}
+
+impl EncodeValue for UniversalStringRef<'_> {
+    fn value_len(&self) -> Result<Length> {
+        self.inner.value_len()
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        self.inner.encode_value(writer)
+    }
+}
+
+impl FixedTag for UniversalStringRef<'_> {
+    const TAG: Tag = Tag::UniversalString;
+}
+
+impl OrdIsValueOrd for UniversalStringRef<'_> {}
+
+impl fmt::Display for UniversalStringRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.chars() {
+            fmt::Write::write_char(f, c)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for UniversalStringRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "UniversalString(\"{}\")", self)
+    }
+}
+
+impl<'a> From<&UniversalStringRef<'a>> for UniversalStringRef<'a> {
+    fn from(value: &UniversalStringRef<'a>) -> UniversalStringRef<'a> {
+        *value
+    }
+}
+
+impl<'a> From<UniversalStringRef<'a>> for AnyRef<'a> {
+    fn from(universal_string: UniversalStringRef<'a>) -> AnyRef<'a> {
+        AnyRef::from_tag_and_value(Tag::UniversalString, universal_string.inner)
+    }
+}
+
+/// Ensure the given bytes are valid UCS-4.
+fn validate_ucs4(bytes: &[u8]) -> Result<()> {
+    if bytes.len() % 4 != 0 {
+        return Err(Tag::UniversalString.length_error());
+    }
+
+    for code_point in bytes.chunks_exact(4) {
+        let code_point =
+            u32::from_be_bytes([code_point[0], code_point[1], code_point[2], code_point[3]]);
+
+        if char::from_u32(code_point).is_none() {
+            return Err(Tag::UniversalString.value_error());
+        }
+    }
+
+    Ok(())
+}
+
+/// Decode bytes previously checked with [`validate_ucs4`].
+fn decode_ucs4(bytes: &[u8]) -> impl Iterator<Item = char> + '_ {
+    bytes.chunks_exact(4).map(|code_point| {
+        let code_point =
+            u32::from_be_bytes([code_point[0], code_point[1], code_point[2], code_point[3]]);
+        char::from_u32(code_point).unwrap_or(char::REPLACEMENT_CHARACTER)
+    })
+}
+
+#[cfg(feature = "alloc")]
+pub use self::allocation::UniversalString;
+
+#[cfg(feature = "alloc")]
+mod allocation {
+    use super::{decode_ucs4, validate_ucs4, UniversalStringRef};
+    use crate::{
+        asn1::AnyRef,
+        ord::OrdIsValueOrd,
+        referenced::{OwnedToRef, RefToOwned},
+        BytesOwned, BytesRef, DecodeValue, EncodeValue, FixedTag, Header, Length, Reader, Result,
+        Tag, Writer,
+    };
+    use alloc::{string::String, vec::Vec};
+    use core::{fmt, str::FromStr};
+
+    /// ASN.1 `UniversalString` type: owned form.
+    ///
+    /// Encodes Unicode characters as UCS-4, i.e. four bytes per character in
+    /// big endian order (equivalent to UTF-32BE).
+    #[derive(Clone, Eq, PartialEq, PartialOrd, Ord)]
+    pub struct UniversalString {
+        /// Inner value
+        inner: BytesOwned,
+    }
+
+    impl UniversalString {
+        /// Create a new ASN.1 `UniversalString` from its UCS-4 encoding.
+        pub fn from_ucs4(bytes: impl Into<Vec<u8>>) -> Result<Self> {
+            let bytes = bytes.into();
+            validate_ucs4(&bytes)?;
+
+            BytesOwned::new(bytes)
+                .map(|inner| Self { inner })
+                .map_err(|_| Self::TAG.length_error())
+        }
+
+        /// Create a new ASN.1 `UniversalString` from a Rust string.
+        pub fn from_utf8(s: &str) -> Result<Self> {
+            let mut bytes = Vec::new();
+
+            for c in s.chars() {
+                bytes.extend_from_slice(&u32::from(c).to_be_bytes());
+            }
+
+            Self::from_ucs4(bytes)
+        }
+
+        /// Borrow the UCS-4 encoded bytes.
+        pub fn as_bytes(&self) -> &[u8] {
+            self.inner.as_slice()
+        }
+
+        /// Get the length of the encoded bytes.
+        pub fn len(&self) -> Length {
+            self.inner.len()
+        }
+
+        /// Is this string empty?
+        pub fn is_empty(&self) -> bool {
+            self.inner.is_empty()
+        }
+
+        /// Iterate over the characters of this string.
+        pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+            decode_ucs4(self.as_bytes())
+        }
+    }
+
+    impl_type!(UniversalString);
+
+    impl AsRef<[u8]> for UniversalString {
+        fn as_ref(&self) -> &[u8] {
+            self.as_bytes()
+        }
+    }
+
+    impl<'a> DecodeValue<'a> for UniversalString {
+        fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+            Self::from_ucs4(BytesRef::decode_value(reader, header)?.as_slice())
+        }
+    }
+
+    impl EncodeValue for UniversalString {
+        fn value_len(&self) -> Result<Length> {
+            self.inner.value_len()
+        }
+
+        fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+            self.inner.encode_value(writer)
+        }
+    }
+
+    impl FixedTag for UniversalString {
+        const TAG: Tag = Tag::UniversalString;
+    }
+
+    impl OrdIsValueOrd for UniversalString {}
+
+    impl FromStr for UniversalString {
+        type Err = crate::Error;
+
+        fn from_str(s: &str) -> Result<Self> {
+            Self::from_utf8(s)
+        }
+    }
+
+    impl fmt::Display for UniversalString {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Display::fmt(&self.owned_to_ref(), f)
+        }
+    }
+
+    impl fmt::Debug for UniversalString {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Debug::fmt(&self.owned_to_ref(), f)
+        }
+    }
+
+    impl From<UniversalString> for String {
+        fn from(universal_string: UniversalString) -> String {
+            universal_string.chars().collect()
+        }
+    }
+
+    impl<'a> From<&'a UniversalString> for AnyRef<'a> {
+        fn from(universal_string: &'a UniversalString) -> AnyRef<'a> {
+            universal_string.owned_to_ref().into()
+        }
+    }
+
+    impl<'a> From<UniversalStringRef<'a>> for UniversalString {
+        fn from(universal_string: UniversalStringRef<'a>) -> UniversalString {
+            universal_string.ref_to_owned()
+        }
+    }
+
+    impl<'a> RefToOwned<'a> for UniversalStringRef<'a> {
+        type Owned = UniversalString;
+        fn ref_to_owned(&self) -> Self::Owned {
+            UniversalString {
+                inner: self.inner.ref_to_owned(),
+            }
+        }
+    }
+
+    impl OwnedToRef for UniversalString {
+        type Borrowed<'a> = UniversalStringRef<'a>;
+        fn owned_to_ref(&self) -> Self::Borrowed<'_> {
+            UniversalStringRef {
+                inner: self.inner.owned_to_ref(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UniversalStringRef;
+    use crate::{Decode, SliceWriter, Tag};
+
+    #[test]
+    fn decode_and_reencode() {
+        // "Aé😀"
+        let example_bytes = &[
+            0x1c, 0x0c, 0x00, 0x00, 0x00, 0x41, 0x00, 0x00, 0x00, 0xe9, 0x00, 0x01, 0xf6, 0x00,
+        ];
+
+        let universal_string = UniversalStringRef::from_der(example_bytes).unwrap();
+        assert!(universal_string.chars().eq("Aé😀".chars()));
+
+        let mut out = [0u8; 16];
+        let mut writer = SliceWriter::new(&mut out);
+        writer.encode(&universal_string).unwrap();
+        assert_eq!(writer.finish().unwrap(), example_bytes);
+    }
+
+    #[test]
+    fn reject_length_not_multiple_of_four() {
+        let err = UniversalStringRef::from_der(&[0x1c, 0x03, 0x00, 0x00, 0x41]).unwrap_err();
+        assert_eq!(err.kind(), Tag::UniversalString.length_error().kind());
+    }
+
+    #[test]
+    fn reject_invalid_code_points() {
+        // above U+10FFFF
+        assert!(UniversalStringRef::new(&[0x00, 0x11, 0x00, 0x00]).is_err());
+        // surrogate
+        assert!(UniversalStringRef::new(&[0x00, 0x00, 0xd8, 0x00]).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn owned_roundtrip() {
+        use super::UniversalString;
+        use crate::Encode;
+        use alloc::string::{String, ToString};
+
+        let universal_string = UniversalString::from_utf8("Grüße 😀").unwrap();
+        assert_eq!(universal_string.to_string(), "Grüße 😀");
+        assert_eq!(universal_string.as_bytes().len(), 7 * 4);
+
+        let der = universal_string.to_vec().unwrap();
+        assert_eq!(&der[..2], &[0x1c, 0x1c]);
+
+        let decoded = UniversalString::from_der(&der).unwrap();
+        assert_eq!(decoded, universal_string);
+        assert_eq!(String::from(decoded), "Grüße 😀");
+    }
+}
@@ -1,12 +1,13 @@
 //! ASN.1 `UTCTime` support.
 
 use crate::{
+    asn1::GeneralizedTime,
     datetime::{self, DateTime},
     ord::OrdIsValueOrd,
     DecodeValue, EncodeValue, Error, ErrorKind, FixedTag, Header, Length, Reader, Result, Tag,
     Writer,
 };
-use core::time::Duration;
+use core::{cmp::Ordering, time::Duration};
 
 #[cfg(feature = "std")]
 use std::time::SystemTime;
@@ -36,6 +37,37 @@ impl UtcTime {
     /// Length of an RFC 5280-flavored ASN.1 DER-encoded [`UtcTime`].
     pub const LENGTH: usize = 13;
 
+    /// First year of the 100-year window two-digit years are mapped into by
+    /// RFC 5280, i.e. the window is 1950-2049.
+    pub const DEFAULT_PIVOT_YEAR: u16 = 1950;
+
+    /// Decode a `UTCTime` as a [`DateTime`], mapping its two-digit year into
+    /// the 100-year window which begins with `pivot_year`.
+    ///
+    /// Each two-digit year `YY` is interpreted as the year within
+    /// `pivot_year..=pivot_year + 99` which ends in `YY`. For example, with a
+    /// pivot year of 1970, `70` is interpreted as 1970 and `69` as 2069.
+    ///
+    /// Decoding via [`Decode`][`crate::Decode`] uses
+    /// [`UtcTime::DEFAULT_PIVOT_YEAR`] as specified in RFC 5280: `50` is
+    /// interpreted as 1950 and `49` as 2049. Note that years prior to 1970
+    /// aren't supported by [`DateTime`], so in practice the earliest year
+    /// which decodes successfully is 1970 (`70`).
+    ///
+    /// A [`DateTime`] is returned rather than a [`UtcTime`], since with
+    /// pivot years other than the default the year may fall outside of the
+    /// 1950-2049 range which a [`UtcTime`] can represent. Such values can be
+    /// re-encoded as a [`GeneralizedTime`].
+    pub fn decode_with_pivot<'a, R: Reader<'a>>(
+        reader: &mut R,
+        pivot_year: u16,
+    ) -> Result<DateTime> {
+        Header::decode_tlv(reader, |reader, header| {
+            header.tag.assert_eq(Self::TAG)?;
+            Self::decode_value_with_pivot(reader, header, pivot_year)
+        })
+    }
+
     /// Create a [`UtcTime`] from a [`DateTime`].
     pub fn from_date_time(datetime: DateTime) -> Result<Self> {
         if datetime.year() <= MAX_YEAR {
@@ -80,8 +112,16 @@ impl UtcTime {
 
 impl_type!(UtcTime);
 
-impl<'a> DecodeValue<'a> for UtcTime {
-    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+impl UtcTime {
+    /// Decode the value of a `UTCTime` as a [`DateTime`] using the given
+    /// pivot year.
+    ///
+    /// See [`UtcTime::decode_with_pivot`].
+    fn decode_value_with_pivot<'a, R: Reader<'a>>(
+        reader: &mut R,
+        header: Header,
+        pivot_year: u16,
+    ) -> Result<DateTime> {
         if Self::LENGTH != usize::try_from(header.length)? {
             return Err(Self::TAG.value_error());
         }
@@ -99,23 +139,31 @@ impl<'a> DecodeValue<'a> for UtcTime {
                 let minute = datetime::decode_decimal(Self::TAG, min1, min2)?;
                 let second = datetime::decode_decimal(Self::TAG, sec1, sec2)?;
 
-                // RFC 5280 rules for interpreting the year
-                let year = if year >= 50 {
-                    year.checked_add(1900)
-                } else {
-                    year.checked_add(2000)
+                // Map the year into the window beginning with the pivot year
+                let century = pivot_year
+                    .checked_div(100)
+                    .and_then(|c| c.checked_mul(100))
+                    .ok_or(ErrorKind::DateTime)?;
+                let year = match century.checked_add(year) {
+                    Some(y) if y < pivot_year => y.checked_add(100),
+                    y => y,
                 }
                 .ok_or(ErrorKind::DateTime)?;
 
                 DateTime::new(year, month, day, hour, minute, second)
                     .map_err(|_| Self::TAG.value_error())
-                    .and_then(|dt| Self::from_unix_duration(dt.unix_duration()))
             }
             _ => Err(Self::TAG.value_error()),
         }
     }
 }
 
+impl<'a> DecodeValue<'a> for UtcTime {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        Self::decode_value_with_pivot(reader, header, Self::DEFAULT_PIVOT_YEAR).map(Self)
+    }
+}
+
 impl EncodeValue for UtcTime {
     fn value_len(&self) -> Result<Length> {
         Self::LENGTH.try_into()
@@ -164,6 +212,30 @@ impl From<&UtcTime> for DateTime {
     }
 }
 
+impl PartialEq<GeneralizedTime> for UtcTime {
+    fn eq(&self, other: &GeneralizedTime) -> bool {
+        self.0 == other.to_date_time()
+    }
+}
+
+impl PartialEq<UtcTime> for GeneralizedTime {
+    fn eq(&self, other: &UtcTime) -> bool {
+        other == self
+    }
+}
+
+impl PartialOrd<GeneralizedTime> for UtcTime {
+    fn partial_cmp(&self, other: &GeneralizedTime) -> Option<Ordering> {
+        Some(self.0.cmp(&other.to_date_time()))
+    }
+}
+
+impl PartialOrd<UtcTime> for GeneralizedTime {
+    fn partial_cmp(&self, other: &UtcTime) -> Option<Ordering> {
+        Some(self.to_date_time().cmp(&other.0))
+    }
+}
+
 impl TryFrom<DateTime> for UtcTime {
     type Error = Error;
 
@@ -225,9 +297,28 @@ impl<'a> arbitrary::Arbitrary<'a> for UtcTime {
 #[cfg(test)]
 mod tests {
     use super::UtcTime;
-    use crate::{Decode, Encode, SliceWriter};
+    use crate::{asn1::GeneralizedTime, Decode, Encode, SliceReader, SliceWriter};
+    use core::cmp::Ordering;
     use hex_literal::hex;
 
+    #[test]
+    fn compare_with_generalized_time() {
+        let utc_time = UtcTime::from_der(b"\x17\x0d910506234540Z").unwrap();
+        let same = GeneralizedTime::from_der(b"\x18\x0f19910506234540Z").unwrap();
+        let later = GeneralizedTime::from_der(b"\x18\x0f20500101000000Z").unwrap();
+        let earlier = GeneralizedTime::from_der(b"\x18\x0f19700101000000Z").unwrap();
+
+        assert_eq!(utc_time, same);
+        assert_eq!(same, utc_time);
+        assert_eq!(utc_time.partial_cmp(&same), Some(Ordering::Equal));
+        assert_eq!(same.partial_cmp(&utc_time), Some(Ordering::Equal));
+        assert!(utc_time < later);
+        assert!(later > utc_time);
+        assert!(utc_time > earlier);
+        assert!(earlier < utc_time);
+        assert!(earlier <= utc_time && utc_time <= later);
+    }
+
     #[test]
     fn round_trip_vector() {
         let example_bytes = hex!("17 0d 39 31 30 35 30 36 32 33 34 35 34 30 5a");
@@ -239,4 +330,48 @@ mod tests {
         utc_time.encode(&mut encoder).unwrap();
         assert_eq!(example_bytes, encoder.finish().unwrap());
     }
+
+    #[test]
+    fn default_pivot() {
+        let utc_time = UtcTime::from_der(b"\x17\x0d491231235959Z").unwrap();
+        assert_eq!(utc_time.to_date_time().year(), 2049);
+
+        // 1950 is prior to the earliest year supported by `DateTime`
+        assert!(UtcTime::from_der(b"\x17\x0d500101000000Z").is_err());
+
+        let utc_time = UtcTime::from_der(b"\x17\x0d700101000000Z").unwrap();
+        assert_eq!(utc_time.to_date_time().year(), 1970);
+    }
+
+    #[test]
+    fn decode_with_pivot() {
+        let mut reader = SliceReader::new(b"\x17\x0d491231235959Z").unwrap();
+        let date_time = UtcTime::decode_with_pivot(&mut reader, 1950).unwrap();
+        assert_eq!(date_time.year(), 2049);
+
+        let mut reader = SliceReader::new(b"\x17\x0d690101000000Z").unwrap();
+        let date_time = UtcTime::decode_with_pivot(&mut reader, 1970).unwrap();
+        assert_eq!(date_time.year(), 2069);
+
+        let mut reader = SliceReader::new(b"\x17\x0d700101000000Z").unwrap();
+        let date_time = UtcTime::decode_with_pivot(&mut reader, 1970).unwrap();
+        assert_eq!(date_time.year(), 1970);
+
+        let mut reader = SliceReader::new(b"\x17\x0d000101000000Z").unwrap();
+        let date_time = UtcTime::decode_with_pivot(&mut reader, 2000).unwrap();
+        assert_eq!(date_time.year(), 2000);
+
+        let mut reader = SliceReader::new(b"\x17\x0d991231235959Z").unwrap();
+        let date_time = UtcTime::decode_with_pivot(&mut reader, 2000).unwrap();
+        assert_eq!(date_time.year(), 2099);
+
+        // Outside of the range supported by `UTCTime`
+        assert!(UtcTime::from_date_time(date_time).is_err());
+        let mut buf = [0u8; 128];
+        let mut encoder = SliceWriter::new(&mut buf);
+        GeneralizedTime::from_date_time(date_time)
+            .encode(&mut encoder)
+            .unwrap();
+        assert_eq!(encoder.finish().unwrap(), b"\x18\x0f20991231235959Z");
+    }
 }
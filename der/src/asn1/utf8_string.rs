@@ -40,6 +40,20 @@ impl<'a> Utf8StringRef<'a> {
     {
         StrRef::from_bytes(input.as_ref()).map(|inner| Self { inner })
     }
+
+    /// Ensure this string doesn't contain any control characters, i.e.
+    /// characters in the Unicode `Cc` general category such as `NUL`, `ESC`,
+    /// `DEL`, or the C1 controls.
+    ///
+    /// This is useful before displaying or comparing values such as the
+    /// attributes of certificate subjects. The string itself is unchanged.
+    pub fn validate_no_control(&self) -> Result<()> {
+        if self.as_str().chars().any(char::is_control) {
+            Err(Self::TAG.value_error())
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl_string_type!(Utf8StringRef<'a>, 'a);
@@ -167,4 +181,16 @@ mod tests {
         let utf8_string = Utf8StringRef::from_der(example_bytes).unwrap();
         assert_eq!(utf8_string.as_str(), "Helló");
     }
+
+    #[test]
+    fn validate_no_control() {
+        let utf8_string = Utf8StringRef::new("Helló Wörld").unwrap();
+        assert!(utf8_string.validate_no_control().is_ok());
+
+        for s in ["Hello\0", "Hello\x1b[31m", "Hello\x7f", "Hello\u{85}"] {
+            let utf8_string = Utf8StringRef::new(s).unwrap();
+            assert!(utf8_string.validate_no_control().is_err());
+            assert_eq!(utf8_string.as_str(), s);
+        }
+    }
 }
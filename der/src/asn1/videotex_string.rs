@@ -1,11 +1,17 @@
 //! ASN.1 `VideotexString` support.
 
-use crate::{asn1::AnyRef, FixedTag, Result, StrRef, Tag};
-use core::{fmt, ops::Deref};
+use crate::{
+    asn1::AnyRef, ord::OrdIsValueOrd, BytesRef, DecodeValue, EncodeValue, FixedTag, Header, Length,
+    Reader, Result, Tag, Writer,
+};
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
 
 /// ASN.1 `VideotexString` type.
 ///
-/// Supports a subset the ASCII character set (described below).
+/// Stores the raw bytes of the string without validating them.
 ///
 /// For UTF-8, use [`Utf8StringRef`][`crate::asn1::Utf8StringRef`] instead.
 /// For the full ASCII character set, use
@@ -15,12 +21,15 @@ use core::{fmt, ops::Deref};
 ///
 /// # Supported characters
 ///
-/// For the practical purposes VideotexString is treated as IA5string, disallowing non-ASCII chars.
-///
+/// The character repertoire of VideotexString (ITU-T T.100 and T.101) has no
+/// widely agreed-upon mapping to Unicode, so this type doesn't attempt to
+/// decode it. Use [`VideotexStringRef::as_bytes`] to access the contents, or
+/// opt in to a lossy decode with [`VideotexStringRef::chars_lossy`], which
+/// keeps ASCII characters and replaces any other byte with `U+FFFD`.
 #[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
 pub struct VideotexStringRef<'a> {
     /// Inner value
-    inner: StrRef<'a>,
+    inner: BytesRef<'a>,
 }
 
 impl<'a> VideotexStringRef<'a> {
@@ -29,27 +38,67 @@ impl<'a> VideotexStringRef<'a> {
     where
         T: AsRef<[u8]> + ?Sized,
     {
-        let input = input.as_ref();
+        BytesRef::new(input.as_ref())
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.length_error())
+    }
 
-        // Validate all characters are within VideotexString's allowed set
-        // FIXME: treat as if it were IA5String
-        if input.iter().any(|&c| c > 0x7F) {
-            return Err(Self::TAG.value_error());
-        }
+    /// Borrow the raw bytes of this string.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_slice()
+    }
 
-        StrRef::from_bytes(input)
-            .map(|inner| Self { inner })
-            .map_err(|_| Self::TAG.value_error())
+    /// Get the length of the encoded bytes.
+    pub fn len(&self) -> Length {
+        self.inner.len()
+    }
+
+    /// Is this string empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Iterate over the characters of this string, replacing any non-ASCII
+    /// byte with [`char::REPLACEMENT_CHARACTER`].
+    pub fn chars_lossy(&self) -> impl Iterator<Item = char> + 'a {
+        self.as_bytes().iter().map(|&b| {
+            if b.is_ascii() {
+                char::from(b)
+            } else {
+                char::REPLACEMENT_CHARACTER
+            }
+        })
+    }
+
+    /// Decode this string into a [`String`], replacing any non-ASCII byte
+    /// with [`char::REPLACEMENT_CHARACTER`].
+    #[cfg(feature = "alloc")]
+    pub fn to_string_lossy(&self) -> String {
+        self.chars_lossy().collect()
+    }
+}
+
+impl_type!(VideotexStringRef<'a>, 'a);
+
+impl AsRef<[u8]> for VideotexStringRef<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
     }
 }
 
-impl_string_type!(VideotexStringRef<'a>, 'a);
+impl<'a> DecodeValue<'a> for VideotexStringRef<'a> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        Self::new(BytesRef::decode_value(reader, header)?.as_slice())
+    }
+}
 
-impl<'a> Deref for VideotexStringRef<'a> {
-    type Target = StrRef<'a>;
+impl EncodeValue for VideotexStringRef<'_> {
+    fn value_len(&self) -> Result<Length> {
+        self.inner.value_len()
+    }
 
-    fn deref(&self) -> &Self::Target {
-        &self.inner
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        self.inner.encode_value(writer)
     }
 }
 
@@ -57,6 +106,8 @@ impl FixedTag for VideotexStringRef<'_> {
     const TAG: Tag = Tag::VideotexString;
 }
 
+impl OrdIsValueOrd for VideotexStringRef<'_> {}
+
 impl<'a> From<&VideotexStringRef<'a>> for VideotexStringRef<'a> {
     fn from(value: &VideotexStringRef<'a>) -> VideotexStringRef<'a> {
         *value
@@ -64,27 +115,31 @@ impl<'a> From<&VideotexStringRef<'a>> for VideotexStringRef<'a> {
 }
 
 impl<'a> From<VideotexStringRef<'a>> for AnyRef<'a> {
-    fn from(printable_string: VideotexStringRef<'a>) -> AnyRef<'a> {
-        AnyRef::from_tag_and_value(Tag::VideotexString, printable_string.inner.into())
+    fn from(videotex_string: VideotexStringRef<'a>) -> AnyRef<'a> {
+        AnyRef::from_tag_and_value(Tag::VideotexString, videotex_string.inner)
     }
 }
 
 impl<'a> From<VideotexStringRef<'a>> for &'a [u8] {
-    fn from(printable_string: VideotexStringRef<'a>) -> &'a [u8] {
-        printable_string.as_bytes()
+    fn from(videotex_string: VideotexStringRef<'a>) -> &'a [u8] {
+        videotex_string.as_bytes()
     }
 }
 
 impl<'a> fmt::Debug for VideotexStringRef<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "VideotexString({:?})", self.as_str())
+        f.write_str("VideotexString(\"")?;
+        for c in self.chars_lossy() {
+            fmt::Write::write_char(f, c)?;
+        }
+        f.write_str("\")")
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::VideotexStringRef;
-    use crate::Decode;
+    use crate::{Decode, SliceWriter, Tag};
 
     #[test]
     fn parse_bytes() {
@@ -92,7 +147,47 @@ mod tests {
             0x15, 0x0b, 0x54, 0x65, 0x73, 0x74, 0x20, 0x55, 0x73, 0x65, 0x72, 0x20, 0x31,
         ];
 
-        let printable_string = VideotexStringRef::from_der(example_bytes).unwrap();
-        assert_eq!(printable_string.as_str(), "Test User 1");
+        let videotex_string = VideotexStringRef::from_der(example_bytes).unwrap();
+        assert_eq!(videotex_string.as_bytes(), b"Test User 1");
+        assert!(videotex_string.chars_lossy().eq("Test User 1".chars()));
+
+        let mut out = [0_u8; 30];
+        let mut writer = SliceWriter::new(&mut out);
+        writer.encode(&videotex_string).unwrap();
+        assert_eq!(writer.finish().unwrap(), example_bytes);
+    }
+
+    #[test]
+    fn preserve_non_ascii_bytes() {
+        let example_bytes = &[0x15, 0x04, 0x54, 0xc8, 0x65, 0x21];
+
+        let videotex_string = VideotexStringRef::from_der(example_bytes).unwrap();
+        assert_eq!(videotex_string.as_bytes(), &[0x54, 0xc8, 0x65, 0x21]);
+        assert!(videotex_string.chars_lossy().eq("T\u{fffd}e!".chars()));
+
+        let mut out = [0_u8; 6];
+        let mut writer = SliceWriter::new(&mut out);
+        writer.encode(&videotex_string).unwrap();
+        assert_eq!(writer.finish().unwrap(), example_bytes);
+    }
+
+    #[test]
+    fn reject_wrong_tag() {
+        // TeletexString
+        let err = VideotexStringRef::from_der(&[0x14, 0x01, 0x41]).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            crate::ErrorKind::TagUnexpected {
+                expected: Some(Tag::VideotexString),
+                actual: Tag::TeletexString
+            }
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_string_lossy() {
+        let videotex_string = VideotexStringRef::new(b"caf\xe9").unwrap();
+        assert_eq!(videotex_string.to_string_lossy(), "caf\u{fffd}");
     }
 }
@@ -6,13 +6,13 @@
 // Released under the MIT OR Apache 2.0 licenses
 
 use crate::{Error, ErrorKind, Result, Tag, Writer};
-use core::{fmt, str::FromStr, time::Duration};
+use core::{cmp::Ordering, fmt, str::FromStr, time::Duration};
 
 #[cfg(feature = "std")]
 use std::time::{SystemTime, UNIX_EPOCH};
 
 #[cfg(feature = "time")]
-use time::PrimitiveDateTime;
+use time::{OffsetDateTime, PrimitiveDateTime};
 
 /// Minimum year allowed in [`DateTime`] values.
 const MIN_YEAR: u16 = 1970;
@@ -23,13 +23,18 @@ const MIN_YEAR: u16 = 1970;
 /// This corresponds to: 9999-12-31T23:59:59Z
 const MAX_UNIX_DURATION: Duration = Duration::from_secs(253_402_300_799);
 
+/// Number of nanoseconds in a second.
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
 /// Date-and-time type shared by multiple ASN.1 types
 /// (e.g. `GeneralizedTime`, `UTCTime`).
 ///
 /// Following conventions from RFC 5280, this type is always Z-normalized
 /// (i.e. represents a UTC time). However, it isn't named "UTC time" in order
 /// to prevent confusion with ASN.1 `UTCTime`.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+///
+/// [`DateTime`] values are ordered by the instant they represent.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct DateTime {
     /// Full year (e.g. 2000).
     ///
@@ -51,7 +56,7 @@ pub struct DateTime {
     /// Seconds (0-59)
     seconds: u8,
 
-    /// [`Duration`] since the Unix epoch.
+    /// [`Duration`] since the Unix epoch, including any fractional seconds.
     unix_duration: Duration,
 }
 
@@ -124,6 +129,9 @@ impl DateTime {
 
     /// Compute a [`DateTime`] from the given [`Duration`] since the `UNIX_EPOCH`.
     ///
+    /// Fractional seconds are truncated: use [`DateTime::with_nanoseconds`]
+    /// to retain them.
+    ///
     /// Returns `None` if the value is outside the supported date range.
     // TODO(tarcieri): checked arithmetic
     #[allow(clippy::integer_arithmetic)]
@@ -233,6 +241,26 @@ impl DateTime {
         self.seconds
     }
 
+    /// Get the fractional seconds, in nanoseconds.
+    pub fn nanoseconds(&self) -> u32 {
+        self.unix_duration.subsec_nanos()
+    }
+
+    /// Set the fractional seconds of this [`DateTime`] to the given number of
+    /// nanoseconds.
+    ///
+    /// Returns an error if `nanoseconds` is not less than one second.
+    pub fn with_nanoseconds(self, nanoseconds: u32) -> Result<Self> {
+        if nanoseconds >= NANOS_PER_SEC {
+            return Err(ErrorKind::DateTime.into());
+        }
+
+        Ok(Self {
+            unix_duration: Duration::new(self.unix_duration.as_secs(), nanoseconds),
+            ..self
+        })
+    }
+
     /// Compute [`Duration`] since `UNIX_EPOCH` from the given calendar date.
     pub fn unix_duration(&self) -> Duration {
         self.unix_duration
@@ -255,6 +283,18 @@ impl DateTime {
     }
 }
 
+impl Ord for DateTime {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.unix_duration.cmp(&other.unix_duration)
+    }
+}
+
+impl PartialOrd for DateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl FromStr for DateTime {
     type Err = Error;
 
@@ -337,12 +377,18 @@ impl TryFrom<DateTime> for PrimitiveDateTime {
     fn try_from(time: DateTime) -> Result<PrimitiveDateTime> {
         let month = time.month().try_into()?;
         let date = time::Date::from_calendar_date(i32::from(time.year()), month, time.day())?;
-        let time = time::Time::from_hms(time.hour(), time.minutes(), time.seconds())?;
+        let time = time::Time::from_hms_nano(
+            time.hour(),
+            time.minutes(),
+            time.seconds(),
+            time.nanoseconds(),
+        )?;
 
         Ok(PrimitiveDateTime::new(date, time))
     }
 }
 
+/// Fractional seconds are truncated.
 #[cfg(feature = "time")]
 #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
 impl TryFrom<PrimitiveDateTime> for DateTime {
@@ -360,6 +406,28 @@ impl TryFrom<PrimitiveDateTime> for DateTime {
     }
 }
 
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<DateTime> for OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(time: DateTime) -> Result<OffsetDateTime> {
+        Ok(PrimitiveDateTime::try_from(time)?.assume_utc())
+    }
+}
+
+/// The time is normalized to UTC, and fractional seconds are truncated.
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<OffsetDateTime> for DateTime {
+    type Error = Error;
+
+    fn try_from(time: OffsetDateTime) -> Result<DateTime> {
+        let secs = u64::try_from(time.unix_timestamp()).map_err(|_| ErrorKind::DateTime)?;
+        DateTime::from_unix_duration(Duration::from_secs(secs))
+    }
+}
+
 // Implement by hand because the derive would create invalid values.
 // Use the conversion from Duration to create a valid value.
 #[cfg(feature = "arbitrary")]
@@ -430,6 +498,72 @@ mod tests {
         assert_eq!(datetime.seconds(), 14);
     }
 
+    #[test]
+    fn with_nanoseconds() {
+        let datetime = DateTime::new(2001, 01, 02, 12, 13, 14).unwrap();
+        assert_eq!(datetime.nanoseconds(), 0);
+
+        let datetime = datetime.with_nanoseconds(123_000_000).unwrap();
+        assert_eq!(datetime.seconds(), 14);
+        assert_eq!(datetime.nanoseconds(), 123_000_000);
+        assert_eq!(datetime.unix_duration().subsec_millis(), 123);
+
+        assert!(datetime.with_nanoseconds(1_000_000_000).is_err());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_conversions() {
+        use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+        let datetime = DateTime::new(2001, 01, 02, 12, 13, 14).unwrap();
+        let primitive = PrimitiveDateTime::try_from(datetime).unwrap();
+        assert_eq!(
+            primitive,
+            PrimitiveDateTime::new(
+                Date::from_calendar_date(2001, Month::January, 2).unwrap(),
+                Time::from_hms(12, 13, 14).unwrap()
+            )
+        );
+        assert_eq!(DateTime::try_from(primitive).unwrap(), datetime);
+
+        let offset = OffsetDateTime::try_from(datetime).unwrap();
+        assert_eq!(offset, primitive.assume_utc());
+        assert_eq!(DateTime::try_from(offset).unwrap(), datetime);
+
+        // Non-UTC offsets are normalized to UTC
+        let offset = PrimitiveDateTime::new(
+            Date::from_calendar_date(2001, Month::January, 2).unwrap(),
+            Time::from_hms_nano(12, 13, 14, 500).unwrap(),
+        )
+        .assume_offset(UtcOffset::from_hms(-1, 0, 0).unwrap());
+        let normalized = DateTime::try_from(offset).unwrap();
+        assert_eq!(normalized.hour(), 13);
+        assert_eq!(normalized.nanoseconds(), 0);
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_conversions_out_of_range() {
+        use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+        let before_epoch = PrimitiveDateTime::new(
+            Date::from_calendar_date(1969, Month::December, 31).unwrap(),
+            Time::from_hms(23, 59, 59).unwrap(),
+        );
+        assert!(DateTime::try_from(before_epoch).is_err());
+        assert!(DateTime::try_from(before_epoch.assume_utc()).is_err());
+
+        // 9999-12-31T23:59:59-01:00 is after 9999-12-31T23:59:59Z
+        let after_max = PrimitiveDateTime::new(
+            Date::from_calendar_date(9999, Month::December, 31).unwrap(),
+            Time::from_hms(23, 59, 59).unwrap(),
+        )
+        .assume_offset(UtcOffset::from_hms(-1, 0, 0).unwrap());
+        assert!(DateTime::try_from(after_max).is_err());
+        assert!(DateTime::try_from(OffsetDateTime::UNIX_EPOCH).is_ok());
+    }
+
     #[cfg(feature = "alloc")]
     #[test]
     fn display() {
@@ -1,6 +1,6 @@
 //! Trait definition for [`Decode`].
 
-use crate::{FixedTag, Header, Reader, Result, SliceReader};
+use crate::{EncodingRules, FixedTag, Header, Reader, Result, SliceReader};
 
 #[cfg(feature = "pem")]
 use crate::{pem::PemLabel, PemReader};
@@ -25,6 +25,17 @@ pub trait Decode<'a>: Sized {
         let result = Self::decode(&mut reader)?;
         reader.finish(result)
     }
+
+    /// Parse `Self` from the provided BER-encoded byte slice.
+    ///
+    /// Unlike [`Decode::from_der`], this accepts constructed values encoded
+    /// with an indefinite length, terminated by end-of-contents octets.
+    /// Values with a definite length must otherwise follow the rules of DER.
+    fn from_ber(bytes: &'a [u8]) -> Result<Self> {
+        let mut reader = SliceReader::new_with_encoding_rules(bytes, EncodingRules::Ber)?;
+        let result = Self::decode(&mut reader)?;
+        reader.finish(result)
+    }
 }
 
 impl<'a, T> Decode<'a> for T
@@ -32,9 +43,10 @@ where
     T: DecodeValue<'a> + FixedTag,
 {
     fn decode<R: Reader<'a>>(reader: &mut R) -> Result<T> {
-        let header = Header::decode(reader)?;
-        header.tag.assert_eq(T::TAG)?;
-        T::decode_value(reader, header)
+        Header::decode_tlv(reader, |reader, header| {
+            header.tag.assert_eq(T::TAG)?;
+            T::decode_value(reader, header)
+        })
     }
 }
 
@@ -1,6 +1,6 @@
 //! Trait definition for [`Encode`].
 
-use crate::{Header, Length, Result, SliceWriter, Tagged, Writer};
+use crate::{ErrorKind, Header, Length, Result, SliceWriter, Tagged, Writer};
 
 #[cfg(feature = "alloc")]
 use {alloc::boxed::Box, alloc::vec::Vec, core::iter};
@@ -12,9 +12,6 @@ use {
     pem_rfc7468::{self as pem, LineEnding, PemLabel},
 };
 
-#[cfg(any(feature = "alloc", feature = "pem"))]
-use crate::ErrorKind;
-
 #[cfg(doc)]
 use crate::Tag;
 
@@ -34,6 +31,29 @@ pub trait Encode {
         writer.finish()
     }
 
+    /// Encode this value as ASN.1 DER into the provided byte slice, returning
+    /// the number of bytes written.
+    ///
+    /// The total length is computed up front with [`Encode::encoded_len`] and
+    /// the value is then written directly into `buf` in a single pass, without
+    /// any intermediate buffers. If `buf` is too small to hold the encoded
+    /// value, an error is returned before anything is written.
+    fn to_der_into(&self, buf: &mut [u8]) -> Result<usize> {
+        let expected_len = usize::try_from(self.encoded_len()?)?;
+        let buf = buf.get_mut(..expected_len).ok_or(ErrorKind::Overlength)?;
+        let actual_len = self.encode_to_slice(buf)?.len();
+
+        if expected_len != actual_len {
+            return Err(ErrorKind::Incomplete {
+                expected_len: expected_len.try_into()?,
+                actual_len: actual_len.try_into()?,
+            }
+            .into());
+        }
+
+        Ok(actual_len)
+    }
+
     /// Encode this message as ASN.1 DER, appending it to the provided
     /// byte vector.
     #[cfg(feature = "alloc")]
@@ -144,3 +164,27 @@ where
         T::encode_value(self, writer)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Encode;
+    use crate::{asn1::OctetStringRef, ErrorKind};
+    use hex_literal::hex;
+
+    #[test]
+    fn to_der_into() {
+        let value = OctetStringRef::new(&hex!("DEADBEEF")).unwrap();
+        let mut buf = [0xFFu8; 8];
+        assert_eq!(value.to_der_into(&mut buf).unwrap(), 6);
+        assert_eq!(buf, hex!("0404DEADBEEF FFFF"));
+    }
+
+    #[test]
+    fn to_der_into_short_buffer() {
+        let value = OctetStringRef::new(&hex!("DEADBEEF")).unwrap();
+        let mut buf = [0xFFu8; 5];
+        let err = value.to_der_into(&mut buf).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Overlength);
+        assert_eq!(buf, [0xFF; 5]);
+    }
+}
@@ -0,0 +1,35 @@
+//! ASN.1 encoding rules.
+
+/// ASN.1 encoding rules.
+///
+/// This enum identifies the specific encoding rules which are applied at the
+/// time a given document is decoded from a byte/octet serialization.
+///
+/// In addition to the Distinguished Encoding Rules (DER), this crate also
+/// supports a strict subset of the Basic Encoding Rules (BER) which permits:
+///
+/// - indefinite lengths for constructed values
+/// - `OCTET STRING`s encoded in constructed form (decoded as an owned
+///   [`OctetString`][`crate::asn1::OctetString`])
+/// - any non-zero octet as the encoding of a `BOOLEAN` `true` value
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum EncodingRules {
+    /// Basic Encoding Rules.
+    Ber,
+
+    /// Distinguished Encoding Rules.
+    #[default]
+    Der,
+}
+
+impl EncodingRules {
+    /// Are these the Basic Encoding Rules?
+    pub fn is_ber(self) -> bool {
+        self == EncodingRules::Ber
+    }
+
+    /// Are these the Distinguished Encoding Rules?
+    pub fn is_der(self) -> bool {
+        self == EncodingRules::Der
+    }
+}
@@ -57,6 +57,18 @@ impl Error {
         self.position
     }
 
+    /// Annotate this error with the given position, unless it already has
+    /// one.
+    ///
+    /// Positions are absolute byte offsets within the input, so an error
+    /// which already carries a position is left untouched.
+    pub(crate) fn or_at(self, position: Length) -> Self {
+        Self {
+            kind: self.kind,
+            position: self.position.or(Some(position)),
+        }
+    }
+
     /// For errors occurring inside of a nested message, extend the position
     /// count by the location where the nested message occurs.
     pub(crate) fn nested(self, nested_position: Length) -> Self {
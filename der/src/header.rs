@@ -1,8 +1,14 @@
 //! ASN.1 DER headers.
 
-use crate::{Decode, DerOrd, Encode, ErrorKind, Length, Reader, Result, Tag, Writer};
+use crate::{
+    length::INDEFINITE_LENGTH_OCTET, Decode, DerOrd, Encode, ErrorKind, Length, Reader, Result,
+    Tag, Writer,
+};
 use core::cmp::Ordering;
 
+/// Identifier octet of an `OCTET STRING` encoded in constructed form.
+const CONSTRUCTED_OCTET_STRING: u8 = 0x24;
+
 /// ASN.1 DER headers: tag + length component of TLV-encoded values
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct Header {
@@ -11,6 +17,12 @@ pub struct Header {
 
     /// Length of the encoded value
     pub length: Length,
+
+    /// Is the value encoded in constructed form?
+    ///
+    /// This is normally implied by the tag, except for BER constructed
+    /// `OCTET STRING`s which share a [`Tag`] with the primitive form.
+    pub(crate) constructed: bool,
 }
 
 impl Header {
@@ -19,13 +31,97 @@ impl Header {
     /// Returns an error if the length exceeds the limits of [`Length`].
     pub fn new(tag: Tag, length: impl TryInto<Length>) -> Result<Self> {
         let length = length.try_into().map_err(|_| ErrorKind::Overflow)?;
-        Ok(Self { tag, length })
+
+        Ok(Self {
+            tag,
+            length,
+            constructed: tag.is_constructed(),
+        })
     }
-}
 
-impl<'a> Decode<'a> for Header {
-    fn decode<R: Reader<'a>>(reader: &mut R) -> Result<Header> {
-        let tag = Tag::decode(reader)?;
+    /// Is the value described by this header encoded in constructed form?
+    ///
+    /// Unlike [`Tag::is_constructed`], this also detects `OCTET STRING`s which
+    /// are encoded in constructed form as permitted by BER.
+    pub fn is_constructed(&self) -> bool {
+        self.constructed
+    }
+
+    /// Decode a [`Header`], then decode the value it describes using the
+    /// provided callback.
+    ///
+    /// When decoding BER, this also consumes the end-of-contents octets which
+    /// terminate a value encoded with an indefinite length.
+    ///
+    /// Errors which occur without a known position are annotated with the
+    /// offset of the TLV.
+    pub(crate) fn decode_tlv<'a, R, T, F>(reader: &mut R, f: F) -> Result<T>
+    where
+        R: Reader<'a>,
+        F: FnOnce(&mut R, Header) -> Result<T>,
+    {
+        // Errors which don't carry a position are reported at the start of the TLV
+        let offset = reader.offset();
+        let (header, indefinite) =
+            Self::decode_with_indefinite(reader).map_err(|e| e.or_at(offset))?;
+        let value = f(reader, header).map_err(|e| e.or_at(offset))?;
+
+        if indefinite {
+            Self::decode_eoc(reader, header.tag)?;
+        }
+
+        Ok(value)
+    }
+
+    /// Consume the end-of-contents octets which terminate the value of an
+    /// indefinite-length TLV with the given tag.
+    pub(crate) fn decode_eoc<'a, R: Reader<'a>>(reader: &mut R, tag: Tag) -> Result<()> {
+        let mut eoc = [0u8; 2];
+        if reader.read_into(&mut eoc)? != [0, 0] {
+            return Err(reader.error(ErrorKind::Length { tag }));
+        }
+
+        Ok(())
+    }
+
+    /// Decode a [`Header`], also returning whether its length was indefinite.
+    ///
+    /// For headers with an indefinite length, the returned [`Header::length`]
+    /// is the length of the contents sans the end-of-contents octets.
+    pub(crate) fn decode_with_indefinite<'a, R: Reader<'a>>(
+        reader: &mut R,
+    ) -> Result<(Header, bool)> {
+        let (tag, constructed) = match reader.peek_byte() {
+            // X.690 Section 8.7.1: BER permits constructed `OCTET STRING`s
+            Some(CONSTRUCTED_OCTET_STRING) if reader.encoding_rules().is_ber() => {
+                reader.read_byte()?;
+                (Tag::OctetString, true)
+            }
+            _ => {
+                let tag = Tag::decode(reader)?;
+                (tag, tag.is_constructed())
+            }
+        };
+
+        // X.690 Section 8.1.3.2: indefinite lengths are only permitted for
+        // constructed values, and only when decoding BER
+        if reader.encoding_rules().is_ber() && reader.peek_byte() == Some(INDEFINITE_LENGTH_OCTET) {
+            if !constructed {
+                return Err(reader.error(ErrorKind::IndefiniteLength));
+            }
+
+            reader.read_byte()?;
+            let length = reader.peek_indefinite_length()?;
+
+            return Ok((
+                Self {
+                    tag,
+                    length,
+                    constructed,
+                },
+                true,
+            ));
+        }
 
         let length = Length::decode(reader).map_err(|e| {
             if e.kind() == ErrorKind::Overlength {
@@ -35,7 +131,20 @@ impl<'a> Decode<'a> for Header {
             }
         })?;
 
-        Ok(Self { tag, length })
+        Ok((
+            Self {
+                tag,
+                length,
+                constructed,
+            },
+            false,
+        ))
+    }
+}
+
+impl<'a> Decode<'a> for Header {
+    fn decode<R: Reader<'a>>(reader: &mut R) -> Result<Header> {
+        Self::decode_with_indefinite(reader).map(|(header, _)| header)
     }
 }
 
@@ -45,7 +154,13 @@ impl Encode for Header {
     }
 
     fn encode(&self, writer: &mut impl Writer) -> Result<()> {
-        self.tag.encode(writer)?;
+        // Preserve the constructed form of BER `OCTET STRING` headers
+        if self.constructed && !self.tag.is_constructed() {
+            writer.write_byte(CONSTRUCTED_OCTET_STRING)?;
+        } else {
+            self.tag.encode(writer)?;
+        }
+
         self.length.encode(writer)
     }
 }
@@ -58,3 +173,23 @@ impl DerOrd for Header {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Header;
+    use crate::{Decode, Encode, EncodingRules, Reader, SliceReader, Tag};
+    use hex_literal::hex;
+
+    #[test]
+    fn round_trip_ber_constructed_octet_string() {
+        let ber = hex!("2408 0402DEAD 0402BEEF");
+        let mut reader = SliceReader::new_with_encoding_rules(&ber, EncodingRules::Ber).unwrap();
+        let header = Header::decode(&mut reader).unwrap();
+        assert_eq!(header.tag, Tag::OctetString);
+        assert!(header.is_constructed());
+
+        let mut buf = [0u8; 2];
+        assert_eq!(header.encode_to_slice(&mut buf).unwrap(), &ber[..2]);
+        assert_eq!(reader.remaining_len(), header.length);
+    }
+}
@@ -19,7 +19,7 @@ const MAX_U32: u32 = 0xfff_ffff;
 ///
 /// > The single octet shall have bit 8 set to one, and bits 7 to
 /// > 1 set to zero.
-const INDEFINITE_LENGTH_OCTET: u8 = 0b10000000; // 0x80
+pub(crate) const INDEFINITE_LENGTH_OCTET: u8 = 0b10000000; // 0x80
 
 /// ASN.1-encoded length.
 ///
@@ -47,12 +47,17 @@
 //! The following ASN.1 types provided by this crate also impl these traits:
 //! - [`Any`], [`AnyRef`]: ASN.1 `ANY`.
 //! - [`BitString`], [`BitStringRef`]: ASN.1 `BIT STRING`
+//! - [`GeneralStringRef`]: ASN.1 `GeneralString`.
 //! - [`GeneralizedTime`]: ASN.1 `GeneralizedTime`.
+//! - [`GraphicStringRef`]: ASN.1 `GraphicString`.
 //! - [`Ia5StringRef`]: ASN.1 `IA5String`.
 //! - [`Null`]: ASN.1 `NULL`.
+//! - [`NumericStringRef`]: ASN.1 `NumericString` (digits and space).
+//! - [`ObjectDescriptor`], [`ObjectDescriptorRef`]: ASN.1 `ObjectDescriptor`.
 //! - [`ObjectIdentifier`]: ASN.1 `OBJECT IDENTIFIER`.
 //! - [`OctetString`], [`OctetStringRef`]: ASN.1 `OCTET STRING`.
 //! - [`PrintableStringRef`]: ASN.1 `PrintableString` (ASCII subset).
+//! - [`RelativeOid`]: ASN.1 `RELATIVE-OID`.
 //! - [`TeletexStringRef`]: ASN.1 `TeletexString`.
 //! - [`VideotexStringRef`]: ASN.1 `VideotexString`.
 //! - [`SequenceOf`]: ASN.1 `SEQUENCE OF`.
@@ -65,6 +70,10 @@
 //! - [`ContextSpecific`]: decoder/encoder for owned context-specific fields
 //! - [`ContextSpecificRef`]: encode-only type for references to context-specific fields
 //!
+//! Fields tagged in the `APPLICATION` or `PRIVATE` class are handled similarly:
+//! - [`Application`], [`ApplicationRef`]: `APPLICATION` class fields
+//! - [`Private`], [`PrivateRef`]: `PRIVATE` class fields
+//!
 //! ## Example
 //! The following example implements X.509's `AlgorithmIdentifier` message type
 //! as defined in [RFC 5280 Section 4.1.1.2].
@@ -207,6 +216,7 @@
 //! When the `derive` feature of this crate is enabled, the following custom
 //! derive macros are available:
 //!
+//! - [`BitStringFlags`]: derive for `BIT STRING` with named bits (see [`der_derive::BitStringFlags`])
 //! - [`Choice`]: derive for `CHOICE` enum (see [`der_derive::Choice`])
 //! - [`Enumerated`]: derive for `ENUMERATED` enum (see [`der_derive::Enumerated`])
 //! - [`Sequence`]: derive for `SEQUENCE` struct (see [`der_derive::Sequence`])
@@ -300,17 +310,27 @@
 //!
 //! [`Any`]: asn1::Any
 //! [`AnyRef`]: asn1::AnyRef
+//! [`Application`]: asn1::Application
+//! [`ApplicationRef`]: asn1::ApplicationRef
 //! [`ContextSpecific`]: asn1::ContextSpecific
 //! [`ContextSpecificRef`]: asn1::ContextSpecificRef
 //! [`BitString`]: asn1::BitString
 //! [`BitStringRef`]: asn1::BitStringRef
+//! [`GeneralStringRef`]: asn1::GeneralStringRef
 //! [`GeneralizedTime`]: asn1::GeneralizedTime
+//! [`GraphicStringRef`]: asn1::GraphicStringRef
 //! [`Ia5StringRef`]: asn1::Ia5StringRef
 //! [`Null`]: asn1::Null
+//! [`NumericStringRef`]: asn1::NumericStringRef
+//! [`ObjectDescriptor`]: asn1::ObjectDescriptor
+//! [`ObjectDescriptorRef`]: asn1::ObjectDescriptorRef
 //! [`ObjectIdentifier`]: asn1::ObjectIdentifier
 //! [`OctetString`]: asn1::OctetString
 //! [`OctetStringRef`]: asn1::OctetStringRef
 //! [`PrintableStringRef`]: asn1::PrintableStringRef
+//! [`Private`]: asn1::Private
+//! [`PrivateRef`]: asn1::PrivateRef
+//! [`RelativeOid`]: asn1::RelativeOid
 //! [`TeletexStringRef`]: asn1::TeletexStringRef
 //! [`VideotexStringRef`]: asn1::VideotexStringRef
 //! [`SequenceOf`]: asn1::SequenceOf
@@ -336,6 +356,7 @@ mod datetime;
 mod decode;
 mod encode;
 mod encode_ref;
+mod encoding_rules;
 mod error;
 mod header;
 mod length;
@@ -358,6 +379,7 @@ pub use crate::{
     decode::{Decode, DecodeOwned, DecodeValue},
     encode::{Encode, EncodeValue},
     encode_ref::{EncodeRef, EncodeValueRef},
+    encoding_rules::EncodingRules,
     error::{Error, ErrorKind, Result},
     header::Header,
     length::{IndefiniteLength, Length},
@@ -376,7 +398,7 @@ pub use crypto_bigint as bigint;
 
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
-pub use der_derive::{Choice, Enumerated, Sequence, ValueOrd};
+pub use der_derive::{BitStringFlags, Choice, Enumerated, Sequence, ValueOrd};
 
 #[cfg(feature = "oid")]
 #[cfg_attr(docsrs, doc(cfg(feature = "oid")))]
@@ -389,6 +411,10 @@ pub use {
     pem_rfc7468 as pem,
 };
 
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use crate::reader::io::IoReader;
+
 #[cfg(feature = "time")]
 #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
 pub use time;
@@ -1,5 +1,7 @@
 //! Reader trait.
 
+#[cfg(feature = "std")]
+pub(crate) mod io;
 mod nested;
 #[cfg(feature = "pem")]
 pub(crate) mod pem;
@@ -8,8 +10,8 @@ pub(crate) mod slice;
 pub(crate) use nested::NestedReader;
 
 use crate::{
-    asn1::ContextSpecific, Decode, DecodeValue, Encode, Error, ErrorKind, FixedTag, Header, Length,
-    Result, Tag, TagMode, TagNumber,
+    asn1::ContextSpecific, Decode, DecodeValue, Encode, EncodingRules, Error, ErrorKind, FixedTag,
+    Header, Length, Result, Tag, TagMode, TagNumber,
 };
 
 #[cfg(feature = "alloc")]
@@ -30,6 +32,14 @@ pub trait Reader<'r>: Sized {
     fn peek_header(&self) -> Result<Header>;
 
     /// Get the position within the buffer.
+    ///
+    /// This is the number of bytes of this reader's input consumed so far.
+    /// For a [`NestedReader`] it's relative to the start of the nested input;
+    /// see [`Reader::offset`] for the position within the original input.
+    ///
+    /// Together with [`Reader::remaining_len`], this can be used to locate the
+    /// exact bytes of a sub-structure within the input, e.g. after peeking at
+    /// its [`Header`].
     fn position(&self) -> Length;
 
     /// Attempt to read data borrowed directly from the input as a slice,
@@ -56,7 +66,16 @@ pub trait Reader<'r>: Sized {
 
     /// Decode a value which impls the [`Decode`] trait.
     fn decode<T: Decode<'r>>(&mut self) -> Result<T> {
-        T::decode(self).map_err(|e| e.nested(self.position()))
+        let offset = self.offset();
+        T::decode(self).map_err(|e| e.or_at(offset))
+    }
+
+    /// Get the [`EncodingRules`] which should be applied when decoding the
+    /// input.
+    ///
+    /// Defaults to [`EncodingRules::Der`].
+    fn encoding_rules(&self) -> EncodingRules {
+        EncodingRules::Der
     }
 
     /// Return an error with the given [`ErrorKind`], annotating it with
@@ -93,6 +112,19 @@ pub trait Reader<'r>: Sized {
         self.position()
     }
 
+    /// Peek forward in the input data, computing the length of the contents
+    /// of an indefinite-length value which begin at the current position.
+    ///
+    /// The returned length doesn't include the end-of-contents octets which
+    /// terminate the value. Indefinite lengths are only permitted when
+    /// decoding BER, so this returns an [`ErrorKind::IndefiniteLength`] error
+    /// unless the reader supports [`EncodingRules::Ber`].
+    ///
+    /// Does not advance the decoder's position.
+    fn peek_indefinite_length(&mut self) -> Result<Length> {
+        Err(ErrorKind::IndefiniteLength.at(self.position()))
+    }
+
     /// Peek at the next byte in the decoder and attempt to decode it as a
     /// [`Tag`] value.
     ///
@@ -154,12 +186,19 @@ pub trait Reader<'r>: Sized {
     where
         F: FnOnce(&mut NestedReader<'n, Self>) -> Result<T>,
     {
-        let header = Header::decode(self)?;
+        let (header, indefinite) = Header::decode_with_indefinite(self)?;
         header.tag.assert_eq(Tag::Sequence)?;
-        self.read_nested(header.length, f)
+
+        let mut reader = NestedReader::new(self, header.length)?;
+        let ret = f(&mut reader)?;
+        reader.finish_tlv(ret, header.tag, indefinite)
     }
 
     /// Obtain a slice of bytes contain a complete TLV production suitable for parsing later.
+    ///
+    /// The default implementation only supports definite lengths: readers
+    /// which support [`EncodingRules::Ber`] override it to also handle
+    /// indefinite lengths.
     fn tlv_bytes(&mut self) -> Result<&'r [u8]> {
         let header = self.peek_header()?;
         let header_len = header.encoded_len()?;
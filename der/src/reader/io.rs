@@ -0,0 +1,297 @@
+//! Streaming reader for [`std::io::Read`] sources.
+
+use super::Reader;
+use crate::{Decode, Encode, Error, ErrorKind, Header, Length, Result, SliceReader};
+use core::cell::RefCell;
+use std::io::Read;
+
+#[allow(clippy::integer_arithmetic)]
+mod utils {
+    use crate::{Error, Result};
+    use alloc::{boxed::Box, vec};
+    use std::io::{self, Read};
+
+    pub(super) struct BufReader<R> {
+        /// Inner I/O reader.
+        inner: R,
+
+        /// Read buffer
+        buf: Box<[u8]>,
+
+        /// Position of the head in the buffer,
+        pos: usize,
+
+        /// Position of the tail in the buffer,
+        cap: usize,
+    }
+
+    impl<R: Read> BufReader<R> {
+        const CAPACITY: usize = 8192;
+
+        pub fn new(inner: R) -> Self {
+            Self {
+                inner,
+                buf: vec![0u8; Self::CAPACITY].into_boxed_slice(),
+                pos: 0,
+                cap: 0,
+            }
+        }
+
+        /// Ensure at least `min` bytes are buffered, unless the end of the
+        /// inner reader is reached first, returning the buffered bytes.
+        pub fn fill_buffer(&mut self, min: usize) -> Result<&[u8]> {
+            debug_assert!(self.pos <= self.cap);
+            debug_assert!(min <= Self::CAPACITY);
+
+            if self.cap - self.pos < min {
+                self.buf.copy_within(self.pos..self.cap, 0);
+                self.cap -= self.pos;
+                self.pos = 0;
+
+                while self.cap < min {
+                    match self.inner.read(&mut self.buf[self.cap..]) {
+                        Ok(0) => break,
+                        Ok(n) => self.cap += n,
+                        Err(err) if err.kind() == io::ErrorKind::Interrupted => (),
+                        Err(err) => return Err(err.into()),
+                    }
+                }
+            }
+
+            Ok(self.as_slice())
+        }
+
+        pub fn peek_byte(&mut self) -> Option<u8> {
+            self.fill_buffer(1).ok()?.first().copied()
+        }
+
+        pub fn copy_to_slice<'o>(&mut self, buf: &'o mut [u8]) -> Result<&'o [u8]> {
+            let mut output_pos = 0;
+
+            while output_pos < buf.len() {
+                let remaining = buf.len() - output_pos;
+
+                // Bypass the read buffer for large reads once it's drained
+                if self.is_empty() && remaining >= Self::CAPACITY {
+                    self.inner.read_exact(&mut buf[output_pos..])?;
+                    break;
+                }
+
+                let available = self.fill_buffer(1)?;
+                if available.is_empty() {
+                    return Err(Error::from(io::Error::from(io::ErrorKind::UnexpectedEof)));
+                }
+
+                let window_len = remaining.min(available.len());
+                buf[output_pos..][..window_len].copy_from_slice(&available[..window_len]);
+                self.pos += window_len;
+                output_pos += window_len;
+            }
+
+            Ok(buf)
+        }
+
+        fn is_empty(&self) -> bool {
+            self.pos == self.cap
+        }
+
+        fn as_slice(&self) -> &[u8] {
+            &self.buf[self.pos..self.cap]
+        }
+    }
+}
+
+/// Maximum length of a [`Header`]: one tag octet followed by up to five
+/// length octets.
+const MAX_HEADER_LEN: usize = 6;
+
+/// `Reader` type which decodes DER from a [`std::io::Read`] source.
+///
+/// The header of the top-level value is read upon construction, which
+/// determines the length of the input. Input is buffered internally so that
+/// values can be decoded (and large `OCTET STRING` contents read in chunks
+/// via [`Reader::read_into`]) without loading the entire input into memory.
+///
+/// Like [`PemReader`][`crate::PemReader`], this reader can't borrow from its
+/// input, so only types which own their data can be decoded from it.
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct IoReader<R> {
+    /// Inner I/O reader wrapped in a BufReader.
+    reader: RefCell<utils::BufReader<R>>,
+
+    /// Header of the top-level value.
+    header: Header,
+
+    /// Input length: the length of the top-level value including its header.
+    input_len: Length,
+
+    /// Position in the input.
+    position: Length,
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<R: Read> IoReader<R> {
+    /// Create a new I/O reader, reading the header of the top-level value
+    /// from the given source.
+    pub fn new(inner: R) -> Result<Self> {
+        let mut reader = utils::BufReader::new(inner);
+        let header = Header::decode(&mut SliceReader::new(reader.fill_buffer(MAX_HEADER_LEN)?)?)?;
+        let input_len = (header.encoded_len()? + header.length)?;
+
+        Ok(Self {
+            reader: RefCell::new(reader),
+            header,
+            input_len,
+            position: Length::ZERO,
+        })
+    }
+
+    /// Get the [`Header`] of the top-level value.
+    ///
+    /// The header is not consumed by [`IoReader::new`], and still needs to be
+    /// decoded before the value itself can be read.
+    pub fn header(&self) -> Header {
+        self.header
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<'r, R: Read> Reader<'r> for IoReader<R> {
+    fn input_len(&self) -> Length {
+        self.input_len
+    }
+
+    fn peek_byte(&self) -> Option<u8> {
+        if self.is_finished() {
+            None
+        } else {
+            self.reader.borrow_mut().peek_byte()
+        }
+    }
+
+    fn peek_header(&self) -> Result<Header> {
+        if self.is_finished() {
+            Err(Error::incomplete(self.offset()))
+        } else {
+            let mut reader = self.reader.borrow_mut();
+            Header::decode(&mut SliceReader::new(reader.fill_buffer(MAX_HEADER_LEN)?)?)
+        }
+    }
+
+    fn position(&self) -> Length {
+        self.position
+    }
+
+    fn read_slice(&mut self, _len: Length) -> Result<&'r [u8]> {
+        // Can't borrow from an I/O source
+        Err(ErrorKind::Reader.into())
+    }
+
+    fn read_into<'o>(&mut self, buf: &'o mut [u8]) -> Result<&'o [u8]> {
+        let len = Length::try_from(buf.len())?;
+
+        if len > self.remaining_len() {
+            return Err(ErrorKind::Incomplete {
+                expected_len: (self.position + len)?,
+                actual_len: self.input_len,
+            }
+            .at(self.position));
+        }
+
+        let bytes = self
+            .reader
+            .get_mut()
+            .copy_to_slice(buf)
+            .map_err(|e| e.nested(self.position))?;
+
+        self.position = (self.position + len)?;
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IoReader;
+    use crate::{Decode, ErrorKind, Header, Length, Reader, Tag};
+    use hex_literal::hex;
+    use std::{io, vec::Vec};
+
+    /// Length of the `OCTET STRING` in [`example_msg`].
+    const CONTENT_LEN: usize = 20000;
+
+    /// I/O source which returns at most a few bytes per read.
+    struct ChunkedReader<'a>(&'a [u8]);
+
+    impl io::Read for ChunkedReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let len = buf.len().min(self.0.len()).min(3);
+            buf[..len].copy_from_slice(&self.0[..len]);
+            self.0 = &self.0[len..];
+            Ok(len)
+        }
+    }
+
+    /// SEQUENCE { INTEGER 42, OCTET STRING (20000 bytes) }
+    fn example_msg() -> Vec<u8> {
+        let mut msg = hex!("30824E27 02012A 04824E20").to_vec();
+        msg.extend((0..CONTENT_LEN).map(|n| n as u8));
+        msg
+    }
+
+    #[test]
+    fn stream_octet_string() {
+        let msg = example_msg();
+        let mut reader = IoReader::new(ChunkedReader(&msg)).unwrap();
+        assert_eq!(
+            reader.header(),
+            Header::new(Tag::Sequence, 20007u16).unwrap()
+        );
+        assert_eq!(reader.input_len(), Length::new(20011));
+        assert_eq!(reader.peek_header().unwrap(), reader.header());
+
+        assert_eq!(Header::decode(&mut reader).unwrap(), reader.header());
+        assert_eq!(reader.decode::<u8>().unwrap(), 42);
+
+        let header = Header::decode(&mut reader).unwrap();
+        assert_eq!(header.tag, Tag::OctetString);
+        assert_eq!(header.length, Length::try_from(CONTENT_LEN).unwrap());
+
+        let mut contents = Vec::new();
+        let mut chunk = [0u8; 4096];
+
+        while !reader.is_finished() {
+            let len = usize::try_from(reader.remaining_len())
+                .unwrap()
+                .min(chunk.len());
+            contents.extend_from_slice(reader.read_into(&mut chunk[..len]).unwrap());
+        }
+
+        assert_eq!(contents, &msg[11..]);
+    }
+
+    #[test]
+    fn read_large_value() {
+        let msg = example_msg();
+        let mut reader = IoReader::new(&msg[..]).unwrap();
+        reader.read_vec(Length::new(11)).unwrap();
+
+        let contents = reader
+            .read_vec(Length::try_from(CONTENT_LEN).unwrap())
+            .unwrap();
+        assert_eq!(contents, &msg[11..]);
+        assert!(reader.is_finished());
+        assert_eq!(reader.peek_byte(), None);
+    }
+
+    #[test]
+    fn truncated_input() {
+        let msg = example_msg();
+        let mut reader = IoReader::new(&msg[..100]).unwrap();
+        reader.read_vec(Length::new(11)).unwrap();
+
+        let err = reader
+            .read_vec(Length::try_from(CONTENT_LEN).unwrap())
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Io(io::ErrorKind::UnexpectedEof));
+    }
+}
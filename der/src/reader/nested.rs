@@ -1,6 +1,6 @@
 //! Reader type for consuming nested TLV records within a DER document.
 
-use crate::{reader::Reader, Error, ErrorKind, Header, Length, Result};
+use crate::{reader::Reader, EncodingRules, Error, ErrorKind, Header, Length, Result, Tag};
 
 /// Reader type used by [`Reader::read_nested`].
 pub struct NestedReader<'i, R> {
@@ -32,6 +32,21 @@ impl<'i, 'r, R: Reader<'r>> NestedReader<'i, R> {
         }
     }
 
+    /// Finish reading the nested input, which is the value of a TLV with the
+    /// given tag, also consuming the end-of-contents octets which terminate
+    /// it if it was encoded with an indefinite length.
+    pub(crate) fn finish_tlv<T>(self, value: T, tag: Tag, indefinite: bool) -> Result<T> {
+        if !self.is_finished() {
+            return self.finish(value);
+        }
+
+        if indefinite {
+            Header::decode_eoc(self.inner, tag)?;
+        }
+
+        Ok(value)
+    }
+
     /// Move the position cursor the given length, returning an error if there
     /// isn't enough remaining data in the nested input.
     fn advance_position(&mut self, len: Length) -> Result<()> {
@@ -72,6 +87,14 @@ impl<'i, 'r, R: Reader<'r>> Reader<'r> for NestedReader<'i, R> {
         }
     }
 
+    fn peek_indefinite_length(&mut self) -> Result<Length> {
+        if self.is_finished() {
+            Err(Error::incomplete(self.offset()))
+        } else {
+            self.inner.peek_indefinite_length()
+        }
+    }
+
     fn position(&self) -> Length {
         self.position
     }
@@ -81,6 +104,20 @@ impl<'i, 'r, R: Reader<'r>> Reader<'r> for NestedReader<'i, R> {
         self.inner.read_slice(len)
     }
 
+    fn tlv_bytes(&mut self) -> Result<&'r [u8]> {
+        if self.is_finished() {
+            return Err(Error::incomplete(self.offset()));
+        }
+
+        let tlv = self.inner.tlv_bytes()?;
+        self.advance_position(Length::try_from(tlv.len())?)?;
+        Ok(tlv)
+    }
+
+    fn encoding_rules(&self) -> EncodingRules {
+        self.inner.encoding_rules()
+    }
+
     fn error(&mut self, kind: ErrorKind) -> Error {
         self.inner.error(kind)
     }
@@ -1,6 +1,13 @@
 //! Slice reader.
 
-use crate::{BytesRef, Decode, Error, ErrorKind, Header, Length, Reader, Result, Tag};
+use crate::{
+    length::INDEFINITE_LENGTH_OCTET, BytesRef, Decode, EncodingRules, Error, ErrorKind, Header,
+    Length, Reader, Result, Tag,
+};
+
+/// Maximum number of nested indefinite-length values whose lengths are
+/// remembered after scanning for the end-of-contents octets of their parent.
+const INDEFINITE_LENGTHS_MAX: usize = 8;
 
 /// [`Reader`] which consumes an input byte slice.
 #[derive(Clone, Debug)]
@@ -8,20 +15,39 @@ pub struct SliceReader<'a> {
     /// Byte slice being decoded.
     bytes: BytesRef<'a>,
 
+    /// Encoding rules to apply when decoding the input.
+    encoding_rules: EncodingRules,
+
     /// Did the decoding operation fail?
     failed: bool,
 
     /// Position within the decoded slice.
     position: Length,
+
+    /// Lengths of the contents of upcoming indefinite-length values, keyed by
+    /// the position at which their contents begin.
+    ///
+    /// These are resolved while scanning an enclosing value for its
+    /// end-of-contents octets, so that nested values don't have to be scanned
+    /// again when they are decoded.
+    indefinite_lengths: [Option<(Length, Length)>; INDEFINITE_LENGTHS_MAX],
 }
 
 impl<'a> SliceReader<'a> {
     /// Create a new slice reader for the given byte slice.
     pub fn new(bytes: &'a [u8]) -> Result<Self> {
+        Self::new_with_encoding_rules(bytes, EncodingRules::Der)
+    }
+
+    /// Create a new slice reader for the given byte slice which decodes it
+    /// using the given [`EncodingRules`].
+    pub fn new_with_encoding_rules(bytes: &'a [u8], encoding_rules: EncodingRules) -> Result<Self> {
         Ok(Self {
             bytes: BytesRef::new(bytes)?,
+            encoding_rules,
             failed: false,
             position: Length::ZERO,
+            indefinite_lengths: [None; INDEFINITE_LENGTHS_MAX],
         })
     }
 
@@ -71,6 +97,89 @@ impl<'a> Reader<'a> for SliceReader<'a> {
         Header::decode(&mut self.clone())
     }
 
+    fn peek_indefinite_length(&mut self) -> Result<Length> {
+        if !self.encoding_rules.is_ber() {
+            return Err(ErrorKind::IndefiniteLength.at(self.position));
+        }
+
+        // Reuse the length if it was resolved while scanning an enclosing value
+        let start = self.position;
+        if let Some((_, length)) = self
+            .indefinite_lengths
+            .iter()
+            .flatten()
+            .find(|(position, _)| *position == start)
+        {
+            return Ok(*length);
+        }
+
+        let mut reader = self.clone();
+
+        // Lengths of the first nested indefinite-length values encountered,
+        // which are the next ones to be decoded after this value
+        let mut lengths = [None; INDEFINITE_LENGTHS_MAX];
+        let mut resolved = 0usize;
+
+        // Stack of the indices in `lengths` of values which haven't been
+        // resolved yet. These are always the outermost nested values.
+        let mut pending = [0usize; INDEFINITE_LENGTHS_MAX];
+        let mut num_pending = 0usize;
+
+        // Number of (nested) indefinite-length values whose end-of-contents
+        // octets haven't been reached yet, not counting the outermost one
+        let mut depth = 0usize;
+
+        loop {
+            let end = reader.position;
+
+            match reader.read_byte()? {
+                // End-of-contents octets
+                0 => {
+                    if reader.read_byte()? != 0 {
+                        return Err(reader.error(ErrorKind::IndefiniteLength));
+                    }
+
+                    if depth == 0 {
+                        self.indefinite_lengths = lengths;
+                        return end - start;
+                    }
+
+                    if depth == num_pending {
+                        num_pending = depth.checked_sub(1).ok_or(ErrorKind::Overflow)?;
+                        if let Some((position, length)) = &mut lengths[pending[num_pending]] {
+                            *length = (end - *position)?;
+                        }
+                    }
+
+                    depth = depth.checked_sub(1).ok_or(ErrorKind::Overflow)?;
+                }
+                // High tag numbers aren't supported
+                tag if tag & 0x1F == 0x1F => return Err(reader.error(ErrorKind::TagNumberInvalid)),
+                tag => {
+                    if reader.peek_byte() == Some(INDEFINITE_LENGTH_OCTET) {
+                        // Only constructed values may have an indefinite length
+                        if tag & 0x20 == 0 {
+                            return Err(reader.error(ErrorKind::IndefiniteLength));
+                        }
+
+                        reader.read_byte()?;
+                        depth = depth.checked_add(1).ok_or(ErrorKind::Overflow)?;
+
+                        if resolved < INDEFINITE_LENGTHS_MAX {
+                            lengths[resolved] = Some((reader.position, Length::ZERO));
+                            pending[num_pending] = resolved;
+                            num_pending = depth;
+                            resolved = resolved.checked_add(1).ok_or(ErrorKind::Overflow)?;
+                        }
+                    } else {
+                        let length = Length::decode(&mut reader)?;
+                        reader.read_slice(length)?;
+                    }
+                }
+            }
+        }
+    }
+
     fn position(&self) -> Length {
         self.position
     }
@@ -92,17 +201,32 @@ impl<'a> Reader<'a> for SliceReader<'a> {
         }
     }
 
+    fn tlv_bytes(&mut self) -> Result<&'a [u8]> {
+        // Decode a copy of the reader to find the end of the TLV, including
+        // the end-of-contents octets of indefinite-length values
+        let mut reader = self.clone();
+        Header::decode_tlv(&mut reader, |reader, header| {
+            reader.read_slice(header.length)
+        })?;
+        self.read_slice((reader.position - self.position)?)
+    }
+
     fn decode<T: Decode<'a>>(&mut self) -> Result<T> {
         if self.is_failed() {
             return Err(self.error(ErrorKind::Failed));
         }
 
+        let offset = self.position;
         T::decode(self).map_err(|e| {
             self.failed = true;
-            e.nested(self.position)
+            e.or_at(offset)
         })
     }
 
+    fn encoding_rules(&self) -> EncodingRules {
+        self.encoding_rules
+    }
+
     fn error(&mut self, kind: ErrorKind) -> Error {
         self.failed = true;
         kind.at(self.position)
@@ -130,8 +254,11 @@ impl<'a> Reader<'a> for SliceReader<'a> {
 
 #[cfg(test)]
 mod tests {
-    use super::SliceReader;
-    use crate::{Decode, ErrorKind, Length, Reader, Tag};
+    use super::{SliceReader, INDEFINITE_LENGTHS_MAX};
+    use crate::{
+        asn1::{AnyRef, Null, OctetStringRef},
+        Decode, EncodingRules, ErrorKind, Header, Length, Reader, Tag, Tagged,
+    };
     use hex_literal::hex;
 
     // INTEGER: 42
@@ -193,6 +320,80 @@ mod tests {
         );
     }
 
+    #[test]
+    fn unexpected_tag_position() {
+        let err = bool::from_der(EXAMPLE_MSG).err().unwrap();
+        assert_eq!(Some(Length::ZERO), err.position());
+        assert_eq!(
+            ErrorKind::TagUnexpected {
+                expected: Some(Tag::Boolean),
+                actual: Tag::Integer
+            },
+            err.kind()
+        );
+    }
+
+    #[test]
+    fn position_and_remaining_len() {
+        // SEQUENCE { INTEGER 1, OCTET STRING } INTEGER 42
+        let msg = hex!("3005 020101 0400 02012A");
+        let mut reader = SliceReader::new(&msg).unwrap();
+        assert_eq!(reader.position(), Length::ZERO);
+        assert_eq!(reader.remaining_len(), Length::from(10u8));
+
+        // Slice out the `SEQUENCE` after peeking at its header
+        let start = usize::try_from(reader.position()).unwrap();
+        let header = reader.peek_header().unwrap();
+        assert_eq!(header.tag, Tag::Sequence);
+        reader
+            .sequence(|reader| {
+                reader.decode::<u8>()?;
+                assert_eq!(reader.position(), Length::from(3u8));
+                assert_eq!(reader.offset(), Length::from(5u8));
+                assert_eq!(reader.remaining_len(), Length::from(2u8));
+                reader.decode::<OctetStringRef<'_>>()
+            })
+            .unwrap();
+
+        let end = usize::try_from(reader.position()).unwrap();
+        assert_eq!(&msg[start..end], &hex!("3005 020101 0400"));
+        assert_eq!(reader.remaining_len(), Length::from(3u8));
+    }
+
+    #[test]
+    fn nested_error_positions() {
+        // SEQUENCE { INTEGER 1, SEQUENCE { INTEGER 1, OCTET STRING } }
+        let msg = hex!("300A 020101 3005 020101 0400");
+        let mut reader = SliceReader::new(&msg).unwrap();
+        let err = reader
+            .sequence(|reader| {
+                reader.decode::<u8>()?;
+                reader.sequence(|reader| {
+                    reader.decode::<u8>()?;
+                    reader.decode::<u8>()
+                })
+            })
+            .err()
+            .unwrap();
+
+        // Offset of the `OCTET STRING` within the outermost message
+        assert_eq!(Some(Length::from(10u8)), err.position());
+
+        // SEQUENCE { INTEGER 1, BOOLEAN (non-canonical) }
+        let msg = hex!("3006 020101 010105");
+        let mut reader = SliceReader::new(&msg).unwrap();
+        let err = reader
+            .sequence(|reader| {
+                reader.decode::<u8>()?;
+                reader.decode::<bool>()
+            })
+            .err()
+            .unwrap();
+
+        assert_eq!(Some(Length::from(5u8)), err.position());
+        assert_eq!(ErrorKind::Noncanonical { tag: Tag::Boolean }, err.kind());
+    }
+
     #[test]
     fn peek_tag() {
         let reader = SliceReader::new(EXAMPLE_MSG).unwrap();
@@ -211,4 +412,119 @@ mod tests {
         assert_eq!(header.length, Length::ONE);
         assert_eq!(reader.position(), Length::ZERO); // Position unchanged
     }
+
+    #[test]
+    fn ber_indefinite_length() {
+        // SEQUENCE (indefinite) { INTEGER 1, INTEGER 2 }
+        let ber = hex!("3080 020101 020102 0000");
+        assert_eq!(<[u8; 2]>::from_ber(&ber).unwrap(), [1, 2]);
+
+        let err = <[u8; 2]>::from_der(&ber).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::IndefiniteLength);
+    }
+
+    #[test]
+    fn ber_nested_indefinite_length() {
+        // SEQUENCE (indefinite) {
+        //   SEQUENCE (indefinite) { INTEGER 1 },
+        //   SEQUENCE { INTEGER 2 } }
+        let ber = hex!("3080 3080 020101 0000 3003 020102 0000");
+        assert_eq!(<[[u8; 1]; 2]>::from_ber(&ber).unwrap(), [[1], [2]]);
+
+        let mut reader = SliceReader::new_with_encoding_rules(&ber, EncodingRules::Ber).unwrap();
+        assert_eq!(reader.peek_header().unwrap().length, Length::new(12));
+
+        let any = AnyRef::decode(&mut reader).unwrap();
+        assert_eq!(any.tag(), Tag::Sequence);
+        assert_eq!(any.value(), &ber[2..14]);
+        assert!(reader.is_finished());
+    }
+
+    #[test]
+    fn ber_sequence_indefinite_length() {
+        // SEQUENCE (indefinite) { INTEGER 1, INTEGER 2 }, NULL
+        let ber = hex!("3080 020101 020102 0000 0500");
+        let mut reader = SliceReader::new_with_encoding_rules(&ber, EncodingRules::Ber).unwrap();
+        let ints = reader
+            .sequence(|reader| Ok([u8::decode(reader)?, u8::decode(reader)?]))
+            .unwrap();
+        assert_eq!(ints, [1, 2]);
+
+        Null::decode(&mut reader).unwrap();
+        assert!(reader.is_finished());
+    }
+
+    #[test]
+    fn ber_tlv_bytes_indefinite_length() {
+        // SEQUENCE (indefinite) {
+        //   SEQUENCE (indefinite) { INTEGER 1 },
+        //   SEQUENCE { INTEGER 2 } }, NULL
+        let ber = hex!("3080 3080 020101 0000 3003 020102 0000 0500");
+        let mut reader = SliceReader::new_with_encoding_rules(&ber, EncodingRules::Ber).unwrap();
+        assert_eq!(reader.tlv_bytes().unwrap(), &ber[..16]);
+        assert_eq!(reader.tlv_bytes().unwrap(), &ber[16..]);
+        assert!(reader.is_finished());
+
+        // Nested within an indefinite-length `SEQUENCE`
+        let mut reader = SliceReader::new_with_encoding_rules(&ber, EncodingRules::Ber).unwrap();
+        let tlvs = reader
+            .sequence(|reader| Ok((reader.tlv_bytes()?, reader.tlv_bytes()?)))
+            .unwrap();
+        assert_eq!(tlvs, (&ber[2..9], &ber[9..14]));
+        assert_eq!(reader.tlv_bytes().unwrap(), &ber[16..]);
+        assert!(reader.is_finished());
+    }
+
+    #[test]
+    fn ber_deeply_nested_indefinite_length() {
+        // Nested more deeply than the lengths a reader remembers
+        const DEPTH: u16 = INDEFINITE_LENGTHS_MAX as u16 * 2 + 1;
+        let mut ber = [0u8; DEPTH as usize * 4 + 3];
+        for i in 0..DEPTH as usize {
+            ber[i * 2..][..2].copy_from_slice(&hex!("3080"));
+        }
+        ber[DEPTH as usize * 2..][..3].copy_from_slice(&hex!("020107"));
+
+        let mut reader = SliceReader::new_with_encoding_rules(&ber, EncodingRules::Ber).unwrap();
+        for depth in 0..DEPTH {
+            let header = Header::decode(&mut reader).unwrap();
+            assert_eq!(header.length, Length::new((DEPTH - depth - 1) * 4 + 3));
+
+            // Lengths of nested values are resolved along with the outermost one
+            if depth == 0 {
+                for (i, resolved) in reader.indefinite_lengths.iter().enumerate() {
+                    let position = (i as u16 + 2) * 2;
+                    let length = (DEPTH - i as u16 - 2) * 4 + 3;
+                    assert_eq!(
+                        resolved.unwrap(),
+                        (Length::new(position), Length::new(length))
+                    );
+                }
+            }
+        }
+
+        assert_eq!(u8::decode(&mut reader).unwrap(), 7);
+        for _ in 0..DEPTH {
+            Header::decode_eoc(&mut reader, Tag::Sequence).unwrap();
+        }
+        assert!(reader.is_finished());
+
+        let any = AnyRef::from_ber(&ber).unwrap();
+        assert_eq!(any.value(), &ber[2..ber.len() - 2]);
+    }
+
+    #[test]
+    fn ber_indefinite_length_errors() {
+        // Primitive values can't have an indefinite length
+        let err = OctetStringRef::from_ber(&hex!("0480 0401aa 0000")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::IndefiniteLength);
+
+        // Missing end-of-contents octets
+        let err = <[u8; 1]>::from_ber(&hex!("3080 020101")).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::Incomplete { .. }));
+
+        // End-of-contents octets must be `00 00`
+        let err = <[u8; 1]>::from_ber(&hex!("3080 020101 0001")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::IndefiniteLength);
+    }
 }
@@ -69,6 +69,12 @@ pub enum Tag {
     /// `OBJECT IDENTIFIER` tag: `6`.
     ObjectIdentifier,
 
+    /// `ObjectDescriptor` tag: `7`.
+    ObjectDescriptor,
+
+    /// `EXTERNAL` tag: `8`.
+    External,
+
     /// `REAL` tag: `9`.
     Real,
 
@@ -78,6 +84,9 @@ pub enum Tag {
     /// `UTF8String` tag: `12`.
     Utf8String,
 
+    /// `RELATIVE-OID` tag: `13`.
+    RelativeOid,
+
     /// `SEQUENCE` tag: `16`.
     Sequence,
 
@@ -105,9 +114,18 @@ pub enum Tag {
     /// `GeneralizedTime` tag: `24`.
     GeneralizedTime,
 
+    /// `GraphicString` tag: `25`.
+    GraphicString,
+
     /// `VisibleString` tag: `26`.
     VisibleString,
 
+    /// `GeneralString` tag: `27`.
+    GeneralString,
+
+    /// `UniversalString` tag: `28`.
+    UniversalString,
+
     /// `BMPString` tag: `30`.
     BmpString,
 
@@ -200,9 +218,12 @@ impl Tag {
             Tag::OctetString => 0x04,
             Tag::Null => 0x05,
             Tag::ObjectIdentifier => 0x06,
+            Tag::ObjectDescriptor => 0x07,
+            Tag::External => 0x08 | CONSTRUCTED_FLAG,
             Tag::Real => 0x09,
             Tag::Enumerated => 0x0A,
             Tag::Utf8String => 0x0C,
+            Tag::RelativeOid => 0x0D,
             Tag::Sequence => 0x10 | CONSTRUCTED_FLAG,
             Tag::Set => 0x11 | CONSTRUCTED_FLAG,
             Tag::NumericString => 0x12,
@@ -212,8 +233,11 @@ impl Tag {
             Tag::Ia5String => 0x16,
             Tag::UtcTime => 0x17,
             Tag::GeneralizedTime => 0x18,
+            Tag::GraphicString => 0x19,
             Tag::VisibleString => 0x1A,
-            Tag::BmpString => 0x1D,
+            Tag::GeneralString => 0x1B,
+            Tag::UniversalString => 0x1C,
+            Tag::BmpString => 0x1E,
             Tag::Application {
                 constructed,
                 number,
@@ -271,9 +295,11 @@ impl TryFrom<u8> for Tag {
             0x04 => Ok(Tag::OctetString),
             0x05 => Ok(Tag::Null),
             0x06 => Ok(Tag::ObjectIdentifier),
+            0x07 => Ok(Tag::ObjectDescriptor),
             0x09 => Ok(Tag::Real),
             0x0A => Ok(Tag::Enumerated),
             0x0C => Ok(Tag::Utf8String),
+            0x0D => Ok(Tag::RelativeOid),
             0x12 => Ok(Tag::NumericString),
             0x13 => Ok(Tag::PrintableString),
             0x14 => Ok(Tag::TeletexString),
@@ -281,8 +307,12 @@ impl TryFrom<u8> for Tag {
             0x16 => Ok(Tag::Ia5String),
             0x17 => Ok(Tag::UtcTime),
             0x18 => Ok(Tag::GeneralizedTime),
+            0x19 => Ok(Tag::GraphicString),
             0x1A => Ok(Tag::VisibleString),
-            0x1d => Ok(Tag::BmpString),
+            0x1B => Ok(Tag::GeneralString),
+            0x1C => Ok(Tag::UniversalString),
+            0x1E => Ok(Tag::BmpString),
+            0x28 => Ok(Tag::External), // constructed
             0x30 => Ok(Tag::Sequence), // constructed
             0x31 => Ok(Tag::Set),      // constructed
             0x40..=0x7E => Ok(Tag::Application {
@@ -347,9 +377,12 @@ impl fmt::Display for Tag {
             Tag::OctetString => f.write_str("OCTET STRING"),
             Tag::Null => f.write_str("NULL"),
             Tag::ObjectIdentifier => f.write_str("OBJECT IDENTIFIER"),
+            Tag::ObjectDescriptor => f.write_str("ObjectDescriptor"),
+            Tag::External => f.write_str("EXTERNAL"),
             Tag::Real => f.write_str("REAL"),
             Tag::Enumerated => f.write_str("ENUMERATED"),
             Tag::Utf8String => f.write_str("UTF8String"),
+            Tag::RelativeOid => f.write_str("RELATIVE-OID"),
             Tag::Set => f.write_str("SET"),
             Tag::NumericString => f.write_str("NumericString"),
             Tag::PrintableString => f.write_str("PrintableString"),
@@ -358,7 +391,10 @@ impl fmt::Display for Tag {
             Tag::Ia5String => f.write_str("IA5String"),
             Tag::UtcTime => f.write_str("UTCTime"),
             Tag::GeneralizedTime => f.write_str("GeneralizedTime"),
+            Tag::GraphicString => f.write_str("GraphicString"),
             Tag::VisibleString => f.write_str("VisibleString"),
+            Tag::GeneralString => f.write_str("GeneralString"),
+            Tag::UniversalString => f.write_str("UniversalString"),
             Tag::BmpString => f.write_str("BMPString"),
             Tag::Sequence => f.write_str("SEQUENCE"),
             Tag::Application {
@@ -411,9 +447,12 @@ mod tests {
         assert_eq!(Tag::OctetString.class(), Class::Universal);
         assert_eq!(Tag::Null.class(), Class::Universal);
         assert_eq!(Tag::ObjectIdentifier.class(), Class::Universal);
+        assert_eq!(Tag::ObjectDescriptor.class(), Class::Universal);
+        assert_eq!(Tag::External.class(), Class::Universal);
         assert_eq!(Tag::Real.class(), Class::Universal);
         assert_eq!(Tag::Enumerated.class(), Class::Universal);
         assert_eq!(Tag::Utf8String.class(), Class::Universal);
+        assert_eq!(Tag::RelativeOid.class(), Class::Universal);
         assert_eq!(Tag::Set.class(), Class::Universal);
         assert_eq!(Tag::NumericString.class(), Class::Universal);
         assert_eq!(Tag::PrintableString.class(), Class::Universal);
@@ -422,6 +461,8 @@ mod tests {
         assert_eq!(Tag::Ia5String.class(), Class::Universal);
         assert_eq!(Tag::UtcTime.class(), Class::Universal);
         assert_eq!(Tag::GeneralizedTime.class(), Class::Universal);
+        assert_eq!(Tag::GraphicString.class(), Class::Universal);
+        assert_eq!(Tag::GeneralString.class(), Class::Universal);
         assert_eq!(Tag::Sequence.class(), Class::Universal);
 
         for num in 0..=30 {
@@ -52,9 +52,30 @@ impl<'a> SliceWriter<'a> {
         self.failed
     }
 
+    /// Get the number of bytes written to the buffer so far.
+    pub fn position(&self) -> Length {
+        self.position
+    }
+
+    /// Rewind the write position to the start of the buffer, clearing any
+    /// previous error, so the buffer can be reused to encode another message.
+    pub fn reset(&mut self) {
+        self.failed = false;
+        self.position = Length::ZERO;
+    }
+
     /// Finish encoding to the buffer, returning a slice containing the data
     /// written to the buffer.
     pub fn finish(self) -> Result<&'a [u8]> {
+        let len = self.as_bytes()?.len();
+        Ok(&self.bytes[..len])
+    }
+
+    /// Borrow the data written to the buffer so far without consuming the
+    /// writer, e.g. before calling [`SliceWriter::reset`].
+    ///
+    /// Performs the same checks as [`SliceWriter::finish`].
+    pub fn as_bytes(&self) -> Result<&[u8]> {
         let position = self.position;
 
         if self.is_failed() {
@@ -146,4 +167,27 @@ mod tests {
         assert_eq!(err.kind(), ErrorKind::Overlength);
         assert_eq!(err.position(), Some(Length::ONE));
     }
+
+    #[test]
+    fn reset() {
+        let mut buffer = [0u8; 8];
+        let mut writer = SliceWriter::new(&mut buffer);
+
+        for n in 0u8..3 {
+            writer.reset();
+            assert_eq!(writer.position(), Length::ZERO);
+
+            n.encode(&mut writer).unwrap();
+            assert_eq!(writer.position(), Length::from(3u8));
+            assert_eq!(writer.as_bytes().unwrap(), &[0x02, 0x01, n]);
+        }
+
+        // Errors are cleared by a reset
+        assert!(writer.error::<()>(ErrorKind::Failed).is_err());
+        assert!(writer.as_bytes().is_err());
+        writer.reset();
+
+        true.encode(&mut writer).unwrap();
+        assert_eq!(writer.finish().unwrap(), &[0x01, 0x01, 0xFF]);
+    }
 }
@@ -9,6 +9,100 @@
 
 #![cfg(all(feature = "derive", feature = "alloc"))]
 
+/// Custom derive test cases for the `BitStringFlags` macro.
+mod bit_string_flags {
+    use der::{BitStringFlags, Decode, Encode, ErrorKind, SliceWriter, Tag};
+    use hex_literal::hex;
+
+    /// X.509 `KeyUsage`.
+    #[derive(BitStringFlags, Copy, Clone, Debug, Default, Eq, PartialEq)]
+    pub struct KeyUsage {
+        pub digital_signature: bool,
+        pub non_repudiation: bool,
+        pub key_encipherment: bool,
+        pub data_encipherment: bool,
+        pub key_agreement: bool,
+        pub key_cert_sign: bool,
+        pub crl_sign: bool,
+        pub encipher_only: bool,
+        pub decipher_only: bool,
+    }
+
+    const EMPTY_DER: &[u8] = &hex!("03 01 00");
+    const DIGITAL_SIGNATURE_DER: &[u8] = &hex!("03 02 07 80");
+    const CA_DER: &[u8] = &hex!("03 02 01 06");
+    const DECIPHER_ONLY_DER: &[u8] = &hex!("03 03 07 00 80");
+
+    fn ca() -> KeyUsage {
+        KeyUsage {
+            key_cert_sign: true,
+            crl_sign: true,
+            ..Default::default()
+        }
+    }
+
+    fn decipher_only() -> KeyUsage {
+        KeyUsage {
+            decipher_only: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn decode() {
+        assert_eq!(KeyUsage::from_der(EMPTY_DER).unwrap(), KeyUsage::default());
+
+        let digital_signature = KeyUsage::from_der(DIGITAL_SIGNATURE_DER).unwrap();
+        assert!(digital_signature.digital_signature);
+        assert!(!digital_signature.non_repudiation);
+
+        assert_eq!(KeyUsage::from_der(CA_DER).unwrap(), ca());
+        assert_eq!(
+            KeyUsage::from_der(DECIPHER_ONLY_DER).unwrap(),
+            decipher_only()
+        );
+    }
+
+    #[test]
+    fn encode() {
+        let mut buf = [0u8; 128];
+
+        let mut encoder = SliceWriter::new(&mut buf);
+        KeyUsage::default().encode(&mut encoder).unwrap();
+        assert_eq!(EMPTY_DER, encoder.finish().unwrap());
+
+        let mut encoder = SliceWriter::new(&mut buf);
+        ca().encode(&mut encoder).unwrap();
+        assert_eq!(CA_DER, encoder.finish().unwrap());
+
+        let mut encoder = SliceWriter::new(&mut buf);
+        decipher_only().encode(&mut encoder).unwrap();
+        assert_eq!(DECIPHER_ONLY_DER, encoder.finish().unwrap());
+    }
+
+    #[test]
+    fn reject_trailing_zero_bits() {
+        let err = KeyUsage::from_der(&hex!("03 02 00 80")).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Noncanonical {
+                tag: Tag::BitString
+            }
+        );
+    }
+
+    #[test]
+    fn reject_unknown_bits() {
+        let err = KeyUsage::from_der(&hex!("03 03 06 00 40")).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Value {
+                tag: Tag::BitString
+            }
+        );
+    }
+}
+
 /// Custom derive test cases for the `Choice` macro.
 mod choice {
     /// `Choice` with `EXPLICIT` tagging.
@@ -149,6 +243,54 @@ mod choice {
             assert_eq!(TIME_DER, encoder.finish().unwrap());
         }
     }
+
+    /// `Choice` with both `IMPLICIT` and `EXPLICIT` tagging.
+    mod mixed {
+        use der::{asn1::Ia5StringRef, Choice, Decode, Encode, SliceWriter};
+        use hex_literal::hex;
+
+        /// `Choice` macro test case for per-variant tagging modes, similar to
+        /// `GeneralName` as defined in RFC 5280.
+        #[derive(Choice, Debug, Eq, PartialEq)]
+        #[asn1(tag_mode = "IMPLICIT")]
+        pub enum MixedChoice<'a> {
+            #[asn1(context_specific = "1")]
+            Email(Ia5StringRef<'a>),
+
+            #[asn1(context_specific = "3", tag_mode = "EXPLICIT")]
+            Number(u8),
+        }
+
+        const EMAIL_DER: &[u8] = &hex!("81 03 61 62 63");
+        const NUMBER_DER: &[u8] = &hex!("a3 03 02 01 05");
+
+        #[test]
+        fn decode() {
+            assert_eq!(
+                MixedChoice::from_der(EMAIL_DER).unwrap(),
+                MixedChoice::Email(Ia5StringRef::new("abc").unwrap())
+            );
+            assert_eq!(
+                MixedChoice::from_der(NUMBER_DER).unwrap(),
+                MixedChoice::Number(5)
+            );
+        }
+
+        #[test]
+        fn encode() {
+            let mut buf = [0u8; 128];
+
+            let mut encoder = SliceWriter::new(&mut buf);
+            MixedChoice::Email(Ia5StringRef::new("abc").unwrap())
+                .encode(&mut encoder)
+                .unwrap();
+            assert_eq!(EMAIL_DER, encoder.finish().unwrap());
+
+            let mut encoder = SliceWriter::new(&mut buf);
+            MixedChoice::Number(5).encode(&mut encoder).unwrap();
+            assert_eq!(NUMBER_DER, encoder.finish().unwrap());
+        }
+    }
 }
 
 /// Custom derive test cases for the `Enumerated` macro.
@@ -203,7 +345,7 @@ mod enumerated {
 mod sequence {
     use der::{
         asn1::{AnyRef, ObjectIdentifier, SetOf},
-        Decode, Encode, Sequence, ValueOrd,
+        Decode, Encode, Enumerated, Sequence, ValueOrd,
     };
     use hex_literal::hex;
 
@@ -332,8 +474,30 @@ mod sequence {
         pub public_key: Option<&'a [u8]>,
     }
 
+    /// X.509 `Version`
+    #[derive(Enumerated, Copy, Clone, Debug, Default, Eq, PartialEq)]
+    #[asn1(type = "INTEGER")]
+    #[repr(u8)]
+    pub enum Version {
+        #[default]
+        V1 = 0,
+        V2 = 1,
+        V3 = 2,
+    }
+
+    /// `SEQUENCE` with a `[0] EXPLICIT Version DEFAULT v1` field, similar to
+    /// X.509 `TBSCertificate`.
+    #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+    pub struct VersionedExample {
+        #[asn1(context_specific = "0", default = "Default::default")]
+        pub version: Version,
+        pub serial_number: u8,
+    }
+
+    const VERSION_V1_DER: &[u8] = &hex!("30 03 02 01 05");
+    const VERSION_V3_DER: &[u8] = &hex!("30 08 a0 03 02 01 02 02 01 05");
+
     /// X.509 extension
-    // TODO(tarcieri): tests for code derived with the `default` attribute
     #[derive(Clone, Debug, Eq, PartialEq, Sequence, ValueOrd)]
     pub struct Extension<'a> {
         extn_id: ObjectIdentifier,
@@ -405,6 +569,26 @@ mod sequence {
         assert_eq!(idp.only_contains_attribute_certs, true);
     }
 
+    #[test]
+    fn default_version_test() {
+        let v1 = VersionedExample::from_der(VERSION_V1_DER).unwrap();
+        assert_eq!(v1.version, Version::V1);
+        assert_eq!(v1.serial_number, 5);
+
+        // Default values are omitted when encoding
+        assert_eq!(v1.to_vec().unwrap(), VERSION_V1_DER);
+
+        let v3 = VersionedExample::from_der(VERSION_V3_DER).unwrap();
+        assert_eq!(v3.version, Version::V3);
+        assert_eq!(v3.to_vec().unwrap(), VERSION_V3_DER);
+
+        let v2 = VersionedExample {
+            version: Version::V2,
+            serial_number: 5,
+        };
+        assert_eq!(v2.to_vec().unwrap(), hex!("30 08 a0 03 02 01 01 02 01 05"));
+    }
+
     // demonstrates default field that is not context specific
     #[test]
     fn extension_test() {
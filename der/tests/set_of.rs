@@ -2,9 +2,19 @@
 
 #![cfg(feature = "alloc")]
 
-use der::{asn1::SetOfVec, DerOrd};
+use der::{
+    asn1::{Null, SequenceOf, SetOf, SetOfVec},
+    Decode, DerOrd, EncodingRules, ErrorKind, Length, Reader, SliceReader,
+};
+use hex_literal::hex;
 use proptest::{prelude::*, string::*};
 
+/// SET OF INTEGER { 2, 1 }
+const MISORDERED_INTEGERS: &[u8] = &hex!("3106020102020101");
+
+/// SET OF INTEGER { 1, 1 }
+const DUPLICATE_INTEGERS: &[u8] = &hex!("3106020101020101");
+
 proptest! {
     #[test]
     fn sort_equiv(bytes in bytes_regex(".{0,64}").unwrap()) {
@@ -16,12 +26,98 @@ proptest! {
     }
 }
 
+#[test]
+fn reject_misordered_integers() {
+    let err = SetOfVec::<u8>::from_der(MISORDERED_INTEGERS).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::SetOrdering);
+
+    let err = SetOf::<u8, 2>::from_der(MISORDERED_INTEGERS).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::SetOrdering);
+}
+
+#[test]
+fn sort_misordered_integers_ber() {
+    let set = SetOfVec::<u8>::from_ber(MISORDERED_INTEGERS).unwrap();
+    assert_eq!(set.as_slice(), &[1, 2]);
+
+    let set = SetOf::<u8, 2>::from_ber(MISORDERED_INTEGERS).unwrap();
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), &[1, 2]);
+}
+
+#[test]
+fn reject_duplicate_integers() {
+    let err = SetOfVec::<u8>::from_der(DUPLICATE_INTEGERS).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::SetOrdering);
+
+    let err = SetOfVec::<u8>::from_ber(DUPLICATE_INTEGERS).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::SetOrdering);
+}
+
+#[test]
+fn decode_bounded() {
+    let set_der = hex!("3109020101020102020103");
+    let mut reader = SliceReader::new(&set_der).unwrap();
+    let set = SetOfVec::<u8>::decode_bounded(&mut reader, 3).unwrap();
+    assert_eq!(set.as_slice(), &[1, 2, 3]);
+
+    let mut reader = SliceReader::new(&set_der).unwrap();
+    let err = SetOfVec::<u8>::decode_bounded(&mut reader, 2).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Overlength);
+    assert_eq!(err.position(), Some(Length::new(8)));
+
+    let mut sequence_der = set_der;
+    sequence_der[0] = 0x30;
+    let mut reader = SliceReader::new(&sequence_der).unwrap();
+    let err = SequenceOf::<u8, 3>::decode_bounded(&mut reader, 2).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Overlength);
+}
+
+#[test]
+fn decode_bounded_ber_indefinite_length() {
+    // SET OF INTEGER (indefinite) { 1, 2, 3 } followed by NULL
+    let set_ber = hex!("3180 020101 020102 020103 0000 0500");
+    let mut reader = SliceReader::new_with_encoding_rules(&set_ber, EncodingRules::Ber).unwrap();
+    let set = SetOfVec::<u8>::decode_bounded(&mut reader, 3).unwrap();
+    assert_eq!(set.as_slice(), &[1, 2, 3]);
+    Null::decode(&mut reader).unwrap();
+    assert!(reader.is_finished());
+
+    let mut reader = SliceReader::new_with_encoding_rules(&set_ber, EncodingRules::Ber).unwrap();
+    let err = SetOfVec::<u8>::decode_bounded(&mut reader, 2).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Overlength);
+
+    let mut sequence_ber = set_ber;
+    sequence_ber[0] = 0x30;
+    let mut reader =
+        SliceReader::new_with_encoding_rules(&sequence_ber, EncodingRules::Ber).unwrap();
+    let sequence = SequenceOf::<u8, 3>::decode_bounded(&mut reader, 3).unwrap();
+    assert_eq!(sequence.iter().copied().collect::<Vec<_>>(), &[1, 2, 3]);
+    Null::decode(&mut reader).unwrap();
+    assert!(reader.is_finished());
+}
+
+#[test]
+fn decode_bounded_rejects_huge_set() {
+    const NUM_ELEMENTS: usize = 10_000_000;
+
+    // SET OF NULL with 10 million elements (20 million bytes of content)
+    let mut bytes = hex!("31 84 01312D00").to_vec();
+    bytes.resize(bytes.len() + NUM_ELEMENTS * 2, 0);
+    for element in bytes[6..].chunks_exact_mut(2) {
+        element[0] = 0x05;
+    }
+
+    let mut reader = SliceReader::new(&bytes).unwrap();
+    let err = SetOfVec::<Null>::decode_bounded(&mut reader, 1000).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Overlength);
+}
+
 /// Set ordering tests.
 #[cfg(all(feature = "derive", feature = "oid"))]
 mod ordering {
     use der::{
         asn1::{AnyRef, ObjectIdentifier, SetOf, SetOfVec},
-        Decode, Sequence, ValueOrd,
+        Decode, ErrorKind, Sequence, ValueOrd,
     };
     use hex_literal::hex;
 
@@ -35,17 +131,43 @@ mod ordering {
     const OUT_OF_ORDER_RDN_EXAMPLE: &[u8] =
         &hex!("311F301106035504030C0A4A4F484E20534D495448300A060355040A0C03313233");
 
-    /// For compatibility reasons, we allow non-canonical DER with out-of-order
-    /// sets in order to match the behavior of other implementations.
+    /// DER requires the elements of a `SET OF` to be sorted.
+    #[test]
+    fn reject_out_of_order_setof() {
+        let err =
+            SetOf::<AttributeTypeAndValue<'_>, 2>::from_der(OUT_OF_ORDER_RDN_EXAMPLE).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SetOrdering);
+    }
+
+    /// Same as above, with `SetOfVec` instead of `SetOf`.
+    #[test]
+    fn reject_out_of_order_setofvec() {
+        let err =
+            SetOfVec::<AttributeTypeAndValue<'_>>::from_der(OUT_OF_ORDER_RDN_EXAMPLE).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SetOrdering);
+    }
+
+    /// For compatibility reasons, out-of-order sets are allowed when decoding
+    /// BER in order to match the behavior of other implementations.
     #[test]
-    fn allow_out_of_order_setof() {
-        assert!(SetOf::<AttributeTypeAndValue<'_>, 2>::from_der(OUT_OF_ORDER_RDN_EXAMPLE).is_ok());
+    fn allow_out_of_order_setof_ber() {
+        let set =
+            SetOf::<AttributeTypeAndValue<'_>, 2>::from_ber(OUT_OF_ORDER_RDN_EXAMPLE).unwrap();
+        assert_eq!(
+            ObjectIdentifier::new("2.5.4.10").unwrap(),
+            set.get(0).unwrap().oid
+        );
     }
 
     /// Same as above, with `SetOfVec` instead of `SetOf`.
     #[test]
-    fn allow_out_of_order_setofvec() {
-        assert!(SetOfVec::<AttributeTypeAndValue<'_>>::from_der(OUT_OF_ORDER_RDN_EXAMPLE).is_ok());
+    fn allow_out_of_order_setofvec_ber() {
+        let set =
+            SetOfVec::<AttributeTypeAndValue<'_>>::from_ber(OUT_OF_ORDER_RDN_EXAMPLE).unwrap();
+        assert_eq!(
+            ObjectIdentifier::new("2.5.4.10").unwrap(),
+            set.get(0).unwrap().oid
+        );
     }
 
     /// Test to ensure ordering is handled correctly.
@@ -5,6 +5,9 @@ use core::fmt;
 #[cfg(feature = "pem")]
 use der::pem;
 
+#[cfg(feature = "bigint")]
+use crate::KeyComponent;
+
 /// Result type
 pub type Result<T> = core::result::Result<T, Error>;
 
@@ -23,6 +26,11 @@ pub enum Error {
     /// a number expected to be a prime was not a prime.
     Crypto,
 
+    /// RSA private key components are inconsistent with each other, as
+    /// reported by [`RsaPrivateKey::validate`][`crate::RsaPrivateKey::validate`].
+    #[cfg(feature = "bigint")]
+    Inconsistent(KeyComponent),
+
     /// PKCS#8 errors.
     #[cfg(feature = "pkcs8")]
     Pkcs8(pkcs8::Error),
@@ -36,6 +44,10 @@ impl fmt::Display for Error {
         match self {
             Error::Asn1(err) => write!(f, "PKCS#1 ASN.1 error: {}", err),
             Error::Crypto => f.write_str("PKCS#1 cryptographic error"),
+            #[cfg(feature = "bigint")]
+            Error::Inconsistent(component) => {
+                write!(f, "PKCS#1 RSA private key inconsistent: {}", component)
+            }
             #[cfg(feature = "pkcs8")]
             Error::Pkcs8(err) => write!(f, "{}", err),
             Error::Version => f.write_str("PKCS#1 version error"),
@@ -62,6 +74,8 @@ impl From<Error> for pkcs8::Error {
         match err {
             Error::Asn1(e) => pkcs8::Error::Asn1(e),
             Error::Crypto | Error::Version => pkcs8::Error::KeyMalformed,
+            #[cfg(feature = "bigint")]
+            Error::Inconsistent(_) => pkcs8::Error::KeyMalformed,
             Error::Pkcs8(e) => e,
         }
     }
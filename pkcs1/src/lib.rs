@@ -47,6 +47,9 @@ pub use crate::{
     traits::{EncodeRsaPrivateKey, EncodeRsaPublicKey},
 };
 
+#[cfg(feature = "bigint")]
+pub use crate::private_key::validate::KeyComponent;
+
 #[cfg(feature = "pem")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
 pub use der::pem::{self, LineEnding};
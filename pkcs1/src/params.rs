@@ -74,7 +74,12 @@ impl FixedTag for TrailerField {
 /// MaskGenAlgorithm ::= AlgorithmIdentifier
 /// ```
 ///
+/// Fields equal to their default value are omitted when encoding, as DER
+/// requires. SHA-1 with either absent or `NULL` parameters is considered equal
+/// to the default, as specified in [RFC 4055 Section 2.1].
+///
 /// [RFC 8017 Appendix 2.3]: https://datatracker.ietf.org/doc/html/rfc8017#appendix-A.2.3
+/// [RFC 4055 Section 2.1]: https://datatracker.ietf.org/doc/html/rfc4055#section-2.1
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RsaPssParams<'a> {
     /// Hash Algorithm
@@ -92,7 +97,7 @@ pub struct RsaPssParams<'a> {
 
 impl<'a> RsaPssParams<'a> {
     fn context_specific_hash(&self) -> Option<ContextSpecificRef<'_, AlgorithmIdentifierRef<'a>>> {
-        if self.hash == SHA_1_AI {
+        if is_sha1(&self.hash) {
             None
         } else {
             Some(ContextSpecificRef {
@@ -106,7 +111,7 @@ impl<'a> RsaPssParams<'a> {
     fn context_specific_mask_gen(
         &self,
     ) -> Option<ContextSpecificRef<'_, AlgorithmIdentifier<AlgorithmIdentifierRef<'a>>>> {
-        if self.mask_gen == default_mgf1_sha1() {
+        if is_mgf1_sha1(&self.mask_gen) {
             None
         } else {
             Some(ContextSpecificRef {
@@ -209,6 +214,22 @@ fn default_mgf1_sha1<'a>() -> AlgorithmIdentifier<AlgorithmIdentifierRef<'a>> {
     }
 }
 
+/// Is `hash` SHA-1, i.e. the default hash algorithm?
+///
+/// [RFC 4055 Section 2.1] requires absent and `NULL` parameters to be
+/// accepted as equivalent encodings of SHA-1, so both are treated as the
+/// default value and omitted when encoding.
+///
+/// [RFC 4055 Section 2.1]: https://datatracker.ietf.org/doc/html/rfc4055#section-2.1
+fn is_sha1(hash: &AlgorithmIdentifierRef<'_>) -> bool {
+    hash.oid == OID_SHA_1 && hash.parameters.map_or(true, AnyRef::is_null)
+}
+
+/// Is `mask_gen` MGF1 with SHA-1, i.e. the default mask generation function?
+fn is_mgf1_sha1(mask_gen: &AlgorithmIdentifier<AlgorithmIdentifierRef<'_>>) -> bool {
+    mask_gen.oid == OID_MGF_1 && mask_gen.parameters.as_ref().map_or(false, is_sha1)
+}
+
 /// PKCS#1 RSAES-OAEP parameters as defined in [RFC 8017 Appendix 2.1]
 ///
 /// ASN.1 structure containing a serialized RSAES-OAEP parameters:
@@ -238,7 +259,7 @@ pub struct RsaOaepParams<'a> {
 
 impl<'a> RsaOaepParams<'a> {
     fn context_specific_hash(&self) -> Option<ContextSpecificRef<'_, AlgorithmIdentifierRef<'a>>> {
-        if self.hash == SHA_1_AI {
+        if is_sha1(&self.hash) {
             None
         } else {
             Some(ContextSpecificRef {
@@ -252,7 +273,7 @@ impl<'a> RsaOaepParams<'a> {
     fn context_specific_mask_gen(
         &self,
     ) -> Option<ContextSpecificRef<'_, AlgorithmIdentifier<AlgorithmIdentifierRef<'a>>>> {
-        if self.mask_gen == default_mgf1_sha1() {
+        if is_mgf1_sha1(&self.mask_gen) {
             None
         } else {
             Some(ContextSpecificRef {
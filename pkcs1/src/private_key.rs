@@ -2,6 +2,8 @@
 
 #[cfg(feature = "alloc")]
 pub(crate) mod other_prime_info;
+#[cfg(feature = "bigint")]
+pub(crate) mod validate;
 
 use crate::{Error, Result, RsaPublicKey, Version};
 use core::fmt;
@@ -66,7 +68,8 @@ pub struct RsaPrivateKey<'a> {
     pub coefficient: UintRef<'a>,
 
     /// Additional primes `r_3`, ..., `r_u`, in order, if this is a multi-prime
-    /// RSA key (i.e. `version` is `multi`).
+    /// RSA key (i.e. `version` is `multi`). Must contain at least one entry
+    /// if present.
     pub other_prime_infos: Option<OtherPrimeInfos<'a>>,
 }
 
@@ -90,6 +93,30 @@ impl<'a> RsaPrivateKey<'a> {
             Version::TwoPrime
         }
     }
+
+    /// Check that the components of this key are consistent with each other.
+    ///
+    /// This checks that the modulus is the product of the primes, and that
+    /// the CRT exponents and coefficients are correct for the private
+    /// exponent and primes, including those of any
+    /// [`RsaPrivateKey::other_prime_infos`]. It doesn't check that the primes
+    /// are actually prime, or that the private exponent is the inverse of the
+    /// public exponent.
+    ///
+    /// Returns [`Error::Inconsistent`] identifying the first component found
+    /// to be inconsistent.
+    ///
+    /// This is intended to catch corrupted keys, and its arithmetic is not
+    /// constant time, so it shouldn't be used on a hot path with secret keys
+    /// an attacker can time.
+    ///
+    /// Components larger than 16384 bits aren't supported, and result in
+    /// [`Error::Crypto`].
+    #[cfg(feature = "bigint")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bigint")))]
+    pub fn validate(&self) -> Result<()> {
+        validate::validate(self)
+    }
 }
 
 impl<'a> DecodeValue<'a> for RsaPrivateKey<'a> {
@@ -114,6 +141,12 @@ impl<'a> DecodeValue<'a> for RsaPrivateKey<'a> {
                 return Err(reader.error(der::ErrorKind::Value { tag: Tag::Integer }));
             }
 
+            // `OtherPrimeInfos ::= SEQUENCE SIZE(1..MAX) OF OtherPrimeInfo`
+            #[cfg(feature = "alloc")]
+            if matches!(&result.other_prime_infos, Some(infos) if infos.is_empty()) {
+                return Err(reader.error(der::ErrorKind::Value { tag: Tag::Sequence }));
+            }
+
             Ok(result)
         })
     }
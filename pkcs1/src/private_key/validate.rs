@@ -0,0 +1,189 @@
+//! Consistency checks for RSA private key components.
+
+use super::RsaPrivateKey;
+use crate::{Error, Result};
+use core::fmt;
+use crypto_bigint::{CheckedMul, CheckedSub, Encoding, NonZero, Uint, U16384, U2048, U4096, U8192};
+use der::asn1::UintRef;
+use zeroize::Zeroizing;
+
+/// Component of an [`RsaPrivateKey`] which is inconsistent with the others,
+/// as reported by [`RsaPrivateKey::validate`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum KeyComponent {
+    /// `modulus` is not the product of the primes.
+    Modulus,
+
+    /// `exponent1` is not `d mod (p-1)`.
+    Exponent1,
+
+    /// `exponent2` is not `d mod (q-1)`.
+    Exponent2,
+
+    /// `coefficient` is not `(inverse of q) mod p`.
+    Coefficient,
+
+    /// `exponent` of the [`OtherPrimeInfo`][`crate::OtherPrimeInfo`] at the
+    /// given index is not `d mod (r_i - 1)`.
+    OtherPrimeExponent(usize),
+
+    /// `coefficient` of the [`OtherPrimeInfo`][`crate::OtherPrimeInfo`] at
+    /// the given index is not `(r_1 * ... * r_(i-1))^(-1) mod r_i`.
+    OtherPrimeCoefficient(usize),
+}
+
+impl fmt::Display for KeyComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyComponent::Modulus => f.write_str("modulus is not the product of the primes"),
+            KeyComponent::Exponent1 => f.write_str("exponent1 is not d mod (p-1)"),
+            KeyComponent::Exponent2 => f.write_str("exponent2 is not d mod (q-1)"),
+            KeyComponent::Coefficient => f.write_str("coefficient is not (inverse of q) mod p"),
+            KeyComponent::OtherPrimeExponent(i) => {
+                write!(f, "exponent of other prime {} is not d mod (r_i - 1)", i)
+            }
+            KeyComponent::OtherPrimeCoefficient(i) => write!(
+                f,
+                "coefficient of other prime {} is not (r_1 * ... * r_(i-1))^(-1) mod r_i",
+                i
+            ),
+        }
+    }
+}
+
+/// Check the relationships between the components of `key`.
+pub(super) fn validate(key: &RsaPrivateKey<'_>) -> Result<()> {
+    let max_len = [
+        key.modulus,
+        key.private_exponent,
+        key.prime1,
+        key.prime2,
+        key.exponent1,
+        key.exponent2,
+        key.coefficient,
+    ]
+    .iter()
+    .map(|uint| uint.as_bytes().len())
+    .max()
+    .unwrap_or(0);
+
+    #[cfg(feature = "alloc")]
+    let max_len = key
+        .other_prime_infos
+        .iter()
+        .flatten()
+        .flat_map(|info| [info.prime, info.exponent, info.coefficient])
+        .fold(max_len, |len, uint| len.max(uint.as_bytes().len()));
+
+    // The products computed while checking a consistent key are at most the
+    // modulus, so the smallest size which fits every component is sufficient
+    match max_len.saturating_mul(8) {
+        0..=2048 => validate_sized::<{ U2048::LIMBS }>(key),
+        2049..=4096 => validate_sized::<{ U4096::LIMBS }>(key),
+        4097..=8192 => validate_sized::<{ U8192::LIMBS }>(key),
+        8193..=16384 => validate_sized::<{ U16384::LIMBS }>(key),
+        _ => Err(Error::Crypto),
+    }
+}
+
+/// Check the relationships between the components of `key` using integers
+/// with the given number of limbs, which must fit every component.
+fn validate_sized<const LIMBS: usize>(key: &RsaPrivateKey<'_>) -> Result<()>
+where
+    Uint<LIMBS>: Encoding,
+{
+    let d = Zeroizing::new(Uint::<LIMBS>::try_from(key.private_exponent)?);
+    let p = Zeroizing::new(Uint::<LIMBS>::try_from(key.prime1)?);
+    let q = Zeroizing::new(Uint::<LIMBS>::try_from(key.prime2)?);
+
+    check_exponent(&d, &p, key.exponent1, KeyComponent::Exponent1)?;
+    check_exponent(&d, &q, key.exponent2, KeyComponent::Exponent2)?;
+    check_coefficient(&q, &p, key.coefficient, KeyComponent::Coefficient)?;
+
+    // Product of the primes seen so far, i.e. `r_1 * ... * r_(i-1)`
+    let product = Zeroizing::new(
+        Option::<Uint<LIMBS>>::from(p.checked_mul(&*q))
+            .ok_or(Error::Inconsistent(KeyComponent::Modulus))?,
+    );
+
+    #[cfg(feature = "alloc")]
+    let product = {
+        let mut product = product;
+
+        for (i, info) in key.other_prime_infos.iter().flatten().enumerate() {
+            let r = Zeroizing::new(Uint::<LIMBS>::try_from(info.prime)?);
+            check_exponent(&d, &r, info.exponent, KeyComponent::OtherPrimeExponent(i))?;
+            check_coefficient(
+                &product,
+                &r,
+                info.coefficient,
+                KeyComponent::OtherPrimeCoefficient(i),
+            )?;
+            *product = Option::from(product.checked_mul(&*r))
+                .ok_or(Error::Inconsistent(KeyComponent::Modulus))?;
+        }
+
+        product
+    };
+
+    if *product != Uint::<LIMBS>::try_from(key.modulus)? {
+        return Err(Error::Inconsistent(KeyComponent::Modulus));
+    }
+
+    Ok(())
+}
+
+/// Check that `exponent == d mod (prime - 1)`.
+fn check_exponent<const LIMBS: usize>(
+    d: &Uint<LIMBS>,
+    prime: &Uint<LIMBS>,
+    exponent: UintRef<'_>,
+    component: KeyComponent,
+) -> Result<()>
+where
+    Uint<LIMBS>: Encoding,
+{
+    let modulus = Option::<Uint<LIMBS>>::from(prime.checked_sub(&Uint::ONE))
+        .and_then(|m| Option::from(NonZero::new(m)))
+        .ok_or(Error::Inconsistent(component))?;
+
+    let expected = Zeroizing::new(d.rem(&modulus));
+
+    if *expected != Uint::<LIMBS>::try_from(exponent)? {
+        return Err(Error::Inconsistent(component));
+    }
+
+    Ok(())
+}
+
+/// Check that `coefficient` is the inverse of `value` modulo `prime`.
+fn check_coefficient<const LIMBS: usize>(
+    value: &Uint<LIMBS>,
+    prime: &Uint<LIMBS>,
+    coefficient: UintRef<'_>,
+    component: KeyComponent,
+) -> Result<()>
+where
+    Uint<LIMBS>: Encoding,
+{
+    let coefficient = Zeroizing::new(Uint::<LIMBS>::try_from(coefficient)?);
+
+    // The coefficient must be fully reduced
+    if *coefficient >= *prime {
+        return Err(Error::Inconsistent(component));
+    }
+
+    let prime = Option::<NonZero<Uint<LIMBS>>>::from(NonZero::new(*prime))
+        .ok_or(Error::Inconsistent(component))?;
+    let product = Zeroizing::new(
+        Option::<Uint<LIMBS>>::from(coefficient.checked_mul(value))
+            .ok_or(Error::Inconsistent(component))?,
+    );
+
+    if product.rem(&prime) != Uint::ONE {
+        return Err(Error::Inconsistent(component));
+    }
+
+    Ok(())
+}
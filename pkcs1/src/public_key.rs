@@ -12,6 +12,19 @@ use der::Document;
 #[cfg(feature = "pem")]
 use der::pem::PemLabel;
 
+#[cfg(feature = "pkcs8")]
+use {
+    crate::{ALGORITHM_ID, ALGORITHM_OID},
+    der::Tag,
+    pkcs8::SubjectPublicKeyInfoRef,
+};
+
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+use {
+    der::asn1::BitString,
+    pkcs8::spki::{SubjectPublicKeyInfo, SubjectPublicKeyInfoOwned},
+};
+
 /// PKCS#1 RSA Public Keys as defined in [RFC 8017 Appendix 1.1].
 ///
 /// ASN.1 structure containing a serialized RSA public key:
@@ -66,6 +79,68 @@ impl<'a> TryFrom<&'a [u8]> for RsaPublicKey<'a> {
     }
 }
 
+/// Extract the [`RsaPublicKey`] from a `SubjectPublicKeyInfo`.
+///
+/// The algorithm must be `rsaEncryption` with `NULL` parameters, as required
+/// by [RFC 3279 Section 2.3.1].
+///
+/// [RFC 3279 Section 2.3.1]: https://www.rfc-editor.org/rfc/rfc3279#section-2.3.1
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl<'a> TryFrom<SubjectPublicKeyInfoRef<'a>> for RsaPublicKey<'a> {
+    type Error = Error;
+
+    fn try_from(spki: SubjectPublicKeyInfoRef<'a>) -> Result<Self> {
+        spki.algorithm.assert_algorithm_oid(ALGORITHM_OID)?;
+        spki.algorithm.assert_parameters_null()?;
+
+        let bytes = spki
+            .subject_public_key
+            .as_bytes()
+            .ok_or_else(|| Tag::BitString.value_error())?;
+
+        Ok(Self::from_der(bytes)?)
+    }
+}
+
+/// Extract the [`RsaPublicKey`] from a `SubjectPublicKeyInfo`.
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "pkcs8"))))]
+impl<'a> TryFrom<&'a SubjectPublicKeyInfoOwned> for RsaPublicKey<'a> {
+    type Error = Error;
+
+    fn try_from(spki: &'a SubjectPublicKeyInfoOwned) -> Result<Self> {
+        Self::try_from(SubjectPublicKeyInfoRef::from(spki))
+    }
+}
+
+/// Wrap an [`RsaPublicKey`] in a `SubjectPublicKeyInfo` with the
+/// `rsaEncryption` algorithm and `NULL` parameters.
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "pkcs8"))))]
+impl TryFrom<RsaPublicKey<'_>> for SubjectPublicKeyInfoOwned {
+    type Error = Error;
+
+    fn try_from(public_key: RsaPublicKey<'_>) -> Result<SubjectPublicKeyInfoOwned> {
+        Self::try_from(&public_key)
+    }
+}
+
+/// Wrap an [`RsaPublicKey`] in a `SubjectPublicKeyInfo` with the
+/// `rsaEncryption` algorithm and `NULL` parameters.
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "pkcs8"))))]
+impl TryFrom<&RsaPublicKey<'_>> for SubjectPublicKeyInfoOwned {
+    type Error = Error;
+
+    fn try_from(public_key: &RsaPublicKey<'_>) -> Result<SubjectPublicKeyInfoOwned> {
+        Ok(SubjectPublicKeyInfo {
+            algorithm: ALGORITHM_ID.into(),
+            subject_public_key: BitString::from_bytes(&public_key.to_vec()?)?,
+        })
+    }
+}
+
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 impl TryFrom<RsaPublicKey<'_>> for Document {
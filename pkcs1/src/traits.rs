@@ -177,11 +177,10 @@ where
     T: for<'a> TryFrom<pkcs8::PrivateKeyInfo<'a>, Error = pkcs8::Error>,
 {
     fn from_pkcs1_der(private_key: &[u8]) -> Result<Self> {
-        Ok(Self::try_from(pkcs8::PrivateKeyInfo {
-            algorithm: ALGORITHM_ID,
+        Ok(Self::try_from(pkcs8::PrivateKeyInfo::new(
+            ALGORITHM_ID,
             private_key,
-            public_key: None,
-        })?)
+        ))?)
     }
 }
 
@@ -1,7 +1,10 @@
 //! PKCS#1 algorithm params tests
 
 use const_oid::db;
-use der::{asn1::OctetStringRef, Encode};
+use der::{
+    asn1::{AnyRef, OctetStringRef},
+    Encode,
+};
 use hex_literal::hex;
 use pkcs1::{RsaOaepParams, RsaPssParams, TrailerField};
 
@@ -9,6 +12,17 @@ use pkcs1::{RsaOaepParams, RsaPssParams, TrailerField};
 const RSA_PSS_PARAMETERS_DEFAULTS: &[u8] = &hex!("3000");
 /// Example PSS parameters using SHA256 instead of SHA1
 const RSA_PSS_PARAMETERS_SHA2_256: &[u8] = &hex!("3030a00d300b0609608648016503040201a11a301806092a864886f70d010108300b0609608648016503040201a203020120");
+/// PSS parameters using SHA256 with `NULL` hash parameters, extracted from a
+/// certificate generated using:
+///
+/// ```text
+/// $ openssl req -x509 -newkey rsa:2048 -nodes -subj "/CN=PSS" -sha256 \
+///     -sigopt rsa_padding_mode:pss -sigopt rsa_pss_saltlen:32 -outform der
+/// ```
+const RSA_PSS_PARAMETERS_SHA2_256_NULL: &[u8] = &hex!("3034a00f300d06096086480165030402010500a11c301a06092a864886f70d010108300d06096086480165030402010500a203020120");
+/// PSS parameters explicitly encoding the default values, with SHA1 using
+/// `NULL` parameters
+const RSA_PSS_PARAMETERS_EXPLICIT_DEFAULTS: &[u8] = &hex!("3031a00b300906052b0e03021a0500a118301606092a864886f70d010108300906052b0e03021a0500a203020114a303020101");
 
 /// Default OAEP parameters using all default values (SHA1, MGF1, Empty)
 const RSA_OAEP_PARAMETERS_DEFAULTS: &[u8] = &hex!("3000");
@@ -81,6 +95,64 @@ fn encode_pss_param_default() {
     );
 }
 
+#[test]
+fn decode_pss_param_null_hash_params() {
+    let param = RsaPssParams::try_from(RSA_PSS_PARAMETERS_SHA2_256_NULL).unwrap();
+
+    assert!(param
+        .hash
+        .assert_algorithm_oid(db::rfc5912::ID_SHA_256)
+        .is_ok());
+    assert_eq!(param.hash.parameters, Some(AnyRef::NULL));
+    assert!(param
+        .mask_gen
+        .assert_algorithm_oid(db::rfc5912::ID_MGF_1)
+        .is_ok());
+    assert_eq!(param.mask_gen.parameters, Some(param.hash));
+    assert_eq!(param.salt_len, 32);
+    assert_eq!(param.trailer_field, TrailerField::BC);
+}
+
+#[test]
+fn encode_pss_param_null_hash_params() {
+    let mut buf = [0_u8; 256];
+    let param = RsaPssParams::try_from(RSA_PSS_PARAMETERS_SHA2_256_NULL).unwrap();
+    assert_eq!(
+        param.encode_to_slice(&mut buf).unwrap(),
+        RSA_PSS_PARAMETERS_SHA2_256_NULL
+    );
+}
+
+#[test]
+fn decode_pss_param_explicit_defaults() {
+    let param = RsaPssParams::try_from(RSA_PSS_PARAMETERS_EXPLICIT_DEFAULTS).unwrap();
+
+    assert!(param
+        .hash
+        .assert_algorithm_oid(db::rfc5912::ID_SHA_1)
+        .is_ok());
+    assert_eq!(param.hash.parameters, Some(AnyRef::NULL));
+    assert!(param
+        .mask_gen
+        .assert_algorithm_oid(db::rfc5912::ID_MGF_1)
+        .is_ok());
+    assert_eq!(param.mask_gen.parameters, Some(param.hash));
+    assert_eq!(param.salt_len, 20);
+    assert_eq!(param.trailer_field, TrailerField::BC);
+}
+
+#[test]
+fn encode_pss_param_explicit_defaults() {
+    // SHA1 with `NULL` parameters is equivalent to the default, so should be
+    // omitted along with the other default values
+    let mut buf = [0_u8; 256];
+    let param = RsaPssParams::try_from(RSA_PSS_PARAMETERS_EXPLICIT_DEFAULTS).unwrap();
+    assert_eq!(
+        param.encode_to_slice(&mut buf).unwrap(),
+        RSA_PSS_PARAMETERS_DEFAULTS
+    );
+}
+
 #[test]
 fn decode_oaep_param() {
     let param = RsaOaepParams::try_from(RSA_OAEP_PARAMETERS_SHA2_256).unwrap();
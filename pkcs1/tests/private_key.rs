@@ -3,6 +3,12 @@
 use hex_literal::hex;
 use pkcs1::{RsaPrivateKey, Version};
 
+#[cfg(feature = "alloc")]
+use pkcs1::der::Encode;
+
+#[cfg(all(feature = "alloc", feature = "bigint"))]
+use pkcs1::{Error, KeyComponent};
+
 /// RSA-2048 PKCS#1 private key encoded as ASN.1 DER.
 ///
 /// Note: this key is extracted from the corresponding `rsa2048-priv.der`
@@ -82,6 +88,107 @@ fn decode_rsa2048_multi_prime_der() {
     assert_eq!(other_prime_infos[0].coefficient.as_bytes(), hex!("39EA226CABFB317E41A5593B9168D1A0124993B45D9CD14A22BD1557CDCB43D28024AC26ED2C8530B53E9B93A878F428807C5282EBB811399F913017CDF2149013D80CDF73F609D6C692475EB7A123D0E93E6A60FC"));
 }
 
+#[cfg(feature = "alloc")]
+#[test]
+fn encode_rsa2048_multi_prime_der() {
+    let key = RsaPrivateKey::try_from(RSA_2048_MULTI_PRIME_DER_EXAMPLE).unwrap();
+    assert_eq!(key.to_vec().unwrap(), RSA_2048_MULTI_PRIME_DER_EXAMPLE);
+}
+
+#[test]
+fn decode_rsa2048_der_version_mismatch() {
+    // Two-prime key with `version` set to `multi`
+    let mut der = RSA_2048_DER_EXAMPLE.to_vec();
+    assert_eq!(der[4..7], [0x02, 0x01, 0x00]);
+    der[6] = 0x01;
+    assert!(RsaPrivateKey::try_from(der.as_slice()).is_err());
+
+    // Multi-prime key with `version` set to `two-prime`
+    let mut der = RSA_2048_MULTI_PRIME_DER_EXAMPLE.to_vec();
+    assert_eq!(der[4..7], [0x02, 0x01, 0x01]);
+    der[6] = 0x00;
+    assert!(RsaPrivateKey::try_from(der.as_slice()).is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn decode_rsa2048_empty_other_prime_infos() {
+    let mut key = RsaPrivateKey::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+    key.other_prime_infos = Some(Vec::new());
+    assert_eq!(key.version(), Version::Multi);
+
+    let der = key.to_vec().unwrap();
+    assert!(RsaPrivateKey::try_from(der.as_slice()).is_err());
+}
+
+#[cfg(all(feature = "alloc", feature = "bigint"))]
+#[test]
+fn validate_rsa_der() {
+    for der in [
+        RSA_2048_DER_EXAMPLE,
+        RSA_4096_DER_EXAMPLE,
+        RSA_2048_MULTI_PRIME_DER_EXAMPLE,
+    ] {
+        RsaPrivateKey::try_from(der).unwrap().validate().unwrap();
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "bigint"))]
+#[test]
+fn validate_rsa2048_der_corrupted_coefficient() {
+    // The coefficient is the last field of a two-prime key
+    let mut der = RSA_2048_DER_EXAMPLE.to_vec();
+    *der.last_mut().unwrap() ^= 1;
+
+    let key = RsaPrivateKey::try_from(der.as_slice()).unwrap();
+    assert_eq!(
+        key.validate(),
+        Err(Error::Inconsistent(KeyComponent::Coefficient))
+    );
+}
+
+#[cfg(all(feature = "alloc", feature = "bigint"))]
+#[test]
+fn validate_rsa2048_multi_prime_der_corrupted_coefficient() {
+    // The coefficient of the third prime is the last field of the key
+    let mut der = RSA_2048_MULTI_PRIME_DER_EXAMPLE.to_vec();
+    *der.last_mut().unwrap() ^= 1;
+
+    let key = RsaPrivateKey::try_from(der.as_slice()).unwrap();
+    assert_eq!(
+        key.validate(),
+        Err(Error::Inconsistent(KeyComponent::OtherPrimeCoefficient(0)))
+    );
+}
+
+#[cfg(all(feature = "alloc", feature = "bigint"))]
+#[test]
+fn validate_rsa2048_der_inconsistent() {
+    let key = RsaPrivateKey::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+    let other_key = RsaPrivateKey::try_from(RSA_4096_DER_EXAMPLE).unwrap();
+
+    let mut bad_key = key.clone();
+    bad_key.exponent1 = key.exponent2;
+    assert_eq!(
+        bad_key.validate(),
+        Err(Error::Inconsistent(KeyComponent::Exponent1))
+    );
+
+    let mut bad_key = key.clone();
+    bad_key.exponent2 = key.exponent1;
+    assert_eq!(
+        bad_key.validate(),
+        Err(Error::Inconsistent(KeyComponent::Exponent2))
+    );
+
+    let mut bad_key = key;
+    bad_key.modulus = other_key.modulus;
+    assert_eq!(
+        bad_key.validate(),
+        Err(Error::Inconsistent(KeyComponent::Modulus))
+    );
+}
+
 #[test]
 fn private_key_to_public_key() {
     let private_key = RsaPrivateKey::try_from(RSA_2048_DER_EXAMPLE).unwrap();
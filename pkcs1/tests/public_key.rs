@@ -3,6 +3,12 @@
 use hex_literal::hex;
 use pkcs1::RsaPublicKey;
 
+#[cfg(feature = "pkcs8")]
+use {pkcs1::ObjectIdentifier, spki::SubjectPublicKeyInfoRef};
+
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+use {der::Encode, spki::SubjectPublicKeyInfoOwned};
+
 /// RSA-2048 PKCS#1 public key encoded as ASN.1 DER.
 ///
 /// Note: this key is extracted from the corresponding `rsa2048-priv.der`
@@ -12,6 +18,15 @@ const RSA_2048_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-pub.der");
 /// RSA-4096 PKCS#1 public key encoded as ASN.1 DER
 const RSA_4096_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa4096-pub.der");
 
+/// RSA-2048 public key encoded as an X.509 `SubjectPublicKeyInfo`, generated
+/// from `rsa2048-pub.der` using:
+///
+/// ```text
+/// $ openssl rsa -RSAPublicKey_in -inform der -in rsa2048-pub.der -pubout -outform der
+/// ```
+#[cfg(feature = "pkcs8")]
+const RSA_2048_SPKI_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-spki.der");
+
 // /// RSA-2048 PKCS#1 public key encoded as PEM
 // #[cfg(feature = "pem")]
 // const RSA_2048_PEM_EXAMPLE: &str = include_str!("examples/rsa2048-pub.pem");
@@ -40,6 +55,35 @@ fn decode_rsa4096_der() {
     assert_eq!(key.public_exponent.as_bytes(), hex!("010001"));
 }
 
+#[test]
+#[cfg(feature = "pkcs8")]
+fn decode_rsa2048_spki_der() {
+    let spki = SubjectPublicKeyInfoRef::try_from(RSA_2048_SPKI_DER_EXAMPLE).unwrap();
+    let key = RsaPublicKey::try_from(spki).unwrap();
+    assert_eq!(key, RsaPublicKey::try_from(RSA_2048_DER_EXAMPLE).unwrap());
+}
+
+#[test]
+#[cfg(feature = "pkcs8")]
+fn decode_rsa2048_spki_der_wrong_params() {
+    let mut spki = SubjectPublicKeyInfoRef::try_from(RSA_2048_SPKI_DER_EXAMPLE).unwrap();
+    spki.algorithm.parameters = None;
+    assert!(RsaPublicKey::try_from(spki).is_err());
+
+    let mut spki = SubjectPublicKeyInfoRef::try_from(RSA_2048_SPKI_DER_EXAMPLE).unwrap();
+    spki.algorithm.oid = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+    assert!(RsaPublicKey::try_from(spki).is_err());
+}
+
+#[test]
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+fn encode_rsa2048_spki_der() {
+    let key = RsaPublicKey::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+    let spki = SubjectPublicKeyInfoOwned::try_from(key).unwrap();
+    assert_eq!(spki.to_vec().unwrap(), RSA_2048_SPKI_DER_EXAMPLE);
+    assert_eq!(RsaPublicKey::try_from(&spki).unwrap(), key);
+}
+
 // TODO(tarcieri): test trait-based PEM decoding
 // #[test]
 // #[cfg(feature = "pem")]
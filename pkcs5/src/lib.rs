@@ -90,6 +90,27 @@ impl<'a> EncryptionScheme<'a> {
         }
     }
 
+    /// Attempt to decrypt the given ciphertext in-place, as with
+    /// [`EncryptionScheme::decrypt_in_place`], but with a custom limit in
+    /// bytes on the memory cost of the scrypt key derivation function.
+    ///
+    /// See [`pbes2::Parameters::decrypt_in_place_with_max_scrypt_memory`].
+    #[cfg(feature = "pbes2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
+    pub fn decrypt_in_place_with_max_scrypt_memory<'b>(
+        &self,
+        password: impl AsRef<[u8]>,
+        buffer: &'b mut [u8],
+        max_memory: usize,
+    ) -> Result<&'b [u8]> {
+        match self {
+            Self::Pbes2(params) => {
+                params.decrypt_in_place_with_max_scrypt_memory(password, buffer, max_memory)
+            }
+            Self::Pbes1(_) => Err(Error::NoPbes1CryptSupport),
+        }
+    }
+
     /// Encrypt the given plaintext, allocating and returning a vector
     /// containing the ciphertext.
     #[cfg(all(feature = "alloc", feature = "pbes2"))]
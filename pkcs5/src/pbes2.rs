@@ -21,6 +21,9 @@ use der::{
 #[cfg(all(feature = "alloc", feature = "pbes2"))]
 use alloc::vec::Vec;
 
+#[cfg(all(feature = "alloc", feature = "pbes2", feature = "zeroize"))]
+use zeroize::Zeroize;
+
 /// 128-bit Advanced Encryption Standard (AES) algorithm with Cipher-Block
 /// Chaining (CBC) mode of operation.
 pub const AES_128_CBC_OID: ObjectIdentifier =
@@ -140,14 +143,28 @@ impl<'a> Parameters<'a> {
 
     /// Attempt to decrypt the given ciphertext, allocating and returning a
     /// byte vector containing the plaintext.
+    ///
+    /// With the `zeroize` feature enabled, the buffer is zeroized if
+    /// decryption fails. The returned plaintext is not zeroized on drop, so
+    /// callers handling secrets should wrap it in `Zeroizing`.
     #[cfg(all(feature = "alloc", feature = "pbes2"))]
     #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
     #[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
     pub fn decrypt(&self, password: impl AsRef<[u8]>, ciphertext: &[u8]) -> Result<Vec<u8>> {
         let mut buffer = ciphertext.to_vec();
-        let pt_len = self.decrypt_in_place(password, &mut buffer)?.len();
-        buffer.truncate(pt_len);
-        Ok(buffer)
+
+        match self.decrypt_in_place(password, &mut buffer) {
+            Ok(plaintext) => {
+                let pt_len = plaintext.len();
+                buffer.truncate(pt_len);
+                Ok(buffer)
+            }
+            Err(err) => {
+                #[cfg(feature = "zeroize")]
+                buffer.as_mut_slice().zeroize();
+                Err(err)
+            }
+        }
     }
 
     /// Attempt to decrypt the given ciphertext in-place using a key derived
@@ -163,7 +180,28 @@ impl<'a> Parameters<'a> {
         password: impl AsRef<[u8]>,
         buffer: &'b mut [u8],
     ) -> Result<&'b [u8]> {
-        encryption::decrypt_in_place(self, password, buffer)
+        self.decrypt_in_place_with_max_scrypt_memory(
+            password,
+            buffer,
+            ScryptParams::DEFAULT_MAX_MEMORY,
+        )
+    }
+
+    /// Attempt to decrypt the given ciphertext in-place, as with
+    /// [`Parameters::decrypt_in_place`], but with a custom limit in bytes on
+    /// the [`ScryptParams::memory_cost`] of the key derivation function.
+    ///
+    /// Returns an error if the KDF is scrypt and its parameters would use
+    /// more memory than `max_memory`. This has no effect on PBKDF2.
+    #[cfg(feature = "pbes2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
+    pub fn decrypt_in_place_with_max_scrypt_memory<'b>(
+        &self,
+        password: impl AsRef<[u8]>,
+        buffer: &'b mut [u8],
+        max_memory: usize,
+    ) -> Result<&'b [u8]> {
+        encryption::decrypt_in_place(self, password, buffer, max_memory)
     }
 
     /// Encrypt the given plaintext, allocating and returning a vector
@@ -177,12 +215,19 @@ impl<'a> Parameters<'a> {
         buffer.extend_from_slice(plaintext);
         buffer.extend_from_slice(&[0u8; AES_BLOCK_SIZE]);
 
-        let ct_len = self
-            .encrypt_in_place(password, &mut buffer, plaintext.len())?
-            .len();
-
-        buffer.truncate(ct_len);
-        Ok(buffer)
+        match self.encrypt_in_place(password, &mut buffer, plaintext.len()) {
+            Ok(ciphertext) => {
+                let ct_len = ciphertext.len();
+                buffer.truncate(ct_len);
+                Ok(buffer)
+            }
+            Err(err) => {
+                // The buffer still holds the plaintext
+                #[cfg(feature = "zeroize")]
+                buffer.as_mut_slice().zeroize();
+                Err(err)
+            }
+        }
     }
 
     /// Encrypt the given plaintext in-place using a key derived from the
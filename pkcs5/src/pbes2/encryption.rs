@@ -17,6 +17,9 @@ use hmac::{
 use pbkdf2::pbkdf2;
 use scrypt::scrypt;
 
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
 /// Maximum size of a derived encryption key
 const MAX_KEY_LEN: usize = 32;
 
@@ -42,7 +45,7 @@ fn cbc_decrypt<'a, C: BlockDecryptMut + BlockCipher + KeyInit>(
     cbc::Decryptor::<C>::new_from_slices(key.as_slice(), iv)
         .map_err(|_| es.to_alg_params_invalid())?
         .decrypt_padded_mut::<Pkcs7>(buffer)
-        .map_err(|_| Error::EncryptFailed)
+        .map_err(|_| Error::DecryptFailed)
 }
 
 pub fn encrypt_in_place<'b>(
@@ -72,11 +75,21 @@ pub fn encrypt_in_place<'b>(
 }
 
 /// Decrypt a message encrypted with PBES2-based key derivation
+///
+/// Returns an error without deriving a key if the KDF is scrypt and its
+/// memory cost exceeds `max_scrypt_memory`.
 pub fn decrypt_in_place<'a>(
     params: &Parameters<'_>,
     password: impl AsRef<[u8]>,
     buf: &'a mut [u8],
+    max_scrypt_memory: usize,
 ) -> Result<&'a [u8]> {
+    if let Kdf::Scrypt(scrypt_params) = &params.kdf {
+        if scrypt_params.memory_cost() > max_scrypt_memory {
+            return Err(params.kdf.to_alg_params_invalid());
+        }
+    }
+
     let es = params.encryption;
     let key = EncryptionKey::derive_from_password(password.as_ref(), &params.kdf, es.key_size())?;
 
@@ -91,8 +104,10 @@ pub fn decrypt_in_place<'a>(
     }
 }
 
-/// Encryption key as derived by PBKDF2
-// TODO(tarcieri): zeroize?
+/// Encryption key as derived by PBKDF2 or scrypt.
+///
+/// With the `zeroize` feature enabled, the key is zeroized on drop. Note that
+/// this doesn't extend to the internal state of the KDF or block cipher.
 struct EncryptionKey {
     buffer: [u8; MAX_KEY_LEN],
     length: usize,
@@ -203,3 +218,10 @@ impl EncryptionKey {
         &self.buffer[..self.length]
     }
 }
+
+#[cfg(feature = "zeroize")]
+impl Drop for EncryptionKey {
+    fn drop(&mut self) {
+        self.buffer.zeroize();
+    }
+}
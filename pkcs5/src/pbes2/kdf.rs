@@ -398,6 +398,29 @@ impl<'a> ScryptParams<'a> {
     #[cfg(feature = "scrypt")]
     const INVALID_ERR: Error = Error::AlgorithmParametersInvalid { oid: SCRYPT_OID };
 
+    /// Default limit on the [`ScryptParams::memory_cost`] of parameters used
+    /// for decryption: 128 MiB.
+    ///
+    /// This is enough for the parameters used by OpenSSL (`N` = 2^14, `r` = 8)
+    /// and the default [`scrypt::Params`] (`N` = 2^15, `r` = 8), but stops
+    /// maliciously large parameters from exhausting memory. A different limit
+    /// can be set using [`Parameters::decrypt_in_place_with_max_scrypt_memory`].
+    ///
+    /// [`Parameters::decrypt_in_place_with_max_scrypt_memory`]: super::Parameters::decrypt_in_place_with_max_scrypt_memory
+    pub const DEFAULT_MAX_MEMORY: usize = 128 * 1024 * 1024;
+
+    /// Amount of memory in bytes needed to derive a key with these
+    /// parameters, which is `128 * r * (N + p)`.
+    pub fn memory_cost(&self) -> usize {
+        let n = usize::from(self.cost_parameter);
+        let r = usize::from(self.block_size);
+        let p = usize::from(self.parallelization);
+
+        128usize
+            .saturating_mul(r)
+            .saturating_mul(n.saturating_add(p))
+    }
+
     /// Get the [`ScryptParams`] for the provided upstream [`scrypt::Params`]
     /// and a provided salt string.
     #[cfg(feature = "scrypt")]
@@ -474,13 +497,13 @@ impl<'a> TryFrom<&ScryptParams<'a>> for scrypt::Params {
     fn try_from(params: &ScryptParams<'a>) -> Result<scrypt::Params> {
         let n = params.cost_parameter;
 
-        // Compute log2 and verify its correctness
-        let log_n = ((8 * core::mem::size_of::<ScryptCost>() as u32) - n.leading_zeros() - 1) as u8;
-
-        if 1 << log_n != n {
+        // `N` must be a power of two, which also rules out zero
+        if !n.is_power_of_two() {
             return Err(ScryptParams::INVALID_ERR);
         }
 
+        let log_n = n.trailing_zeros() as u8;
+
         scrypt::Params::new(
             log_n,
             params.block_size.into(),
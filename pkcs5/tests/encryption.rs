@@ -127,3 +127,73 @@ fn decrypt_pbes2_pbkdf2_sha256_descbc() {
     let plaintext = scheme.decrypt_in_place(PASSWORD, &mut buffer).unwrap();
     assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
 }
+
+#[test]
+fn decrypt_pbes2_scrypt_max_memory() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_SCRYPT_AES256CBC_ALG_ID).unwrap();
+    let params = scheme.pbes2().unwrap();
+
+    // OpenSSL's default parameters: N = 2^14, r = 8, p = 1
+    let memory_cost = params.kdf.scrypt().unwrap().memory_cost();
+    assert_eq!(memory_cost, 128 * 8 * (16384 + 1));
+    assert!(memory_cost <= pkcs5::pbes2::ScryptParams::DEFAULT_MAX_MEMORY);
+
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_SCRYPT);
+    let plaintext = params
+        .decrypt_in_place_with_max_scrypt_memory(PASSWORD, &mut buffer, memory_cost)
+        .unwrap();
+    assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
+
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_SCRYPT);
+    let err = params
+        .decrypt_in_place_with_max_scrypt_memory(PASSWORD, &mut buffer, memory_cost - 1)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        pkcs5::Error::AlgorithmParametersInvalid {
+            oid: pkcs5::pbes2::SCRYPT_OID
+        }
+    );
+    assert_eq!(buffer, ED25519_PKCS8_KEY_CIPHERTEXT_SCRYPT);
+}
+
+#[test]
+fn decrypt_pbes2_scrypt_excessive_memory() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_SCRYPT_AES256CBC_ALG_ID).unwrap();
+    let mut params = scheme.pbes2().unwrap().clone();
+
+    // N = 2^15, r = 65535 would need roughly 275 GiB
+    if let pkcs5::pbes2::Kdf::Scrypt(scrypt_params) = &mut params.kdf {
+        scrypt_params.cost_parameter = 1 << 15;
+        scrypt_params.block_size = u16::MAX;
+    }
+
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_SCRYPT);
+    assert_eq!(
+        params.decrypt_in_place(PASSWORD, &mut buffer),
+        Err(pkcs5::Error::AlgorithmParametersInvalid {
+            oid: pkcs5::pbes2::SCRYPT_OID
+        })
+    );
+}
+
+#[test]
+fn decrypt_pbes2_scrypt_invalid_cost() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_SCRYPT_AES256CBC_ALG_ID).unwrap();
+
+    for cost_parameter in [0, 3, 16383] {
+        let mut params = scheme.pbes2().unwrap().clone();
+
+        if let pkcs5::pbes2::Kdf::Scrypt(scrypt_params) = &mut params.kdf {
+            scrypt_params.cost_parameter = cost_parameter;
+        }
+
+        let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_SCRYPT);
+        assert_eq!(
+            params.decrypt_in_place(PASSWORD, &mut buffer),
+            Err(pkcs5::Error::AlgorithmParametersInvalid {
+                oid: pkcs5::pbes2::SCRYPT_OID
+            })
+        );
+    }
+}
@@ -53,6 +53,6 @@ pub enum CertificateChoices<'a> {
     V2AttrCert(AttributeCertificateV2<'a>),
 
     /// any other certificate forma
-    #[asn1(context_specific = "3", tag_mode = "IMPLICIT")]
+    #[asn1(context_specific = "3", tag_mode = "IMPLICIT", constructed = "true")]
     Other(OtherCertificateFormat<'a>),
 }
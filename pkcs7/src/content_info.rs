@@ -1,13 +1,18 @@
 use crate::{
-    data_content::DataContent, encrypted_data_content::EncryptedDataContent,
+    data_content::DataContent, digested_data_content::DigestedDataContent,
+    encrypted_data_content::EncryptedDataContent, enveloped_data_content::EnvelopedDataContent,
     signed_data_content::SignedDataContent, ContentType,
 };
 
 use der::{
-    asn1::{ContextSpecific, OctetStringRef},
-    DecodeValue, Encode, EncodeValue, Header, Length, Reader, Sequence, TagMode, TagNumber, Writer,
+    asn1::{ContextSpecific, ObjectIdentifier},
+    AnyRef, DecodeValue, Encode, EncodeValue, Header, Length, Reader, Sequence, TagMode, TagNumber,
+    Writer,
 };
 
+#[cfg(feature = "pem")]
+use der::{pem::PemLabel, Decode};
+
 const CONTENT_TAG: TagNumber = TagNumber::new(0);
 
 /// Content exchanged between entities [RFC 5652 § 3](https://datatracker.ietf.org/doc/html/rfc5652#section-3)
@@ -29,20 +34,31 @@ pub enum ContentInfo<'a> {
     /// Content type `signed-data`
     SignedData(Option<SignedDataContent<'a>>),
 
-    /// Catch-all case for content types that are not explicitly supported
-    ///   - enveloped-data
+    /// Content type `enveloped-data`
+    EnvelopedData(Option<EnvelopedDataContent<'a>>),
+
+    /// Content type `digested-data`
+    DigestedData(Option<DigestedDataContent<'a>>),
+
+    /// Catch-all case for content types that are not explicitly supported,
+    /// holding the content type OID and the raw content:
     ///   - signed-and-enveloped-data
-    ///   - digested-data
-    Other((ContentType, Option<OctetStringRef<'a>>)),
+    ///   - any content type not defined by PKCS #7
+    Other((ObjectIdentifier, Option<AnyRef<'a>>)),
 }
 
 impl<'a> ContentInfo<'a> {
-    /// return content type of content info
-    pub fn content_type(&self) -> ContentType {
+    /// return content type OID of content info
+    ///
+    /// Use [`ContentType::try_from`] to convert it into a [`ContentType`]
+    /// when the content type is defined by PKCS#7.
+    pub fn content_type(&self) -> ObjectIdentifier {
         match self {
-            Self::Data(_) => ContentType::Data,
-            Self::EncryptedData(_) => ContentType::EncryptedData,
-            Self::SignedData(_) => ContentType::SignedData,
+            Self::Data(_) => ContentType::Data.into(),
+            Self::EncryptedData(_) => ContentType::EncryptedData.into(),
+            Self::SignedData(_) => ContentType::SignedData.into(),
+            Self::EnvelopedData(_) => ContentType::EnvelopedData.into(),
+            Self::DigestedData(_) => ContentType::DigestedData.into(),
             Self::Other((content_type, _)) => *content_type,
         }
     }
@@ -60,15 +76,20 @@ impl<'a> ContentInfo<'a> {
             ContentType::Data => ContentInfo::Data(None),
             ContentType::EncryptedData => ContentInfo::EncryptedData(None),
             ContentType::SignedData => ContentInfo::SignedData(None),
-            _ => ContentInfo::Other((content_type, None)),
+            ContentType::EnvelopedData => ContentInfo::EnvelopedData(None),
+            ContentType::DigestedData => ContentInfo::DigestedData(None),
+            _ => ContentInfo::Other((content_type.into(), None)),
         }
     }
 
-    /// new Content info of given content type with given raw content
-    pub fn new_raw(content_type: ContentType, content: &'a [u8]) -> der::Result<Self> {
+    /// new Content info of given content type with given raw DER-encoded content
+    pub fn new_raw(
+        content_type: impl Into<ObjectIdentifier>,
+        content: &'a [u8],
+    ) -> der::Result<Self> {
         Ok(ContentInfo::Other((
-            content_type,
-            Some(OctetStringRef::new(content)?),
+            content_type.into(),
+            Some(AnyRef::try_from(content)?),
         )))
     }
 }
@@ -76,24 +97,30 @@ impl<'a> ContentInfo<'a> {
 impl<'a> DecodeValue<'a> for ContentInfo<'a> {
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<ContentInfo<'a>> {
         reader.read_nested(header.length, |reader| {
-            let content_type = reader.decode()?;
-            match content_type {
-                ContentType::Data => Ok(ContentInfo::Data(
+            let oid: ObjectIdentifier = reader.decode()?;
+            match ContentType::try_from(oid) {
+                Ok(ContentType::Data) => Ok(ContentInfo::Data(
                     reader.context_specific::<DataContent<'_>>(CONTENT_TAG, TagMode::Explicit)?,
                 )),
-                ContentType::EncryptedData => Ok(ContentInfo::EncryptedData(
+                Ok(ContentType::EncryptedData) => Ok(ContentInfo::EncryptedData(
                     reader.context_specific(CONTENT_TAG, TagMode::Explicit)?,
                 )),
-                ContentType::SignedData => Ok(ContentInfo::SignedData(
+                Ok(ContentType::SignedData) => Ok(ContentInfo::SignedData(
                     reader.context_specific::<SignedDataContent<'_>>(
                         CONTENT_TAG,
                         TagMode::Explicit,
                     )?,
                 )),
+                Ok(ContentType::EnvelopedData) => Ok(ContentInfo::EnvelopedData(
+                    reader.context_specific(CONTENT_TAG, TagMode::Explicit)?,
+                )),
+                Ok(ContentType::DigestedData) => Ok(ContentInfo::DigestedData(
+                    reader.context_specific(CONTENT_TAG, TagMode::Explicit)?,
+                )),
                 _ => Ok(ContentInfo::Other((
-                    content_type,
-                    reader
-                        .context_specific::<OctetStringRef<'_>>(CONTENT_TAG, TagMode::Explicit)?,
+                    oid,
+                    ContextSpecific::<AnyRef<'_>>::decode_explicit(reader, CONTENT_TAG)?
+                        .map(|field| field.value),
                 ))),
             }
         })
@@ -128,7 +155,23 @@ impl EncodeValue for ContentInfo<'_> {
                         value: d.clone(),
                     })
                     .encoded_len(),
-                Self::Other((_, opt_oct_str)) => opt_oct_str
+                Self::EnvelopedData(data) => data
+                    .as_ref()
+                    .map(|d| ContextSpecific {
+                        tag_number: CONTENT_TAG,
+                        tag_mode: TagMode::Explicit,
+                        value: d.clone(),
+                    })
+                    .encoded_len(),
+                Self::DigestedData(data) => data
+                    .as_ref()
+                    .map(|d| ContextSpecific {
+                        tag_number: CONTENT_TAG,
+                        tag_mode: TagMode::Explicit,
+                        value: *d,
+                    })
+                    .encoded_len(),
+                Self::Other((_, opt_any)) => opt_any
                     .as_ref()
                     .map(|d| ContextSpecific {
                         tag_number: CONTENT_TAG,
@@ -167,7 +210,23 @@ impl EncodeValue for ContentInfo<'_> {
                     value: d.clone(),
                 })
                 .encode(writer)?,
-            Self::Other((_, opt_oct_str)) => opt_oct_str
+            Self::EnvelopedData(data) => data
+                .as_ref()
+                .map(|d| ContextSpecific {
+                    tag_number: CONTENT_TAG,
+                    tag_mode: TagMode::Explicit,
+                    value: d.clone(),
+                })
+                .encode(writer)?,
+            Self::DigestedData(data) => data
+                .as_ref()
+                .map(|d| ContextSpecific {
+                    tag_number: CONTENT_TAG,
+                    tag_mode: TagMode::Explicit,
+                    value: *d,
+                })
+                .encode(writer)?,
+            Self::Other((_, opt_any)) => opt_any
                 .as_ref()
                 .map(|d| ContextSpecific {
                     tag_number: CONTENT_TAG,
@@ -183,6 +242,50 @@ impl EncodeValue for ContentInfo<'_> {
 
 impl<'a> Sequence<'a> for ContentInfo<'a> {}
 
+impl<'a> From<SignedDataContent<'a>> for ContentInfo<'a> {
+    fn from(signed_data: SignedDataContent<'a>) -> Self {
+        ContentInfo::SignedData(Some(signed_data))
+    }
+}
+
+/// PEM encoding with the `PKCS7` label [RFC 7468 § 10](https://datatracker.ietf.org/doc/html/rfc7468#section-10).
+///
+/// Encoding to PEM is provided by [`der::EncodePem`]. As [`ContentInfo`]
+/// borrows from its input, decoding from PEM is done with
+/// [`ContentInfo::from_pem`].
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl PemLabel for ContentInfo<'_> {
+    const PEM_LABEL: &'static str = "PKCS7";
+}
+
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl<'a> ContentInfo<'a> {
+    /// Decode a PEM-encoded [`ContentInfo`] with the `PKCS7` label.
+    ///
+    /// The DER encoding is decoded into `buf`, which the returned
+    /// [`ContentInfo`] borrows from. A buffer as large as `pem` is always
+    /// sufficient.
+    ///
+    /// ```
+    /// # fn main() -> der::Result<()> {
+    /// use pkcs7::ContentInfo;
+    ///
+    /// let pem = "-----BEGIN PKCS7-----\nMAsGCSqGSIb3DQEHAQ==\n-----END PKCS7-----\n";
+    /// let mut buf = [0u8; 64];
+    /// let content_info = ContentInfo::from_pem(pem, &mut buf)?;
+    /// assert!(matches!(content_info, ContentInfo::Data(None)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_pem(pem: impl AsRef<[u8]>, buf: &'a mut [u8]) -> der::Result<Self> {
+        let (label, der_bytes) = der::pem::decode(pem.as_ref(), buf)?;
+        Self::validate_pem_label(label)?;
+        Self::from_der(der_bytes)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{ContentInfo, DataContent};
@@ -0,0 +1,62 @@
+//! `digested-data` content type [RFC 5652 § 7](https://datatracker.ietf.org/doc/html/rfc5652#section-7)
+
+use crate::{cms_version::CmsVersion, encapsulated_content_info::EncapsulatedContentInfo};
+use der::{asn1::OctetStringRef, Sequence};
+use spki::AlgorithmIdentifierRef;
+
+#[cfg(feature = "subtle")]
+use subtle::ConstantTimeEq;
+
+/// ```text
+/// DigestAlgorithmIdentifier ::= AlgorithmIdentifier
+/// ```
+type DigestAlgorithmIdentifier<'a> = AlgorithmIdentifierRef<'a>;
+
+/// Digested-data content type [RFC 5652 § 7](https://datatracker.ietf.org/doc/html/rfc5652#section-7)
+///
+/// ```text
+/// DigestedData ::= SEQUENCE {
+///   version CMSVersion,
+///   digestAlgorithm DigestAlgorithmIdentifier,
+///   encapContentInfo EncapsulatedContentInfo,
+///   digest Digest }
+///
+/// Digest ::= OCTET STRING
+/// ```
+///
+/// The digested-data content type consists of content of any type and a
+/// message digest of the content.
+///
+/// The fields of type DigestedData have the following meanings:
+///   - [`version`](DigestedDataContent::version) is the syntax version number.
+///   - [`digest_algorithm`](DigestedDataContent::digest_algorithm) identifies the
+///     message digest algorithm, and any associated parameters, under which the
+///     content is digested.
+///   - [`encap_content_info`](DigestedDataContent::encap_content_info) is the content
+///     that is digested, as in [EncapsulatedContentInfo].
+///   - [`digest`](DigestedDataContent::digest) is the result of the message digest
+///     process.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Sequence)]
+pub struct DigestedDataContent<'a> {
+    /// the syntax version number.
+    pub version: CmsVersion,
+
+    /// the message digest algorithm
+    pub digest_algorithm: DigestAlgorithmIdentifier<'a>,
+
+    /// the digested content
+    pub encap_content_info: EncapsulatedContentInfo<'a>,
+
+    /// the message digest
+    pub digest: OctetStringRef<'a>,
+}
+
+#[cfg(feature = "subtle")]
+#[cfg_attr(docsrs, doc(cfg(feature = "subtle")))]
+impl<'a> DigestedDataContent<'a> {
+    /// Compare the stored digest against a digest computed by the caller
+    /// over the encapsulated content, in constant time.
+    pub fn verify_digest(&self, computed: &[u8]) -> bool {
+        self.digest.as_bytes().ct_eq(computed).into()
+    }
+}
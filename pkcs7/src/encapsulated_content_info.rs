@@ -1,6 +1,7 @@
 //! `encapsulated-data` content type [RFC 5652 § 5.2](https://datatracker.ietf.org/doc/html/rfc5652#section-5.2)
 
-use der::{AnyRef, Sequence};
+use crate::tst_info::TstInfo;
+use der::{asn1::OctetStringRef, AnyRef, Decode, Sequence};
 use spki::ObjectIdentifier;
 
 /// Encapsulated content information [RFC 5652 § 5.2](https://datatracker.ietf.org/doc/html/rfc5652#section-5.2)
@@ -27,3 +28,22 @@ pub struct EncapsulatedContentInfo<'a> {
     #[asn1(context_specific = "0", optional = "true", tag_mode = "EXPLICIT")]
     pub e_content: Option<AnyRef<'a>>,
 }
+
+impl<'a> EncapsulatedContentInfo<'a> {
+    /// Decode the encapsulated content as a time-stamp token [`TstInfo`].
+    ///
+    /// Returns `Ok(None)` if the content type isn't `id-ct-TSTInfo` or the
+    /// content is absent.
+    pub fn tst_info(&self) -> der::Result<Option<TstInfo<'a>>> {
+        if self.e_content_type != crate::PKCS_9_TST_INFO_OID {
+            return Ok(None);
+        }
+
+        self.e_content
+            .map(|e_content| {
+                let content = e_content.decode_as::<OctetStringRef<'a>>()?;
+                TstInfo::from_der(content.as_bytes())
+            })
+            .transpose()
+    }
+}
@@ -1,12 +1,23 @@
 //! `enveloped-data` content type [RFC 5652 § 6](https://datatracker.ietf.org/doc/html/rfc5652#section-6)
 
-use crate::ContentType;
+use crate::{
+    certificate_choices::CertificateChoices, cms_version::CmsVersion,
+    revocation_info_choices::RevocationInfoChoices, signer_info::IssuerAndSerialNumber,
+    ContentType,
+};
 
-use der::{asn1::OctetStringRef, Sequence};
+use core::cmp::Ordering;
+use der::{
+    asn1::{OctetStringRef, SetOfVec},
+    AnyRef, Choice, DerOrd, Encode, Sequence, ValueOrd,
+};
 use spki::AlgorithmIdentifierRef;
+use x509_cert::{attr::Attribute, ext::pkix::SubjectKeyIdentifier};
 
 type ContentEncryptionAlgorithmIdentifier<'a> = AlgorithmIdentifierRef<'a>;
 
+type KeyEncryptionAlgorithmIdentifier<'a> = AlgorithmIdentifierRef<'a>;
+
 /// Encrypted content information [RFC 5652 § 6](https://datatracker.ietf.org/doc/html/rfc5652#section-6)
 ///
 /// ```text
@@ -46,3 +57,157 @@ pub struct EncryptedContentInfo<'a> {
     #[asn1(context_specific = "0", optional = "true", tag_mode = "IMPLICIT")]
     pub encrypted_content: Option<OctetStringRef<'a>>,
 }
+
+/// Enveloped-data content type [RFC 5652 § 6.1](https://datatracker.ietf.org/doc/html/rfc5652#section-6.1)
+///
+/// ```text
+/// EnvelopedData ::= SEQUENCE {
+///   version CMSVersion,
+///   originatorInfo [0] IMPLICIT OriginatorInfo OPTIONAL,
+///   recipientInfos RecipientInfos,
+///   encryptedContentInfo EncryptedContentInfo,
+///   unprotectedAttrs [1] IMPLICIT UnprotectedAttributes OPTIONAL }
+/// ```
+///
+/// The fields of type `EnvelopedData` have the following meanings:
+///   - [`version`](EnvelopedDataContent::version) is the syntax version number.
+///   - [`originator_info`](EnvelopedDataContent::originator_info) optionally provides
+///     information about the originator.
+///   - [`recipient_infos`](EnvelopedDataContent::recipient_infos) is a collection of
+///     per-recipient information. There must be at least one element in the collection.
+///   - [`encrypted_content_info`](EnvelopedDataContent::encrypted_content_info) is the
+///     encrypted content information, as in [EncryptedContentInfo].
+///   - [`unprotected_attrs`](EnvelopedDataContent::unprotected_attrs) is a collection of
+///     attributes that are not encrypted.
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+pub struct EnvelopedDataContent<'a> {
+    /// the syntax version number.
+    pub version: CmsVersion,
+
+    /// information about the originator
+    #[asn1(context_specific = "0", optional = "true", tag_mode = "IMPLICIT")]
+    pub originator_info: Option<OriginatorInfo<'a>>,
+
+    /// per-recipient information
+    pub recipient_infos: RecipientInfos<'a>,
+
+    /// the encrypted content information.
+    pub encrypted_content_info: EncryptedContentInfo<'a>,
+
+    /// attributes that are not encrypted
+    #[asn1(context_specific = "1", optional = "true", tag_mode = "IMPLICIT")]
+    pub unprotected_attrs: Option<UnprotectedAttributes>,
+}
+
+/// Originator information [RFC 5652 § 6.1](https://datatracker.ietf.org/doc/html/rfc5652#section-6.1)
+///
+/// ```text
+/// OriginatorInfo ::= SEQUENCE {
+///   certs [0] IMPLICIT CertificateSet OPTIONAL,
+///   crls [1] IMPLICIT RevocationInfoChoices OPTIONAL }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+pub struct OriginatorInfo<'a> {
+    /// certs
+    #[asn1(context_specific = "0", optional = "true", tag_mode = "IMPLICIT")]
+    pub certs: Option<SetOfVec<CertificateChoices<'a>>>,
+
+    /// crls
+    #[asn1(context_specific = "1", optional = "true", tag_mode = "IMPLICIT")]
+    pub crls: Option<RevocationInfoChoices<'a>>,
+}
+
+/// ```text
+/// RecipientInfos ::= SET SIZE (1..MAX) OF RecipientInfo
+/// ```
+pub type RecipientInfos<'a> = SetOfVec<RecipientInfo<'a>>;
+
+/// ```text
+/// UnprotectedAttributes ::= SET SIZE (1..MAX) OF Attribute
+/// ```
+pub type UnprotectedAttributes = SetOfVec<Attribute>;
+
+/// Per-recipient information [RFC 5652 § 6.2](https://datatracker.ietf.org/doc/html/rfc5652#section-6.2)
+///
+/// ```text
+/// RecipientInfo ::= CHOICE {
+///   ktri KeyTransRecipientInfo,
+///   kari [1] KeyAgreeRecipientInfo,
+///   kekri [2] KEKRecipientInfo,
+///   pwri [3] PasswordRecipientinfo,
+///   ori [4] OtherRecipientInfo }
+/// ```
+///
+/// Only [`KeyTransRecipientInfo`] is decoded into its fields; the other
+/// alternatives are kept as their raw encoding.
+#[derive(Clone, Debug, Eq, PartialEq, Choice)]
+pub enum RecipientInfo<'a> {
+    /// key transport recipient information
+    Ktri(KeyTransRecipientInfo<'a>),
+
+    /// key agreement recipient information
+    #[asn1(context_specific = "1", tag_mode = "IMPLICIT", constructed = "true")]
+    Kari(AnyRef<'a>),
+
+    /// key encryption key recipient information
+    #[asn1(context_specific = "2", tag_mode = "IMPLICIT", constructed = "true")]
+    Kekri(AnyRef<'a>),
+
+    /// password recipient information
+    #[asn1(context_specific = "3", tag_mode = "IMPLICIT", constructed = "true")]
+    Pwri(AnyRef<'a>),
+
+    /// any other recipient information format
+    #[asn1(context_specific = "4", tag_mode = "IMPLICIT", constructed = "true")]
+    Ori(AnyRef<'a>),
+}
+
+// The derived `ValueOrd` can't compare distinct CHOICE alternatives, so
+// compare the DER encodings instead.
+impl ValueOrd for RecipientInfo<'_> {
+    fn value_cmp(&self, other: &Self) -> der::Result<Ordering> {
+        self.to_vec()?.der_cmp(&other.to_vec()?)
+    }
+}
+
+/// Key transport recipient information [RFC 5652 § 6.2.1](https://datatracker.ietf.org/doc/html/rfc5652#section-6.2.1)
+///
+/// ```text
+/// KeyTransRecipientInfo ::= SEQUENCE {
+///   version CMSVersion,  -- always set to 0 or 2
+///   rid RecipientIdentifier,
+///   keyEncryptionAlgorithm KeyEncryptionAlgorithmIdentifier,
+///   encryptedKey EncryptedKey }
+///
+/// EncryptedKey ::= OCTET STRING
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+pub struct KeyTransRecipientInfo<'a> {
+    /// the syntax version number; 0 for `issuerAndSerialNumber` and 2 for
+    /// `subjectKeyIdentifier` recipient identifiers.
+    pub version: CmsVersion,
+
+    /// the recipient's certificate or key
+    pub rid: RecipientIdentifier,
+
+    /// the key-encryption algorithm used to encrypt the content-encryption key
+    pub key_encryption_algorithm: KeyEncryptionAlgorithmIdentifier<'a>,
+
+    /// the encrypted content-encryption key
+    pub encrypted_key: OctetStringRef<'a>,
+}
+
+/// ```text
+/// RecipientIdentifier ::= CHOICE {
+///   issuerAndSerialNumber IssuerAndSerialNumber,
+///   subjectKeyIdentifier [0] SubjectKeyIdentifier }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Choice)]
+pub enum RecipientIdentifier {
+    /// issuer and serial number
+    IssuerAndSerialNumber(IssuerAndSerialNumber),
+
+    /// subject key identifier
+    #[asn1(context_specific = "0", tag_mode = "IMPLICIT")]
+    SubjectKeyIdentifier(SubjectKeyIdentifier),
+}
@@ -0,0 +1,62 @@
+//! Error types
+
+use core::fmt;
+use der::asn1::ObjectIdentifier;
+
+/// Result type
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Error type
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// ASN.1 DER-related errors.
+    Asn1(der::Error),
+
+    /// The algorithm with the given OID is not supported.
+    AlgorithmUnsupported(ObjectIdentifier),
+
+    /// The signature algorithm implies a different digest algorithm than the
+    /// one used to compute the message digest.
+    AlgorithmMismatch,
+
+    /// The public key is malformed or doesn't match the signature algorithm.
+    KeyMalformed,
+
+    /// The `message-digest` signed attribute is absent or doesn't match the
+    /// digest of the content.
+    MessageDigestMismatch,
+
+    /// The signature is malformed or invalid.
+    SignatureInvalid,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Asn1(err) => write!(f, "PKCS#7 ASN.1 error: {}", err),
+            Error::AlgorithmUnsupported(oid) => write!(f, "unsupported algorithm: {}", oid),
+            Error::AlgorithmMismatch => {
+                f.write_str("PKCS#7 signature and digest algorithms mismatch")
+            }
+            Error::KeyMalformed => f.write_str("PKCS#7 public key malformed"),
+            Error::MessageDigestMismatch => f.write_str("PKCS#7 message digest mismatch"),
+            Error::SignatureInvalid => f.write_str("PKCS#7 signature invalid"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<der::Error> for Error {
+    fn from(err: der::Error) -> Error {
+        Error::Asn1(err)
+    }
+}
+
+impl From<der::ErrorKind> for Error {
+    fn from(err: der::ErrorKind) -> Error {
+        Error::Asn1(err.into())
+    }
+}
@@ -18,17 +18,32 @@
 pub mod certificate_choices;
 pub mod cms_version;
 pub mod data_content;
+pub mod digested_data_content;
 pub mod encapsulated_content_info;
 pub mod encrypted_data_content;
 pub mod enveloped_data_content;
 pub mod revocation_info_choices;
 pub mod signed_data_content;
 pub mod signer_info;
+pub mod tst_info;
 
 mod content_info;
 mod content_type;
+mod error;
 
-pub use crate::{content_info::ContentInfo, content_type::ContentType};
+#[cfg(feature = "verify")]
+mod verify;
+
+pub use crate::{
+    content_info::ContentInfo,
+    content_type::ContentType,
+    error::{Error, Result},
+};
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
 
 use der::asn1::ObjectIdentifier;
 
@@ -57,3 +72,15 @@ pub const PKCS_7_DIGESTED_DATA_OID: ObjectIdentifier =
 /// `pkcs-7 encryptedData` Object Identifier (OID).
 pub const PKCS_7_ENCRYPTED_DATA_OID: ObjectIdentifier =
     ObjectIdentifier::new_unwrap("1.2.840.113549.1.7.6");
+
+/// `pkcs-9 contentType` Object Identifier (OID).
+pub const PKCS_9_CONTENT_TYPE_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.3");
+
+/// `pkcs-9 messageDigest` Object Identifier (OID).
+pub const PKCS_9_MESSAGE_DIGEST_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.4");
+
+/// `id-ct-TSTInfo` Object Identifier (OID).
+pub const PKCS_9_TST_INFO_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.16.1.4");
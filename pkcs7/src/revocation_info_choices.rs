@@ -40,8 +40,11 @@ pub type RevocationInfoChoices<'a> = SetOfVec<RevocationInfoChoice<'a>>;
 /// ```
 #[derive(Clone, Debug, PartialEq, Eq, Sequence)]
 pub struct OtherRevocationInfoFormat<'a> {
-    other_rev_info_format: ObjectIdentifier,
-    other_rev_info: AnyRef<'a>,
+    /// identifies the revocation information format
+    pub other_rev_info_format: ObjectIdentifier,
+
+    /// the revocation information, as defined by `other_rev_info_format`
+    pub other_rev_info: AnyRef<'a>,
 }
 
 // TODO: figure out what ordering makes sense - if any
@@ -1,12 +1,23 @@
 //! `signed-data` content type [RFC 5652 § 5](https://datatracker.ietf.org/doc/html/rfc5652#section-5)
 
 use crate::{
-    certificate_choices::CertificateChoices, cms_version::CmsVersion,
+    certificate_choices::CertificateChoices,
+    cms_version::CmsVersion,
     encapsulated_content_info::EncapsulatedContentInfo,
-    revocation_info_choices::RevocationInfoChoices, signer_info::SignerInfos,
+    revocation_info_choices::{RevocationInfoChoice, RevocationInfoChoices},
+    signer_info::{SignerIdentifier, SignerInfo, SignerInfos},
+    ContentInfo,
+};
+use alloc::vec::Vec;
+use der::{asn1::SetOfVec, AnyRef, Sequence, Tag};
+use spki::{AlgorithmIdentifierRef, ObjectIdentifier};
+use x509_cert::Certificate;
+
+#[cfg(feature = "pem")]
+use {
+    alloc::string::String,
+    der::{pem::LineEnding, EncodePem},
 };
-use der::{asn1::SetOfVec, Sequence};
-use spki::AlgorithmIdentifierRef;
 
 /// ```text
 /// DigestAlgorithmIdentifier ::= AlgorithmIdentifier
@@ -56,3 +67,241 @@ pub struct SignedDataContent<'a> {
     /// signer info
     pub signer_infos: SignerInfos<'a>,
 }
+
+impl<'a> SignedDataContent<'a> {
+    /// Find the certificate of the given signer among the certificates.
+    pub fn signer_certificate(&self, signer_info: &SignerInfo<'_>) -> Option<&Certificate> {
+        self.certificates
+            .iter()
+            .flat_map(|certs| certs.iter())
+            .find_map(|certificate| match certificate {
+                CertificateChoices::Certificate(cert) if signer_info.sid.matches(cert) => {
+                    Some(cert)
+                }
+                _ => None,
+            })
+    }
+
+    /// Compute the syntax version from the contents, following the rules of
+    /// [RFC 5652 § 5.1](https://datatracker.ietf.org/doc/html/rfc5652#section-5.1):
+    ///
+    /// ```text
+    /// IF ((certificates is present) AND
+    ///    (any certificates with a type of other are present)) OR
+    ///    ((crls is present) AND
+    ///    (any crls with a type of other are present))
+    /// THEN version MUST be 5
+    /// ELSE
+    ///    IF (certificates is present) AND
+    ///       (any version 2 attribute certificates are present)
+    ///    THEN version MUST be 4
+    ///    ELSE
+    ///       IF ((certificates is present) AND
+    ///          (any version 1 attribute certificates are present)) OR
+    ///          (any SignerInfo structures are version 3) OR
+    ///          (encapContentInfo eContentType is other than id-data)
+    ///       THEN version MUST be 3
+    ///       ELSE version MUST be 1
+    /// ```
+    ///
+    /// A `SignerInfo` identifying its signer by subject key identifier is
+    /// treated as version 3 regardless of its `version` field.
+    #[allow(deprecated)]
+    pub fn compute_version(&self) -> CmsVersion {
+        let certificates = self.certificates.iter().flat_map(|certs| certs.iter());
+
+        let mut has_other = false;
+        let mut has_v2_attr_cert = false;
+        let mut has_v1_attr_cert = false;
+
+        for certificate in certificates {
+            match certificate {
+                CertificateChoices::Other(_) => has_other = true,
+                CertificateChoices::V2AttrCert(_) => has_v2_attr_cert = true,
+                CertificateChoices::V1AttrCert(_) => has_v1_attr_cert = true,
+                _ => {}
+            }
+        }
+
+        let has_other_crl = self
+            .crls
+            .iter()
+            .flat_map(|crls| crls.iter())
+            .any(|crl| matches!(crl, RevocationInfoChoice::Other(_)));
+
+        if has_other || has_other_crl {
+            return CmsVersion::V5;
+        }
+
+        if has_v2_attr_cert {
+            return CmsVersion::V4;
+        }
+
+        let has_v3_signer = self.signer_infos.iter().any(|signer_info| {
+            signer_info.version == CmsVersion::V3
+                || matches!(signer_info.sid, SignerIdentifier::SubjectKeyIdentifier(_))
+        });
+
+        if has_v1_attr_cert
+            || has_v3_signer
+            || self.encap_content_info.e_content_type != crate::PKCS_7_DATA_OID
+        {
+            CmsVersion::V3
+        } else {
+            CmsVersion::V1
+        }
+    }
+}
+
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl<'a> SignedDataContent<'a> {
+    /// Decode a PEM-encoded `ContentInfo` with the `PKCS7` label whose
+    /// content is `signed-data`.
+    ///
+    /// See [`ContentInfo::from_pem`].
+    pub fn from_pem(pem: impl AsRef<[u8]>, buf: &'a mut [u8]) -> der::Result<Self> {
+        match ContentInfo::from_pem(pem, buf)? {
+            ContentInfo::SignedData(Some(signed_data)) => Ok(signed_data),
+            _ => Err(Tag::Sequence.value_error()),
+        }
+    }
+
+    /// Encode this `signed-data` content as a PEM-encoded `ContentInfo`
+    /// with the `PKCS7` label.
+    pub fn to_pem(&self, line_ending: LineEnding) -> der::Result<String> {
+        ContentInfo::SignedData(Some(self.clone())).to_pem(line_ending)
+    }
+}
+
+/// Builder for [`SignedDataContent`].
+///
+/// The signer infos are expected to be fully formed, i.e. signed by the
+/// caller. The builder takes care of collecting the digest algorithms of all
+/// signers and computing the syntax version.
+#[derive(Clone, Debug)]
+pub struct SignedDataBuilder<'a> {
+    digest_algorithms: Vec<DigestAlgorithmIdentifier<'a>>,
+    encap_content_info: EncapsulatedContentInfo<'a>,
+    certificates: Vec<CertificateChoices<'a>>,
+    crls: Vec<RevocationInfoChoice<'a>>,
+    signer_infos: Vec<SignerInfo<'a>>,
+}
+
+impl<'a> SignedDataBuilder<'a> {
+    /// Create a new builder for signed data with encapsulated content of the
+    /// given content type.
+    pub fn new(content_type: ObjectIdentifier, content: &'a [u8]) -> der::Result<Self> {
+        let e_content = AnyRef::new(Tag::OctetString, content)?;
+        Ok(Self::from_encap_content_info(EncapsulatedContentInfo {
+            e_content_type: content_type,
+            e_content: Some(e_content),
+        }))
+    }
+
+    /// Create a new builder for a detached signature over content of the
+    /// given content type, i.e. with the `eContent` omitted.
+    pub fn new_detached(content_type: ObjectIdentifier) -> Self {
+        Self::from_encap_content_info(EncapsulatedContentInfo {
+            e_content_type: content_type,
+            e_content: None,
+        })
+    }
+
+    /// Create a new builder for a degenerate "certs-only" signed data, as used
+    /// for certificate bundles (`.p7b` files).
+    ///
+    /// This has no signers and no encapsulated content, just certificates:
+    ///
+    /// ```text
+    /// SignedData ::= SEQUENCE {
+    ///     version 1,
+    ///     digestAlgorithms {},
+    ///     encapContentInfo { id-data },
+    ///     certificates [0] IMPLICIT CertificateSet,
+    ///     signerInfos {} }
+    /// ```
+    pub fn certs_only(certificates: impl IntoIterator<Item = Certificate>) -> Self {
+        let mut builder = Self::new_detached(crate::PKCS_7_DATA_OID);
+        for certificate in certificates {
+            builder.add_certificate(CertificateChoices::Certificate(certificate));
+        }
+        builder
+    }
+
+    fn from_encap_content_info(encap_content_info: EncapsulatedContentInfo<'a>) -> Self {
+        Self {
+            digest_algorithms: Vec::new(),
+            encap_content_info,
+            certificates: Vec::new(),
+            crls: Vec::new(),
+            signer_infos: Vec::new(),
+        }
+    }
+
+    /// Add a digest algorithm.
+    ///
+    /// This is only needed for digest algorithms not used by any signer, as
+    /// the digest algorithms of signers are added automatically.
+    pub fn add_digest_algorithm(
+        &mut self,
+        digest_algorithm: DigestAlgorithmIdentifier<'a>,
+    ) -> &mut Self {
+        if !self.digest_algorithms.contains(&digest_algorithm) {
+            self.digest_algorithms.push(digest_algorithm);
+        }
+        self
+    }
+
+    /// Add a certificate.
+    pub fn add_certificate(&mut self, certificate: CertificateChoices<'a>) -> &mut Self {
+        self.certificates.push(certificate);
+        self
+    }
+
+    /// Add a certificate revocation list.
+    pub fn add_crl(&mut self, crl: RevocationInfoChoice<'a>) -> &mut Self {
+        self.crls.push(crl);
+        self
+    }
+
+    /// Add a signer info, along with its digest algorithm.
+    pub fn add_signer_info(&mut self, signer_info: SignerInfo<'a>) -> &mut Self {
+        self.add_digest_algorithm(signer_info.digest_algorithm);
+        self.signer_infos.push(signer_info);
+        self
+    }
+
+    /// Build the [`SignedDataContent`].
+    ///
+    /// The syntax version is computed with [`SignedDataContent::compute_version`].
+    pub fn build(&self) -> der::Result<SignedDataContent<'a>> {
+        let certificates = if self.certificates.is_empty() {
+            None
+        } else {
+            Some(self.certificates.clone().try_into()?)
+        };
+        let crls = if self.crls.is_empty() {
+            None
+        } else {
+            Some(self.crls.clone().try_into()?)
+        };
+
+        let mut signed_data = SignedDataContent {
+            version: CmsVersion::V1,
+            digest_algorithms: self.digest_algorithms.clone().try_into()?,
+            encap_content_info: self.encap_content_info,
+            certificates,
+            crls,
+            signer_infos: self.signer_infos.clone().try_into()?,
+        };
+        signed_data.version = signed_data.compute_version();
+
+        Ok(signed_data)
+    }
+
+    /// Build the [`SignedDataContent`] and wrap it in a [`ContentInfo`].
+    pub fn build_content_info(&self) -> der::Result<ContentInfo<'a>> {
+        Ok(ContentInfo::SignedData(Some(self.build()?)))
+    }
+}
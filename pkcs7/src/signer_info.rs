@@ -1,13 +1,22 @@
 //! `SignerInfo` data type [RFC 5652 § 5.3](https://datatracker.ietf.org/doc/html/rfc5652#section-5.3)
 
 use crate::cms_version::CmsVersion;
+use alloc::vec::Vec;
 use der::{
-    asn1::{OctetStringRef, SetOfVec},
-    Choice, Sequence, ValueOrd,
+    asn1::{ObjectIdentifier, OctetStringRef, SetOfVec},
+    oid::AssociatedOid,
+    Any, Choice, Decode, Encode, Sequence, Tag, ValueOrd,
 };
 use spki::AlgorithmIdentifierRef;
+
 use x509_cert::{
     attr::Attribute, ext::pkix::SubjectKeyIdentifier, name::Name, serial_number::SerialNumber,
+    Certificate,
+};
+#[cfg(feature = "verify")]
+use {
+    crate::{verify, Error},
+    spki::SubjectPublicKeyInfoRef,
 };
 
 /// ```text
@@ -23,17 +32,17 @@ type SignatureAlgorithmIdentifier<'a> = AlgorithmIdentifierRef<'a>;
 /// ```text
 /// SignedAttributes ::= SET SIZE (1..MAX) OF Attribute
 /// ```
-type SignedAttributes<'a> = SetOfVec<Attribute>;
+pub type SignedAttributes = SetOfVec<Attribute>;
 
 /// ```text
 /// UnsignedAttributes ::= SET SIZE (1..MAX) OF Attribute
 /// ```
-type UnsignedAttributes<'a> = SetOfVec<Attribute>;
+pub type UnsignedAttributes = SetOfVec<Attribute>;
 
 /// ```text
 /// SignerIdentifier ::= CHOICE {
-//    issuerAndSerialNumber IssuerAndSerialNumber,
-//    subjectKeyIdentifier [0] SubjectKeyIdentifier }
+///    issuerAndSerialNumber IssuerAndSerialNumber,
+///    subjectKeyIdentifier [0] SubjectKeyIdentifier }
 /// ```
 #[derive(Clone, Debug, PartialEq, Eq, Choice, ValueOrd)]
 pub enum SignerIdentifier {
@@ -41,14 +50,47 @@ pub enum SignerIdentifier {
     IssuerAndSerialNumber(IssuerAndSerialNumber),
 
     /// subject key identifier
-    #[asn1(context_specific = "0")]
+    #[asn1(context_specific = "0", tag_mode = "IMPLICIT")]
     SubjectKeyIdentifier(SubjectKeyIdentifier),
 }
 
+impl SignerIdentifier {
+    /// Whether this identifies the given certificate.
+    ///
+    /// A subject key identifier only matches certificates having a
+    /// `SubjectKeyIdentifier` extension with the same value.
+    pub fn matches(&self, certificate: &Certificate) -> bool {
+        let tbs_certificate = &certificate.tbs_certificate;
+
+        match self {
+            Self::IssuerAndSerialNumber(sid) => {
+                sid.issuer == tbs_certificate.issuer
+                    && sid.serial_number == tbs_certificate.serial_number
+            }
+            Self::SubjectKeyIdentifier(ski) => tbs_certificate
+                .extensions
+                .iter()
+                .flatten()
+                .filter(|extension| extension.extn_id == SubjectKeyIdentifier::OID)
+                .any(|extension| {
+                    SubjectKeyIdentifier::from_der(extension.extn_value.as_bytes())
+                        .map_or(false, |cert_ski| cert_ski == *ski)
+                }),
+        }
+    }
+}
+
+/// ```text
+/// IssuerAndSerialNumber ::= SEQUENCE {
+///    issuer Name,
+///    serialNumber CertificateSerialNumber }
+/// ```
 #[derive(Clone, Debug, Eq, PartialEq, Sequence, ValueOrd)]
-#[allow(missing_docs)]
 pub struct IssuerAndSerialNumber {
-    pub name: Name,
+    /// the distinguished name of the certificate issuer
+    pub issuer: Name,
+
+    /// the certificate serial number
     pub serial_number: SerialNumber,
 }
 
@@ -82,7 +124,7 @@ pub struct SignerInfo<'a> {
 
     /// the signed attributes
     #[asn1(context_specific = "0", tag_mode = "IMPLICIT", optional = "true")]
-    pub signed_attributes: Option<SignedAttributes<'a>>,
+    pub signed_attributes: Option<SignedAttributes>,
 
     /// the signature algorithm
     pub signature_algorithm: SignatureAlgorithmIdentifier<'a>,
@@ -92,5 +134,97 @@ pub struct SignerInfo<'a> {
 
     /// the unsigned attributes
     #[asn1(context_specific = "1", tag_mode = "IMPLICIT", optional = "true")]
-    pub unsigned_attributes: Option<UnsignedAttributes<'a>>,
+    pub unsigned_attributes: Option<UnsignedAttributes>,
+}
+
+impl<'a> SignerInfo<'a> {
+    /// Get the value of the signed attribute of the given type.
+    ///
+    /// Returns `Ok(None)` if there are no signed attributes or the attribute
+    /// is absent, and an error if the attribute occurs more than once or
+    /// doesn't have exactly one value.
+    pub fn signed_attribute(&self, oid: ObjectIdentifier) -> der::Result<Option<&Any>> {
+        let attributes = match &self.signed_attributes {
+            Some(attributes) => attributes,
+            None => return Ok(None),
+        };
+
+        let mut matching = attributes.iter().filter(|attr| attr.oid == oid);
+
+        let attr = match matching.next() {
+            Some(attr) => attr,
+            None => return Ok(None),
+        };
+
+        if matching.next().is_some() || attr.values.len() != 1 {
+            return Err(Tag::Set.value_error());
+        }
+
+        Ok(attr.values.get(0))
+    }
+
+    /// Get the `content-type` signed attribute, which identifies the type of
+    /// the content that was signed.
+    pub fn content_type(&self) -> der::Result<Option<ObjectIdentifier>> {
+        self.signed_attribute(crate::PKCS_9_CONTENT_TYPE_OID)?
+            .map(|value| value.decode_as())
+            .transpose()
+    }
+
+    /// Get the `message-digest` signed attribute, which holds the digest of
+    /// the content that was signed.
+    pub fn message_digest(&self) -> der::Result<Option<OctetStringRef<'_>>> {
+        self.signed_attribute(crate::PKCS_9_MESSAGE_DIGEST_OID)?
+            .map(|value| value.decode_as())
+            .transpose()
+    }
+
+    /// Encode the signed attributes as the input to the signature algorithm.
+    ///
+    /// Per [RFC 5652 § 5.4](https://datatracker.ietf.org/doc/html/rfc5652#section-5.4)
+    /// the signature is computed over the DER encoding of the attributes
+    /// with an EXPLICIT `SET OF` tag, not the `[0] IMPLICIT` tag used when
+    /// they're stored in a `SignerInfo`.
+    pub fn signed_attributes_der(&self) -> der::Result<Option<Vec<u8>>> {
+        self.signed_attributes
+            .as_ref()
+            .map(|attributes| attributes.to_vec())
+            .transpose()
+    }
+
+    /// Verify the signature of this signer over the given content with the
+    /// signer's public key.
+    ///
+    /// If signed attributes are present, the `message-digest` attribute must
+    /// match the digest of the content and the signature is verified over the
+    /// DER encoding of the signed attributes. Otherwise the signature is
+    /// verified over the content itself.
+    ///
+    /// Supports RSA PKCS#1 v1.5 and ECDSA (P-256 and P-384) signatures with
+    /// SHA-1 and SHA-2 digests.
+    #[cfg(feature = "verify")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "verify")))]
+    pub fn verify(&self, spki: &SubjectPublicKeyInfoRef<'_>, content: &[u8]) -> crate::Result<()> {
+        let content_digest = verify::digest(&self.digest_algorithm, content)?;
+
+        let prehash = match self.signed_attributes_der()? {
+            Some(signed_attributes) => {
+                match self.message_digest()? {
+                    Some(message_digest) if message_digest.as_bytes() == content_digest => (),
+                    _ => return Err(Error::MessageDigestMismatch),
+                }
+
+                verify::digest(&self.digest_algorithm, &signed_attributes)?
+            }
+            None => content_digest,
+        };
+
+        verify::verify_prehash(
+            spki,
+            &self.signature_algorithm,
+            &self.digest_algorithm,
+            &prehash,
+            self.signature.as_bytes(),
+        )
+    }
 }
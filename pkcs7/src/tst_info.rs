@@ -0,0 +1,101 @@
+//! `TSTInfo` content type [RFC 3161 § 2.4.2](https://datatracker.ietf.org/doc/html/rfc3161#section-2.4.2)
+
+use der::{
+    asn1::{GeneralizedTime, ObjectIdentifier, OctetStringRef, UintRef},
+    Sequence,
+};
+use spki::AlgorithmIdentifierRef;
+use x509_cert::ext::{pkix::name::GeneralName, Extensions};
+
+/// ```text
+/// TSAPolicyId ::= OBJECT IDENTIFIER
+/// ```
+type TsaPolicyId = ObjectIdentifier;
+
+/// Time-stamp token information [RFC 3161 § 2.4.2](https://datatracker.ietf.org/doc/html/rfc3161#section-2.4.2)
+///
+/// This is the encapsulated content of the `SignedData` of a time-stamp
+/// token, with the `id-ct-TSTInfo` content type.
+///
+/// ```text
+/// TSTInfo ::= SEQUENCE  {
+///    version                      INTEGER  { v1(1) },
+///    policy                       TSAPolicyId,
+///    messageImprint               MessageImprint,
+///    serialNumber                 INTEGER,
+///    genTime                      GeneralizedTime,
+///    accuracy                     Accuracy                 OPTIONAL,
+///    ordering                     BOOLEAN             DEFAULT FALSE,
+///    nonce                        INTEGER                  OPTIONAL,
+///    tsa                          [0] GeneralName          OPTIONAL,
+///    extensions                   [1] IMPLICIT Extensions   OPTIONAL  }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+pub struct TstInfo<'a> {
+    /// the syntax version number.
+    pub version: u8,
+
+    /// the TSA policy under which the response was produced
+    pub policy: TsaPolicyId,
+
+    /// the hash of the time-stamped data
+    pub message_imprint: MessageImprint<'a>,
+
+    /// the serial number assigned by the TSA
+    pub serial_number: UintRef<'a>,
+
+    /// the time at which the time-stamp token was created
+    pub gen_time: GeneralizedTime,
+
+    /// the accuracy of `gen_time`
+    pub accuracy: Option<Accuracy>,
+
+    /// whether time-stamp tokens from this TSA can be ordered by `gen_time`
+    #[asn1(default = "Default::default")]
+    pub ordering: bool,
+
+    /// the nonce of the time-stamp request
+    pub nonce: Option<UintRef<'a>>,
+
+    /// the name of the TSA
+    #[asn1(context_specific = "0", tag_mode = "EXPLICIT", optional = "true")]
+    pub tsa: Option<GeneralName>,
+
+    /// extensions
+    #[asn1(context_specific = "1", tag_mode = "IMPLICIT", optional = "true")]
+    pub extensions: Option<Extensions>,
+}
+
+/// ```text
+/// MessageImprint ::= SEQUENCE  {
+///      hashAlgorithm                AlgorithmIdentifier,
+///      hashedMessage                OCTET STRING  }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Sequence)]
+pub struct MessageImprint<'a> {
+    /// the hash algorithm
+    pub hash_algorithm: AlgorithmIdentifierRef<'a>,
+
+    /// the hash of the time-stamped data
+    pub hashed_message: OctetStringRef<'a>,
+}
+
+/// ```text
+/// Accuracy ::= SEQUENCE {
+///      seconds        INTEGER              OPTIONAL,
+///      millis     [0] INTEGER  (1..999)    OPTIONAL,
+///      micros     [1] INTEGER  (1..999)    OPTIONAL  }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Sequence)]
+pub struct Accuracy {
+    /// seconds
+    pub seconds: Option<u32>,
+
+    /// milliseconds
+    #[asn1(context_specific = "0", tag_mode = "IMPLICIT", optional = "true")]
+    pub millis: Option<u16>,
+
+    /// microseconds
+    #[asn1(context_specific = "1", tag_mode = "IMPLICIT", optional = "true")]
+    pub micros: Option<u16>,
+}
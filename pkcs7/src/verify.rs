@@ -0,0 +1,124 @@
+//! Digest computation and signature verification.
+
+use crate::{Error, Result};
+use alloc::vec::Vec;
+use const_oid::db::rfc5912::{
+    ECDSA_WITH_SHA_224, ECDSA_WITH_SHA_256, ECDSA_WITH_SHA_384, ECDSA_WITH_SHA_512,
+    ID_EC_PUBLIC_KEY, ID_SHA_1, ID_SHA_224, ID_SHA_256, ID_SHA_384, ID_SHA_512, RSA_ENCRYPTION,
+    SECP_256_R_1, SECP_384_R_1, SHA_1_WITH_RSA_ENCRYPTION, SHA_224_WITH_RSA_ENCRYPTION,
+    SHA_256_WITH_RSA_ENCRYPTION, SHA_384_WITH_RSA_ENCRYPTION, SHA_512_WITH_RSA_ENCRYPTION,
+};
+use rsa::{pkcs1::DecodeRsaPublicKey, Pkcs1v15Sign, RsaPublicKey};
+use sha1::Sha1;
+use sha2::{Digest, Sha224, Sha256, Sha384, Sha512};
+use spki::{AlgorithmIdentifierRef, ObjectIdentifier, SubjectPublicKeyInfoRef};
+
+/// Compute the digest of `data` with the given digest algorithm.
+pub(crate) fn digest(algorithm: &AlgorithmIdentifierRef<'_>, data: &[u8]) -> Result<Vec<u8>> {
+    match algorithm.oid {
+        ID_SHA_1 => Ok(Sha1::digest(data).to_vec()),
+        ID_SHA_224 => Ok(Sha224::digest(data).to_vec()),
+        ID_SHA_256 => Ok(Sha256::digest(data).to_vec()),
+        ID_SHA_384 => Ok(Sha384::digest(data).to_vec()),
+        ID_SHA_512 => Ok(Sha512::digest(data).to_vec()),
+        oid => Err(Error::AlgorithmUnsupported(oid)),
+    }
+}
+
+/// Verify `signature` over the message with the given `prehash` digest.
+///
+/// Signature algorithms which specify a digest algorithm, e.g.
+/// `sha256WithRSAEncryption`, must match `digest_algorithm`.
+pub(crate) fn verify_prehash(
+    spki: &SubjectPublicKeyInfoRef<'_>,
+    signature_algorithm: &AlgorithmIdentifierRef<'_>,
+    digest_algorithm: &AlgorithmIdentifierRef<'_>,
+    prehash: &[u8],
+    signature: &[u8],
+) -> Result<()> {
+    if let Some(oid) = signature_digest_oid(signature_algorithm.oid) {
+        if oid != digest_algorithm.oid {
+            return Err(Error::AlgorithmMismatch);
+        }
+    }
+
+    let public_key = spki
+        .subject_public_key
+        .as_bytes()
+        .ok_or(Error::KeyMalformed)?;
+
+    match signature_algorithm.oid {
+        RSA_ENCRYPTION
+        | SHA_1_WITH_RSA_ENCRYPTION
+        | SHA_224_WITH_RSA_ENCRYPTION
+        | SHA_256_WITH_RSA_ENCRYPTION
+        | SHA_384_WITH_RSA_ENCRYPTION
+        | SHA_512_WITH_RSA_ENCRYPTION => {
+            if spki.algorithm.oid != RSA_ENCRYPTION {
+                return Err(Error::KeyMalformed);
+            }
+
+            let key = RsaPublicKey::from_pkcs1_der(public_key).map_err(|_| Error::KeyMalformed)?;
+            key.verify(rsa_scheme(digest_algorithm.oid)?, prehash, signature)
+                .map_err(|_| Error::SignatureInvalid)
+        }
+        ECDSA_WITH_SHA_224 | ECDSA_WITH_SHA_256 | ECDSA_WITH_SHA_384 | ECDSA_WITH_SHA_512 => {
+            if spki.algorithm.oid != ID_EC_PUBLIC_KEY {
+                return Err(Error::KeyMalformed);
+            }
+
+            let curve = spki
+                .algorithm
+                .parameters_oid()
+                .map_err(|_| Error::KeyMalformed)?;
+
+            match curve {
+                SECP_256_R_1 => verify_ecdsa_p256(public_key, prehash, signature),
+                SECP_384_R_1 => verify_ecdsa_p384(public_key, prehash, signature),
+                oid => Err(Error::AlgorithmUnsupported(oid)),
+            }
+        }
+        oid => Err(Error::AlgorithmUnsupported(oid)),
+    }
+}
+
+/// Digest algorithm specified by the given signature algorithm, if any.
+fn signature_digest_oid(signature_oid: ObjectIdentifier) -> Option<ObjectIdentifier> {
+    match signature_oid {
+        SHA_1_WITH_RSA_ENCRYPTION => Some(ID_SHA_1),
+        SHA_224_WITH_RSA_ENCRYPTION | ECDSA_WITH_SHA_224 => Some(ID_SHA_224),
+        SHA_256_WITH_RSA_ENCRYPTION | ECDSA_WITH_SHA_256 => Some(ID_SHA_256),
+        SHA_384_WITH_RSA_ENCRYPTION | ECDSA_WITH_SHA_384 => Some(ID_SHA_384),
+        SHA_512_WITH_RSA_ENCRYPTION | ECDSA_WITH_SHA_512 => Some(ID_SHA_512),
+        _ => None,
+    }
+}
+
+fn rsa_scheme(digest_oid: ObjectIdentifier) -> Result<Pkcs1v15Sign> {
+    match digest_oid {
+        ID_SHA_1 => Ok(Pkcs1v15Sign::new::<Sha1>()),
+        ID_SHA_224 => Ok(Pkcs1v15Sign::new::<Sha224>()),
+        ID_SHA_256 => Ok(Pkcs1v15Sign::new::<Sha256>()),
+        ID_SHA_384 => Ok(Pkcs1v15Sign::new::<Sha384>()),
+        ID_SHA_512 => Ok(Pkcs1v15Sign::new::<Sha512>()),
+        oid => Err(Error::AlgorithmUnsupported(oid)),
+    }
+}
+
+fn verify_ecdsa_p256(public_key: &[u8], prehash: &[u8], signature: &[u8]) -> Result<()> {
+    use p256::ecdsa::{signature::hazmat::PrehashVerifier, Signature, VerifyingKey};
+
+    let key = VerifyingKey::from_sec1_bytes(public_key).map_err(|_| Error::KeyMalformed)?;
+    let signature = Signature::from_der(signature).map_err(|_| Error::SignatureInvalid)?;
+    key.verify_prehash(prehash, &signature)
+        .map_err(|_| Error::SignatureInvalid)
+}
+
+fn verify_ecdsa_p384(public_key: &[u8], prehash: &[u8], signature: &[u8]) -> Result<()> {
+    use p384::ecdsa::{signature::hazmat::PrehashVerifier, Signature, VerifyingKey};
+
+    let key = VerifyingKey::from_sec1_bytes(public_key).map_err(|_| Error::KeyMalformed)?;
+    let signature = Signature::from_der(signature).map_err(|_| Error::SignatureInvalid)?;
+    key.verify_prehash(prehash, &signature)
+        .map_err(|_| Error::SignatureInvalid)
+}
@@ -1,17 +1,39 @@
 //! PKCS#7 example tests
 
 use der::{
-    asn1::{ObjectIdentifier, OctetStringRef, SequenceRef},
-    Decode, SliceWriter,
+    asn1::{BitStringRef, ObjectIdentifier, OctetString, OctetStringRef, SequenceRef, SetOfVec},
+    AnyRef, Decode, Encode, Reader, SliceReader, SliceWriter,
 };
 use hex_literal::hex;
 use pkcs7::{
-    cms_version::CmsVersion, encapsulated_content_info::EncapsulatedContentInfo,
-    encrypted_data_content::EncryptedDataContent, enveloped_data_content::EncryptedContentInfo,
-    signed_data_content::SignedDataContent, ContentInfo, ContentType,
+    certificate_choices::CertificateChoices,
+    cms_version::CmsVersion,
+    digested_data_content::DigestedDataContent,
+    encapsulated_content_info::EncapsulatedContentInfo,
+    encrypted_data_content::EncryptedDataContent,
+    enveloped_data_content::{
+        EncryptedContentInfo, EnvelopedDataContent, RecipientIdentifier, RecipientInfo,
+    },
+    revocation_info_choices::{OtherRevocationInfoFormat, RevocationInfoChoice},
+    signed_data_content::{SignedDataBuilder, SignedDataContent},
+    signer_info::{SignerIdentifier, SignerInfo},
+    tst_info::Accuracy,
+    ContentInfo, ContentType,
 };
 use spki::AlgorithmIdentifierRef;
+#[cfg(feature = "verify")]
+use spki::SubjectPublicKeyInfoRef;
+
+#[cfg(feature = "pem")]
+use der::{
+    pem::{LineEnding, PemLabel},
+    Document, EncodePem,
+};
 use std::fs;
+use x509_cert::{
+    ext::pkix::{name::GeneralName, SubjectKeyIdentifier},
+    Certificate,
+};
 
 fn encode_content_info<'a>(content_info: &ContentInfo<'a>, buf: &'a mut [u8]) -> &'a [u8] {
     let mut encoder = SliceWriter::new(buf);
@@ -19,6 +41,26 @@ fn encode_content_info<'a>(content_info: &ContentInfo<'a>, buf: &'a mut [u8]) ->
     encoder.finish().expect("encoding success")
 }
 
+/// Raw DER encodings of the elements of the constructed value in `bytes`
+fn der_elements(bytes: &[u8]) -> Vec<&[u8]> {
+    let value = AnyRef::from_der(bytes).expect("valid DER").value();
+    let mut reader = SliceReader::new(value).expect("valid length");
+    let mut elements = Vec::new();
+
+    while !reader.is_finished() {
+        elements.push(reader.tlv_bytes().expect("valid element"));
+    }
+
+    elements
+}
+
+/// Raw DER encoding of the `[0] EXPLICIT` content of the `ContentInfo` in `bytes`
+fn content_info_content(bytes: &[u8]) -> &[u8] {
+    let elements = der_elements(bytes);
+    assert_eq!(elements.len(), 2, "expected content type and content");
+    der_elements(elements[1])[0]
+}
+
 #[test]
 fn decode_cert_example() {
     let path = "./tests/examples/certData.bin";
@@ -165,6 +207,43 @@ fn decode_signed_ber() {
     );
 }
 
+#[test]
+fn decode_signed_detached_ber() {
+    // Detached signature produced by `gpgsm`, which uses indefinite lengths
+    // for the outer structures and definite lengths for everything else
+    let bytes = include_bytes!("examples/signed_detached_ber.bin");
+
+    let err = ContentInfo::from_der(bytes).unwrap_err();
+    assert_eq!(err.kind(), der::ErrorKind::IndefiniteLength);
+
+    let content = match ContentInfo::from_ber(bytes) {
+        Ok(ContentInfo::SignedData(Some(data))) => data,
+        other => panic!("unexpected result: {:?}", other),
+    };
+
+    assert_eq!(content.version, CmsVersion::V1);
+    assert_eq!(
+        content.digest_algorithms.get(0).unwrap().oid,
+        ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.1")
+    );
+    assert_eq!(
+        content.encap_content_info.e_content_type,
+        pkcs7::PKCS_7_DATA_OID
+    );
+    assert_eq!(content.encap_content_info.e_content, None);
+
+    let cert = match content.certificates.as_ref().and_then(|certs| certs.get(0)) {
+        Some(CertificateChoices::Certificate(cert)) => cert,
+        other => panic!("unexpected certificate: {:?}", other),
+    };
+    assert_eq!(cert.tbs_certificate.subject.to_string(), "CN=example.org");
+
+    assert_eq!(content.signer_infos.len(), 1);
+    let signer_info = content.signer_infos.get(0).unwrap();
+    assert_eq!(signer_info.version, CmsVersion::V1);
+    assert_eq!(signer_info.signature.as_bytes().len(), 256);
+}
+
 #[test]
 fn decode_signed_der() {
     let bytes = include_bytes!("examples/cms_der.bin");
@@ -186,3 +265,1116 @@ fn decode_signed_der() {
         10034
     );
 }
+
+#[test]
+fn decode_encapsulated_content_info_der() {
+    let bytes = include_bytes!("examples/cms_der_encap_content_info.bin");
+
+    // the fixture is the `encapContentInfo` field of the `SignedData` in `cms_der.bin`
+    let signed_data_bytes = content_info_content(include_bytes!("examples/cms_der.bin"));
+    assert_eq!(der_elements(signed_data_bytes)[2], bytes);
+
+    let encap = EncapsulatedContentInfo::from_der(bytes).expect("expected valid data");
+    assert_eq!(encap.e_content_type, pkcs7::PKCS_7_DATA_OID);
+
+    let content = encap
+        .e_content
+        .expect("eContent should be present")
+        .decode_as::<OctetStringRef>()
+        .expect("eContent should be an OCTET STRING");
+    assert_eq!(content.as_bytes().len(), 10034);
+    assert!(content.as_bytes().starts_with(b"Inizio contenuto"));
+
+    let mut buf = vec![0u8; bytes.len()];
+    let encoded = encap.encode_to_slice(&mut buf).expect("encoding success");
+    assert_eq!(encoded, bytes);
+}
+
+#[test]
+fn decode_encapsulated_content_info_absent_content() {
+    // EncapsulatedContentInfo of a detached signature: `eContent` omitted
+    let bytes = hex!("300B06092A864886F70D010701");
+
+    let encap = EncapsulatedContentInfo::from_der(&bytes).expect("expected valid data");
+    assert_eq!(encap.e_content_type, pkcs7::PKCS_7_DATA_OID);
+    assert_eq!(encap.e_content, None);
+
+    let mut buf = [0u8; 13];
+    let encoded = encap.encode_to_slice(&mut buf).expect("encoding success");
+    assert_eq!(encoded, bytes);
+}
+
+#[test]
+fn roundtrip_signed_der() {
+    let bytes = include_bytes!("examples/cms_der.bin");
+
+    let content = ContentInfo::from_der(bytes).expect("expected valid data");
+
+    let mut buf = vec![0u8; bytes.len()];
+    let encoded_content = encode_content_info(&content, &mut buf);
+
+    assert_eq!(encoded_content, bytes);
+}
+
+#[test]
+fn roundtrip_signed_data_content() {
+    for path in [
+        "./tests/examples/apple_mdm_signature_der.bin",
+        "./tests/examples/scep_der.bin",
+        "./tests/examples/cms_der.bin",
+    ] {
+        let bytes = fs::read(path).unwrap_or_else(|_| panic!("Failed to read from {}", path));
+
+        let signed_data_bytes = content_info_content(&bytes);
+        let signed_data =
+            SignedDataContent::from_der(signed_data_bytes).expect("expected valid data");
+
+        let mut buf = vec![0u8; signed_data_bytes.len()];
+        let encoded = signed_data
+            .encode_to_slice(&mut buf)
+            .expect("encoding success");
+        assert_eq!(encoded, signed_data_bytes);
+    }
+}
+
+#[test]
+fn decode_signed_der_certificates() {
+    let bytes = include_bytes!("examples/cms_der.bin");
+
+    let content = match ContentInfo::from_der(bytes) {
+        Ok(ContentInfo::SignedData(Some(data))) => data,
+        other => panic!("unexpected result: {:?}", other),
+    };
+
+    let certs = content
+        .certificates
+        .expect("certificates should be present");
+    assert_eq!(certs.len(), 1);
+
+    match certs.get(0) {
+        Some(CertificateChoices::Certificate(cert)) => {
+            assert_eq!(cert.tbs_certificate.subject.to_string(), "CN=Test");
+            assert_eq!(cert.tbs_certificate.issuer.to_string(), "CN=Test");
+        }
+        other => panic!("unexpected certificate: {:?}", other),
+    }
+}
+
+#[test]
+fn signed_data_certificates_absent_and_empty() {
+    let bytes = include_bytes!("examples/cms_der.bin");
+
+    let mut signed_data = match ContentInfo::from_der(bytes) {
+        Ok(ContentInfo::SignedData(Some(data))) => data,
+        other => panic!("unexpected result: {:?}", other),
+    };
+
+    let mut buf = vec![0u8; bytes.len()];
+
+    signed_data.certificates = None;
+    let encoded = signed_data
+        .encode_to_slice(&mut buf)
+        .expect("encoding success");
+    let decoded = SignedDataContent::from_der(encoded).expect("expected valid data");
+    assert_eq!(decoded.certificates, None);
+
+    signed_data.certificates = Some(SetOfVec::new());
+    let encoded = signed_data
+        .encode_to_slice(&mut buf)
+        .expect("encoding success");
+    let decoded = SignedDataContent::from_der(encoded).expect("expected valid data");
+    assert_eq!(decoded.certificates, Some(SetOfVec::new()));
+}
+
+#[test]
+fn decode_signed_crls_example() {
+    let bytes = include_bytes!("examples/crl_der.bin");
+
+    let content = match ContentInfo::from_der(bytes) {
+        Ok(ContentInfo::SignedData(Some(data))) => data,
+        other => panic!("unexpected result: {:?}", other),
+    };
+
+    let crls = content.crls.as_ref().expect("crls should be present");
+    assert_eq!(crls.len(), 1);
+
+    match crls.get(0) {
+        Some(RevocationInfoChoice::Crl(crl)) => {
+            assert_eq!(crl.tbs_cert_list.issuer.to_string(), "CN=example.org");
+            assert!(crl.tbs_cert_list.revoked_certificates.is_none());
+        }
+        other => panic!("unexpected revocation info: {:?}", other),
+    }
+
+    let mut buf = vec![0u8; bytes.len()];
+    let encoded_content = encode_content_info(&ContentInfo::SignedData(Some(content)), &mut buf);
+
+    assert_eq!(encoded_content, bytes);
+}
+
+#[test]
+fn decode_signed_der_no_crls() {
+    let bytes = include_bytes!("examples/cms_der.bin");
+
+    let content = match ContentInfo::from_der(bytes) {
+        Ok(ContentInfo::SignedData(Some(data))) => data,
+        other => panic!("unexpected result: {:?}", other),
+    };
+
+    assert_eq!(content.crls, None);
+}
+
+#[test]
+fn decode_signer_info_der() {
+    let bytes = include_bytes!("examples/cms_der.bin");
+
+    let content = match ContentInfo::from_der(bytes) {
+        Ok(ContentInfo::SignedData(Some(data))) => data,
+        other => panic!("unexpected result: {:?}", other),
+    };
+
+    assert_eq!(content.signer_infos.len(), 1);
+    let signer_info = content.signer_infos.get(0).expect("signer info");
+
+    assert_eq!(signer_info.version, CmsVersion::V1);
+    match &signer_info.sid {
+        SignerIdentifier::IssuerAndSerialNumber(sid) => {
+            assert_eq!(sid.issuer.to_string(), "CN=Test");
+            assert_eq!(
+                sid.serial_number.as_bytes(),
+                hex!("605CD2D52675B392A5AA9B02B26A5566")
+            );
+        }
+        other => panic!("unexpected signer identifier: {:?}", other),
+    }
+    assert_eq!(
+        signer_info.digest_algorithm.oid,
+        ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.1")
+    );
+    assert_eq!(
+        signer_info.signature_algorithm.oid,
+        ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.2")
+    );
+    assert_eq!(
+        signer_info
+            .signed_attributes
+            .as_ref()
+            .expect("signed attributes")
+            .len(),
+        5
+    );
+    assert_eq!(signer_info.signature.as_bytes().len(), 71);
+    assert!(signer_info.unsigned_attributes.is_none());
+
+    // `SignerInfos` is the trailing element of the `SignedData` SEQUENCE
+    let signed_data_elements = der_elements(content_info_content(bytes));
+    let signer_infos_bytes = *signed_data_elements.last().expect("signer infos");
+    let mut buf = vec![0u8; signer_infos_bytes.len()];
+    let encoded = content
+        .signer_infos
+        .encode_to_slice(&mut buf)
+        .expect("encoding success");
+    assert_eq!(encoded, signer_infos_bytes);
+}
+
+#[test]
+fn signer_info_unsigned_attributes_roundtrip() {
+    let bytes = include_bytes!("examples/cms_der.bin");
+
+    let content = match ContentInfo::from_der(bytes) {
+        Ok(ContentInfo::SignedData(Some(data))) => data,
+        other => panic!("unexpected result: {:?}", other),
+    };
+
+    let mut signer_info = content.signer_infos.get(0).expect("signer info").clone();
+
+    // reuse the signed `signingTime` attribute as an unsigned one
+    let signing_time = signer_info
+        .signed_attributes
+        .as_ref()
+        .expect("signed attributes")
+        .iter()
+        .find(|attr| attr.oid == ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.5"))
+        .expect("signing time attribute")
+        .clone();
+
+    let mut unsigned_attributes = SetOfVec::new();
+    unsigned_attributes
+        .add(signing_time)
+        .expect("attribute added");
+    signer_info.unsigned_attributes = Some(unsigned_attributes);
+
+    let mut buf = vec![0u8; 1024];
+    let encoded = signer_info
+        .encode_to_slice(&mut buf)
+        .expect("encoding success");
+    let decoded = SignerInfo::from_der(encoded).expect("expected valid data");
+    assert_eq!(decoded, signer_info);
+}
+
+#[test]
+fn decode_enveloped_example() {
+    let bytes = include_bytes!("examples/enveloped_der.bin");
+
+    let content = ContentInfo::from_der(bytes).expect("expected valid data");
+    assert_eq!(content.content_type(), pkcs7::PKCS_7_ENVELOPED_DATA_OID);
+
+    match &content {
+        ContentInfo::EnvelopedData(Some(EnvelopedDataContent {
+            version: CmsVersion::V0,
+            originator_info: None,
+            recipient_infos,
+            encrypted_content_info,
+            unprotected_attrs: None,
+        })) => {
+            assert_eq!(recipient_infos.len(), 1);
+            match recipient_infos.get(0) {
+                Some(RecipientInfo::Ktri(ktri)) => {
+                    assert_eq!(ktri.version, CmsVersion::V0);
+                    match &ktri.rid {
+                        RecipientIdentifier::IssuerAndSerialNumber(rid) => {
+                            assert_eq!(rid.issuer.to_string(), "CN=example.org");
+                            assert_eq!(rid.serial_number.as_bytes(), hex!("009DB5D9F405A5E8FA"));
+                        }
+                        other => panic!("unexpected recipient identifier: {:?}", other),
+                    }
+                    assert_eq!(
+                        ktri.key_encryption_algorithm.oid,
+                        ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1")
+                    );
+                    assert_eq!(ktri.encrypted_key.as_bytes().len(), 128);
+                }
+                other => panic!("unexpected recipient info: {:?}", other),
+            }
+
+            assert_eq!(encrypted_content_info.content_type, ContentType::Data);
+            assert_eq!(
+                encrypted_content_info.content_encryption_algorithm.oid,
+                ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.1.42")
+            );
+            assert_eq!(
+                encrypted_content_info
+                    .encrypted_content
+                    .expect("encrypted content")
+                    .as_bytes()
+                    .len(),
+                16
+            );
+        }
+        _ => panic!("expected ContentInfo::EnvelopedData(Some(_))"),
+    }
+
+    let mut buf = vec![0u8; bytes.len()];
+    let encoded_content = encode_content_info(&content, &mut buf);
+
+    assert_eq!(encoded_content, bytes);
+}
+
+#[test]
+fn decode_unknown_content_type() {
+    // ContentInfo of content type `id-ct-TSTInfo` with an INTEGER as content
+    let bytes = hex!("3012060B2A864886F70D0109100104A003020101");
+    let oid = ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.16.1.4");
+
+    let content = ContentInfo::from_der(&bytes).expect("expected valid data");
+    assert_eq!(content.content_type(), oid);
+
+    match &content {
+        ContentInfo::Other((_, Some(any))) => assert_eq!(any.decode_as::<u8>(), Ok(1)),
+        _ => panic!("expected ContentInfo::Other(_)"),
+    }
+
+    let mut buf = [0u8; 20];
+    let encoded_content = encode_content_info(&content, &mut buf);
+
+    assert_eq!(encoded_content, bytes);
+}
+
+#[test]
+fn decode_enveloped_multi_recipient_example() {
+    let bytes = include_bytes!("examples/enveloped_data_multi.bin");
+
+    let content = ContentInfo::from_der(bytes).expect("expected valid data");
+
+    match &content {
+        ContentInfo::EnvelopedData(Some(data)) => {
+            assert_eq!(data.version, CmsVersion::V3);
+            assert_eq!(data.recipient_infos.len(), 4);
+
+            let mut recipients = data.recipient_infos.iter();
+            assert!(matches!(recipients.next(), Some(RecipientInfo::Ktri(_))));
+            assert!(matches!(recipients.next(), Some(RecipientInfo::Kari(_))));
+            assert!(matches!(recipients.next(), Some(RecipientInfo::Kekri(_))));
+            assert!(matches!(recipients.next(), Some(RecipientInfo::Pwri(_))));
+        }
+        _ => panic!("expected ContentInfo::EnvelopedData(Some(_))"),
+    }
+
+    let mut buf = vec![0u8; bytes.len()];
+    let encoded_content = encode_content_info(&content, &mut buf);
+
+    assert_eq!(encoded_content, bytes);
+}
+
+#[test]
+fn encrypted_data_absent_encrypted_content() {
+    let bytes = fs::read("./tests/examples/keyEncryptedData.bin").expect("keyEncryptedData.bin");
+
+    let mut data = match ContentInfo::from_der(&bytes) {
+        Ok(ContentInfo::EncryptedData(Some(data))) => data,
+        other => panic!("unexpected result: {:?}", other),
+    };
+
+    // the encrypted content is `[0] IMPLICIT`, i.e. a primitive context-specific tag
+    let mut buf = vec![0u8; bytes.len()];
+    let encoded = data.encode_to_slice(&mut buf).expect("encoding success");
+    let encrypted_content_info = der_elements(encoded)[1];
+    let encrypted_content = *der_elements(encrypted_content_info)
+        .last()
+        .expect("encrypted content");
+    assert_eq!(encrypted_content[0], 0x80);
+
+    data.encrypted_content_info.encrypted_content = None;
+    let encoded = data.encode_to_slice(&mut buf).expect("encoding success");
+    let decoded = EncryptedDataContent::from_der(encoded).expect("expected valid data");
+    assert_eq!(decoded, data);
+    assert_eq!(decoded.encrypted_content_info.encrypted_content, None);
+}
+
+#[test]
+fn decode_digested_example() {
+    let bytes = include_bytes!("examples/digested_der.bin");
+
+    let content = ContentInfo::from_der(bytes).expect("expected valid data");
+
+    match &content {
+        ContentInfo::DigestedData(Some(data)) => {
+            assert_eq!(data.version, CmsVersion::V0);
+            assert_eq!(
+                data.digest_algorithm.oid,
+                ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.1")
+            );
+            assert_eq!(
+                data.encap_content_info.e_content_type,
+                pkcs7::PKCS_7_DATA_OID
+            );
+            assert_eq!(
+                data.encap_content_info
+                    .e_content
+                    .expect("content")
+                    .decode_as::<OctetStringRef>()
+                    .expect("content should be an OCTET STRING")
+                    .as_bytes(),
+                b"hello world\n"
+            );
+            assert_eq!(
+                data.digest.as_bytes(),
+                hex!("a948904f2f0f479b8f8197694b30184b0d2ed1c1cd2a1ec0fb85d299a192a447")
+            );
+        }
+        _ => panic!("expected ContentInfo::DigestedData(Some(_))"),
+    }
+
+    let mut buf = vec![0u8; bytes.len()];
+    let encoded_content = encode_content_info(&content, &mut buf);
+
+    assert_eq!(encoded_content, bytes);
+}
+
+#[cfg(feature = "subtle")]
+#[test]
+fn digested_data_verify_digest() {
+    let bytes = include_bytes!("examples/digested_der.bin");
+
+    let data = match ContentInfo::from_der(bytes) {
+        Ok(ContentInfo::DigestedData(Some(data))) => data,
+        other => panic!("unexpected result: {:?}", other),
+    };
+
+    let digest = hex!("a948904f2f0f479b8f8197694b30184b0d2ed1c1cd2a1ec0fb85d299a192a447");
+    assert!(data.verify_digest(&digest));
+    assert!(!data.verify_digest(&digest[..31]));
+    assert!(!data.verify_digest(&[0u8; 32]));
+}
+
+#[test]
+fn digested_data_roundtrip() {
+    let bytes = include_bytes!("examples/digested_der.bin");
+
+    let digested_data_bytes = content_info_content(bytes);
+    let data = DigestedDataContent::from_der(digested_data_bytes).expect("expected valid data");
+
+    let mut buf = vec![0u8; digested_data_bytes.len()];
+    let encoded = data.encode_to_slice(&mut buf).expect("encoding success");
+    assert_eq!(encoded, digested_data_bytes);
+}
+
+#[test]
+fn signer_info_signed_attributes() {
+    let bytes = include_bytes!("examples/cms_der.bin");
+
+    let content = match ContentInfo::from_der(bytes) {
+        Ok(ContentInfo::SignedData(Some(data))) => data,
+        other => panic!("unexpected result: {:?}", other),
+    };
+    let signer_info = content.signer_infos.get(0).expect("signer info");
+
+    assert_eq!(
+        signer_info.content_type().expect("valid attribute"),
+        Some(pkcs7::PKCS_7_DATA_OID)
+    );
+    assert_eq!(
+        signer_info
+            .message_digest()
+            .expect("valid attribute")
+            .expect("message digest")
+            .as_bytes(),
+        hex!("724C51BBE76DA05AFB20CBE8EB037CDAE1AFD713125D2DC13D552DA9F442D24D")
+    );
+    assert_eq!(
+        signer_info
+            .signed_attribute(ObjectIdentifier::new_unwrap("1.2.3.4"))
+            .expect("valid attribute"),
+        None
+    );
+
+    // the signed attributes are stored as `[0] IMPLICIT` but signed as `SET OF`
+    let signer_infos = *der_elements(content_info_content(bytes))
+        .last()
+        .expect("signer infos");
+    let stored = der_elements(der_elements(signer_infos)[0])
+        .into_iter()
+        .find(|element| element[0] == 0xA0)
+        .expect("stored signed attributes");
+    let signed_attrs_der = signer_info
+        .signed_attributes_der()
+        .expect("encoding success")
+        .expect("signed attributes");
+    assert_eq!(signed_attrs_der[0], 0x31);
+    assert_eq!(&signed_attrs_der[1..], &stored[1..]);
+}
+
+fn rebuild_signed_data<'a>(
+    original: &SignedDataContent<'a>,
+    mut builder: SignedDataBuilder<'a>,
+) -> Vec<u8> {
+    for cert in original.certificates.as_ref().expect("certificates").iter() {
+        builder.add_certificate(cert.clone());
+    }
+    for signer_info in original.signer_infos.iter() {
+        builder.add_signer_info(signer_info.clone());
+    }
+
+    let content_info = builder.build_content_info().expect("build success");
+    content_info.to_vec().expect("encoding success")
+}
+
+#[test]
+fn build_signed_data_attached() {
+    let bytes = include_bytes!("examples/signed_attached_der.bin");
+
+    let original = match ContentInfo::from_der(bytes) {
+        Ok(ContentInfo::SignedData(Some(data))) => data,
+        other => panic!("unexpected result: {:?}", other),
+    };
+
+    let builder =
+        SignedDataBuilder::new(pkcs7::PKCS_7_DATA_OID, b"hello world\n").expect("valid content");
+    let rebuilt = rebuild_signed_data(&original, builder);
+    assert_eq!(rebuilt.as_slice(), bytes.as_slice());
+}
+
+#[test]
+fn build_signed_data_detached() {
+    let bytes = include_bytes!("examples/signed_detached_der.bin");
+
+    let original = match ContentInfo::from_der(bytes) {
+        Ok(ContentInfo::SignedData(Some(data))) => data,
+        other => panic!("unexpected result: {:?}", other),
+    };
+
+    let builder = SignedDataBuilder::new_detached(pkcs7::PKCS_7_DATA_OID);
+    let rebuilt = rebuild_signed_data(&original, builder);
+    assert_eq!(rebuilt.as_slice(), bytes.as_slice());
+
+    match ContentInfo::from_der(&rebuilt) {
+        Ok(ContentInfo::SignedData(Some(data))) => {
+            assert_eq!(data.version, CmsVersion::V1);
+            assert_eq!(data.encap_content_info.e_content, None);
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn build_signed_data_digest_algorithm_union() {
+    let bytes = include_bytes!("examples/cms_der.bin");
+
+    let signer_info = match ContentInfo::from_der(bytes) {
+        Ok(ContentInfo::SignedData(Some(data))) => {
+            data.signer_infos.get(0).expect("signer").clone()
+        }
+        other => panic!("unexpected result: {:?}", other),
+    };
+
+    let sha1 = AlgorithmIdentifierRef {
+        oid: ObjectIdentifier::new_unwrap("1.3.14.3.2.26"),
+        parameters: None,
+    };
+
+    let mut builder = SignedDataBuilder::new_detached(pkcs7::PKCS_7_DATA_OID);
+    builder
+        .add_digest_algorithm(sha1)
+        .add_signer_info(signer_info.clone())
+        .add_signer_info(signer_info.clone())
+        .add_digest_algorithm(signer_info.digest_algorithm);
+
+    let signed_data = builder.build().expect("build success");
+    assert_eq!(signed_data.digest_algorithms.len(), 2);
+    assert!(signed_data
+        .digest_algorithms
+        .iter()
+        .any(|alg| *alg == signer_info.digest_algorithm));
+    assert!(signed_data.digest_algorithms.iter().any(|alg| *alg == sha1));
+    assert_eq!(signed_data.certificates, None);
+    assert_eq!(signed_data.crls, None);
+}
+
+#[test]
+fn build_signed_data_non_data_content_type() {
+    let content_type = ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.16.1.4");
+    let builder = SignedDataBuilder::new(content_type, &[0x30, 0x00]).expect("valid content");
+    let signed_data = builder.build().expect("build success");
+
+    assert_eq!(signed_data.version, CmsVersion::V3);
+    assert_eq!(signed_data.encap_content_info.e_content_type, content_type);
+    assert!(signed_data.digest_algorithms.is_empty());
+}
+
+fn cms_der_signer_info() -> SignerInfo<'static> {
+    let bytes = include_bytes!("examples/cms_der.bin");
+
+    match ContentInfo::from_der(bytes) {
+        Ok(ContentInfo::SignedData(Some(data))) => {
+            data.signer_infos.get(0).expect("signer").clone()
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+fn compute_version(builder: &SignedDataBuilder<'_>) -> CmsVersion {
+    let signed_data = builder.build().expect("build success");
+    assert_eq!(signed_data.version, signed_data.compute_version());
+    signed_data.version
+}
+
+#[test]
+fn signed_data_version_v1() {
+    let mut builder = SignedDataBuilder::new_detached(pkcs7::PKCS_7_DATA_OID);
+    assert_eq!(compute_version(&builder), CmsVersion::V1);
+
+    builder.add_signer_info(cms_der_signer_info());
+    assert_eq!(compute_version(&builder), CmsVersion::V1);
+}
+
+#[test]
+fn signed_data_version_v3_content_type() {
+    let content_type = ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.16.1.4");
+    let builder = SignedDataBuilder::new_detached(content_type);
+    assert_eq!(compute_version(&builder), CmsVersion::V3);
+}
+
+#[test]
+fn signed_data_version_v3_signer_info_version() {
+    let mut signer_info = cms_der_signer_info();
+    signer_info.version = CmsVersion::V3;
+
+    let mut builder = SignedDataBuilder::new_detached(pkcs7::PKCS_7_DATA_OID);
+    builder.add_signer_info(signer_info);
+    assert_eq!(compute_version(&builder), CmsVersion::V3);
+}
+
+#[test]
+fn signed_data_version_v3_subject_key_identifier() {
+    let mut signer_info = cms_der_signer_info();
+    signer_info.sid = SignerIdentifier::SubjectKeyIdentifier(SubjectKeyIdentifier(
+        OctetString::new(hex!("0102030405060708090A0B0C0D0E0F1011121314")).expect("octet string"),
+    ));
+
+    let mut builder = SignedDataBuilder::new_detached(pkcs7::PKCS_7_DATA_OID);
+    builder.add_signer_info(signer_info);
+    assert_eq!(compute_version(&builder), CmsVersion::V3);
+}
+
+#[test]
+#[allow(deprecated)]
+fn signed_data_version_v3_v1_attr_cert() {
+    let mut builder = SignedDataBuilder::new_detached(pkcs7::PKCS_7_DATA_OID);
+    builder.add_certificate(CertificateChoices::V1AttrCert(
+        BitStringRef::from_bytes(&[0x00]).expect("bit string"),
+    ));
+    assert_eq!(compute_version(&builder), CmsVersion::V3);
+}
+
+#[test]
+fn signed_data_version_v4_v2_attr_cert() {
+    let mut builder = SignedDataBuilder::new_detached(pkcs7::PKCS_7_DATA_OID);
+    builder.add_certificate(CertificateChoices::V2AttrCert(
+        BitStringRef::from_bytes(&[0x00]).expect("bit string"),
+    ));
+    assert_eq!(compute_version(&builder), CmsVersion::V4);
+}
+
+#[test]
+fn signed_data_version_v5_other_certificate() {
+    // [3] IMPLICIT OtherCertificateFormat { 1.2.3.4, NULL }
+    let other = hex!("A30706032A03040500");
+    let mut builder = SignedDataBuilder::new_detached(pkcs7::PKCS_7_DATA_OID);
+    builder.add_certificate(CertificateChoices::from_der(&other).expect("other certificate"));
+    assert_eq!(compute_version(&builder), CmsVersion::V5);
+}
+
+#[test]
+fn signed_data_version_v5_other_crl() {
+    let mut builder = SignedDataBuilder::new_detached(pkcs7::PKCS_7_DATA_OID);
+    builder.add_crl(RevocationInfoChoice::Other(OtherRevocationInfoFormat {
+        other_rev_info_format: ObjectIdentifier::new_unwrap("1.2.3.4"),
+        other_rev_info: AnyRef::NULL,
+    }));
+    assert_eq!(compute_version(&builder), CmsVersion::V5);
+}
+
+#[cfg(feature = "verify")]
+fn verify_signed_data(bytes: &[u8], content: Option<&[u8]>) -> pkcs7::Result<()> {
+    verify_signed_data_with(bytes, content, |_| ())
+}
+
+/// Verify the first signer of the given `SignedData`, after modifying its
+/// `SignerInfo` with `f`.
+#[cfg(feature = "verify")]
+fn verify_signed_data_with(
+    bytes: &[u8],
+    content: Option<&[u8]>,
+    f: impl FnOnce(&mut SignerInfo<'_>),
+) -> pkcs7::Result<()> {
+    let signed_data = match ContentInfo::from_der(bytes) {
+        Ok(ContentInfo::SignedData(Some(data))) => data,
+        other => panic!("unexpected result: {:?}", other),
+    };
+
+    let cert = match signed_data
+        .certificates
+        .as_ref()
+        .and_then(|certs| certs.get(0))
+    {
+        Some(CertificateChoices::Certificate(cert)) => cert,
+        other => panic!("unexpected certificate: {:?}", other),
+    };
+    let spki_der = cert
+        .tbs_certificate
+        .subject_public_key_info
+        .to_vec()
+        .expect("encoding success");
+    let spki = SubjectPublicKeyInfoRef::from_der(&spki_der).expect("valid SPKI");
+
+    let content = content.unwrap_or_else(|| {
+        signed_data
+            .encap_content_info
+            .e_content
+            .expect("encapsulated content")
+            .value()
+    });
+
+    let mut signer_info = signed_data
+        .signer_infos
+        .get(0)
+        .expect("signer info")
+        .clone();
+    f(&mut signer_info);
+    signer_info.verify(&spki, content)
+}
+
+#[test]
+#[cfg(feature = "verify")]
+fn verify_signer_info_rsa() {
+    let bytes = include_bytes!("examples/signed_attached_der.bin");
+    assert_eq!(verify_signed_data(bytes, None), Ok(()));
+    assert_eq!(
+        verify_signed_data(bytes, Some(b"hello world")),
+        Err(pkcs7::Error::MessageDigestMismatch)
+    );
+}
+
+#[test]
+#[cfg(feature = "verify")]
+fn verify_signer_info_rsa_detached() {
+    let bytes = include_bytes!("examples/signed_detached_der.bin");
+    assert_eq!(verify_signed_data(bytes, Some(b"hello world\n")), Ok(()));
+}
+
+#[test]
+#[cfg(feature = "verify")]
+fn verify_signer_info_rsa_without_signed_attributes() {
+    let bytes = include_bytes!("examples/signed_noattr_der.bin");
+    assert_eq!(verify_signed_data(bytes, None), Ok(()));
+    assert_eq!(
+        verify_signed_data(bytes, Some(b"hello world")),
+        Err(pkcs7::Error::SignatureInvalid)
+    );
+}
+
+#[test]
+#[cfg(feature = "verify")]
+fn verify_signer_info_algorithm_mismatch() {
+    // SHA-1 digest under `sha256WithRSAEncryption`
+    let bytes = include_bytes!("examples/signed_noattr_der.bin");
+    let result = verify_signed_data_with(bytes, None, |signer_info| {
+        signer_info.digest_algorithm.oid = ObjectIdentifier::new_unwrap("1.3.14.3.2.26");
+        signer_info.signature_algorithm.oid = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.11");
+    });
+    assert_eq!(result, Err(pkcs7::Error::AlgorithmMismatch));
+}
+
+#[test]
+#[cfg(feature = "verify")]
+fn verify_signer_info_ecdsa_p256() {
+    let bytes = include_bytes!("examples/cms_der.bin");
+    assert_eq!(verify_signed_data(bytes, None), Ok(()));
+}
+
+#[test]
+#[cfg(feature = "verify")]
+fn verify_signer_info_ecdsa_p384() {
+    let bytes = include_bytes!("examples/signed_p384_der.bin");
+    assert_eq!(verify_signed_data(bytes, None), Ok(()));
+}
+
+#[test]
+#[cfg(feature = "verify")]
+fn verify_signer_info_tampered_signature() {
+    let mut bytes = Vec::from(&include_bytes!("examples/signed_attached_der.bin")[..]);
+    let last = bytes.len() - 1;
+    bytes[last] ^= 0x01;
+    assert_eq!(
+        verify_signed_data(&bytes, None),
+        Err(pkcs7::Error::SignatureInvalid)
+    );
+}
+
+#[test]
+fn signer_certificate_issuer_and_serial_number() {
+    let bytes = include_bytes!("examples/cms_der.bin");
+
+    let signed_data = match ContentInfo::from_der(bytes) {
+        Ok(ContentInfo::SignedData(Some(data))) => data,
+        other => panic!("unexpected result: {:?}", other),
+    };
+    let signer_info = signed_data.signer_infos.get(0).expect("signer info");
+
+    let cert = signed_data
+        .signer_certificate(signer_info)
+        .expect("signer certificate");
+    assert_eq!(
+        cert.tbs_certificate.serial_number.as_bytes(),
+        hex!("605CD2D52675B392A5AA9B02B26A5566")
+    );
+
+    let mut other_signer = signer_info.clone();
+    other_signer.sid = SignerIdentifier::SubjectKeyIdentifier(SubjectKeyIdentifier(
+        OctetString::new(hex!("0102030405060708090A0B0C0D0E0F1011121314")).expect("octet string"),
+    ));
+    assert!(signed_data.signer_certificate(&other_signer).is_none());
+}
+
+#[test]
+fn signer_certificate_subject_key_identifier() {
+    let bytes = include_bytes!("examples/signed_keyid_der.bin");
+
+    let signed_data = match ContentInfo::from_der(bytes) {
+        Ok(ContentInfo::SignedData(Some(data))) => data,
+        other => panic!("unexpected result: {:?}", other),
+    };
+    assert_eq!(signed_data.version, CmsVersion::V3);
+    assert_eq!(signed_data.version, signed_data.compute_version());
+
+    let signer_info = signed_data.signer_infos.get(0).expect("signer info");
+    assert_eq!(signer_info.version, CmsVersion::V3);
+    assert!(matches!(
+        signer_info.sid,
+        SignerIdentifier::SubjectKeyIdentifier(_)
+    ));
+
+    let cert = signed_data
+        .signer_certificate(signer_info)
+        .expect("signer certificate");
+    assert_eq!(
+        cert.tbs_certificate.serial_number.as_bytes(),
+        hex!("12DDE97AB9F5E910FAB61274C9DC50E7934342FC")
+    );
+
+    // [0] IMPLICIT SubjectKeyIdentifier
+    let sid_der = signer_info.sid.to_vec().expect("encoding success");
+    assert_eq!(sid_der[0], 0x80);
+    assert_eq!(
+        SignerIdentifier::from_der(&sid_der).expect("decoding success"),
+        signer_info.sid
+    );
+
+    let reencoded = ContentInfo::SignedData(Some(signed_data.clone()))
+        .to_vec()
+        .expect("encoding success");
+    assert_eq!(reencoded.as_slice(), bytes.as_slice());
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn pem_roundtrip_content_info() {
+    let pem = include_str!("examples/signed_attached.pem");
+    let der_bytes = include_bytes!("examples/signed_attached_der.bin");
+
+    let (label, doc) = Document::from_pem(pem).expect("valid PEM");
+    assert_eq!(label, "PKCS7");
+    ContentInfo::validate_pem_label(label).expect("PKCS7 label");
+    assert_eq!(doc.as_bytes(), der_bytes.as_slice());
+
+    let content_info = doc
+        .decode_msg::<ContentInfo<'_>>()
+        .expect("valid content info");
+    let reencoded = content_info.to_pem(LineEnding::LF).expect("PEM encoding");
+    assert_eq!(reencoded, pem);
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn pem_encode_signed_data_content() {
+    let pem = include_str!("examples/signed_attached.pem");
+    let bytes = include_bytes!("examples/signed_attached_der.bin");
+
+    let signed_data = match ContentInfo::from_der(bytes) {
+        Ok(ContentInfo::SignedData(Some(data))) => data,
+        other => panic!("unexpected result: {:?}", other),
+    };
+
+    let content_info = ContentInfo::from(signed_data);
+    assert_eq!(
+        content_info.to_pem(LineEnding::LF).expect("PEM encoding"),
+        pem
+    );
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn pem_decode_content_info() {
+    let pem = include_str!("examples/signed_attached.pem");
+    let der_bytes = include_bytes!("examples/signed_attached_der.bin");
+
+    let mut buf = vec![0u8; pem.len()];
+    let content_info = ContentInfo::from_pem(pem, &mut buf).expect("valid PEM");
+    assert_eq!(content_info.to_vec().expect("encoding success"), der_bytes);
+    assert_eq!(
+        content_info.to_pem(LineEnding::LF).expect("PEM encoding"),
+        pem
+    );
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn pem_roundtrip_signed_data_content() {
+    let pem = include_str!("examples/signed_attached.pem");
+
+    let mut buf = vec![0u8; pem.len()];
+    let signed_data = SignedDataContent::from_pem(pem, &mut buf).expect("valid PEM");
+    assert_eq!(signed_data.signer_infos.len(), 1);
+    assert_eq!(
+        signed_data.to_pem(LineEnding::LF).expect("PEM encoding"),
+        pem
+    );
+
+    // `ContentInfo` whose content isn't `signed-data`
+    let data_pem = "-----BEGIN PKCS7-----\nMAsGCSqGSIb3DQEHAQ==\n-----END PKCS7-----\n";
+    let mut buf = [0u8; 64];
+    assert!(ContentInfo::from_pem(data_pem, &mut buf).is_ok());
+    assert!(SignedDataContent::from_pem(data_pem, &mut buf).is_err());
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn pem_unexpected_label() {
+    assert!(ContentInfo::validate_pem_label("CERTIFICATE").is_err());
+
+    let pem = "-----BEGIN CERTIFICATE-----\nMAsGCSqGSIb3DQEHAQ==\n-----END CERTIFICATE-----\n";
+    let mut buf = [0u8; 64];
+    assert!(ContentInfo::from_pem(pem, &mut buf).is_err());
+}
+
+fn first_certificate(bytes: &[u8]) -> Certificate {
+    match ContentInfo::from_der(bytes) {
+        Ok(ContentInfo::SignedData(Some(data))) => {
+            match data.certificates.as_ref().and_then(|certs| certs.get(0)) {
+                Some(CertificateChoices::Certificate(cert)) => cert.clone(),
+                other => panic!("unexpected certificate: {:?}", other),
+            }
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn build_certs_only() {
+    let bytes = include_bytes!("examples/certs_only_der.bin");
+
+    let certs = [
+        first_certificate(include_bytes!("examples/signed_p384_der.bin")),
+        first_certificate(include_bytes!("examples/signed_attached_der.bin")),
+    ];
+
+    let signed_data = SignedDataBuilder::certs_only(certs.clone())
+        .build()
+        .expect("build success");
+    assert_eq!(signed_data.version, CmsVersion::V1);
+    assert!(signed_data.digest_algorithms.is_empty());
+    assert_eq!(
+        signed_data.encap_content_info.e_content_type,
+        pkcs7::PKCS_7_DATA_OID
+    );
+    assert_eq!(signed_data.encap_content_info.e_content, None);
+    assert_eq!(signed_data.crls, None);
+    assert!(signed_data.signer_infos.is_empty());
+
+    let encoded = ContentInfo::from(signed_data)
+        .to_vec()
+        .expect("encoding success");
+    assert_eq!(encoded.as_slice(), bytes.as_slice());
+
+    match ContentInfo::from_der(&encoded) {
+        Ok(ContentInfo::SignedData(Some(data))) => {
+            let decoded = data.certificates.expect("certificates");
+            assert_eq!(decoded.len(), 2);
+            for cert in certs {
+                assert!(decoded
+                    .iter()
+                    .any(|choice| *choice == CertificateChoices::Certificate(cert.clone())));
+            }
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn build_certs_only_from_pem() {
+    // `certs_only_der.bin` bundles the certificates from these PEM files
+    let certs = [
+        include_str!("examples/cert.pem"),
+        include_str!("examples/p384_cert.pem"),
+    ]
+    .map(|pem| {
+        let (label, doc) = Document::from_pem(pem).expect("valid PEM");
+        assert_eq!(label, "CERTIFICATE");
+        doc.decode_msg::<Certificate>().expect("valid certificate")
+    });
+
+    // ...which are also the signers of the signed fixtures
+    assert_eq!(
+        certs[0],
+        first_certificate(include_bytes!("examples/signed_attached_der.bin"))
+    );
+    assert_eq!(
+        certs[1],
+        first_certificate(include_bytes!("examples/signed_p384_der.bin"))
+    );
+
+    let encoded = ContentInfo::from(
+        SignedDataBuilder::certs_only(certs)
+            .build()
+            .expect("build success"),
+    )
+    .to_vec()
+    .expect("encoding success");
+    assert_eq!(
+        encoded.as_slice(),
+        include_bytes!("examples/certs_only_der.bin").as_slice()
+    );
+}
+
+#[test]
+fn decode_tst_info() {
+    let bytes = include_bytes!("examples/tst_token_der.bin");
+
+    let signed_data = match ContentInfo::from_der(bytes) {
+        Ok(ContentInfo::SignedData(Some(data))) => data,
+        other => panic!("unexpected result: {:?}", other),
+    };
+    assert_eq!(
+        signed_data.encap_content_info.e_content_type,
+        pkcs7::PKCS_9_TST_INFO_OID
+    );
+
+    let tst_info = signed_data
+        .encap_content_info
+        .tst_info()
+        .expect("valid TSTInfo")
+        .expect("TSTInfo content");
+
+    assert_eq!(tst_info.version, 1);
+    assert_eq!(tst_info.policy, ObjectIdentifier::new_unwrap("1.2.3.4.1"));
+    assert_eq!(
+        tst_info.message_imprint.hash_algorithm.oid,
+        ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.1")
+    );
+    assert_eq!(
+        tst_info.message_imprint.hashed_message.as_bytes(),
+        hex!("a948904f2f0f479b8f8197694b30184b0d2ed1c1cd2a1ec0fb85d299a192a447")
+    );
+    assert_eq!(tst_info.serial_number.as_bytes(), hex!("02"));
+    assert_eq!(
+        tst_info.gen_time.to_unix_duration().as_secs(),
+        1_792_004_792
+    );
+    assert_eq!(
+        tst_info.accuracy,
+        Some(Accuracy {
+            seconds: Some(1),
+            millis: Some(500),
+            micros: Some(100),
+        })
+    );
+    assert!(tst_info.ordering);
+    assert_eq!(
+        tst_info.nonce.expect("nonce").as_bytes(),
+        hex!("68E6F5A826CAB423")
+    );
+    match &tst_info.tsa {
+        Some(GeneralName::DirectoryName(name)) => {
+            assert_eq!(name.to_string(), "CN=tsa.example.org")
+        }
+        other => panic!("unexpected TSA name: {:?}", other),
+    }
+    assert_eq!(tst_info.extensions, None);
+
+    let reencoded = tst_info.to_vec().expect("encoding success");
+    let e_content = signed_data
+        .encap_content_info
+        .e_content
+        .expect("encapsulated content");
+    assert_eq!(reencoded.as_slice(), e_content.value());
+}
+
+#[test]
+fn tst_info_other_content_type() {
+    let bytes = include_bytes!("examples/signed_attached_der.bin");
+
+    match ContentInfo::from_der(bytes) {
+        Ok(ContentInfo::SignedData(Some(data))) => {
+            assert_eq!(data.encap_content_info.tst_info().expect("no error"), None)
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+#[cfg(feature = "verify")]
+fn verify_signer_info_tst_token() {
+    let bytes = include_bytes!("examples/tst_token_der.bin");
+    assert_eq!(verify_signed_data(bytes, None), Ok(()));
+}
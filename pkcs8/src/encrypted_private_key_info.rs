@@ -13,12 +13,14 @@ use der::SecretDocument;
 
 #[cfg(feature = "encryption")]
 use {
+    crate::{ObjectIdentifier, PrivateKeyInfo},
+    der::zeroize::Zeroizing,
     pkcs5::pbes2,
     rand_core::{CryptoRng, RngCore},
 };
 
 #[cfg(feature = "pem")]
-use der::pem::PemLabel;
+use der::{pem::PemLabel, Document};
 
 /// PKCS#8 `EncryptedPrivateKeyInfo`.
 ///
@@ -53,13 +55,61 @@ pub struct EncryptedPrivateKeyInfo<'a> {
 impl<'a> EncryptedPrivateKeyInfo<'a> {
     /// Attempt to decrypt this encrypted private key using the provided
     /// password to derive an encryption key.
+    ///
+    /// The returned document is checked to contain a [`PrivateKeyInfo`],
+    /// which can be obtained using [`SecretDocument::decode_msg`].
+    ///
+    /// Returns [`pkcs5::Error::DecryptFailed`] if the password is incorrect
+    /// and the decrypted padding is invalid. A wrong password which happens
+    /// to produce valid padding is caught by the [`PrivateKeyInfo`] check.
+    ///
+    /// The decrypted plaintext is zeroized on drop, both in the returned
+    /// [`SecretDocument`] and in the intermediate buffer used for decryption,
+    /// including when decryption fails. The key derived from the password is
+    /// only zeroized if the `zeroize` feature is enabled.
     #[cfg(feature = "encryption")]
     #[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
     pub fn decrypt(&self, password: impl AsRef<[u8]>) -> Result<SecretDocument> {
-        Ok(self
+        self.decrypt_with_max_scrypt_memory(password, pbes2::ScryptParams::DEFAULT_MAX_MEMORY)
+    }
+
+    /// Attempt to decrypt this encrypted private key, as with
+    /// [`EncryptedPrivateKeyInfo::decrypt`], but with a custom limit in bytes
+    /// on the memory cost of the scrypt key derivation function.
+    ///
+    /// The default limit is [`pbes2::ScryptParams::DEFAULT_MAX_MEMORY`], and
+    /// keys whose scrypt parameters exceed the limit are rejected with
+    /// [`pkcs5::Error::AlgorithmParametersInvalid`] before deriving a key.
+    #[cfg(feature = "encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+    pub fn decrypt_with_max_scrypt_memory(
+        &self,
+        password: impl AsRef<[u8]>,
+        max_memory: usize,
+    ) -> Result<SecretDocument> {
+        let mut buffer = Zeroizing::new(self.encrypted_data.to_vec());
+        let plaintext = self
             .encryption_algorithm
-            .decrypt(password, self.encrypted_data)?
-            .try_into()?)
+            .decrypt_in_place_with_max_scrypt_memory(password, buffer.as_mut_slice(), max_memory)?;
+
+        let doc = SecretDocument::try_from(plaintext)?;
+
+        doc.decode_msg::<PrivateKeyInfo<'_>>()?;
+        Ok(doc)
+    }
+
+    /// Decode a PEM-encoded [`EncryptedPrivateKeyInfo`], checking that it has
+    /// the `ENCRYPTED PRIVATE KEY` type label.
+    ///
+    /// The decoded key borrows from the DER document this returns, and can be
+    /// obtained using [`Document::decode_msg`].
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    pub fn from_pem(s: &str) -> Result<Document> {
+        let (label, doc) = Document::from_pem(s)?;
+        Self::validate_pem_label(label)?;
+        doc.decode_msg::<EncryptedPrivateKeyInfo<'_>>()?;
+        Ok(doc)
     }
 
     /// Encrypt the given ASN.1 DER document using a symmetric encryption key
@@ -81,6 +131,39 @@ impl<'a> EncryptedPrivateKeyInfo<'a> {
         EncryptedPrivateKeyInfo::encrypt_with(pbes2_params, password, doc)
     }
 
+    /// Encrypt the given ASN.1 DER document using PBES2 with PBKDF2-SHA256
+    /// and the AES-CBC cipher identified by `cipher`, with a random salt and
+    /// IV.
+    #[cfg(feature = "encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+    pub(crate) fn encrypt_pbkdf2(
+        mut rng: impl CryptoRng + RngCore,
+        password: impl AsRef<[u8]>,
+        iteration_count: u32,
+        cipher: ObjectIdentifier,
+        doc: &[u8],
+    ) -> Result<SecretDocument> {
+        let mut salt = [0u8; 16];
+        rng.fill_bytes(&mut salt);
+
+        let mut iv = [0u8; 16];
+        rng.fill_bytes(&mut iv);
+
+        let encryption = match cipher {
+            pbes2::AES_128_CBC_OID => pbes2::EncryptionScheme::Aes128Cbc { iv: &iv },
+            pbes2::AES_192_CBC_OID => pbes2::EncryptionScheme::Aes192Cbc { iv: &iv },
+            pbes2::AES_256_CBC_OID => pbes2::EncryptionScheme::Aes256Cbc { iv: &iv },
+            oid => return Err(pkcs5::Error::UnsupportedAlgorithm { oid }.into()),
+        };
+
+        let pbes2_params = pbes2::Parameters {
+            kdf: pbes2::Pbkdf2Params::hmac_with_sha256(iteration_count, &salt)?.into(),
+            encryption,
+        };
+
+        EncryptedPrivateKeyInfo::encrypt_with(pbes2_params, password, doc)
+    }
+
     /// Encrypt this private key using a symmetric encryption key derived
     /// from the provided password and [`pbes2::Parameters`].
     #[cfg(feature = "encryption")]
@@ -48,6 +48,17 @@
 //!       - SHA-1 based PRF with HMAC-SHA1, when the `sha1` feature of this crate is enabled.
 //!   - Symmetric encryption: AES-128-CBC, AES-192-CBC, or AES-256-CBC
 //!     (best available options for PKCS#5v2)
+//!
+//! ## Zeroization
+//! Private keys decoded or decrypted into a [`der::SecretDocument`] are
+//! zeroized on drop, as is the intermediate buffer used when decrypting them.
+//! PEM-encoded private keys are returned as `Zeroizing<String>`.
+//!
+//! When the `zeroize` feature is enabled, the symmetric key derived from the
+//! password is also zeroized once decryption or encryption completes, as is
+//! the copy of the plaintext made by [`pkcs5`] if encryption fails. The
+//! internal state of the key derivation function and block cipher isn't
+//! zeroized, nor is the password itself, which is owned by the caller.
 //!  
 //! ## Legacy DES-CBC and DES-EDE3-CBC (3DES) support (optional)
 //! When the `des-insecure` and/or `3des` features are enabled this crate provides support for
@@ -70,7 +81,12 @@
 //! [PKCS#5v2 Password Based Encryption Scheme 2 (RFC 8018)]: https://tools.ietf.org/html/rfc8018#section-6.2
 //! [scrypt]: https://en.wikipedia.org/wiki/Scrypt
 
-#[cfg(feature = "pem")]
+#[cfg(any(
+    feature = "pem",
+    feature = "ed25519",
+    feature = "p256",
+    feature = "p384"
+))]
 extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
@@ -82,6 +98,8 @@ mod version;
 
 #[cfg(feature = "pkcs5")]
 pub(crate) mod encrypted_private_key_info;
+#[cfg(feature = "pem")]
+mod pem;
 
 pub use crate::{
     error::{Error, Result},
@@ -104,7 +122,7 @@ pub use {
 
 #[cfg(feature = "pem")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
-pub use der::pem::LineEnding;
+pub use {crate::pem::PrivateKeyPem, der::pem::LineEnding};
 
 #[cfg(feature = "pkcs5")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pkcs5")))]
@@ -0,0 +1,64 @@
+//! PEM-encoded PKCS#8 private keys
+
+use crate::{Error, PrivateKeyInfo, Result};
+use der::{pem::PemLabel, SecretDocument};
+
+#[cfg(feature = "pkcs5")]
+use {crate::EncryptedPrivateKeyInfo, der::Document};
+
+/// PEM-encoded PKCS#8 private key, which may or may not be encrypted.
+///
+/// Use [`PrivateKeyPem::from_pem_auto`] to decode a key whose type label
+/// isn't known in advance.
+#[derive(Clone, Debug)]
+pub enum PrivateKeyPem {
+    /// [`PrivateKeyInfo`], with the `PRIVATE KEY` type label.
+    Plaintext(SecretDocument),
+
+    /// [`EncryptedPrivateKeyInfo`], with the `ENCRYPTED PRIVATE KEY` type
+    /// label.
+    #[cfg(feature = "pkcs5")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pkcs5")))]
+    Encrypted(Document),
+}
+
+impl PrivateKeyPem {
+    /// Decode a PEM-encoded private key, using its type label to determine
+    /// whether it's a [`PrivateKeyInfo`] or an [`EncryptedPrivateKeyInfo`].
+    ///
+    /// Returns an error for any other label, or if the document doesn't
+    /// contain the structure its label indicates.
+    pub fn from_pem_auto(s: &str) -> Result<Self> {
+        let label = der::pem::decode_label(s.as_bytes())?;
+
+        if label == PrivateKeyInfo::PEM_LABEL {
+            return PrivateKeyInfo::from_pem(s).map(Self::Plaintext);
+        }
+
+        #[cfg(feature = "pkcs5")]
+        if label == EncryptedPrivateKeyInfo::PEM_LABEL {
+            return EncryptedPrivateKeyInfo::from_pem(s).map(Self::Encrypted);
+        }
+
+        Err(Error::from(der::pem::Error::Label))
+    }
+
+    /// Get the [`PrivateKeyInfo`] document, if the key isn't encrypted.
+    pub fn plaintext(&self) -> Option<&SecretDocument> {
+        match self {
+            Self::Plaintext(doc) => Some(doc),
+            #[cfg(feature = "pkcs5")]
+            _ => None,
+        }
+    }
+
+    /// Get the [`EncryptedPrivateKeyInfo`] document, if the key is encrypted.
+    #[cfg(feature = "pkcs5")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pkcs5")))]
+    pub fn encrypted(&self) -> Option<&Document> {
+        match self {
+            Self::Encrypted(doc) => Some(doc),
+            _ => None,
+        }
+    }
+}
@@ -1,11 +1,13 @@
 //! PKCS#8 `PrivateKeyInfo`.
 
-use crate::{AlgorithmIdentifierRef, Error, Result, Version};
+use crate::{
+    AlgorithmIdentifierRef, Error, ObjectIdentifier, Result, SubjectPublicKeyInfoRef, Version,
+};
 use core::fmt;
 use der::{
     asn1::{AnyRef, BitStringRef, ContextSpecific, OctetStringRef},
-    Decode, DecodeValue, Encode, EncodeValue, Header, Length, Reader, Sequence, TagMode, TagNumber,
-    Writer,
+    Decode, DecodeValue, Encode, EncodeValue, Header, Length, Reader, Sequence, SliceReader,
+    TagMode, TagNumber, Writer,
 };
 
 #[cfg(feature = "alloc")]
@@ -25,14 +27,34 @@ use der::pem::PemLabel;
 #[cfg(feature = "subtle")]
 use subtle::{Choice, ConstantTimeEq};
 
+#[cfg(any(feature = "ed25519", feature = "p256", feature = "p384"))]
+use {alloc::vec::Vec, der::asn1::BitString, spki::SubjectPublicKeyInfoOwned};
+
+/// Context-specific tag number for the attributes.
+const ATTRIBUTES_TAG: TagNumber = TagNumber::N0;
+
 /// Context-specific tag number for the public key.
 const PUBLIC_KEY_TAG: TagNumber = TagNumber::N1;
 
+/// Elliptic curve public key cryptography (`id-ecPublicKey`) OID.
+const ID_EC_PUBLIC_KEY: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+
+/// Ed25519 (`id-Ed25519`) OID.
+#[cfg(feature = "ed25519")]
+const ID_ED_25519: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.112");
+
+/// NIST P-256 (`secp256r1`) curve OID.
+#[cfg(feature = "p256")]
+const SECP_256_R_1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
+
+/// NIST P-384 (`secp384r1`) curve OID.
+#[cfg(feature = "p384")]
+const SECP_384_R_1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.34");
+
 /// PKCS#8 `PrivateKeyInfo`.
 ///
 /// ASN.1 structure containing an `AlgorithmIdentifier`, private key
-/// data in an algorithm specific format, and optional attributes
-/// (ignored by this implementation).
+/// data in an algorithm specific format, and optional attributes.
 ///
 /// Supports PKCS#8 v1 as described in [RFC 5208] and PKCS#8 v2 as described
 /// in [RFC 5958]. PKCS#8 v2 keys include an additional public key field.
@@ -97,6 +119,10 @@ pub struct PrivateKeyInfo<'a> {
     /// Private key data.
     pub private_key: &'a [u8],
 
+    /// DER-encoded `Attributes`, i.e. the contents of the `SET OF Attribute`
+    /// without the `[0]` tag and length.
+    pub attributes: Option<&'a [u8]>,
+
     /// Public key data, optionally available if version is V2.
     pub public_key: Option<&'a [u8]>,
 }
@@ -110,6 +136,7 @@ impl<'a> PrivateKeyInfo<'a> {
         Self {
             algorithm,
             private_key,
+            attributes: None,
             public_key: None,
         }
     }
@@ -125,6 +152,88 @@ impl<'a> PrivateKeyInfo<'a> {
         }
     }
 
+    /// Decode a PEM-encoded [`PrivateKeyInfo`], checking that it has the
+    /// `PRIVATE KEY` type label.
+    ///
+    /// The decoded key borrows from the DER document this returns, and can be
+    /// obtained using [`SecretDocument::decode_msg`].
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    pub fn from_pem(s: &str) -> Result<SecretDocument> {
+        let (label, doc) = SecretDocument::from_pem(s)?;
+        Self::validate_pem_label(label)?;
+        doc.decode_msg::<PrivateKeyInfo<'_>>()?;
+        Ok(doc)
+    }
+
+    /// Get the [`SubjectPublicKeyInfoRef`] corresponding to this private key,
+    /// if it's available without performing any cryptographic operations.
+    ///
+    /// This is:
+    /// - the `publicKey` field of PKCS#8 v2 keys, if present
+    /// - the `publicKey` field of the SEC1 `ECPrivateKey` contained in
+    ///   elliptic curve (`id-ecPublicKey`) keys, if present
+    ///
+    /// Otherwise, `Ok(None)` is returned. In particular, the public key of a
+    /// PKCS#8 v1 Ed25519 or X25519 key isn't stored anywhere, and computing it
+    /// requires curve arithmetic: see [`PrivateKeyInfo::derive_public_key`].
+    pub fn public_key(&self) -> Result<Option<SubjectPublicKeyInfoRef<'a>>> {
+        let subject_public_key = match self.public_key {
+            Some(public_key) => Some(BitStringRef::from_bytes(public_key)?),
+            None if self.algorithm.oid == ID_EC_PUBLIC_KEY => {
+                decode_ec_private_key(self.private_key)
+                    .map_err(|_| Error::KeyMalformed)?
+                    .1
+            }
+            None => None,
+        };
+
+        Ok(
+            subject_public_key.map(|subject_public_key| SubjectPublicKeyInfoRef {
+                algorithm: self.algorithm,
+                subject_public_key,
+            }),
+        )
+    }
+
+    /// Get the [`SubjectPublicKeyInfoOwned`] corresponding to this private
+    /// key, computing it from the private key if it isn't embedded.
+    ///
+    /// An embedded public key is returned as-is, as with
+    /// [`PrivateKeyInfo::public_key`]. Otherwise, the public key is derived
+    /// for the following algorithms, each enabled by the crate feature of
+    /// the same name:
+    /// - `ed25519`: Ed25519 (`id-Ed25519`)
+    /// - `p256`: elliptic curve (`id-ecPublicKey`) keys over NIST P-256
+    /// - `p384`: elliptic curve (`id-ecPublicKey`) keys over NIST P-384
+    ///
+    /// Elliptic curve public keys are encoded as uncompressed SEC1 points.
+    #[cfg(any(feature = "ed25519", feature = "p256", feature = "p384"))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(any(feature = "ed25519", feature = "p256", feature = "p384")))
+    )]
+    pub fn derive_public_key(&self) -> Result<SubjectPublicKeyInfoOwned> {
+        if let Some(public_key) = self.public_key()? {
+            return Ok(public_key.into());
+        }
+
+        let public_key = match self.algorithm.oid {
+            #[cfg(feature = "ed25519")]
+            ID_ED_25519 => derive_ed25519_public_key(self.private_key)?,
+            #[cfg(any(feature = "p256", feature = "p384"))]
+            ID_EC_PUBLIC_KEY => {
+                derive_ec_public_key(self.algorithm.parameters_oid()?, self.private_key)?
+            }
+            oid => return Err(spki::Error::OidUnknown { oid }.into()),
+        };
+
+        Ok(SubjectPublicKeyInfoOwned {
+            algorithm: self.algorithm.into(),
+            subject_public_key: BitString::from_bytes(&public_key)?,
+        })
+    }
+
     /// Encrypt this private key using a symmetric encryption key derived
     /// from the provided password.
     ///
@@ -158,6 +267,45 @@ impl<'a> PrivateKeyInfo<'a> {
         EncryptedPrivateKeyInfo::encrypt_with(pbes2_params, password, der.as_ref())
     }
 
+    /// Encrypt this private key using PBES2 with PBKDF2-SHA256 as the key
+    /// derivation function and the given number of iterations.
+    ///
+    /// `cipher` selects the symmetric cipher, and must be one of
+    /// [`pbes2::AES_128_CBC_OID`], [`pbes2::AES_192_CBC_OID`] or
+    /// [`pbes2::AES_256_CBC_OID`]. A random 16-byte salt and IV are generated
+    /// using `rng`.
+    ///
+    /// Unlike [`PrivateKeyInfo::encrypt`], which uses scrypt, the result can
+    /// be decrypted by tools like OpenSSL which don't support scrypt.
+    #[cfg(feature = "encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+    pub fn encrypt_pbkdf2(
+        &self,
+        rng: impl CryptoRng + RngCore,
+        password: impl AsRef<[u8]>,
+        iteration_count: u32,
+        cipher: ObjectIdentifier,
+    ) -> Result<SecretDocument> {
+        let der = Zeroizing::new(self.to_vec()?);
+        EncryptedPrivateKeyInfo::encrypt_pbkdf2(
+            rng,
+            password,
+            iteration_count,
+            cipher,
+            der.as_ref(),
+        )
+    }
+
+    /// Get the encoded length of the `[0]`-tagged attributes, if present.
+    fn attributes_len(&self) -> der::Result<Length> {
+        match self.attributes {
+            Some(attributes) => {
+                Header::new(attributes_tag(), attributes.len())?.encoded_len()? + attributes.len()
+            }
+            None => Ok(Length::ZERO),
+        }
+    }
+
     /// Get a `BIT STRING` representation of the public key, if present.
     fn public_key_bit_string(&self) -> der::Result<Option<ContextSpecific<BitStringRef<'a>>>> {
         self.public_key
@@ -172,6 +320,111 @@ impl<'a> PrivateKeyInfo<'a> {
     }
 }
 
+/// Tag for the `[0] IMPLICIT Attributes` field, which is a constructed
+/// `SET OF`.
+fn attributes_tag() -> der::Tag {
+    der::Tag::ContextSpecific {
+        constructed: true,
+        number: ATTRIBUTES_TAG,
+    }
+}
+
+/// Tag for the `[0] EXPLICIT ECParameters` field of a SEC1 `ECPrivateKey`.
+fn ec_parameters_tag() -> der::Tag {
+    der::Tag::ContextSpecific {
+        constructed: true,
+        number: TagNumber::N0,
+    }
+}
+
+/// Decode the `privateKey` and `publicKey` fields of a SEC1 `ECPrivateKey`,
+/// as described in [RFC 5915 Section 3]:
+///
+/// ```text
+/// ECPrivateKey ::= SEQUENCE {
+///   version        INTEGER { ecPrivkeyVer1(1) } (ecPrivkeyVer1),
+///   privateKey     OCTET STRING,
+///   parameters [0] ECParameters {{ NamedCurve }} OPTIONAL,
+///   publicKey  [1] BIT STRING OPTIONAL
+/// }
+/// ```
+///
+/// [RFC 5915 Section 3]: https://datatracker.ietf.org/doc/html/rfc5915#section-3
+fn decode_ec_private_key(private_key: &[u8]) -> der::Result<(&[u8], Option<BitStringRef<'_>>)> {
+    let mut reader = SliceReader::new(private_key)?;
+
+    let fields = reader.sequence(|reader| {
+        if u8::decode(reader)? != 1 {
+            return Err(der::Tag::Integer.value_error());
+        }
+
+        let private_key = OctetStringRef::decode(reader)?;
+
+        // Skip the optional `parameters` field
+        if reader.peek_tag().ok() == Some(ec_parameters_tag()) {
+            let header = Header::decode(reader)?;
+            reader.read_slice(header.length)?;
+        }
+
+        let public_key =
+            reader.context_specific::<BitStringRef<'_>>(TagNumber::N1, TagMode::Explicit)?;
+
+        Ok((private_key.as_bytes(), public_key))
+    })?;
+
+    reader.finish(fields)
+}
+
+/// Derive an Ed25519 public key from the `CurvePrivateKey` described in
+/// [RFC 8410 Section 7].
+///
+/// [RFC 8410 Section 7]: https://datatracker.ietf.org/doc/html/rfc8410#section-7
+#[cfg(feature = "ed25519")]
+fn derive_ed25519_public_key(private_key: &[u8]) -> Result<Vec<u8>> {
+    let seed = OctetStringRef::from_der(private_key)?
+        .as_bytes()
+        .try_into()
+        .map_err(|_| Error::KeyMalformed)?;
+
+    let signing_key = ed25519_dalek::SigningKey::from_bytes(seed);
+    Ok(Vec::from(signing_key.verifying_key().to_bytes()))
+}
+
+/// Derive an elliptic curve public key over the given `curve` from a SEC1
+/// `ECPrivateKey`, encoded as an uncompressed SEC1 point.
+#[cfg(any(feature = "p256", feature = "p384"))]
+fn derive_ec_public_key(curve: ObjectIdentifier, private_key: &[u8]) -> Result<Vec<u8>> {
+    let (secret, _) = decode_ec_private_key(private_key).map_err(|_| Error::KeyMalformed)?;
+
+    match curve {
+        #[cfg(feature = "p256")]
+        SECP_256_R_1 => {
+            use p256::elliptic_curve::sec1::ToEncodedPoint;
+
+            let secret_key =
+                p256::SecretKey::from_slice(secret).map_err(|_| Error::KeyMalformed)?;
+            Ok(secret_key
+                .public_key()
+                .to_encoded_point(false)
+                .as_bytes()
+                .to_vec())
+        }
+        #[cfg(feature = "p384")]
+        SECP_384_R_1 => {
+            use p384::elliptic_curve::sec1::ToEncodedPoint;
+
+            let secret_key =
+                p384::SecretKey::from_slice(secret).map_err(|_| Error::KeyMalformed)?;
+            Ok(secret_key
+                .public_key()
+                .to_encoded_point(false)
+                .as_bytes()
+                .to_vec())
+        }
+        oid => Err(spki::Error::OidUnknown { oid }.into()),
+    }
+}
+
 impl<'a> DecodeValue<'a> for PrivateKeyInfo<'a> {
     fn decode_value<R: Reader<'a>>(
         reader: &mut R,
@@ -182,6 +435,15 @@ impl<'a> DecodeValue<'a> for PrivateKeyInfo<'a> {
             let version = Version::decode(reader)?;
             let algorithm = reader.decode()?;
             let private_key = OctetStringRef::decode(reader)?.into();
+
+            let attributes = match reader.peek_tag() {
+                Ok(tag) if tag == attributes_tag() => {
+                    let header = Header::decode(reader)?;
+                    Some(reader.read_slice(header.length)?)
+                }
+                _ => None,
+            };
+
             let public_key = reader
                 .context_specific::<BitStringRef<'_>>(PUBLIC_KEY_TAG, TagMode::Implicit)?
                 .map(|bs| {
@@ -209,6 +471,7 @@ impl<'a> DecodeValue<'a> for PrivateKeyInfo<'a> {
             Ok(Self {
                 algorithm,
                 private_key,
+                attributes,
                 public_key,
             })
         })
@@ -220,6 +483,7 @@ impl EncodeValue for PrivateKeyInfo<'_> {
         self.version().encoded_len()?
             + self.algorithm.encoded_len()?
             + OctetStringRef::new(self.private_key)?.encoded_len()?
+            + self.attributes_len()?
             + self.public_key_bit_string()?.encoded_len()?
     }
 
@@ -227,6 +491,12 @@ impl EncodeValue for PrivateKeyInfo<'_> {
         self.version().encode(writer)?;
         self.algorithm.encode(writer)?;
         OctetStringRef::new(self.private_key)?.encode(writer)?;
+
+        if let Some(attributes) = self.attributes {
+            Header::new(attributes_tag(), attributes.len())?.encode(writer)?;
+            writer.write(attributes)?;
+        }
+
         self.public_key_bit_string()?.encode(writer)?;
         Ok(())
     }
@@ -247,6 +517,7 @@ impl<'a> fmt::Debug for PrivateKeyInfo<'a> {
         f.debug_struct("PrivateKeyInfo")
             .field("version", &self.version())
             .field("algorithm", &self.algorithm)
+            .field("attributes", &self.attributes)
             .field("public_key", &self.public_key)
             .finish_non_exhaustive()
     }
@@ -283,8 +554,9 @@ impl PemLabel for PrivateKeyInfo<'_> {
 impl<'a> ConstantTimeEq for PrivateKeyInfo<'a> {
     fn ct_eq(&self, other: &Self) -> Choice {
         // NOTE: public fields are not compared in constant time
-        let public_fields_eq =
-            self.algorithm == other.algorithm && self.public_key == other.public_key;
+        let public_fields_eq = self.algorithm == other.algorithm
+            && self.attributes == other.attributes
+            && self.public_key == other.public_key;
 
         self.private_key.ct_eq(other.private_key) & Choice::from(public_fields_eq as u8)
     }
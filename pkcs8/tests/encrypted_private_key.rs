@@ -5,11 +5,17 @@
 use hex_literal::hex;
 use pkcs8::{pkcs5::pbes2, EncryptedPrivateKeyInfo, PrivateKeyInfo};
 
+#[cfg(feature = "encryption")]
+use pkcs8::{
+    rand_core::{self, CryptoRng, RngCore},
+    Error, ObjectIdentifier,
+};
+
 #[cfg(feature = "alloc")]
 use der::Encode;
 
 #[cfg(feature = "pem")]
-use der::EncodePem;
+use {der::EncodePem, pkcs8::PrivateKeyPem};
 
 /// Ed25519 PKCS#8 private key plaintext encoded as ASN.1 DER
 #[cfg(feature = "encryption")]
@@ -46,6 +52,34 @@ const ED25519_DER_AES256_PBKDF2_SHA256_EXAMPLE: &[u8] =
 const ED25519_DER_AES256_SCRYPT_EXAMPLE: &[u8] =
     include_bytes!("examples/ed25519-encpriv-aes256-scrypt.der");
 
+/// NIST P-256 PKCS#8 private key plaintext encoded as ASN.1 DER
+#[cfg(feature = "encryption")]
+const P256_DER_PLAINTEXT_EXAMPLE: &[u8] = include_bytes!("examples/p256-priv.der");
+
+/// NIST P-256 PKCS#8 encrypted private key (PBES2 + AES-256-CBC + PBKDF2-SHA256) encoded as ASN.1 DER.
+///
+/// Generated with OpenSSL's default PBES2 parameters using:
+///
+/// ```
+/// $ openssl pkcs8 -topk8 -v2 aes-256-cbc -inform der -in p256-priv.der -outform der -out p256-encpriv-aes256-pbkdf2-sha256.der
+/// ```
+#[cfg(feature = "encryption")]
+const P256_DER_AES256_PBKDF2_SHA256_EXAMPLE: &[u8] =
+    include_bytes!("examples/p256-encpriv-aes256-pbkdf2-sha256.der");
+
+/// Ed25519 PKCS#8 encrypted private key (PBES2 + AES-256-CBC + PBKDF2-SHA256) encoded as ASN.1 DER,
+/// with 10,000 PBKDF2 iterations, the salt `000102..0F` and the IV `101112..1F`.
+///
+/// OpenSSL can't be given a fixed IV, so this was produced by encrypting `ed25519-priv-pkcs8v1.der`
+/// with those parameters and checked to decrypt using:
+///
+/// ```
+/// $ openssl pkcs8 -inform der -in ed25519-encpriv-aes256-pbkdf2-sha256-10000.der -outform der
+/// ```
+#[cfg(feature = "encryption")]
+const ED25519_DER_AES256_PBKDF2_SHA256_10000_EXAMPLE: &[u8] =
+    include_bytes!("examples/ed25519-encpriv-aes256-pbkdf2-sha256-10000.der");
+
 /// Ed25519 PKCS#8 encrypted private key encoded as PEM
 #[cfg(feature = "pem")]
 const ED25519_PEM_AES256_PBKDF2_SHA256_EXAMPLE: &str =
@@ -77,6 +111,36 @@ const ED25519_DER_DES_PBKDF2_SHA256_EXAMPLE: &[u8] =
 #[cfg(feature = "encryption")]
 const PASSWORD: &[u8] = b"hunter42"; // Bad password; don't actually use outside tests!
 
+/// RNG which outputs consecutive byte values, starting from the given one.
+#[cfg(feature = "encryption")]
+struct MockRng(u8);
+
+#[cfg(feature = "encryption")]
+impl RngCore for MockRng {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for byte in dest {
+            *byte = self.0;
+            self.0 = self.0.wrapping_add(1);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "encryption")]
+impl CryptoRng for MockRng {}
+
 #[test]
 fn decode_ed25519_encpriv_aes128_pbkdf2_sha1_der() {
     let pk = EncryptedPrivateKeyInfo::try_from(ED25519_DER_AES128_PBKDF2_SHA1_EXAMPLE).unwrap();
@@ -158,6 +222,102 @@ fn decrypt_ed25519_der_encpriv_aes256_scrypt() {
     assert_eq!(pk.as_bytes(), ED25519_DER_PLAINTEXT_EXAMPLE);
 }
 
+#[cfg(feature = "encryption")]
+#[test]
+fn decrypt_ed25519_der_encpriv_aes256_scrypt_max_memory() {
+    let enc_pk = EncryptedPrivateKeyInfo::try_from(ED25519_DER_AES256_SCRYPT_EXAMPLE).unwrap();
+
+    // Memory cost of the scrypt parameters: `128 * r * (N + p)` with N = 2^15, r = 8, p = 1
+    let memory_cost = 128 * 8 * ((1 << 15) + 1);
+
+    let pk = enc_pk
+        .decrypt_with_max_scrypt_memory(PASSWORD, memory_cost)
+        .unwrap();
+    assert_eq!(pk.as_bytes(), ED25519_DER_PLAINTEXT_EXAMPLE);
+
+    assert_eq!(
+        enc_pk
+            .decrypt_with_max_scrypt_memory(PASSWORD, memory_cost - 1)
+            .err(),
+        Some(Error::EncryptedPrivateKey(
+            pkcs5::Error::AlgorithmParametersInvalid {
+                oid: pbes2::SCRYPT_OID
+            }
+        ))
+    );
+}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn decrypt_p256_der_encpriv_aes256_pbkdf2_sha256() {
+    let enc_pk = EncryptedPrivateKeyInfo::try_from(P256_DER_AES256_PBKDF2_SHA256_EXAMPLE).unwrap();
+    let doc = enc_pk.decrypt(PASSWORD).unwrap();
+    assert_eq!(doc.as_bytes(), P256_DER_PLAINTEXT_EXAMPLE);
+
+    let pk: PrivateKeyInfo<'_> = doc.decode_msg().unwrap();
+    assert_eq!(pk.algorithm.oid, "1.2.840.10045.2.1".parse().unwrap()); // id-ecPublicKey
+    assert_eq!(
+        pk.algorithm.parameters_oid().unwrap(),
+        "1.2.840.10045.3.1.7".parse().unwrap()
+    ); // secp256r1
+}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn decrypt_ed25519_der_encpriv_wrong_password() {
+    let enc_pk =
+        EncryptedPrivateKeyInfo::try_from(ED25519_DER_AES256_PBKDF2_SHA256_EXAMPLE).unwrap();
+
+    for password in [&b"hunter43"[..], b"wrong password", b""] {
+        assert_eq!(
+            enc_pk.decrypt(password).err(),
+            Some(Error::EncryptedPrivateKey(pkcs5::Error::DecryptFailed))
+        );
+    }
+}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn decrypt_ed25519_der_encpriv_bad_padding() {
+    let enc_pk =
+        EncryptedPrivateKeyInfo::try_from(ED25519_DER_AES256_PBKDF2_SHA256_EXAMPLE).unwrap();
+
+    // The plaintext is followed by a full block of `0x10` padding. Flipping
+    // bits in the second-to-last ciphertext block flips the same bits in the
+    // last plaintext block, so this changes the final padding byte to `0x11`.
+    let mut ciphertext = enc_pk.encrypted_data.to_vec();
+    let pos = ciphertext.len() - 17;
+    ciphertext[pos] ^= 0x01;
+
+    let tampered = EncryptedPrivateKeyInfo {
+        encryption_algorithm: enc_pk.encryption_algorithm.clone(),
+        encrypted_data: &ciphertext,
+    };
+    assert_eq!(
+        tampered.decrypt(PASSWORD).err(),
+        Some(Error::EncryptedPrivateKey(pkcs5::Error::DecryptFailed))
+    );
+
+    // A final byte of `0x01` is valid padding, but leaves trailing garbage
+    ciphertext[pos] ^= 0x01 ^ 0x11;
+
+    let tampered = EncryptedPrivateKeyInfo {
+        encryption_algorithm: enc_pk.encryption_algorithm.clone(),
+        encrypted_data: &ciphertext,
+    };
+    assert!(matches!(tampered.decrypt(PASSWORD), Err(Error::Asn1(_))));
+
+    // Ciphertext which isn't a multiple of the block size
+    let truncated = EncryptedPrivateKeyInfo {
+        encryption_algorithm: enc_pk.encryption_algorithm.clone(),
+        encrypted_data: &enc_pk.encrypted_data[1..],
+    };
+    assert_eq!(
+        truncated.decrypt(PASSWORD).err(),
+        Some(Error::EncryptedPrivateKey(pkcs5::Error::DecryptFailed))
+    );
+}
+
 #[cfg(feature = "encryption")]
 #[test]
 fn encrypt_ed25519_der_encpriv_aes256_pbkdf2_sha256() {
@@ -179,6 +339,59 @@ fn encrypt_ed25519_der_encpriv_aes256_pbkdf2_sha256() {
     );
 }
 
+#[cfg(feature = "encryption")]
+#[test]
+fn encrypt_ed25519_der_encpriv_aes256_pbkdf2_sha256_10000() {
+    let pk_plaintext = PrivateKeyInfo::try_from(ED25519_DER_PLAINTEXT_EXAMPLE).unwrap();
+    let pk_encrypted = pk_plaintext
+        .encrypt_pbkdf2(MockRng(0), PASSWORD, 10_000, pbes2::AES_256_CBC_OID)
+        .unwrap();
+
+    assert_eq!(
+        pk_encrypted.as_bytes(),
+        ED25519_DER_AES256_PBKDF2_SHA256_10000_EXAMPLE
+    );
+}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn encrypt_ed25519_der_encpriv_pbkdf2_round_trip() {
+    let pk_plaintext = PrivateKeyInfo::try_from(ED25519_DER_PLAINTEXT_EXAMPLE).unwrap();
+
+    for cipher in [
+        pbes2::AES_128_CBC_OID,
+        pbes2::AES_192_CBC_OID,
+        pbes2::AES_256_CBC_OID,
+    ] {
+        let pk_encrypted = pk_plaintext
+            .encrypt_pbkdf2(MockRng(0x42), PASSWORD, 1000, cipher)
+            .unwrap();
+
+        let enc_pk: EncryptedPrivateKeyInfo<'_> = pk_encrypted.decode_msg().unwrap();
+        let pbes2_params = enc_pk.encryption_algorithm.pbes2().unwrap();
+        let pbkdf2_params = pbes2_params.kdf.pbkdf2().unwrap();
+
+        assert_eq!(pbes2_params.encryption.oid(), cipher);
+        assert_eq!(pbkdf2_params.iteration_count, 1000);
+        assert_eq!(pbkdf2_params.prf, pbes2::Pbkdf2Prf::HmacWithSha256);
+        assert_eq!(pbkdf2_params.salt.len(), 16);
+
+        let pk = enc_pk.decrypt(PASSWORD).unwrap();
+        assert_eq!(pk.as_bytes(), ED25519_DER_PLAINTEXT_EXAMPLE);
+    }
+
+    // Only AES-CBC ciphers are supported
+    let des_ede3_cbc = ObjectIdentifier::new_unwrap("1.2.840.113549.3.7");
+    assert_eq!(
+        pk_plaintext
+            .encrypt_pbkdf2(MockRng(0), PASSWORD, 1000, des_ede3_cbc)
+            .err(),
+        Some(Error::EncryptedPrivateKey(
+            pkcs5::Error::UnsupportedAlgorithm { oid: des_ede3_cbc }
+        ))
+    );
+}
+
 #[cfg(feature = "encryption")]
 #[test]
 fn encrypt_ed25519_der_encpriv_aes256_scrypt() {
@@ -217,6 +430,42 @@ fn encode_ed25519_encpriv_aes256_pbkdf2_sha256_pem() {
     );
 }
 
+#[test]
+#[cfg(feature = "pem")]
+fn decode_ed25519_encpriv_aes256_pbkdf2_sha256_pem() {
+    let doc = EncryptedPrivateKeyInfo::from_pem(ED25519_PEM_AES256_PBKDF2_SHA256_EXAMPLE).unwrap();
+    assert_eq!(doc.as_bytes(), ED25519_DER_AES256_PBKDF2_SHA256_EXAMPLE);
+
+    let pk: EncryptedPrivateKeyInfo<'_> = doc.decode_msg().unwrap();
+    assert_eq!(
+        ED25519_PEM_AES256_PBKDF2_SHA256_EXAMPLE,
+        pk.to_pem(Default::default()).unwrap()
+    );
+
+    // The labels of encrypted and unencrypted keys aren't interchangeable
+    assert!(PrivateKeyInfo::from_pem(ED25519_PEM_AES256_PBKDF2_SHA256_EXAMPLE).is_err());
+    assert!(
+        EncryptedPrivateKeyInfo::from_pem(include_str!("examples/ed25519-priv-pkcs8v1.pem"))
+            .is_err()
+    );
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn decode_ed25519_encpriv_pem_auto() {
+    let key = PrivateKeyPem::from_pem_auto(ED25519_PEM_AES256_PBKDF2_SHA256_EXAMPLE).unwrap();
+    assert!(key.plaintext().is_none());
+    assert_eq!(
+        key.encrypted().unwrap().as_bytes(),
+        ED25519_DER_AES256_PBKDF2_SHA256_EXAMPLE
+    );
+
+    let key =
+        PrivateKeyPem::from_pem_auto(include_str!("examples/ed25519-priv-pkcs8v1.pem")).unwrap();
+    assert!(key.encrypted().is_none());
+    assert!(key.plaintext().is_some());
+}
+
 #[test]
 #[cfg(feature = "3des")]
 fn decrypt_ed25519_der_encpriv_des3_pbkdf2_sha256() {
@@ -2,23 +2,48 @@
 
 use der::asn1::ObjectIdentifier;
 use hex_literal::hex;
-use pkcs8::{PrivateKeyInfo, Version};
+use pkcs8::{PrivateKeyInfo, SubjectPublicKeyInfoRef, Version};
 
 #[cfg(feature = "alloc")]
 use der::Encode;
 
 #[cfg(feature = "pem")]
-use der::{pem::LineEnding, EncodePem};
+use {
+    der::{pem::LineEnding, EncodePem},
+    pkcs8::PrivateKeyPem,
+};
 
 /// Elliptic Curve (P-256) PKCS#8 private key encoded as ASN.1 DER
 const EC_P256_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-priv.der");
 
+/// Elliptic Curve (P-256) public key corresponding to `EC_P256_DER_EXAMPLE`
+const EC_P256_PUB_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-pub.der");
+
 /// Ed25519 PKCS#8 v1 private key encoded as ASN.1 DER
 const ED25519_DER_V1_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-priv-pkcs8v1.der");
 
 /// Ed25519 PKCS#8 v2 private key + public key encoded as ASN.1 DER
 const ED25519_DER_V2_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-priv-pkcs8v2.der");
 
+/// Ed25519 public key corresponding to `ED25519_DER_V1_EXAMPLE`
+#[cfg(feature = "ed25519")]
+const ED25519_PUB_DER_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-pub.der");
+
+/// Elliptic Curve (P-384) PKCS#8 private key without an embedded public key,
+/// generated using:
+///
+/// ```text
+/// $ openssl ecparam -name secp384r1 -genkey -noout -out p384.pem
+/// $ openssl ec -in p384.pem -no_public -outform der \
+///     | openssl pkcs8 -topk8 -nocrypt -inform der -outform der
+/// ```
+#[cfg(feature = "p384")]
+const EC_P384_DER_EXAMPLE: &[u8] = include_bytes!("examples/p384-priv-no-public-key.der");
+
+/// Elliptic Curve (P-384) public key corresponding to `EC_P384_DER_EXAMPLE`
+#[cfg(feature = "p384")]
+const EC_P384_PUB_DER_EXAMPLE: &[u8] = include_bytes!("examples/p384-pub.der");
+
 /// RSA-2048 PKCS#8 private key encoded as ASN.1 DER
 const RSA_2048_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-priv.der");
 
@@ -70,6 +95,7 @@ fn decode_ed25519_der_v1() {
     assert_eq!(pk.version(), Version::V1);
     assert_eq!(pk.algorithm.oid, "1.3.101.112".parse().unwrap());
     assert_eq!(pk.algorithm.parameters, None);
+    assert_eq!(pk.attributes, None);
 
     // Extracted with:
     // $ openssl asn1parse -inform der -in tests/examples/ed25519-priv.der
@@ -90,14 +116,159 @@ fn decode_ed25519_der_v2() {
     const PUB_KEY: [u8; 32] =
         hex!("19BF44096984CDFE8541BAC167DC3B96C85086AA30B6B6CB0C5C38AD703166E1");
 
+    // Attribute from the RFC 8410 example, with the value "Curdle Chairs"
+    const ATTRIBUTES: [u8; 31] =
+        hex!("301D060A2A864886F70D01090914310F0C0D437572646C6520436861697273");
+
     let pk = PrivateKeyInfo::try_from(ED25519_DER_V2_EXAMPLE).unwrap();
     assert_eq!(pk.version(), Version::V2);
     assert_eq!(pk.algorithm.oid, "1.3.101.112".parse().unwrap());
     assert_eq!(pk.algorithm.parameters, None);
     assert_eq!(pk.private_key, PRIV_KEY);
+    assert_eq!(pk.attributes, Some(&ATTRIBUTES[..]));
     assert_eq!(pk.public_key, Some(&PUB_KEY[..]));
 }
 
+#[test]
+fn public_key_ed25519_der_v2() {
+    let pk = PrivateKeyInfo::try_from(ED25519_DER_V2_EXAMPLE).unwrap();
+    let spki = pk.public_key().unwrap().unwrap();
+
+    assert_eq!(spki.algorithm, pk.algorithm);
+    assert_eq!(
+        spki.subject_public_key.raw_bytes(),
+        hex!("19BF44096984CDFE8541BAC167DC3B96C85086AA30B6B6CB0C5C38AD703166E1")
+    );
+}
+
+#[test]
+fn public_key_ec_p256_der() {
+    let pk = PrivateKeyInfo::try_from(EC_P256_DER_EXAMPLE).unwrap();
+    assert_eq!(pk.version(), Version::V1);
+
+    // Extracted from the SEC1 `ECPrivateKey`
+    let spki = pk.public_key().unwrap().unwrap();
+    assert_eq!(
+        spki,
+        SubjectPublicKeyInfoRef::try_from(EC_P256_PUB_DER_EXAMPLE).unwrap()
+    );
+
+    // `ECPrivateKey` without the optional `publicKey`, extracted from:
+    // $ openssl ec -inform der -in p256-priv.der -no_public -outform der \
+    //     | openssl pkcs8 -topk8 -nocrypt -inform der -outform der
+    const NO_PUBLIC_KEY: [u8; 39] =
+        hex!("3025020101042069624171561A63340DE0E7D869F2A05492558E1A04868B6A9F854A866788188D");
+
+    let no_public_key = PrivateKeyInfo::new(pk.algorithm, &NO_PUBLIC_KEY);
+    assert_eq!(no_public_key.public_key().unwrap(), None);
+
+    // Malformed `ECPrivateKey`
+    let malformed = PrivateKeyInfo::new(pk.algorithm, &pk.private_key[..20]);
+    assert_eq!(malformed.public_key(), Err(pkcs8::Error::KeyMalformed));
+}
+
+#[test]
+fn public_key_not_embedded() {
+    // Deriving these requires curve arithmetic: see `derive_public_key`
+    for der in [
+        ED25519_DER_V1_EXAMPLE,
+        X25519_DER_EXAMPLE,
+        RSA_2048_DER_EXAMPLE,
+    ] {
+        let pk = PrivateKeyInfo::try_from(der).unwrap();
+        assert_eq!(pk.public_key().unwrap(), None);
+    }
+}
+
+#[test]
+#[cfg(feature = "ed25519")]
+fn derive_public_key_ed25519() {
+    // Derived from the private key of a PKCS#8 v1 key
+    let pk = PrivateKeyInfo::try_from(ED25519_DER_V1_EXAMPLE).unwrap();
+    assert_eq!(pk.public_key().unwrap(), None);
+    assert_eq!(
+        pk.derive_public_key().unwrap().to_vec().unwrap(),
+        ED25519_PUB_DER_EXAMPLE
+    );
+
+    // The embedded public key of a PKCS#8 v2 key is returned as-is
+    let pk = PrivateKeyInfo::try_from(ED25519_DER_V2_EXAMPLE).unwrap();
+    assert_eq!(
+        pk.derive_public_key()
+            .unwrap()
+            .subject_public_key
+            .raw_bytes(),
+        hex!("19BF44096984CDFE8541BAC167DC3B96C85086AA30B6B6CB0C5C38AD703166E1")
+    );
+
+    let embedded = PrivateKeyInfo {
+        public_key: Some(&[0x42; 32]),
+        ..PrivateKeyInfo::try_from(ED25519_DER_V1_EXAMPLE).unwrap()
+    };
+    assert_eq!(
+        embedded
+            .derive_public_key()
+            .unwrap()
+            .subject_public_key
+            .raw_bytes(),
+        [0x42; 32]
+    );
+}
+
+#[test]
+#[cfg(feature = "p256")]
+fn derive_public_key_ec_p256() {
+    // Embedded in the SEC1 `ECPrivateKey`
+    let pk = PrivateKeyInfo::try_from(EC_P256_DER_EXAMPLE).unwrap();
+    assert_eq!(
+        pk.derive_public_key().unwrap().to_vec().unwrap(),
+        EC_P256_PUB_DER_EXAMPLE
+    );
+
+    // Derived from the private key, see `public_key_ec_p256_der`
+    const NO_PUBLIC_KEY: [u8; 39] =
+        hex!("3025020101042069624171561A63340DE0E7D869F2A05492558E1A04868B6A9F854A866788188D");
+
+    let no_public_key = PrivateKeyInfo::new(pk.algorithm, &NO_PUBLIC_KEY);
+    assert_eq!(no_public_key.public_key().unwrap(), None);
+    assert_eq!(
+        no_public_key.derive_public_key().unwrap().to_vec().unwrap(),
+        EC_P256_PUB_DER_EXAMPLE
+    );
+
+    // Malformed `ECPrivateKey`
+    let malformed = PrivateKeyInfo::new(pk.algorithm, &NO_PUBLIC_KEY[..20]);
+    assert_eq!(
+        malformed.derive_public_key(),
+        Err(pkcs8::Error::KeyMalformed)
+    );
+}
+
+#[test]
+#[cfg(feature = "p384")]
+fn derive_public_key_ec_p384() {
+    let pk = PrivateKeyInfo::try_from(EC_P384_DER_EXAMPLE).unwrap();
+    assert_eq!(pk.public_key().unwrap(), None);
+    assert_eq!(
+        pk.derive_public_key().unwrap().to_vec().unwrap(),
+        EC_P384_PUB_DER_EXAMPLE
+    );
+}
+
+#[test]
+#[cfg(all(feature = "ed25519", feature = "p256", feature = "p384"))]
+fn derive_public_key_unsupported() {
+    for der in [X25519_DER_EXAMPLE, RSA_2048_DER_EXAMPLE] {
+        let pk = PrivateKeyInfo::try_from(der).unwrap();
+        assert_eq!(
+            pk.derive_public_key(),
+            Err(pkcs8::Error::PublicKey(pkcs8::spki::Error::OidUnknown {
+                oid: pk.algorithm.oid
+            }))
+        );
+    }
+}
+
 #[test]
 fn decode_rsa_2048_der() {
     let pk = PrivateKeyInfo::try_from(RSA_2048_DER_EXAMPLE).unwrap();
@@ -145,12 +316,30 @@ fn encode_ed25519_der_v1() {
 fn encode_ed25519_der_v2() {
     let private_key = PrivateKeyInfo::try_from(ED25519_DER_V2_EXAMPLE).unwrap();
     let private_der = private_key.to_vec().unwrap();
+    assert_eq!(private_der, ED25519_DER_V2_EXAMPLE);
     assert_eq!(
         private_key,
         PrivateKeyInfo::try_from(private_der.as_ref()).unwrap()
     );
 }
 
+#[test]
+#[cfg(feature = "alloc")]
+fn encode_ed25519_der_v1_with_attributes() {
+    let v2 = PrivateKeyInfo::try_from(ED25519_DER_V2_EXAMPLE).unwrap();
+    let mut private_key = PrivateKeyInfo::try_from(ED25519_DER_V1_EXAMPLE).unwrap();
+    private_key.attributes = v2.attributes;
+
+    let private_der = private_key.to_vec().unwrap();
+    assert_eq!(private_der.len(), ED25519_DER_V1_EXAMPLE.len() + 33);
+
+    let decoded = PrivateKeyInfo::try_from(private_der.as_ref()).unwrap();
+    assert_eq!(decoded.version(), Version::V1);
+    assert_eq!(decoded.private_key, private_key.private_key);
+    assert_eq!(decoded.attributes, v2.attributes);
+    assert_eq!(decoded.public_key, None);
+}
+
 #[test]
 #[cfg(feature = "alloc")]
 fn encode_rsa_2048_der() {
@@ -185,3 +374,31 @@ fn encode_x25519_pem() {
     let pk = PrivateKeyInfo::try_from(X25519_DER_EXAMPLE).unwrap();
     assert_eq!(X25519_PEM_EXAMPLE, pk.to_pem(LineEnding::LF).unwrap());
 }
+
+#[test]
+#[cfg(feature = "pem")]
+fn decode_ed25519_pem() {
+    let doc = PrivateKeyInfo::from_pem(ED25519_PEM_V1_EXAMPLE).unwrap();
+    assert_eq!(doc.as_bytes(), ED25519_DER_V1_EXAMPLE);
+
+    let pk: PrivateKeyInfo<'_> = doc.decode_msg().unwrap();
+    assert_eq!(ED25519_PEM_V1_EXAMPLE, pk.to_pem(LineEnding::LF).unwrap());
+
+    // Public keys have a different label
+    assert!(PrivateKeyInfo::from_pem(include_str!("examples/ed25519-pub.pem")).is_err());
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn decode_pem_auto() {
+    for (pem, der) in [
+        (EC_P256_PEM_EXAMPLE, EC_P256_DER_EXAMPLE),
+        (ED25519_PEM_V1_EXAMPLE, ED25519_DER_V1_EXAMPLE),
+        (RSA_2048_PEM_EXAMPLE, RSA_2048_DER_EXAMPLE),
+    ] {
+        let key = PrivateKeyPem::from_pem_auto(pem).unwrap();
+        assert_eq!(key.plaintext().unwrap().as_bytes(), der);
+    }
+
+    assert!(PrivateKeyPem::from_pem_auto(include_str!("examples/p256-pub.pem")).is_err());
+}
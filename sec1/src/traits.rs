@@ -111,11 +111,10 @@ where
             parameters: params_oid.as_ref().map(Into::into),
         };
 
-        Ok(Self::try_from(pkcs8::PrivateKeyInfo {
+        Ok(Self::try_from(pkcs8::PrivateKeyInfo::new(
             algorithm,
             private_key,
-            public_key: None,
-        })?)
+        ))?)
     }
 }
 
@@ -3,7 +3,7 @@
 use crate::{Error, Result};
 use core::cmp::Ordering;
 use der::{
-    asn1::{AnyRef, Choice, ObjectIdentifier},
+    asn1::{AnyRef, Choice, Null, ObjectIdentifier},
     Decode, DecodeValue, DerOrd, Encode, EncodeValue, Header, Length, Reader, Sequence, ValueOrd,
     Writer,
 };
@@ -11,6 +11,17 @@ use der::{
 #[cfg(feature = "alloc")]
 use der::asn1::Any;
 
+/// Algorithms whose `parameters` MUST be absent as specified in
+/// [RFC 8410 Section 3]: `id-X25519`, `id-X448`, `id-Ed25519` and `id-Ed448`.
+///
+/// [RFC 8410 Section 3]: https://www.rfc-editor.org/rfc/rfc8410#section-3
+const ABSENT_PARAMETERS_OIDS: &[ObjectIdentifier] = &[
+    ObjectIdentifier::new_unwrap("1.3.101.110"),
+    ObjectIdentifier::new_unwrap("1.3.101.111"),
+    ObjectIdentifier::new_unwrap("1.3.101.112"),
+    ObjectIdentifier::new_unwrap("1.3.101.113"),
+];
+
 /// X.509 `AlgorithmIdentifier` as defined in [RFC 5280 Section 4.1.1.2].
 ///
 /// ```text
@@ -93,6 +104,52 @@ pub type AlgorithmIdentifierRef<'a> = AlgorithmIdentifier<AnyRef<'a>>;
 pub type AlgorithmIdentifierOwned = AlgorithmIdentifier<Any>;
 
 impl<Params> AlgorithmIdentifier<Params> {
+    /// Create a new [`AlgorithmIdentifier`] with the given `algorithm` OID
+    /// and absent `parameters`.
+    ///
+    /// Parameters can then be set using e.g. [`AlgorithmIdentifier::with_params`].
+    pub const fn new(oid: ObjectIdentifier) -> Self {
+        Self {
+            oid,
+            parameters: None,
+        }
+    }
+
+    /// Set the `parameters` field.
+    ///
+    /// Returns [`Error::AlgorithmParametersAlreadySet`] if it has already been
+    /// set.
+    pub fn with_params(mut self, params: Params) -> Result<Self> {
+        if self.parameters.is_some() {
+            return Err(Error::AlgorithmParametersAlreadySet);
+        }
+
+        self.parameters = Some(params);
+        Ok(self)
+    }
+
+    /// Validate `parameters` against the constraints of well-known
+    /// algorithms, which the ASN.1 schema alone can't express.
+    ///
+    /// Presently this rejects any `parameters` (including `NULL`) for the
+    /// following algorithms, which [RFC 8410 Section 3] requires be absent:
+    ///
+    /// - `id-X25519` (1.3.101.110)
+    /// - `id-X448` (1.3.101.111)
+    /// - `id-Ed25519` (1.3.101.112)
+    /// - `id-Ed448` (1.3.101.113)
+    ///
+    /// Returns [`Error::AlgorithmParametersInvalid`] if validation fails.
+    ///
+    /// [RFC 8410 Section 3]: https://www.rfc-editor.org/rfc/rfc8410#section-3
+    pub fn validate(&self) -> Result<()> {
+        if self.parameters.is_some() && ABSENT_PARAMETERS_OIDS.contains(&self.oid) {
+            return Err(Error::AlgorithmParametersInvalid { oid: self.oid });
+        }
+
+        Ok(())
+    }
+
     /// Assert the `algorithm` OID is an expected value.
     pub fn assert_algorithm_oid(&self, expected_oid: ObjectIdentifier) -> Result<ObjectIdentifier> {
         if self.oid == expected_oid {
@@ -104,6 +161,50 @@ impl<Params> AlgorithmIdentifier<Params> {
 }
 
 impl<'a> AlgorithmIdentifierRef<'a> {
+    /// `id-Ed25519` signature algorithm, whose `parameters` MUST be absent
+    /// as specified in [RFC 8410 Section 3].
+    ///
+    /// [RFC 8410 Section 3]: https://www.rfc-editor.org/rfc/rfc8410#section-3
+    pub const ED25519: Self = Self::new(ObjectIdentifier::new_unwrap("1.3.101.112"));
+
+    /// `id-Ed448` signature algorithm, whose `parameters` MUST be absent
+    /// as specified in [RFC 8410 Section 3].
+    ///
+    /// [RFC 8410 Section 3]: https://www.rfc-editor.org/rfc/rfc8410#section-3
+    pub const ED448: Self = Self::new(ObjectIdentifier::new_unwrap("1.3.101.113"));
+
+    /// `ecdsa-with-SHA256` signature algorithm, whose `parameters` MUST be
+    /// absent as specified in [RFC 5758 Section 3.2].
+    ///
+    /// [RFC 5758 Section 3.2]: https://www.rfc-editor.org/rfc/rfc5758#section-3.2
+    pub const ECDSA_WITH_SHA256: Self =
+        Self::new(ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.2"));
+
+    /// `ecdsa-with-SHA384` signature algorithm, whose `parameters` MUST be
+    /// absent as specified in [RFC 5758 Section 3.2].
+    ///
+    /// [RFC 5758 Section 3.2]: https://www.rfc-editor.org/rfc/rfc5758#section-3.2
+    pub const ECDSA_WITH_SHA384: Self =
+        Self::new(ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.3"));
+
+    /// `ecdsa-with-SHA512` signature algorithm, whose `parameters` MUST be
+    /// absent as specified in [RFC 5758 Section 3.2].
+    ///
+    /// [RFC 5758 Section 3.2]: https://www.rfc-editor.org/rfc/rfc5758#section-3.2
+    pub const ECDSA_WITH_SHA512: Self =
+        Self::new(ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.4"));
+
+    /// Set the `parameters` field to `NULL`, e.g. for `rsaEncryption`.
+    pub fn with_null_params(self) -> Result<Self> {
+        self.with_params(AnyRef::NULL)
+    }
+
+    /// Set the `parameters` field to an OID, e.g. the named curve of an
+    /// elliptic curve key.
+    pub fn with_oid_params(self, oid: &'a ObjectIdentifier) -> Result<Self> {
+        self.with_params(oid.into())
+    }
+
     /// Assert `parameters` is an OID and has the expected value.
     pub fn assert_parameters_oid(
         &self,
@@ -136,11 +237,35 @@ impl<'a> AlgorithmIdentifierRef<'a> {
         self.parameters.ok_or(Error::AlgorithmParametersMissing)
     }
 
-    /// Get the `parameters` field as an [`ObjectIdentifier`].
+    /// Decode the `parameters` field as the given type `T`.
+    ///
+    /// Returns [`Error::AlgorithmParametersMissing`] if `parameters` are
+    /// `None`, or an ASN.1 error if they can't be decoded as `T`.
+    pub fn parameters_as<T>(&self) -> Result<T>
+    where
+        T: TryFrom<AnyRef<'a>, Error = der::Error>,
+    {
+        Ok(self.parameters_any()?.decode_into()?)
+    }
+
+    /// Get the `parameters` field as an [`ObjectIdentifier`], e.g. the named
+    /// curve of an elliptic curve public key.
     ///
     /// Returns an error if it is absent or not an OID.
     pub fn parameters_oid(&self) -> Result<ObjectIdentifier> {
-        Ok(ObjectIdentifier::try_from(self.parameters_any()?)?)
+        self.parameters_as()
+    }
+
+    /// Assert `parameters` is present and `NULL`, as required for e.g.
+    /// `rsaEncryption` by [RFC 3279 Section 2.3.1].
+    ///
+    /// Returns [`Error::AlgorithmParametersMissing`] if `parameters` are
+    /// `None`, or an ASN.1 error if they are anything other than `NULL`.
+    ///
+    /// [RFC 3279 Section 2.3.1]: https://www.rfc-editor.org/rfc/rfc3279#section-2.3.1
+    pub fn assert_parameters_null(&self) -> Result<()> {
+        self.parameters_as::<Null>()?;
+        Ok(())
     }
 
     /// Convert to a pair of [`ObjectIdentifier`]s.
@@ -167,7 +292,140 @@ impl<'a> AlgorithmIdentifierRef<'a> {
 #[cfg(feature = "alloc")]
 mod allocating {
     use super::*;
-    use der::referenced::*;
+    use der::{asn1::ContextSpecificRef, referenced::*, FixedTag, Tag, TagMode, TagNumber};
+
+    /// `id-RSASSA-PSS` algorithm OID.
+    const RSASSA_PSS_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.10");
+
+    /// `id-mgf1` algorithm OID.
+    const MGF1_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.8");
+
+    /// `id-sha1` algorithm OID.
+    const SHA1_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.14.3.2.26");
+
+    /// Default `saltLength` of `RSASSA-PSS-params`.
+    const SALT_LEN_DEFAULT: u8 = 20;
+
+    /// `RSASSA-PSS-params` as defined in [RFC 4055 Section 3.1], which use
+    /// the same hash algorithm for hashing messages and with MGF1.
+    ///
+    /// ```text
+    /// RSASSA-PSS-params  ::=  SEQUENCE  {
+    ///     hashAlgorithm      [0] HashAlgorithm DEFAULT sha1Identifier,
+    ///     maskGenAlgorithm   [1] MaskGenAlgorithm DEFAULT mgf1SHA1Identifier,
+    ///     saltLength         [2] INTEGER DEFAULT 20,
+    ///     trailerField       [3] INTEGER DEFAULT 1  }
+    /// ```
+    ///
+    /// [RFC 4055 Section 3.1]: https://www.rfc-editor.org/rfc/rfc4055#section-3.1
+    struct RsaPssParams<'a> {
+        /// Hash algorithm.
+        hash: AlgorithmIdentifierRef<'a>,
+
+        /// MGF1 with `hash`.
+        mask_gen: AlgorithmIdentifier<AlgorithmIdentifierRef<'a>>,
+
+        /// Salt length in bytes.
+        salt_len: u8,
+    }
+
+    impl<'a> RsaPssParams<'a> {
+        fn new(hash: AlgorithmIdentifierRef<'a>, salt_len: u8) -> Self {
+            Self {
+                hash,
+                mask_gen: AlgorithmIdentifier {
+                    oid: MGF1_OID,
+                    parameters: Some(hash),
+                },
+                salt_len,
+            }
+        }
+
+        /// Is the hash algorithm SHA-1, i.e. the default for both the
+        /// `hashAlgorithm` and `maskGenAlgorithm` fields?
+        fn is_sha1(&self) -> bool {
+            self.hash.oid == SHA1_OID && self.hash.parameters.map_or(true, AnyRef::is_null)
+        }
+
+        fn context_specific_hash(
+            &self,
+        ) -> Option<ContextSpecificRef<'_, AlgorithmIdentifierRef<'a>>> {
+            (!self.is_sha1()).then_some(ContextSpecificRef {
+                tag_number: TagNumber::N0,
+                tag_mode: TagMode::Explicit,
+                value: &self.hash,
+            })
+        }
+
+        fn context_specific_mask_gen(
+            &self,
+        ) -> Option<ContextSpecificRef<'_, AlgorithmIdentifier<AlgorithmIdentifierRef<'a>>>>
+        {
+            (!self.is_sha1()).then_some(ContextSpecificRef {
+                tag_number: TagNumber::N1,
+                tag_mode: TagMode::Explicit,
+                value: &self.mask_gen,
+            })
+        }
+
+        fn context_specific_salt_len(&self) -> Option<ContextSpecificRef<'_, u8>> {
+            (self.salt_len != SALT_LEN_DEFAULT).then_some(ContextSpecificRef {
+                tag_number: TagNumber::N2,
+                tag_mode: TagMode::Explicit,
+                value: &self.salt_len,
+            })
+        }
+    }
+
+    impl EncodeValue for RsaPssParams<'_> {
+        fn value_len(&self) -> der::Result<Length> {
+            self.context_specific_hash().encoded_len()?
+                + self.context_specific_mask_gen().encoded_len()?
+                + self.context_specific_salt_len().encoded_len()?
+        }
+
+        fn encode_value(&self, writer: &mut impl Writer) -> der::Result<()> {
+            self.context_specific_hash().encode(writer)?;
+            self.context_specific_mask_gen().encode(writer)?;
+            self.context_specific_salt_len().encode(writer)?;
+            Ok(())
+        }
+    }
+
+    impl FixedTag for RsaPssParams<'_> {
+        const TAG: Tag = Tag::Sequence;
+    }
+
+    impl AlgorithmIdentifierOwned {
+        /// Create an `id-RSASSA-PSS` signature algorithm with
+        /// `RSASSA-PSS-params` as specified in [RFC 4055 Section 3.1].
+        ///
+        /// The given `hash` algorithm is used both for hashing messages and
+        /// with MGF1, and `salt_len` is the length of the salt in bytes.
+        /// Fields which have their default value are omitted as DER requires,
+        /// and the `trailerField` always has its default value.
+        ///
+        /// [RFC 4055 Section 3.1]: https://www.rfc-editor.org/rfc/rfc4055#section-3.1
+        pub fn rsassa_pss(hash: AlgorithmIdentifierRef<'_>, salt_len: u8) -> Result<Self> {
+            Self::new(RSASSA_PSS_OID).with_encoded_params(&RsaPssParams::new(hash, salt_len))
+        }
+
+        /// Set the `parameters` field to `NULL`, e.g. for `rsaEncryption`.
+        pub fn with_null_params(self) -> Result<Self> {
+            self.with_params(AnyRef::NULL.into())
+        }
+
+        /// Set the `parameters` field to an OID, e.g. the named curve of an
+        /// elliptic curve key.
+        pub fn with_oid_params(self, oid: ObjectIdentifier) -> Result<Self> {
+            self.with_params((&oid).into())
+        }
+
+        /// Set the `parameters` field to the DER encoding of the given value.
+        pub fn with_encoded_params<T: Encode>(self, params: &T) -> Result<Self> {
+            self.with_params(Any::from_der(&params.to_vec()?)?)
+        }
+    }
 
     impl<'a> RefToOwned<'a> for AlgorithmIdentifierRef<'a> {
         type Owned = AlgorithmIdentifierOwned;
@@ -188,4 +446,16 @@ mod allocating {
             }
         }
     }
+
+    impl<'a> From<AlgorithmIdentifierRef<'a>> for AlgorithmIdentifierOwned {
+        fn from(borrowed: AlgorithmIdentifierRef<'a>) -> AlgorithmIdentifierOwned {
+            borrowed.ref_to_owned()
+        }
+    }
+
+    impl<'a> From<&'a AlgorithmIdentifierOwned> for AlgorithmIdentifierRef<'a> {
+        fn from(owned: &'a AlgorithmIdentifierOwned) -> AlgorithmIdentifierRef<'a> {
+            owned.owned_to_ref()
+        }
+    }
 }
@@ -13,6 +13,15 @@ use der::pem;
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum Error {
+    /// Algorithm parameters are already set.
+    AlgorithmParametersAlreadySet,
+
+    /// Algorithm parameters are invalid for the given algorithm.
+    AlgorithmParametersInvalid {
+        /// OID of the algorithm whose parameters are invalid.
+        oid: ObjectIdentifier,
+    },
+
     /// Algorithm parameters are missing.
     AlgorithmParametersMissing,
 
@@ -35,6 +44,12 @@ pub enum Error {
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Error::AlgorithmParametersAlreadySet => {
+                f.write_str("AlgorithmIdentifier parameters already set")
+            }
+            Error::AlgorithmParametersInvalid { oid } => {
+                write!(f, "AlgorithmIdentifier parameters invalid for OID: {}", oid)
+            }
             Error::AlgorithmParametersMissing => {
                 f.write_str("AlgorithmIdentifier parameters missing")
             }
@@ -41,3 +41,154 @@ impl Writer for Builder {
         Ok(())
     }
 }
+
+#[cfg(feature = "base64ct")]
+pub(crate) use self::jwk::jwk_thumbprint;
+
+/// JWK thumbprint support as described in [RFC7638].
+///
+/// [RFC7638]: https://datatracker.ietf.org/doc/html/rfc7638
+#[cfg(feature = "base64ct")]
+mod jwk {
+    use super::FingerprintBytes;
+    use crate::{Error, ObjectIdentifier, Result, SubjectPublicKeyInfoRef};
+    use base64ct::{Base64UrlUnpadded, Encoding};
+    use der::{
+        asn1::{AnyRef, UintRef},
+        Decode,
+    };
+    use sha2::{Digest, Sha256};
+
+    /// `rsaEncryption` algorithm OID.
+    const RSA_ENCRYPTION: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+
+    /// `id-ecPublicKey` algorithm OID.
+    const ID_EC_PUBLIC_KEY: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+
+    /// `id-Ed25519` algorithm OID.
+    const ID_ED_25519: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.112");
+
+    /// `secp256r1` named curve OID.
+    const SECP_256_R_1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
+
+    /// `secp384r1` named curve OID.
+    const SECP_384_R_1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.34");
+
+    /// `secp521r1` named curve OID.
+    const SECP_521_R_1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.35");
+
+    /// Compute the JWK thumbprint of the given public key, i.e. the SHA-256
+    /// digest of the JSON object containing its required JWK members in
+    /// lexicographic order, without whitespace ([RFC7638 § 3.2]).
+    ///
+    /// [RFC7638 § 3.2]: https://datatracker.ietf.org/doc/html/rfc7638#section-3.2
+    pub(crate) fn jwk_thumbprint(spki: &SubjectPublicKeyInfoRef<'_>) -> Result<FingerprintBytes> {
+        let key = spki
+            .subject_public_key
+            .as_bytes()
+            .ok_or(Error::KeyMalformed)?;
+
+        let mut jwk = Builder::default();
+
+        match spki.algorithm.oid {
+            RSA_ENCRYPTION => {
+                let (n, e) = AnyRef::from_der(key)
+                    .and_then(|any| {
+                        any.sequence(|reader| {
+                            Ok((UintRef::decode(reader)?, UintRef::decode(reader)?))
+                        })
+                    })
+                    .map_err(|_| Error::KeyMalformed)?;
+
+                jwk.base64_member("e", e.as_bytes());
+                jwk.str_member("kty", "RSA");
+                jwk.base64_member("n", n.as_bytes());
+            }
+            ID_EC_PUBLIC_KEY => {
+                let (crv, coordinate_len) = match spki.algorithm.parameters_oid()? {
+                    SECP_256_R_1 => ("P-256", 32),
+                    SECP_384_R_1 => ("P-384", 48),
+                    SECP_521_R_1 => ("P-521", 66),
+                    oid => return Err(Error::OidUnknown { oid }),
+                };
+
+                // Only the uncompressed SEC1 point encoding is supported
+                let (x, y) = match key.split_first() {
+                    Some((0x04, point)) if point.len() == coordinate_len * 2 => {
+                        point.split_at(coordinate_len)
+                    }
+                    _ => return Err(Error::KeyMalformed),
+                };
+
+                jwk.str_member("crv", crv);
+                jwk.str_member("kty", "EC");
+                jwk.base64_member("x", x);
+                jwk.base64_member("y", y);
+            }
+            ID_ED_25519 => {
+                if key.len() != 32 {
+                    return Err(Error::KeyMalformed);
+                }
+
+                jwk.str_member("crv", "Ed25519");
+                jwk.str_member("kty", "OKP");
+                jwk.base64_member("x", key);
+            }
+            oid => return Err(Error::OidUnknown { oid }),
+        }
+
+        Ok(jwk.finish())
+    }
+
+    /// Computes a digest of a JSON object as its members are written.
+    #[derive(Default)]
+    struct Builder {
+        /// In-progress digest of the serialized JSON.
+        digest: Sha256,
+
+        /// Has at least one member been written?
+        has_members: bool,
+    }
+
+    impl Builder {
+        /// Write a member with a string value.
+        ///
+        /// Names and values are never escaped, so they must not contain any
+        /// characters which require it.
+        fn str_member(&mut self, name: &str, value: &str) {
+            self.begin_member(name);
+            self.digest.update(value);
+            self.digest.update("\"");
+        }
+
+        /// Write a member whose value is the unpadded Base64url encoding of
+        /// the given bytes.
+        fn base64_member(&mut self, name: &str, value: &[u8]) {
+            self.begin_member(name);
+
+            // Chunks are a multiple of 3 bytes, so they encode without padding
+            let mut buf = [0u8; 64];
+            for chunk in value.chunks(48) {
+                let encoded = Base64UrlUnpadded::encode(chunk, &mut buf).expect("buffer too small");
+                self.digest.update(encoded);
+            }
+
+            self.digest.update("\"");
+        }
+
+        /// Write a member's name and the opening quote of its value.
+        fn begin_member(&mut self, name: &str) {
+            self.digest
+                .update(if self.has_members { ",\"" } else { "{\"" });
+            self.digest.update(name);
+            self.digest.update("\":\"");
+            self.has_members = true;
+        }
+
+        /// Close the JSON object, returning the computed digest.
+        fn finish(mut self) -> FingerprintBytes {
+            self.digest.update("}");
+            self.digest.finalize().into()
+        }
+    }
+}
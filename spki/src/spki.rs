@@ -72,6 +72,24 @@ where
         Ok(Base64::encode_string(&self.fingerprint_bytes()?))
     }
 
+    /// Calculate the SHA-256 fingerprint of this [`SubjectPublicKeyInfo`] and
+    /// format it as a `pin-sha256` directive, e.g. `pin-sha256="..."`.
+    ///
+    /// See [RFC7469 § 2.1] for more information.
+    ///
+    /// [RFC7469 § 2.1]: https://datatracker.ietf.org/doc/html/rfc7469#section-2.1
+    #[cfg(all(feature = "fingerprint", feature = "alloc", feature = "base64ct"))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(all(feature = "fingerprint", feature = "alloc", feature = "base64ct")))
+    )]
+    pub fn fingerprint_pin(&self) -> Result<alloc::string::String> {
+        Ok(alloc::format!(
+            "pin-sha256=\"{}\"",
+            self.fingerprint_base64()?
+        ))
+    }
+
     /// Calculate the SHA-256 fingerprint of this [`SubjectPublicKeyInfo`] as
     /// a raw byte array.
     ///
@@ -87,6 +105,52 @@ where
     }
 }
 
+impl<Params, Key> SubjectPublicKeyInfo<Params, Key> {
+    /// Validate the [`AlgorithmIdentifier`] of this [`SubjectPublicKeyInfo`].
+    ///
+    /// See [`AlgorithmIdentifier::validate`] for the checks performed.
+    pub fn validate(&self) -> Result<()> {
+        self.algorithm.validate()
+    }
+}
+
+impl<'a> SubjectPublicKeyInfoRef<'a> {
+    /// Calculate the [RFC7638] JWK thumbprint of this public key, i.e. the
+    /// SHA-256 digest of its canonical JWK representation.
+    ///
+    /// Supported key types are RSA, EC (P-256, P-384 and P-521, with
+    /// uncompressed points) and Ed25519. Other algorithms or curves return
+    /// [`Error::OidUnknown`].
+    ///
+    /// [RFC7638]: https://datatracker.ietf.org/doc/html/rfc7638
+    #[cfg(all(feature = "fingerprint", feature = "base64ct"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "fingerprint", feature = "base64ct"))))]
+    pub fn jwk_thumbprint(&self) -> Result<FingerprintBytes> {
+        fingerprint::jwk_thumbprint(self)
+    }
+
+    /// Does this [`SubjectPublicKeyInfo`] describe the same public key as
+    /// `other`?
+    ///
+    /// Unlike comparing the two for equality, this normalizes the following
+    /// ambiguities in how algorithm parameters are encoded in practice:
+    ///
+    /// - `NULL` parameters are treated the same as absent parameters, e.g.
+    ///   `rsaEncryption` keys are commonly encoded both ways.
+    ///
+    /// The algorithm OIDs, any remaining parameters (e.g. the named curve of
+    /// an EC key), and the public key bits must otherwise be identical.
+    pub fn matches_key(&self, other: &SubjectPublicKeyInfoRef<'_>) -> bool {
+        fn normalize(params: Option<AnyRef<'_>>) -> Option<AnyRef<'_>> {
+            params.filter(|params| !params.is_null())
+        }
+
+        self.algorithm.oid == other.algorithm.oid
+            && normalize(self.algorithm.parameters) == normalize(other.algorithm.parameters)
+            && self.subject_public_key == other.subject_public_key
+    }
+}
+
 impl<'a: 'k, 'k, Params, Key: 'k> DecodeValue<'a> for SubjectPublicKeyInfo<Params, Key>
 where
     Params: Choice<'a> + Encode,
@@ -210,4 +274,16 @@ mod allocating {
             }
         }
     }
+
+    impl<'a> From<SubjectPublicKeyInfoRef<'a>> for SubjectPublicKeyInfoOwned {
+        fn from(borrowed: SubjectPublicKeyInfoRef<'a>) -> SubjectPublicKeyInfoOwned {
+            borrowed.ref_to_owned()
+        }
+    }
+
+    impl<'a> From<&'a SubjectPublicKeyInfoOwned> for SubjectPublicKeyInfoRef<'a> {
+        fn from(owned: &'a SubjectPublicKeyInfoOwned) -> SubjectPublicKeyInfoRef<'a> {
+            owned.owned_to_ref()
+        }
+    }
 }
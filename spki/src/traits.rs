@@ -1,9 +1,13 @@
 //! Traits for encoding/decoding SPKI public keys.
 
-use crate::{Error, Result, SubjectPublicKeyInfoRef};
+use crate::{AlgorithmIdentifier, Error, Result, SubjectPublicKeyInfoRef};
+use der::{EncodeValue, Tagged};
 
 #[cfg(feature = "alloc")]
-use der::Document;
+use {
+    crate::AlgorithmIdentifierOwned,
+    der::{asn1::Any, Decode, Document, Encode},
+};
 
 #[cfg(feature = "pem")]
 use {
@@ -100,3 +104,47 @@ pub trait EncodePublicKey {
         Ok(doc.write_pem_file(path, SubjectPublicKeyInfoRef::PEM_LABEL, line_ending)?)
     }
 }
+
+/// Returns the [`AlgorithmIdentifier`] which describes signatures produced
+/// by a signature system, e.g. a signing key type.
+///
+/// Parameters are encoded exactly as given: algorithms such as Ed25519 and
+/// ECDSA require them to be absent, i.e. `None`.
+pub trait SignatureAlgorithmIdentifier {
+    /// Algorithm parameters.
+    type Params: Tagged + EncodeValue;
+
+    /// `AlgorithmIdentifier` for the corresponding signature system.
+    const SIGNATURE_ALGORITHM_IDENTIFIER: AlgorithmIdentifier<Self::Params>;
+}
+
+/// Returns the [`AlgorithmIdentifier`] which describes signatures produced
+/// by a signature system, where it may not be known until runtime.
+///
+/// This is useful for algorithms such as RSASSA-PSS, whose parameters depend
+/// on how the signing key was configured. It's automatically impl'd for any
+/// type which impls [`SignatureAlgorithmIdentifier`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub trait DynSignatureAlgorithmIdentifier {
+    /// `AlgorithmIdentifier` for the corresponding signature system.
+    fn signature_algorithm_identifier(&self) -> Result<AlgorithmIdentifierOwned>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> DynSignatureAlgorithmIdentifier for T
+where
+    T: SignatureAlgorithmIdentifier,
+{
+    fn signature_algorithm_identifier(&self) -> Result<AlgorithmIdentifierOwned> {
+        let parameters = match &T::SIGNATURE_ALGORITHM_IDENTIFIER.parameters {
+            Some(params) => Some(Any::from_der(&params.to_vec()?)?),
+            None => None,
+        };
+
+        Ok(AlgorithmIdentifierOwned {
+            oid: T::SIGNATURE_ALGORITHM_IDENTIFIER.oid,
+            parameters,
+        })
+    }
+}
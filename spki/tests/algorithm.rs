@@ -0,0 +1,96 @@
+//! `AlgorithmIdentifier` tests.
+
+use der::Encode;
+use hex_literal::hex;
+use spki::{AlgorithmIdentifierRef, Error, ObjectIdentifier};
+
+#[cfg(feature = "alloc")]
+use spki::AlgorithmIdentifierOwned;
+
+/// `rsaEncryption` algorithm OID.
+const RSA_ENCRYPTION: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+
+/// `id-ecPublicKey` algorithm OID.
+const ID_EC_PUBLIC_KEY: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+
+/// `secp256r1` named curve OID.
+const SECP_256_R_1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
+
+/// `AlgorithmIdentifier` for `rsaEncryption` with `NULL` parameters.
+const RSA_ENCRYPTION_DER: &[u8] = &hex!("300D06092A864886F70D0101010500");
+
+/// `AlgorithmIdentifier` for `id-ecPublicKey` with the `secp256r1` curve.
+const EC_P256_DER: &[u8] = &hex!("301306072A8648CE3D020106082A8648CE3D030107");
+
+#[test]
+fn builder_null_params() {
+    let alg_id = AlgorithmIdentifierRef::new(RSA_ENCRYPTION)
+        .with_null_params()
+        .unwrap();
+
+    let mut buf = [0u8; 32];
+    assert_eq!(
+        alg_id.encode_to_slice(&mut buf).unwrap(),
+        RSA_ENCRYPTION_DER
+    );
+}
+
+#[test]
+fn builder_oid_params() {
+    let alg_id = AlgorithmIdentifierRef::new(ID_EC_PUBLIC_KEY)
+        .with_oid_params(&SECP_256_R_1)
+        .unwrap();
+
+    let mut buf = [0u8; 32];
+    assert_eq!(alg_id.encode_to_slice(&mut buf).unwrap(), EC_P256_DER);
+    assert_eq!(alg_id.parameters_oid().unwrap(), SECP_256_R_1);
+}
+
+#[test]
+fn builder_params_already_set() {
+    let alg_id = AlgorithmIdentifierRef::new(ID_EC_PUBLIC_KEY)
+        .with_oid_params(&SECP_256_R_1)
+        .unwrap();
+
+    assert_eq!(
+        alg_id.with_null_params(),
+        Err(Error::AlgorithmParametersAlreadySet)
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn builder_owned() {
+    let alg_id = AlgorithmIdentifierOwned::new(RSA_ENCRYPTION)
+        .with_null_params()
+        .unwrap();
+    assert_eq!(alg_id.to_vec().unwrap(), RSA_ENCRYPTION_DER);
+
+    let alg_id = AlgorithmIdentifierOwned::new(ID_EC_PUBLIC_KEY)
+        .with_oid_params(SECP_256_R_1)
+        .unwrap();
+    assert_eq!(alg_id.to_vec().unwrap(), EC_P256_DER);
+    assert_eq!(
+        alg_id.with_oid_params(SECP_256_R_1),
+        Err(Error::AlgorithmParametersAlreadySet)
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn builder_encoded_params() {
+    // MGF1 with SHA-256, as used in RSASSA-PSS parameters
+    let sha256 =
+        AlgorithmIdentifierRef::new(ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.1"))
+            .with_null_params()
+            .unwrap();
+
+    let mgf1 = AlgorithmIdentifierOwned::new(ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.8"))
+        .with_encoded_params(&sha256)
+        .unwrap();
+
+    assert_eq!(
+        mgf1.to_vec().unwrap(),
+        hex!("301A06092A864886F70D010108300D06096086480165030402010500")
+    );
+}
@@ -0,0 +1,156 @@
+//! Tests for signature `AlgorithmIdentifier` helpers and traits.
+//!
+//! The expected `AlgorithmIdentifier`s are the `signatureAlgorithm` fields of
+//! self-signed certificates generated with OpenSSL, e.g.:
+//!
+//! ```text
+//! $ openssl genpkey -algorithm ed25519 -out ed25519.pem
+//! $ openssl req -x509 -new -key ed25519.pem -subj /CN=example.org -days 3650 \
+//!     -outform der -out ed25519-cert.der
+//! $ openssl req -x509 -new -key p256.pem -subj /CN=example.org -days 3650 \
+//!     -sha256 -outform der -out p256-sha256-cert.der
+//! $ openssl req -x509 -new -key rsa.pem -subj /CN=example.org -days 3650 \
+//!     -sha256 -sigopt rsa_padding_mode:pss -sigopt rsa_pss_saltlen:32 \
+//!     -outform der -out rsa-pss-sha256-cert.der
+//! ```
+
+#![cfg(feature = "alloc")]
+
+use der::{
+    asn1::{AnyRef, ObjectIdentifier},
+    Decode, Encode, Reader, SliceReader, Tag, Tagged,
+};
+use spki::{
+    AlgorithmIdentifier, AlgorithmIdentifierOwned, AlgorithmIdentifierRef,
+    DynSignatureAlgorithmIdentifier, Result, SignatureAlgorithmIdentifier,
+};
+
+/// Ed25519 self-signed certificate.
+const ED25519_CERT: &[u8] = include_bytes!("examples/ed25519-cert.der");
+
+/// Ed448 self-signed certificate.
+const ED448_CERT: &[u8] = include_bytes!("examples/ed448-cert.der");
+
+/// P-256 self-signed certificate signed with `ecdsa-with-SHA256`.
+const P256_SHA256_CERT: &[u8] = include_bytes!("examples/p256-sha256-cert.der");
+
+/// P-384 self-signed certificate signed with `ecdsa-with-SHA384`.
+const P384_SHA384_CERT: &[u8] = include_bytes!("examples/p384-sha384-cert.der");
+
+/// P-521 self-signed certificate signed with `ecdsa-with-SHA512`.
+const P521_SHA512_CERT: &[u8] = include_bytes!("examples/p521-sha512-cert.der");
+
+/// RSA self-signed certificate signed with `sha256WithRSAEncryption`.
+const RSA_SHA256_CERT: &[u8] = include_bytes!("examples/rsa-sha256-cert.der");
+
+/// RSA self-signed certificate signed with RSASSA-PSS using SHA-256, MGF1
+/// with SHA-256, and a 32-byte salt.
+const RSA_PSS_SHA256_CERT: &[u8] = include_bytes!("examples/rsa-pss-sha256-cert.der");
+
+/// `id-sha256` hash algorithm with `NULL` parameters.
+const SHA256: AlgorithmIdentifierRef<'static> = AlgorithmIdentifier {
+    oid: ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.1"),
+    parameters: Some(AnyRef::NULL),
+};
+
+/// Get the DER encoding of the `signatureAlgorithm` field of a certificate.
+fn signature_algorithm(cert: &[u8]) -> &[u8] {
+    let cert = AnyRef::from_der(cert).unwrap();
+    assert_eq!(cert.tag(), Tag::Sequence);
+
+    // Skip `tbsCertificate`
+    let mut reader = SliceReader::new(cert.value()).unwrap();
+    reader.tlv_bytes().unwrap();
+    reader.tlv_bytes().unwrap()
+}
+
+/// Check `alg_id` is the `signatureAlgorithm` of `cert`.
+fn assert_signature_algorithm(alg_id: &impl Encode, cert: &[u8]) {
+    assert_eq!(alg_id.to_vec().unwrap(), signature_algorithm(cert));
+}
+
+#[test]
+fn ed25519() {
+    assert_signature_algorithm(&AlgorithmIdentifierRef::ED25519, ED25519_CERT);
+}
+
+#[test]
+fn ed448() {
+    assert_signature_algorithm(&AlgorithmIdentifierRef::ED448, ED448_CERT);
+}
+
+#[test]
+fn ecdsa_with_sha2() {
+    assert_signature_algorithm(&AlgorithmIdentifierRef::ECDSA_WITH_SHA256, P256_SHA256_CERT);
+    assert_signature_algorithm(&AlgorithmIdentifierRef::ECDSA_WITH_SHA384, P384_SHA384_CERT);
+    assert_signature_algorithm(&AlgorithmIdentifierRef::ECDSA_WITH_SHA512, P521_SHA512_CERT);
+
+    // ECDSA signature algorithms must omit the parameters rather than use `NULL`
+    let alg_id = AlgorithmIdentifierRef::from_der(signature_algorithm(P256_SHA256_CERT)).unwrap();
+    assert_eq!(alg_id, AlgorithmIdentifierRef::ECDSA_WITH_SHA256);
+}
+
+#[test]
+fn rsassa_pss() {
+    let alg_id = AlgorithmIdentifierOwned::rsassa_pss(SHA256, 32).unwrap();
+    assert_signature_algorithm(&alg_id, RSA_PSS_SHA256_CERT);
+    assert_eq!(
+        alg_id,
+        AlgorithmIdentifierOwned::from_der(signature_algorithm(RSA_PSS_SHA256_CERT)).unwrap()
+    );
+
+    // Parameters describing a different salt length don't match the certificate
+    let alg_id = AlgorithmIdentifierOwned::rsassa_pss(SHA256, 20).unwrap();
+    assert_ne!(
+        alg_id.to_vec().unwrap(),
+        signature_algorithm(RSA_PSS_SHA256_CERT)
+    );
+}
+
+#[test]
+fn rsassa_pss_defaults() {
+    // SHA-1 with a 20-byte salt is the default, so all parameters are omitted
+    let sha1 = AlgorithmIdentifierRef::new(ObjectIdentifier::new_unwrap("1.3.14.3.2.26"));
+    let alg_id = AlgorithmIdentifierOwned::rsassa_pss(sha1, 20).unwrap();
+    assert_eq!(alg_id.parameters.unwrap().to_vec().unwrap(), [0x30, 0x00]);
+}
+
+#[test]
+fn signature_algorithm_identifier() {
+    /// Signing key type which produces `sha256WithRSAEncryption` signatures.
+    struct RsaSha256SigningKey;
+
+    impl SignatureAlgorithmIdentifier for RsaSha256SigningKey {
+        type Params = AnyRef<'static>;
+
+        const SIGNATURE_ALGORITHM_IDENTIFIER: AlgorithmIdentifierRef<'static> =
+            AlgorithmIdentifier {
+                oid: ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.11"),
+                parameters: Some(AnyRef::NULL),
+            };
+    }
+
+    let alg_id = RsaSha256SigningKey
+        .signature_algorithm_identifier()
+        .unwrap();
+    assert_signature_algorithm(&alg_id, RSA_SHA256_CERT);
+}
+
+#[test]
+fn dyn_signature_algorithm_identifier() {
+    /// Signing key type whose RSASSA-PSS salt length is only known at runtime.
+    struct RsaPssSha256SigningKey {
+        salt_len: u8,
+    }
+
+    impl DynSignatureAlgorithmIdentifier for RsaPssSha256SigningKey {
+        fn signature_algorithm_identifier(&self) -> Result<AlgorithmIdentifierOwned> {
+            AlgorithmIdentifierOwned::rsassa_pss(SHA256, self.salt_len)
+        }
+    }
+
+    let alg_id = RsaPssSha256SigningKey { salt_len: 32 }
+        .signature_algorithm_identifier()
+        .unwrap();
+    assert_signature_algorithm(&alg_id, RSA_PSS_SHA256_CERT);
+}
@@ -2,10 +2,13 @@
 
 use der::asn1::ObjectIdentifier;
 use hex_literal::hex;
-use spki::SubjectPublicKeyInfoRef;
+use spki::{Error, SubjectPublicKeyInfoRef};
 
 #[cfg(feature = "alloc")]
-use der::Encode;
+use {
+    der::{Decode, Encode},
+    spki::SubjectPublicKeyInfoOwned,
+};
 
 #[cfg(feature = "pem")]
 use der::{pem::LineEnding, EncodePem};
@@ -14,12 +17,15 @@ use der::{pem::LineEnding, EncodePem};
 const EC_P256_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-pub.der");
 
 /// Ed25519 `SubjectPublicKeyInfo` encoded as ASN.1 DER
-#[cfg(any(feature = "alloc", feature = "fingerprint"))]
 const ED25519_DER_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-pub.der");
 
 /// RSA-2048 `SubjectPublicKeyInfo` encoded as ASN.1 DER
 const RSA_2048_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-pub.der");
 
+/// RSA-2048 `SubjectPublicKeyInfo` for the example key in RFC 7638 § 3.1
+#[cfg(all(feature = "fingerprint", feature = "base64ct"))]
+const RFC7638_RSA_DER_EXAMPLE: &[u8] = include_bytes!("examples/rfc7638-rsa-pub.der");
+
 /// Elliptic Curve (P-256) public key encoded as PEM
 #[cfg(feature = "pem")]
 const EC_P256_PEM_EXAMPLE: &str = include_str!("examples/p256-pub.pem");
@@ -45,6 +51,19 @@ const ED25519_SPKI_FINGERPRINT_BASE64: &str = "Vd1MdLDkhTTi9OFzzs61DfjyenrCqomRz
 const ED25519_SPKI_FINGERPRINT: &[u8] =
     &hex!("55dd4c74b0e48534e2f4e173ceceb50df8f27a7ac2aa8991cc7ae914e030bced");
 
+/// The SPKI fingerprint for `RSA_2048_DER_EXAMPLE` as straight hash bytes
+///
+/// Generated using `cat rsa2048-pub.der | openssl dgst -sha256`
+#[cfg(feature = "fingerprint")]
+const RSA_2048_SPKI_FINGERPRINT: &[u8] =
+    &hex!("efeda9bfead9fd0594f6a5cf6fdf6c163116a3b1fad6d73cea05295b68fd1794");
+
+/// The HPKP pin for `RSA_2048_DER_EXAMPLE`
+///
+/// Generated using `cat rsa2048-pub.der | openssl dgst -binary -sha256 | base64`
+#[cfg(all(feature = "fingerprint", feature = "alloc", feature = "base64ct"))]
+const RSA_2048_SPKI_PIN: &str = "pin-sha256=\"7+2pv+rZ/QWU9qXPb99sFjEWo7H61tc86gUpW2j9F5Q=\"";
+
 #[test]
 fn decode_ec_p256_der() {
     let spki = SubjectPublicKeyInfoRef::try_from(EC_P256_DER_EXAMPLE).unwrap();
@@ -59,6 +78,11 @@ fn decode_ec_p256_der() {
             .unwrap(),
         "1.2.840.10045.3.1.7".parse().unwrap()
     );
+    assert_eq!(
+        spki.algorithm.parameters_as::<ObjectIdentifier>().unwrap(),
+        spki.algorithm.parameters_oid().unwrap()
+    );
+    assert!(spki.algorithm.assert_parameters_null().is_err());
 
     assert_eq!(spki.subject_public_key.raw_bytes(), &hex!("041CACFFB55F2F2CEFD89D89EB374B2681152452802DEEA09916068137D839CF7FC481A44492304D7EF66AC117BEFE83A8D08F155F2B52F9F618DD447029048E0F")[..]);
 }
@@ -103,15 +127,177 @@ fn decode_ed25519_and_fingerprint_base64() {
     );
 }
 
+#[test]
+#[cfg(feature = "fingerprint")]
+fn fingerprint_rsa_2048() {
+    // The fingerprint covers the full SPKI, not just the key bits
+    let spki = SubjectPublicKeyInfoRef::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+    assert_eq!(
+        spki.fingerprint_bytes().unwrap().as_slice(),
+        RSA_2048_SPKI_FINGERPRINT
+    );
+}
+
+#[test]
+#[cfg(all(feature = "fingerprint", feature = "alloc", feature = "base64ct"))]
+fn fingerprint_pin_rsa_2048() {
+    let spki = SubjectPublicKeyInfoRef::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+    assert_eq!(spki.fingerprint_pin().unwrap(), RSA_2048_SPKI_PIN);
+}
+
+/// JWK thumbprints of the example keys.
+///
+/// Computed with Python's `json.dumps(jwk, separators=(',', ':'), sort_keys=True)`
+/// and `hashlib.sha256`.
+#[test]
+#[cfg(all(feature = "fingerprint", feature = "base64ct"))]
+fn jwk_thumbprint() {
+    let spki = SubjectPublicKeyInfoRef::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+    assert_eq!(
+        spki.jwk_thumbprint().unwrap(),
+        hex!("752058b0a07bedfff1af8f4fb0b68dbfe2439a7463b612885f1e203f7c34a70d")
+    );
+
+    let spki = SubjectPublicKeyInfoRef::try_from(EC_P256_DER_EXAMPLE).unwrap();
+    assert_eq!(
+        spki.jwk_thumbprint().unwrap(),
+        hex!("f32acd7c66ec695cf3911a1fd414c7a79f251db320d3c9502a2e7b5d21f2285b")
+    );
+
+    let spki = SubjectPublicKeyInfoRef::try_from(ED25519_DER_EXAMPLE).unwrap();
+    assert_eq!(
+        spki.jwk_thumbprint().unwrap(),
+        hex!("4bbb18889e455dec732dc12d42ad091b326b6f2f1f6abe4ebea7e8ac198ebd86")
+    );
+}
+
+/// Example from RFC 7638 § 3.1, whose thumbprint is
+/// `NzbLsXh8uDCcd-6MNwXF4W_7noWXFZAfHkxZsRGC9Xs` in Base64url.
+#[test]
+#[cfg(all(feature = "fingerprint", feature = "base64ct"))]
+fn jwk_thumbprint_rfc7638() {
+    let spki = SubjectPublicKeyInfoRef::try_from(RFC7638_RSA_DER_EXAMPLE).unwrap();
+    assert_eq!(
+        spki.jwk_thumbprint().unwrap(),
+        hex!("3736cbb1787cb8309c77ee8c3705c5e16ffb9e859715901f1e4c59b11182f57b")
+    );
+}
+
+#[test]
+#[cfg(all(feature = "fingerprint", feature = "base64ct"))]
+fn jwk_thumbprint_unsupported() {
+    let mut spki = SubjectPublicKeyInfoRef::try_from(ED25519_DER_EXAMPLE).unwrap();
+    spki.algorithm.oid = ObjectIdentifier::new_unwrap("1.3.101.113");
+    assert_eq!(
+        spki.jwk_thumbprint(),
+        Err(Error::OidUnknown {
+            oid: ObjectIdentifier::new_unwrap("1.3.101.113")
+        })
+    );
+}
+
 #[test]
 fn decode_rsa_2048_der() {
     let spki = SubjectPublicKeyInfoRef::try_from(RSA_2048_DER_EXAMPLE).unwrap();
 
     assert_eq!(spki.algorithm.oid, "1.2.840.113549.1.1.1".parse().unwrap());
     assert!(spki.algorithm.parameters.unwrap().is_null());
+    spki.algorithm.assert_parameters_null().unwrap();
+    assert!(spki.algorithm.parameters_oid().is_err());
     assert_eq!(spki.subject_public_key.raw_bytes(), &hex!("3082010A0282010100B6C42C515F10A6AAF282C63EDBE24243A170F3FA2633BD4833637F47CA4F6F36E03A5D29EFC3191AC80F390D874B39E30F414FCEC1FCA0ED81E547EDC2CD382C76F61C9018973DB9FA537972A7C701F6B77E0982DFC15FC01927EE5E7CD94B4F599FF07013A7C8281BDF22DCBC9AD7CABB7C4311C982F58EDB7213AD4558B332266D743AED8192D1884CADB8B14739A8DADA66DC970806D9C7AC450CB13D0D7C575FB198534FC61BC41BC0F0574E0E0130C7BBBFBDFDC9F6A6E2E3E2AFF1CBEAC89BA57884528D55CFB08327A1E8C89F4E003CF2888E933241D9D695BCBBACDC90B44E3E095FA37058EA25B13F5E295CBEAC6DE838AB8C50AF61E298975B872F0203010001")[..]);
 }
 
+#[test]
+fn ed25519_parameters_missing() {
+    let spki = SubjectPublicKeyInfoRef::try_from(ED25519_DER_EXAMPLE).unwrap();
+
+    assert_eq!(
+        spki.algorithm.parameters_as::<ObjectIdentifier>(),
+        Err(Error::AlgorithmParametersMissing)
+    );
+    assert_eq!(
+        spki.algorithm.assert_parameters_null(),
+        Err(Error::AlgorithmParametersMissing)
+    );
+}
+
+#[test]
+fn validate_ed25519_parameters() {
+    let spki = SubjectPublicKeyInfoRef::try_from(ED25519_DER_EXAMPLE).unwrap();
+    assert_eq!(spki.validate(), Ok(()));
+
+    // Same key, but with `NULL` parameters in violation of RFC 8410
+    let der = hex!("302C300706032B65700500032100 4D29167F3F1912A6F7ADFA293A051A15C05EC67B8F17267B1C5550DCE853BD0D");
+    let spki = SubjectPublicKeyInfoRef::try_from(&der[..]).unwrap();
+    assert_eq!(
+        spki.validate(),
+        Err(Error::AlgorithmParametersInvalid {
+            oid: ObjectIdentifier::new_unwrap("1.3.101.112")
+        })
+    );
+
+    // Other algorithms may have parameters
+    let spki = SubjectPublicKeyInfoRef::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+    assert_eq!(spki.validate(), Ok(()));
+}
+
+#[test]
+fn matches_key_rsa_null_parameters() {
+    let spki = SubjectPublicKeyInfoRef::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+    assert!(spki.algorithm.parameters.unwrap().is_null());
+
+    let mut absent = spki.clone();
+    absent.algorithm.parameters = None;
+    assert_ne!(spki, absent);
+    assert!(spki.matches_key(&absent));
+    assert!(absent.matches_key(&spki));
+}
+
+#[test]
+fn matches_key_ec_named_curve() {
+    let spki = SubjectPublicKeyInfoRef::try_from(EC_P256_DER_EXAMPLE).unwrap();
+    assert!(spki.matches_key(&spki));
+
+    let secp384r1 = ObjectIdentifier::new_unwrap("1.3.132.0.34");
+    let mut other_curve = spki.clone();
+    other_curve.algorithm.parameters = Some((&secp384r1).into());
+    assert!(!spki.matches_key(&other_curve));
+
+    let mut absent = spki.clone();
+    absent.algorithm.parameters = None;
+    assert!(!spki.matches_key(&absent));
+
+    let ed25519 = SubjectPublicKeyInfoRef::try_from(ED25519_DER_EXAMPLE).unwrap();
+    let mut other_key = spki.clone();
+    other_key.subject_public_key = ed25519.subject_public_key;
+    assert!(!spki.matches_key(&other_key));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn owned_round_trip() {
+    for example in [
+        EC_P256_DER_EXAMPLE,
+        ED25519_DER_EXAMPLE,
+        RSA_2048_DER_EXAMPLE,
+    ] {
+        // Decode from a temporary buffer which is dropped before use
+        let owned = {
+            let buf = example.to_vec();
+            SubjectPublicKeyInfoOwned::from(SubjectPublicKeyInfoRef::try_from(&buf[..]).unwrap())
+        };
+        assert_eq!(owned.to_vec().unwrap(), example);
+        assert_eq!(SubjectPublicKeyInfoOwned::from_der(example).unwrap(), owned);
+
+        let borrowed = SubjectPublicKeyInfoRef::from(&owned);
+        assert_eq!(
+            borrowed,
+            SubjectPublicKeyInfoRef::try_from(example).unwrap()
+        );
+        assert_eq!(SubjectPublicKeyInfoOwned::from(borrowed), owned);
+    }
+}
+
 #[test]
 #[cfg(feature = "alloc")]
 fn encode_ec_p256_der() {
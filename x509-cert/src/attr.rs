@@ -1,6 +1,6 @@
 //! Attribute-related definitions as defined in X.501 (and updated by RFC 5280).
 
-use alloc::vec::Vec;
+use alloc::{borrow::Cow, string::ToString, vec::Vec};
 use const_oid::db::rfc4519::{COUNTRY_NAME, DOMAIN_COMPONENT, SERIAL_NUMBER};
 use core::fmt::{self, Write};
 
@@ -178,19 +178,34 @@ impl AttributeTypeAndValue {
             parser.add(c)?;
         }
 
-        let tag = match oid {
+        // Serialize.
+        let value = Any::new(Self::string_tag(oid), parser.as_bytes())?;
+        let atv = AttributeTypeAndValue { oid, value };
+        atv.to_vec()
+    }
+
+    /// Selects the string type used to encode values of the given attribute.
+    fn string_tag(oid: ObjectIdentifier) -> Tag {
+        match oid {
             COUNTRY_NAME => Tag::PrintableString,
             DOMAIN_COMPONENT => Tag::Ia5String,
             // Serial numbers are formated as Printable String as per RFC 5280 Appendix A.1:
             // https://datatracker.ietf.org/doc/html/rfc5280#appendix-A.1
             SERIAL_NUMBER => Tag::PrintableString,
             _ => Tag::Utf8String,
+        }
+    }
+
+    /// Creates an attribute with a string value, using the same string type
+    /// as [`AttributeTypeAndValue::encode_from_string`] does.
+    pub(crate) fn from_str_value(oid: ObjectIdentifier, val: &str) -> Result<Self, Error> {
+        let value = match Self::string_tag(oid) {
+            Tag::PrintableString => Any::from(PrintableStringRef::new(val)?),
+            Tag::Ia5String => Any::from(Ia5StringRef::new(val)?),
+            _ => Any::from(Utf8StringRef::new(val)?),
         };
 
-        // Serialize.
-        let value = Any::new(tag, parser.as_bytes())?;
-        let atv = AttributeTypeAndValue { oid, value };
-        atv.to_vec()
+        Ok(Self { oid, value })
     }
 
     /// Converts an AttributeTypeAndValue string into an encoded AttributeTypeAndValue
@@ -225,14 +240,16 @@ impl fmt::Display for AttributeTypeAndValue {
         let val = match self.value.tag() {
             Tag::PrintableString => PrintableStringRef::try_from(&self.value)
                 .ok()
-                .map(|s| s.as_str()),
+                .map(|s| Cow::Borrowed(s.as_str())),
             Tag::Utf8String => Utf8StringRef::try_from(&self.value)
                 .ok()
-                .map(|s| s.as_str()),
-            Tag::Ia5String => Ia5StringRef::try_from(&self.value).ok().map(|s| s.as_str()),
+                .map(|s| Cow::Borrowed(s.as_str())),
+            Tag::Ia5String => Ia5StringRef::try_from(&self.value)
+                .ok()
+                .map(|s| Cow::Borrowed(s.as_str())),
             Tag::TeletexString => TeletexStringRef::try_from(&self.value)
                 .ok()
-                .map(|s| s.as_str()),
+                .map(|s| Cow::Owned(s.to_string())),
             _ => None,
         };
 
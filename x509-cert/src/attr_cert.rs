@@ -0,0 +1,289 @@
+//! Attribute certificate types as defined in RFC 5755
+
+use crate::attr::Attribute;
+use crate::ext::{pkix::name::GeneralNames, Extensions};
+use crate::serial_number::SerialNumber;
+
+use alloc::vec::Vec;
+
+use der::asn1::{BitString, GeneralizedTime, ObjectIdentifier};
+use der::{Choice, DateTime, Enumerated, Sequence};
+use spki::AlgorithmIdentifierOwned;
+
+#[cfg(feature = "pem")]
+use der::pem::PemLabel;
+
+/// Attribute certificate version as defined in [RFC 5755 Section 4.1].
+///
+/// ```text
+/// AttCertVersion ::= INTEGER { v2(1) }
+/// ```
+///
+/// [RFC 5755 Section 4.1]: https://datatracker.ietf.org/doc/html/rfc5755#section-4.1
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Enumerated)]
+#[asn1(type = "INTEGER")]
+#[repr(u8)]
+pub enum Version {
+    /// Version 2, the only version permitted by RFC 5755
+    V2 = 1,
+}
+
+/// `AttributeCertificate` as defined in [RFC 5755 Section 4.1].
+///
+/// ```text
+/// AttributeCertificate ::= SEQUENCE {
+///     acinfo               AttributeCertificateInfo,
+///     signatureAlgorithm   AlgorithmIdentifier,
+///     signatureValue       BIT STRING
+/// }
+/// ```
+///
+/// [RFC 5755 Section 4.1]: https://datatracker.ietf.org/doc/html/rfc5755#section-4.1
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+pub struct AttributeCertificate {
+    /// The to-be-signed attribute certificate information.
+    pub acinfo: AttributeCertificateInfo,
+
+    /// Algorithm the issuer used to sign `acinfo`.
+    pub signature_algorithm: AlgorithmIdentifierOwned,
+
+    /// The issuer's signature over the DER encoding of `acinfo`.
+    pub signature: BitString,
+}
+
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl PemLabel for AttributeCertificate {
+    const PEM_LABEL: &'static str = "ATTRIBUTE CERTIFICATE";
+}
+
+/// `AttributeCertificateInfo` as defined in [RFC 5755 Section 4.1].
+///
+/// ```text
+/// AttributeCertificateInfo ::= SEQUENCE {
+///     version                 AttCertVersion, -- version is v2
+///     holder                  Holder,
+///     issuer                  AttCertIssuer,
+///     signature               AlgorithmIdentifier,
+///     serialNumber            CertificateSerialNumber,
+///     attrCertValidityPeriod  AttCertValidityPeriod,
+///     attributes              SEQUENCE OF Attribute,
+///     issuerUniqueID          UniqueIdentifier OPTIONAL,
+///     extensions              Extensions OPTIONAL
+/// }
+/// ```
+///
+/// [RFC 5755 Section 4.1]: https://datatracker.ietf.org/doc/html/rfc5755#section-4.1
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+pub struct AttributeCertificateInfo {
+    /// Attribute certificate version.
+    pub version: Version,
+
+    /// The entity the attributes are bound to.
+    pub holder: Holder,
+
+    /// The authority which issued the attribute certificate.
+    pub issuer: AttCertIssuer,
+
+    /// Algorithm the issuer used to sign the attribute certificate.
+    pub signature: AlgorithmIdentifierOwned,
+
+    /// Serial number, which is unique for each attribute certificate issued
+    /// by a given issuer.
+    pub serial_number: SerialNumber,
+
+    /// Period during which the attribute certificate is valid.
+    pub attr_cert_validity_period: AttCertValidityPeriod,
+
+    /// Attributes of the holder, such as roles or group memberships.
+    pub attributes: Vec<Attribute>,
+
+    /// Unique identifier of the issuer.
+    pub issuer_unique_id: Option<BitString>,
+
+    /// Attribute certificate extensions.
+    pub extensions: Option<Extensions>,
+}
+
+impl AttributeCertificateInfo {
+    /// Get the attribute of the given type, if present.
+    ///
+    /// [RFC 5755 Section 4.2.7] forbids multiple attributes of the same type,
+    /// so the first one is returned.
+    ///
+    /// [RFC 5755 Section 4.2.7]: https://datatracker.ietf.org/doc/html/rfc5755#section-4.2.7
+    pub fn attribute(&self, oid: ObjectIdentifier) -> Option<&Attribute> {
+        self.attributes.iter().find(|attr| attr.oid == oid)
+    }
+}
+
+/// `Holder` as defined in [RFC 5755 Section 4.1].
+///
+/// ```text
+/// Holder ::= SEQUENCE {
+///     baseCertificateID   [0] IssuerSerial OPTIONAL,
+///     entityName          [1] GeneralNames OPTIONAL,
+///     objectDigestInfo    [2] ObjectDigestInfo OPTIONAL
+/// }
+/// ```
+///
+/// [RFC 5755 Section 4.1]: https://datatracker.ietf.org/doc/html/rfc5755#section-4.1
+#[derive(Clone, Debug, Default, Eq, PartialEq, Sequence)]
+pub struct Holder {
+    /// Issuer and serial number of the holder's public key certificate.
+    #[asn1(context_specific = "0", tag_mode = "IMPLICIT", optional = "true")]
+    pub base_certificate_id: Option<IssuerSerial>,
+
+    /// Names of the holder.
+    #[asn1(context_specific = "1", tag_mode = "IMPLICIT", optional = "true")]
+    pub entity_name: Option<GeneralNames>,
+
+    /// Digest of the holder's public key or certificate.
+    #[asn1(context_specific = "2", tag_mode = "IMPLICIT", optional = "true")]
+    pub object_digest_info: Option<ObjectDigestInfo>,
+}
+
+/// `ObjectDigestInfo` as defined in [RFC 5755 Section 4.1].
+///
+/// ```text
+/// ObjectDigestInfo ::= SEQUENCE {
+///     digestedObjectType  ENUMERATED {
+///         publicKey            (0),
+///         publicKeyCert        (1),
+///         otherObjectTypes     (2) },
+///     otherObjectTypeID   OBJECT IDENTIFIER OPTIONAL,
+///     digestAlgorithm     AlgorithmIdentifier,
+///     objectDigest        BIT STRING
+/// }
+/// ```
+///
+/// [RFC 5755 Section 4.1]: https://datatracker.ietf.org/doc/html/rfc5755#section-4.1
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+pub struct ObjectDigestInfo {
+    /// Type of the digested object.
+    pub digested_object_type: DigestedObjectType,
+
+    /// Type of the digested object, if it is [`DigestedObjectType::OtherObjectTypes`].
+    pub other_object_type_id: Option<ObjectIdentifier>,
+
+    /// Algorithm used to compute `object_digest`.
+    pub digest_algorithm: AlgorithmIdentifierOwned,
+
+    /// Digest of the object.
+    pub object_digest: BitString,
+}
+
+/// The `digestedObjectType` of an [`ObjectDigestInfo`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Enumerated)]
+#[repr(u32)]
+pub enum DigestedObjectType {
+    /// The holder's public key.
+    PublicKey = 0,
+
+    /// The holder's public key certificate.
+    PublicKeyCert = 1,
+
+    /// Another type of object, identified by `otherObjectTypeID`.
+    OtherObjectTypes = 2,
+}
+
+/// `AttCertIssuer` as defined in [RFC 5755 Section 4.1].
+///
+/// ```text
+/// AttCertIssuer ::= CHOICE {
+///     v1Form   GeneralNames,  -- MUST NOT be used in this profile
+///     v2Form   [0] V2Form     -- v2 only
+/// }
+/// ```
+///
+/// [RFC 5755 Section 4.1]: https://datatracker.ietf.org/doc/html/rfc5755#section-4.1
+#[derive(Clone, Debug, Eq, PartialEq, Choice)]
+#[allow(clippy::large_enum_variant)]
+pub enum AttCertIssuer {
+    /// Legacy issuer names, which RFC 5755 forbids.
+    V1Form(GeneralNames),
+
+    /// Issuer identified by name, certificate, or digest.
+    #[asn1(context_specific = "0", tag_mode = "IMPLICIT", constructed = "true")]
+    V2Form(V2Form),
+}
+
+/// `V2Form` as defined in [RFC 5755 Section 4.1].
+///
+/// ```text
+/// V2Form ::= SEQUENCE {
+///     issuerName            GeneralNames  OPTIONAL,
+///     baseCertificateID     [0] IssuerSerial  OPTIONAL,
+///     objectDigestInfo      [1] ObjectDigestInfo  OPTIONAL
+/// }
+/// ```
+///
+/// [RFC 5755 Section 4.1]: https://datatracker.ietf.org/doc/html/rfc5755#section-4.1
+#[derive(Clone, Debug, Default, Eq, PartialEq, Sequence)]
+pub struct V2Form {
+    /// Names of the issuer. RFC 5755 requires exactly one directory name.
+    pub issuer_name: Option<GeneralNames>,
+
+    /// Issuer and serial number of the issuer's public key certificate.
+    #[asn1(context_specific = "0", tag_mode = "IMPLICIT", optional = "true")]
+    pub base_certificate_id: Option<IssuerSerial>,
+
+    /// Digest of the issuer's public key or certificate.
+    #[asn1(context_specific = "1", tag_mode = "IMPLICIT", optional = "true")]
+    pub object_digest_info: Option<ObjectDigestInfo>,
+}
+
+/// `IssuerSerial` as defined in [RFC 5755 Section 4.1].
+///
+/// ```text
+/// IssuerSerial ::= SEQUENCE {
+///     issuer         GeneralNames,
+///     serial         CertificateSerialNumber,
+///     issuerUID      UniqueIdentifier OPTIONAL
+/// }
+/// ```
+///
+/// [RFC 5755 Section 4.1]: https://datatracker.ietf.org/doc/html/rfc5755#section-4.1
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+pub struct IssuerSerial {
+    /// Names of the certificate's issuer.
+    pub issuer: GeneralNames,
+
+    /// Serial number of the certificate.
+    pub serial: SerialNumber,
+
+    /// Unique identifier of the certificate's issuer.
+    pub issuer_uid: Option<BitString>,
+}
+
+/// `AttCertValidityPeriod` as defined in [RFC 5755 Section 4.1].
+///
+/// ```text
+/// AttCertValidityPeriod ::= SEQUENCE {
+///     notBeforeTime  GeneralizedTime,
+///     notAfterTime   GeneralizedTime
+/// }
+/// ```
+///
+/// [RFC 5755 Section 4.1]: https://datatracker.ietf.org/doc/html/rfc5755#section-4.1
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Sequence)]
+pub struct AttCertValidityPeriod {
+    /// notBeforeTime value
+    pub not_before_time: GeneralizedTime,
+
+    /// notAfterTime value
+    pub not_after_time: GeneralizedTime,
+}
+
+impl AttCertValidityPeriod {
+    /// Is the given `time` within this validity period?
+    ///
+    /// Both bounds are inclusive, as with [`Validity::is_valid_at`].
+    ///
+    /// [`Validity::is_valid_at`]: crate::time::Validity::is_valid_at
+    pub fn is_valid_at(&self, time: DateTime) -> bool {
+        let time = time.unix_duration();
+        self.not_before_time.to_unix_duration() <= time
+            && time <= self.not_after_time.to_unix_duration()
+    }
+}
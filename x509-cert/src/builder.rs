@@ -0,0 +1,160 @@
+//! Certificate and certification request builders
+
+use crate::{
+    attr::{Attribute, Attributes},
+    certificate::{TbsCertificate, Version},
+    ext::{Extension, Extensions},
+    name::Name,
+    request::{self, CertReqInfo, ExtensionReq},
+    serial_number::SerialNumber,
+    time::Validity,
+};
+
+use alloc::vec::Vec;
+
+use const_oid::AssociatedOid;
+use der::{asn1::Any, asn1::SetOfVec, Decode, Encode};
+use spki::{AlgorithmIdentifierOwned, SubjectPublicKeyInfoOwned};
+
+#[cfg(doc)]
+use crate::{request::CertReq, Certificate};
+
+/// Builder for X.509 certificates.
+///
+/// Produces a [`TbsCertificate`] which can be signed by the issuer, after
+/// which [`Certificate::new`] assembles it with the resulting signature.
+///
+/// The certificate is self-issued unless [`CertificateBuilder::issuer`] is
+/// called. Its `version` is v3 if any extensions are added, otherwise v1.
+#[derive(Clone, Debug)]
+pub struct CertificateBuilder {
+    serial_number: SerialNumber,
+    validity: Validity,
+    subject: Name,
+    subject_public_key_info: SubjectPublicKeyInfoOwned,
+    issuer: Option<Name>,
+    extensions: Extensions,
+}
+
+impl CertificateBuilder {
+    /// Create a new certificate builder.
+    pub fn new(
+        serial_number: SerialNumber,
+        validity: Validity,
+        subject: Name,
+        subject_public_key_info: SubjectPublicKeyInfoOwned,
+    ) -> Self {
+        Self {
+            serial_number,
+            validity,
+            subject,
+            subject_public_key_info,
+            issuer: None,
+            extensions: Vec::new(),
+        }
+    }
+
+    /// Set the name of the certificate's issuer.
+    ///
+    /// If this isn't called, the issuer is the same as the subject.
+    pub fn issuer(mut self, issuer: Name) -> Self {
+        self.issuer = Some(issuer);
+        self
+    }
+
+    /// Add an extension to the certificate.
+    pub fn extension(mut self, extension: Extension) -> Self {
+        self.extensions.push(extension);
+        self
+    }
+
+    /// Add several extensions to the certificate.
+    pub fn extensions(mut self, extensions: impl IntoIterator<Item = Extension>) -> Self {
+        self.extensions.extend(extensions);
+        self
+    }
+
+    /// Build the [`TbsCertificate`] to be signed using the given signature
+    /// algorithm.
+    pub fn build(self, signature_algorithm: AlgorithmIdentifierOwned) -> TbsCertificate {
+        // `Extensions` must contain at least one `Extension` if present
+        let (version, extensions) = if self.extensions.is_empty() {
+            (Version::V1, None)
+        } else {
+            (Version::V3, Some(self.extensions))
+        };
+
+        TbsCertificate {
+            version,
+            serial_number: self.serial_number,
+            signature: signature_algorithm,
+            issuer: self.issuer.unwrap_or_else(|| self.subject.clone()),
+            validity: self.validity,
+            subject: self.subject,
+            subject_public_key_info: self.subject_public_key_info,
+            issuer_unique_id: None,
+            subject_unique_id: None,
+            extensions,
+        }
+    }
+}
+
+/// Builder for PKCS#10 certification requests.
+///
+/// Produces a [`CertReqInfo`] which can be signed with the private key
+/// corresponding to the subject public key, after which [`CertReq::new`]
+/// assembles it with the resulting signature.
+///
+/// Any extensions added are requested using the `extensionRequest` attribute.
+#[derive(Clone, Debug)]
+pub struct CertReqBuilder {
+    subject: Name,
+    public_key: SubjectPublicKeyInfoOwned,
+    extensions: Extensions,
+}
+
+impl CertReqBuilder {
+    /// Create a new certification request builder.
+    pub fn new(subject: Name, public_key: SubjectPublicKeyInfoOwned) -> Self {
+        Self {
+            subject,
+            public_key,
+            extensions: Vec::new(),
+        }
+    }
+
+    /// Request an extension for the certificate.
+    pub fn extension(mut self, extension: Extension) -> Self {
+        self.extensions.push(extension);
+        self
+    }
+
+    /// Request several extensions for the certificate.
+    pub fn extensions(mut self, extensions: impl IntoIterator<Item = Extension>) -> Self {
+        self.extensions.extend(extensions);
+        self
+    }
+
+    /// Build the [`CertReqInfo`] to be signed.
+    pub fn build(self) -> der::Result<CertReqInfo> {
+        let mut attributes = Attributes::new();
+
+        // `ExtensionReq` must contain at least one `Extension` if present
+        if !self.extensions.is_empty() {
+            let mut values = SetOfVec::new();
+            values.add(Any::from_der(&ExtensionReq(self.extensions).to_vec()?)?)?;
+
+            attributes.add(Attribute {
+                oid: ExtensionReq::OID,
+                values,
+            })?;
+        }
+
+        Ok(CertReqInfo {
+            version: request::Version::V1,
+            subject: self.subject,
+            public_key: self.public_key,
+            attributes,
+        })
+    }
+}
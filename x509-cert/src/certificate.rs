@@ -1,17 +1,29 @@
 //! Certificate types
 
-use crate::{name::Name, serial_number::SerialNumber, time::Validity};
+use crate::{ext::Extension, name::Name, serial_number::SerialNumber, time::Validity};
 
 use alloc::vec::Vec;
 use core::cmp::Ordering;
 
-use const_oid::AssociatedOid;
+use const_oid::{AssociatedOid, ObjectIdentifier};
 use der::asn1::BitString;
-use der::{Decode, Enumerated, Error, ErrorKind, Sequence, ValueOrd};
+use der::{DateTime, Decode, Enumerated, Error, ErrorKind, Sequence, ValueOrd};
 use spki::{AlgorithmIdentifierOwned, SubjectPublicKeyInfoOwned};
 
 #[cfg(feature = "pem")]
-use der::pem::PemLabel;
+use der::{
+    pem::{self, PemLabel},
+    DecodePem,
+};
+
+#[cfg(any(feature = "sha1", feature = "sha2"))]
+use alloc::{format, string::String};
+
+#[cfg(any(feature = "sha1", feature = "sha2", feature = "verify"))]
+use der::Encode;
+
+#[cfg(feature = "verify")]
+use signature::{SignatureEncoding, Verifier};
 
 /// Certificate `Version` as defined in [RFC 5280 Section 4.1].
 ///
@@ -125,13 +137,71 @@ impl TbsCertificate {
     pub fn filter<'a, T: Decode<'a> + AssociatedOid>(
         &'a self,
     ) -> impl 'a + Iterator<Item = Result<(bool, T), Error>> {
-        self.extensions
-            .as_deref()
-            .unwrap_or(&[])
+        self.extensions()
             .iter()
             .filter(|e| e.extn_id == T::OID)
             .map(|e| Ok((e.critical, T::from_der(e.extn_value.as_bytes())?)))
     }
+
+    /// Get the extension with the given OID, if present.
+    ///
+    /// Unlike looking the OID up in `extensions` directly, this returns
+    /// [`crate::Error::ExtensionDuplicated`] if the extension appears more
+    /// than once, rather than silently picking one of them.
+    pub fn get_unique(&self, oid: ObjectIdentifier) -> crate::Result<Option<&Extension>> {
+        let mut iter = self.extensions().iter().filter(|e| e.extn_id == oid);
+        match (iter.next(), iter.next()) {
+            (_, Some(_)) => Err(crate::Error::ExtensionDuplicated(oid)),
+            (extension, None) => Ok(extension),
+        }
+    }
+
+    /// Check that no extension appears more than once.
+    ///
+    /// [RFC 5280 Section 4.2] forbids a certificate from including more than
+    /// one instance of a particular extension. Decoding doesn't enforce this,
+    /// so callers which make decisions based on extensions should call this
+    /// first to avoid being confused by conflicting duplicates.
+    ///
+    /// [RFC 5280 Section 4.2]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2
+    pub fn check_unique_extensions(&self) -> crate::Result<()> {
+        let extensions = self.extensions();
+
+        for (i, extension) in extensions.iter().enumerate() {
+            if extensions[..i]
+                .iter()
+                .any(|e| e.extn_id == extension.extn_id)
+            {
+                return Err(crate::Error::ExtensionDuplicated(extension.extn_id));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Collect the OIDs of critical extensions which aren't in `known`.
+    ///
+    /// [RFC 5280 Section 4.2] requires that a certificate be rejected if it
+    /// contains a critical extension which the validator doesn't recognize or
+    /// can't process, so a non-empty result means the certificate should be
+    /// rejected.
+    ///
+    /// [RFC 5280 Section 4.2]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2
+    pub fn unhandled_critical_extensions(
+        &self,
+        known: &[ObjectIdentifier],
+    ) -> Vec<ObjectIdentifier> {
+        self.extensions()
+            .iter()
+            .filter(|e| e.critical && !known.contains(&e.extn_id))
+            .map(|e| e.extn_id)
+            .collect()
+    }
+
+    /// All of the certificate's extensions, which is empty if there are none.
+    fn extensions(&self) -> &[Extension] {
+        self.extensions.as_deref().unwrap_or(&[])
+    }
 }
 
 /// X.509 certificates are defined in [RFC 5280 Section 4.1].
@@ -154,12 +224,195 @@ pub struct Certificate {
     pub signature: BitString,
 }
 
+impl Certificate {
+    /// Assemble a [`Certificate`] from a [`TbsCertificate`] and the issuer's
+    /// signature over its DER encoding.
+    ///
+    /// The `signatureAlgorithm` of the certificate is the `signature` field of
+    /// the [`TbsCertificate`], as required by [RFC 5280 Section 4.1.1.2].
+    ///
+    /// [RFC 5280 Section 4.1.1.2]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.1.1.2
+    pub fn new(tbs_certificate: TbsCertificate, signature: BitString) -> Self {
+        Self {
+            signature_algorithm: tbs_certificate.signature.clone(),
+            tbs_certificate,
+            signature,
+        }
+    }
+
+    /// Check that this certificate is valid at the given time `now`.
+    ///
+    /// Returns [`Error::CertificateNotYetValid`][crate::Error::CertificateNotYetValid]
+    /// if `now` is before `notBefore`, or
+    /// [`Error::CertificateExpired`][crate::Error::CertificateExpired] if it is
+    /// after `notAfter`. See [`Validity::is_valid_at`] for details.
+    pub fn check_validity(&self, now: DateTime) -> crate::Result<()> {
+        let validity = &self.tbs_certificate.validity;
+        let now_duration = now.unix_duration();
+
+        if now_duration < validity.not_before.to_unix_duration() {
+            Err(crate::Error::CertificateNotYetValid)
+        } else if now_duration > validity.not_after.to_unix_duration() {
+            Err(crate::Error::CertificateExpired)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Is this certificate self-issued, i.e. are its subject and issuer the
+    /// same?
+    ///
+    /// See [RFC 5280 Section 3.2]. A self-issued certificate isn't necessarily
+    /// self-signed, e.g. when a CA rolls over to a new key.
+    ///
+    /// [RFC 5280 Section 3.2]: https://datatracker.ietf.org/doc/html/rfc5280#section-3.2
+    pub fn is_self_issued(&self) -> bool {
+        self.tbs_certificate.subject == self.tbs_certificate.issuer
+    }
+
+    /// Is this certificate self-signed, i.e. is it self-issued and does its
+    /// signature verify with its own subject public key?
+    ///
+    /// The `verifier` must be constructed from this certificate's
+    /// [`TbsCertificate::subject_public_key_info`]. This returns `false` for
+    /// self-issued certificates whose signature doesn't verify.
+    #[cfg(feature = "verify")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "verify")))]
+    pub fn is_self_signed<V, S>(&self, verifier: &V) -> bool
+    where
+        V: Verifier<S>,
+        S: SignatureEncoding,
+    {
+        self.is_self_issued() && self.verify_signature(verifier).is_ok()
+    }
+
+    /// Verify the signature on this certificate using a `verifier` for the
+    /// issuer's public key.
+    ///
+    /// The `signature` algorithm of the [`TbsCertificate`] must match the
+    /// certificate's `signatureAlgorithm`. The signature is verified over the
+    /// DER re-encoding of the [`TbsCertificate`], so certificates which were
+    /// signed over a non-canonical encoding will fail to verify.
+    ///
+    /// Any [`signature::Verifier`] can be used, e.g. an ECDSA or Ed25519
+    /// verifying key. It's up to the caller to pick one which implements the
+    /// certificate's [`Certificate::signature_algorithm`].
+    #[cfg(feature = "verify")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "verify")))]
+    pub fn verify_signature<V, S>(&self, verifier: &V) -> crate::Result<()>
+    where
+        V: Verifier<S>,
+        S: SignatureEncoding,
+    {
+        if self.tbs_certificate.signature != self.signature_algorithm {
+            return Err(crate::Error::AlgorithmMismatch);
+        }
+
+        let signature = self
+            .signature
+            .as_bytes()
+            .and_then(|bytes| S::try_from(bytes).ok())
+            .ok_or(crate::Error::SignatureInvalid)?;
+
+        verifier
+            .verify(&self.tbs_certificate.to_vec()?, &signature)
+            .map_err(|_| crate::Error::SignatureInvalid)
+    }
+
+    /// Compute the fingerprint of this certificate, i.e. the digest of its
+    /// entire DER encoding using the given `algorithm`.
+    ///
+    /// This is the value shown as the certificate's "thumbprint" by browsers
+    /// and by `openssl x509 -fingerprint`.
+    #[cfg(any(feature = "sha1", feature = "sha2"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "sha1", feature = "sha2"))))]
+    pub fn fingerprint(&self, algorithm: FingerprintAlgorithm) -> der::Result<Vec<u8>> {
+        let der = self.to_vec()?;
+
+        Ok(match algorithm {
+            #[cfg(feature = "sha1")]
+            FingerprintAlgorithm::Sha1 => <sha1::Sha1 as sha1::Digest>::digest(der).to_vec(),
+            #[cfg(feature = "sha2")]
+            FingerprintAlgorithm::Sha256 => <sha2::Sha256 as sha2::Digest>::digest(der).to_vec(),
+        })
+    }
+
+    /// Compute the fingerprint of this certificate as colon-separated
+    /// uppercase hex, e.g. `5B:F3:D7:...`.
+    ///
+    /// See [`Certificate::fingerprint`].
+    #[cfg(any(feature = "sha1", feature = "sha2"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "sha1", feature = "sha2"))))]
+    pub fn fingerprint_hex(&self, algorithm: FingerprintAlgorithm) -> der::Result<String> {
+        Ok(self
+            .fingerprint(algorithm)?
+            .iter()
+            .map(|byte| format!("{:02X}", byte))
+            .collect::<Vec<_>>()
+            .join(":"))
+    }
+}
+
+/// Digest algorithms for computing a [`Certificate::fingerprint`].
+#[cfg(any(feature = "sha1", feature = "sha2"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "sha1", feature = "sha2"))))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum FingerprintAlgorithm {
+    /// SHA-1, which is still used by some legacy tooling.
+    #[cfg(feature = "sha1")]
+    Sha1,
+
+    /// SHA-256.
+    #[cfg(feature = "sha2")]
+    Sha256,
+}
+
 #[cfg(feature = "pem")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
 impl PemLabel for Certificate {
     const PEM_LABEL: &'static str = "CERTIFICATE";
 }
 
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl Certificate {
+    /// Parse a bundle of concatenated PEM-encoded certificates, such as a
+    /// `fullchain.pem` file, returning them in the order they appear.
+    ///
+    /// Text between the certificates, such as comments, is ignored, as is any
+    /// text after the last certificate. Returns an error if any certificate
+    /// is malformed or truncated.
+    ///
+    /// Single certificates can be decoded with [`DecodePem::from_pem`] and
+    /// encoded with [`EncodePem::to_pem`][der::EncodePem::to_pem].
+    pub fn from_pem_multi(pem: impl AsRef<[u8]>) -> der::Result<Vec<Self>> {
+        const BEGIN_BOUNDARY: &[u8] = b"-----BEGIN ";
+        const END_BOUNDARY: &[u8] = b"-----END CERTIFICATE-----";
+
+        fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+            haystack
+                .windows(needle.len())
+                .position(|window| window == needle)
+        }
+
+        let mut input = pem.as_ref();
+        let mut certs = Vec::new();
+
+        while let Some(pos) = find(input, END_BOUNDARY) {
+            let (cert, remaining) = input.split_at(pos + END_BOUNDARY.len());
+            certs.push(Self::from_pem(cert)?);
+            input = remaining;
+        }
+
+        if find(input, BEGIN_BOUNDARY).is_some() {
+            return Err(pem::Error::PostEncapsulationBoundary.into());
+        }
+
+        Ok(certs)
+    }
+}
+
 /// `PkiPath` as defined by X.509 and referenced by [RFC 6066].
 ///
 /// This contains a series of certificates in validation order from the
@@ -1,5 +1,6 @@
 //! Certificate Revocation List types
 
+use crate::ext::pkix::CrlReason;
 use crate::ext::Extensions;
 use crate::name::Name;
 use crate::serial_number::SerialNumber;
@@ -8,8 +9,9 @@ use crate::Version;
 
 use alloc::vec::Vec;
 
+use const_oid::AssociatedOid;
 use der::asn1::BitString;
-use der::{Sequence, ValueOrd};
+use der::{Decode, Sequence, ValueOrd};
 use spki::AlgorithmIdentifierOwned;
 
 /// `CertificateList` as defined in [RFC 5280 Section 5.1].
@@ -24,13 +26,46 @@ use spki::AlgorithmIdentifierOwned;
 ///
 /// [RFC 5280 Section 5.1]: https://datatracker.ietf.org/doc/html/rfc5280#section-5.1
 #[derive(Clone, Debug, Eq, PartialEq, Sequence, ValueOrd)]
-#[allow(missing_docs)]
 pub struct CertificateList {
+    /// The to-be-signed list of revoked certificates.
     pub tbs_cert_list: TbsCertList,
+
+    /// Algorithm the issuer used to sign `tbs_cert_list`.
     pub signature_algorithm: AlgorithmIdentifierOwned,
+
+    /// The issuer's signature over the DER encoding of `tbs_cert_list`.
     pub signature: BitString,
 }
 
+impl CertificateList {
+    /// Iterate over the serial numbers of the certificates revoked by this
+    /// CRL.
+    pub fn revoked_serials(&self) -> impl Iterator<Item = &SerialNumber> {
+        self.tbs_cert_list
+            .revoked_certificates
+            .iter()
+            .flatten()
+            .map(|revoked| &revoked.serial_number)
+    }
+
+    /// Look up the certificate with the given `serial_number` in this CRL.
+    ///
+    /// Returns the [`RevokedCert`] entry if the certificate is revoked, which
+    /// includes the revocation date and any entry extensions such as the
+    /// [`RevokedCert::reason`].
+    ///
+    /// This is a linear scan of the revoked certificates. Callers checking
+    /// many serials against a large CRL may prefer to collect the entries into
+    /// an ordered map keyed by [`SerialNumber`].
+    pub fn is_revoked(&self, serial_number: &SerialNumber) -> Option<&RevokedCert> {
+        self.tbs_cert_list
+            .revoked_certificates
+            .iter()
+            .flatten()
+            .find(|revoked| &revoked.serial_number == serial_number)
+    }
+}
+
 /// Implicit intermediate structure from the ASN.1 definition of `TBSCertList`.
 ///
 /// This type is used for the `revoked_certificates` field of `TbsCertList`.
@@ -46,13 +81,30 @@ pub struct CertificateList {
 ///
 /// [RFC 5280 Section 5.1]: https://datatracker.ietf.org/doc/html/rfc5280#section-5.1
 #[derive(Clone, Debug, Eq, PartialEq, Sequence, ValueOrd)]
-#[allow(missing_docs)]
 pub struct RevokedCert {
+    /// Serial number of the revoked certificate.
     pub serial_number: SerialNumber,
+
+    /// Date on which the revocation occurred.
     pub revocation_date: Time,
+
+    /// Extensions for this entry, such as the revocation reason.
     pub crl_entry_extensions: Option<Extensions>,
 }
 
+impl RevokedCert {
+    /// Decode the [`CrlReason`] entry extension, if present.
+    pub fn reason(&self) -> der::Result<Option<CrlReason>> {
+        self.crl_entry_extensions
+            .as_deref()
+            .unwrap_or(&[])
+            .iter()
+            .find(|ext| ext.extn_id == CrlReason::OID)
+            .map(|ext| CrlReason::from_der(ext.extn_value.as_bytes()))
+            .transpose()
+    }
+}
+
 /// `TbsCertList` as defined in [RFC 5280 Section 5.1].
 ///
 /// ```text
@@ -73,15 +125,27 @@ pub struct RevokedCert {
 ///
 /// [RFC 5280 Section 5.1]: https://datatracker.ietf.org/doc/html/rfc5280#section-5.1
 #[derive(Clone, Debug, Eq, PartialEq, Sequence, ValueOrd)]
-#[allow(missing_docs)]
 pub struct TbsCertList {
+    /// CRL version. This is omitted from the encoding of v1 CRLs.
+    #[asn1(default = "Default::default")]
     pub version: Version,
+
+    /// Algorithm the issuer used to sign the CRL.
     pub signature: AlgorithmIdentifierOwned,
+
+    /// Name of the CRL issuer.
     pub issuer: Name,
+
+    /// Date on which this CRL was issued.
     pub this_update: Time,
+
+    /// Date by which the next CRL will be issued.
     pub next_update: Option<Time>,
+
+    /// Revoked certificates, if there are any.
     pub revoked_certificates: Option<Vec<RevokedCert>>,
 
+    /// CRL extensions. These are only present in v2 CRLs.
     #[asn1(context_specific = "0", tag_mode = "EXPLICIT", optional = "true")]
     pub crl_extensions: Option<Extensions>,
 }
@@ -0,0 +1,62 @@
+//! Error types
+
+use core::fmt;
+use der::asn1::ObjectIdentifier;
+
+/// Result type
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Error type
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// ASN.1 DER-related errors.
+    Asn1(der::Error),
+
+    /// The `signature` algorithm of the `TBSCertificate` doesn't match the
+    /// `signatureAlgorithm` of the `Certificate`.
+    AlgorithmMismatch,
+
+    /// The signature is malformed or invalid.
+    SignatureInvalid,
+
+    /// The certificate's `notBefore` time is later than the time it was
+    /// checked at.
+    CertificateNotYetValid,
+
+    /// The certificate's `notAfter` time is earlier than the time it was
+    /// checked at.
+    CertificateExpired,
+
+    /// An extension with the given OID appears more than once, which
+    /// RFC 5280 forbids.
+    ExtensionDuplicated(ObjectIdentifier),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Asn1(err) => write!(f, "X.509 ASN.1 error: {}", err),
+            Error::AlgorithmMismatch => f.write_str("X.509 signature algorithm mismatch"),
+            Error::SignatureInvalid => f.write_str("X.509 signature invalid"),
+            Error::CertificateNotYetValid => f.write_str("X.509 certificate not yet valid"),
+            Error::CertificateExpired => f.write_str("X.509 certificate expired"),
+            Error::ExtensionDuplicated(oid) => write!(f, "duplicate X.509 extension: {}", oid),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<der::Error> for Error {
+    fn from(err: der::Error) -> Error {
+        Error::Asn1(err)
+    }
+}
+
+impl From<der::ErrorKind> for Error {
+    fn from(err: der::ErrorKind) -> Error {
+        Error::Asn1(err.into())
+    }
+}
@@ -31,7 +31,20 @@ pub use const_oid::db::rfc5280::{
 
 use alloc::vec::Vec;
 
-use der::asn1::OctetString;
+use der::asn1::{Ia5String, OctetString};
+use name::GeneralName;
+
+#[cfg(feature = "sha1")]
+use {
+    sha1::{Digest, Sha1},
+    spki::SubjectPublicKeyInfoRef,
+};
+
+#[cfg(feature = "std")]
+use {
+    der::{Tag, TagNumber},
+    std::net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
 
 /// SubjectKeyIdentifier as defined in [RFC 5280 Section 4.2.1.2].
 ///
@@ -49,6 +62,23 @@ impl AssociatedOid for SubjectKeyIdentifier {
 
 impl_newtype!(SubjectKeyIdentifier, OctetString);
 
+/// Derive a [`SubjectKeyIdentifier`] from a public key using method (1) of
+/// [RFC 5280 Section 4.2.1.2], i.e. the SHA-1 hash of the value of the
+/// `subjectPublicKey` BIT STRING (excluding the tag, length, and number of
+/// unused bits).
+///
+/// [RFC 5280 Section 4.2.1.2]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.1.2
+#[cfg(feature = "sha1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sha1")))]
+impl<'a> TryFrom<SubjectPublicKeyInfoRef<'a>> for SubjectKeyIdentifier {
+    type Error = der::Error;
+
+    fn try_from(spki: SubjectPublicKeyInfoRef<'a>) -> der::Result<Self> {
+        let digest = Sha1::digest(spki.subject_public_key.raw_bytes());
+        Ok(Self(OctetString::new(&digest[..])?))
+    }
+}
+
 /// SubjectAltName as defined in [RFC 5280 Section 4.2.1.6].
 ///
 /// ```text
@@ -65,6 +95,89 @@ impl AssociatedOid for SubjectAltName {
 
 impl_newtype!(SubjectAltName, name::GeneralNames);
 
+impl SubjectAltName {
+    /// Iterate over the `dNSName` entries.
+    pub fn dns_names(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().filter_map(|general_name| match general_name {
+            GeneralName::DnsName(dns_name) => Some(dns_name.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Iterate over the `rfc822Name` (i.e. email address) entries.
+    pub fn email_addresses(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().filter_map(|general_name| match general_name {
+            GeneralName::Rfc822Name(email) => Some(email.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Iterate over the `uniformResourceIdentifier` entries.
+    pub fn uris(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().filter_map(|general_name| match general_name {
+            GeneralName::UniformResourceIdentifier(uri) => Some(uri.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Iterate over the `iPAddress` entries.
+    ///
+    /// Returns an error for any entry which isn't 4 octets (IPv4) or 16
+    /// octets (IPv6) long.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn ip_addresses(&self) -> impl Iterator<Item = der::Result<IpAddr>> + '_ {
+        self.0.iter().filter_map(|general_name| match general_name {
+            GeneralName::IpAddress(ip) => Some(match ip.as_bytes() {
+                &[a, b, c, d] => Ok(IpAddr::from(Ipv4Addr::new(a, b, c, d))),
+                bytes => <[u8; 16]>::try_from(bytes)
+                    .map(|octets| IpAddr::from(Ipv6Addr::from(octets)))
+                    .map_err(|_| {
+                        Tag::ContextSpecific {
+                            constructed: false,
+                            number: TagNumber::N7,
+                        }
+                        .length_error()
+                    }),
+            }),
+            _ => None,
+        })
+    }
+
+    /// Add a `dNSName` entry.
+    pub fn with_dns_name(mut self, dns_name: &str) -> der::Result<Self> {
+        self.0.push(GeneralName::DnsName(Ia5String::new(dns_name)?));
+        Ok(self)
+    }
+
+    /// Add an `rfc822Name` (i.e. email address) entry.
+    pub fn with_email_address(mut self, email: &str) -> der::Result<Self> {
+        self.0.push(GeneralName::Rfc822Name(Ia5String::new(email)?));
+        Ok(self)
+    }
+
+    /// Add a `uniformResourceIdentifier` entry.
+    pub fn with_uri(mut self, uri: &str) -> der::Result<Self> {
+        self.0
+            .push(GeneralName::UniformResourceIdentifier(Ia5String::new(uri)?));
+        Ok(self)
+    }
+
+    /// Add an `iPAddress` entry.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn with_ip_address(mut self, ip: IpAddr) -> der::Result<Self> {
+        let octets = match ip {
+            IpAddr::V4(ip) => ip.octets().to_vec(),
+            IpAddr::V6(ip) => ip.octets().to_vec(),
+        };
+
+        self.0
+            .push(GeneralName::IpAddress(OctetString::new(octets)?));
+        Ok(self)
+    }
+}
+
 /// IssuerAltName as defined in [RFC 5280 Section 4.2.1.7].
 ///
 /// ```text
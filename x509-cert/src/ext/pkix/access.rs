@@ -3,7 +3,9 @@ use super::name::GeneralName;
 use alloc::vec::Vec;
 
 use const_oid::{
-    db::rfc5280::{ID_PE_AUTHORITY_INFO_ACCESS, ID_PE_SUBJECT_INFO_ACCESS},
+    db::rfc5280::{
+        ID_AD_CA_ISSUERS, ID_AD_OCSP, ID_PE_AUTHORITY_INFO_ACCESS, ID_PE_SUBJECT_INFO_ACCESS,
+    },
     AssociatedOid,
 };
 use der::{asn1::ObjectIdentifier, Sequence, ValueOrd};
@@ -24,6 +26,31 @@ impl AssociatedOid for AuthorityInfoAccessSyntax {
 
 impl_newtype!(AuthorityInfoAccessSyntax, Vec<AccessDescription>);
 
+impl AuthorityInfoAccessSyntax {
+    /// Iterate over the URIs of the issuer's OCSP responders (`id-ad-ocsp`).
+    pub fn ocsp(&self) -> impl Iterator<Item = &str> {
+        self.uris(ID_AD_OCSP)
+    }
+
+    /// Iterate over the URIs from which the issuer's certificate can be
+    /// retrieved (`id-ad-caIssuers`).
+    pub fn ca_issuers(&self) -> impl Iterator<Item = &str> {
+        self.uris(ID_AD_CA_ISSUERS)
+    }
+
+    /// Iterate over the `uniformResourceIdentifier` access locations with the
+    /// given access method.
+    fn uris(&self, access_method: ObjectIdentifier) -> impl Iterator<Item = &str> {
+        self.0
+            .iter()
+            .filter(move |ad| ad.access_method == access_method)
+            .filter_map(|ad| match &ad.access_location {
+                GeneralName::UniformResourceIdentifier(uri) => Some(uri.as_str()),
+                _ => None,
+            })
+    }
+}
+
 /// SubjectInfoAccessSyntax as defined in [RFC 5280 Section 4.2.2.2].
 ///
 /// ```text
@@ -19,19 +19,42 @@ use der::Sequence;
 /// ```
 ///
 /// [RFC 5280 Section 4.2.1.1]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.1.1
-#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
-#[allow(missing_docs)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Sequence)]
 pub struct AuthorityKeyIdentifier {
+    /// Identifier of the issuer's public key, which is expected to match the
+    /// issuer certificate's subject key identifier.
     #[asn1(context_specific = "0", tag_mode = "IMPLICIT", optional = "true")]
     pub key_identifier: Option<OctetString>,
 
+    /// Name of the issuer of the issuer's certificate.
     #[asn1(context_specific = "1", tag_mode = "IMPLICIT", optional = "true")]
     pub authority_cert_issuer: Option<GeneralNames>,
 
+    /// Serial number of the issuer's certificate.
     #[asn1(context_specific = "2", tag_mode = "IMPLICIT", optional = "true")]
     pub authority_cert_serial_number: Option<SerialNumber>,
 }
 
+impl AuthorityKeyIdentifier {
+    /// Set the `keyIdentifier` field.
+    pub fn with_key_identifier(mut self, key_identifier: OctetString) -> Self {
+        self.key_identifier = Some(key_identifier);
+        self
+    }
+
+    /// Set the `authorityCertIssuer` field.
+    pub fn with_authority_cert_issuer(mut self, issuer: GeneralNames) -> Self {
+        self.authority_cert_issuer = Some(issuer);
+        self
+    }
+
+    /// Set the `authorityCertSerialNumber` field.
+    pub fn with_authority_cert_serial_number(mut self, serial_number: SerialNumber) -> Self {
+        self.authority_cert_serial_number = Some(serial_number);
+        self
+    }
+}
+
 impl AssociatedOid for AuthorityKeyIdentifier {
     const OID: ObjectIdentifier = ID_CE_AUTHORITY_KEY_IDENTIFIER;
 }
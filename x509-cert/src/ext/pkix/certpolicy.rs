@@ -2,7 +2,7 @@
 
 use alloc::{string::String, vec::Vec};
 
-use const_oid::db::rfc5912::ID_CE_CERTIFICATE_POLICIES;
+use const_oid::db::{rfc5280::ANY_POLICY, rfc5912::ID_CE_CERTIFICATE_POLICIES};
 use const_oid::AssociatedOid;
 use der::asn1::{GeneralizedTime, Ia5String, ObjectIdentifier, Uint};
 use der::{Any, Choice, Sequence, ValueOrd};
@@ -23,6 +23,19 @@ impl AssociatedOid for CertificatePolicies {
 
 impl_newtype!(CertificatePolicies, Vec<PolicyInformation>);
 
+impl CertificatePolicies {
+    /// `anyPolicy`: the certificate may be used under any policy.
+    pub const ANY_POLICY: ObjectIdentifier = ANY_POLICY;
+
+    /// Is the given policy asserted by this extension?
+    ///
+    /// Note that this doesn't take [`CertificatePolicies::ANY_POLICY`] into
+    /// account: callers which honor it need to check for it separately.
+    pub fn contains(&self, policy: &ObjectIdentifier) -> bool {
+        self.0.iter().any(|info| &info.policy_identifier == policy)
+    }
+}
+
 /// PolicyInformation as defined in [RFC 5280 Section 4.2.1.4].
 ///
 /// ```text
@@ -36,9 +49,11 @@ impl_newtype!(CertificatePolicies, Vec<PolicyInformation>);
 ///
 /// [RFC 5280 Section 4.2.1.4]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.1.4
 #[derive(Clone, Debug, Eq, PartialEq, Sequence, ValueOrd)]
-#[allow(missing_docs)]
 pub struct PolicyInformation {
+    /// Identifier of the asserted policy.
     pub policy_identifier: ObjectIdentifier,
+
+    /// Optional qualifiers, e.g. a CPS URI or user notice.
     pub policy_qualifiers: Option<Vec<PolicyQualifierInfo>>,
 }
 
@@ -53,9 +68,12 @@ pub struct PolicyInformation {
 ///
 /// [RFC 5280 Section 4.2.1.4]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.1.4
 #[derive(Clone, Debug, Eq, PartialEq, Sequence, ValueOrd)]
-#[allow(missing_docs)]
 pub struct PolicyQualifierInfo {
+    /// Identifier of the qualifier type, e.g. `id-qt-cps` or `id-qt-unotice`.
     pub policy_qualifier_id: ObjectIdentifier,
+
+    /// Undecoded qualifier, whose type is determined by
+    /// `policy_qualifier_id` (e.g. [`CpsUri`] or [`UserNotice`]).
     pub qualifier: Option<Any>,
 }
 
@@ -12,11 +12,14 @@ use der::Sequence;
 ///
 /// [RFC 5280 Section 4.2.1.9]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.1.9
 #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
-#[allow(missing_docs)]
 pub struct BasicConstraints {
+    /// Is the subject a CA? Omitted from the encoding when `false`.
     #[asn1(default = "Default::default")]
     pub ca: bool,
-    pub path_len_constraint: Option<u8>,
+
+    /// Maximum number of non-self-issued intermediate certificates which may
+    /// follow this one in a certification path.
+    pub path_len_constraint: Option<u32>,
 }
 
 impl AssociatedOid for BasicConstraints {
@@ -17,16 +17,35 @@ use super::super::name::GeneralName;
 /// ```
 ///
 /// [RFC 5280 Section 4.2.1.10]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.1.10
-#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
-#[allow(missing_docs)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Sequence)]
 pub struct NameConstraints {
+    /// Subtrees which all names in subsequent certificates must fall within.
     #[asn1(context_specific = "0", optional = "true", tag_mode = "IMPLICIT")]
     pub permitted_subtrees: Option<GeneralSubtrees>,
 
+    /// Subtrees which no name in subsequent certificates may fall within.
     #[asn1(context_specific = "1", optional = "true", tag_mode = "IMPLICIT")]
     pub excluded_subtrees: Option<GeneralSubtrees>,
 }
 
+impl NameConstraints {
+    /// Add a subtree to the `permittedSubtrees` field.
+    pub fn with_permitted_subtree(mut self, subtree: GeneralSubtree) -> Self {
+        self.permitted_subtrees
+            .get_or_insert_with(Vec::new)
+            .push(subtree);
+        self
+    }
+
+    /// Add a subtree to the `excludedSubtrees` field.
+    pub fn with_excluded_subtree(mut self, subtree: GeneralSubtree) -> Self {
+        self.excluded_subtrees
+            .get_or_insert_with(Vec::new)
+            .push(subtree);
+        self
+    }
+}
+
 impl AssociatedOid for NameConstraints {
     const OID: ObjectIdentifier = ID_CE_NAME_CONSTRAINTS;
 }
@@ -52,10 +71,11 @@ pub type GeneralSubtrees = Vec<GeneralSubtree>;
 ///
 /// [RFC 5280 Section 4.2.1.10]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.1.10
 #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
-#[allow(missing_docs)]
 pub struct GeneralSubtree {
+    /// Name identifying the root of the subtree.
     pub base: GeneralName,
 
+    /// Minimum distance from the base. Must be zero per RFC 5280.
     #[asn1(
         context_specific = "0",
         tag_mode = "IMPLICIT",
@@ -63,6 +83,19 @@ pub struct GeneralSubtree {
     )]
     pub minimum: u32,
 
+    /// Maximum distance from the base. Must be absent per RFC 5280.
     #[asn1(context_specific = "1", tag_mode = "IMPLICIT", optional = "true")]
     pub maximum: Option<u32>,
 }
+
+impl GeneralSubtree {
+    /// Create a new subtree rooted at the given name, with the `minimum` and
+    /// `maximum` fields set as required by RFC 5280.
+    pub fn new(base: GeneralName) -> Self {
+        Self {
+            base,
+            minimum: 0,
+            maximum: None,
+        }
+    }
+}
@@ -61,6 +61,16 @@ impl AssociatedOid for CrlDistributionPoints {
 
 impl_newtype!(CrlDistributionPoints, Vec<dp::DistributionPoint>);
 
+impl CrlDistributionPoints {
+    /// Iterate over the URIs (e.g. HTTP or LDAP URLs) from which CRLs can be
+    /// retrieved, across all distribution points.
+    ///
+    /// See [`dp::DistributionPoint::uris`].
+    pub fn uris(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().flat_map(|dp| dp.uris())
+    }
+}
+
 /// FreshestCrl as defined in [RFC 5280 Section 5.2.6].
 ///
 /// ```text
@@ -4,7 +4,7 @@ use const_oid::{db::rfc5280::ID_PE_SUBJECT_INFO_ACCESS, AssociatedOid, ObjectIde
 use der::{Sequence, ValueOrd};
 use flagset::{flags, FlagSet};
 
-use crate::ext::pkix::name::{DistributionPointName, GeneralNames};
+use crate::ext::pkix::name::{DistributionPointName, GeneralName, GeneralNames};
 
 /// IssuingDistributionPoint as defined in [RFC 5280 Section 5.2.5].
 ///
@@ -87,6 +87,27 @@ pub struct DistributionPoint {
     pub crl_issuer: Option<GeneralNames>,
 }
 
+impl DistributionPoint {
+    /// Iterate over the `uniformResourceIdentifier` entries of the
+    /// `fullName` form of the `distributionPoint` field.
+    ///
+    /// Entries of other name types, and distribution points named relative to
+    /// the CRL issuer, are skipped.
+    pub fn uris(&self) -> impl Iterator<Item = &str> {
+        let full_name = match &self.distribution_point {
+            Some(DistributionPointName::FullName(names)) => names.as_slice(),
+            _ => &[],
+        };
+
+        full_name
+            .iter()
+            .filter_map(|general_name| match general_name {
+                GeneralName::UniformResourceIdentifier(uri) => Some(uri.as_str()),
+                _ => None,
+            })
+    }
+}
+
 /// ReasonFlags as defined in [RFC 5280 Section 4.2.1.13].
 ///
 /// [RFC 5280 Section 4.2.1.13]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.1.13
@@ -1,7 +1,9 @@
 use alloc::vec::Vec;
 
 use const_oid::db::rfc5280::{
-    ID_CE_EXT_KEY_USAGE, ID_CE_KEY_USAGE, ID_CE_PRIVATE_KEY_USAGE_PERIOD,
+    ANY_EXTENDED_KEY_USAGE, ID_CE_EXT_KEY_USAGE, ID_CE_KEY_USAGE, ID_CE_PRIVATE_KEY_USAGE_PERIOD,
+    ID_KP_CLIENT_AUTH, ID_KP_CODE_SIGNING, ID_KP_EMAIL_PROTECTION, ID_KP_SERVER_AUTH,
+    ID_KP_TIME_STAMPING,
 };
 use const_oid::AssociatedOid;
 use der::asn1::{GeneralizedTime, ObjectIdentifier};
@@ -44,7 +46,7 @@ flags! {
 /// KeyUsage as defined in [RFC 5280 Section 4.2.1.3].
 ///
 /// [RFC 5280 Section 4.2.1.3]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.1.3
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub struct KeyUsage(pub FlagSet<KeyUsages>);
 
 impl AssociatedOid for KeyUsage {
@@ -54,45 +56,54 @@ impl AssociatedOid for KeyUsage {
 impl_newtype!(KeyUsage, FlagSet<KeyUsages>);
 
 impl KeyUsage {
+    /// Add the given key usage, e.g. `KeyUsage::default().with_usage(KeyUsages::KeyCertSign)`.
+    ///
+    /// The resulting `KeyUsage` always encodes as the minimum-length
+    /// `BIT STRING` required by DER, i.e. with trailing zero bits removed.
+    pub fn with_usage(mut self, usage: KeyUsages) -> Self {
+        self.0 |= usage;
+        self
+    }
+
     /// The subject public key is used for verifying digital signatures
     pub fn digital_signature(&self) -> bool {
-        self.0.bits() & KeyUsages::DigitalSignature as u16 == KeyUsages::DigitalSignature as u16
+        self.0.contains(KeyUsages::DigitalSignature)
     }
 
     /// When the subject public key is used to verify digital signatures,
     /// it is asserted as non-repudiation.
     pub fn non_repudiation(&self) -> bool {
-        self.0.bits() & KeyUsages::NonRepudiation as u16 == KeyUsages::NonRepudiation as u16
+        self.0.contains(KeyUsages::NonRepudiation)
     }
 
     /// The subject public key is used for enciphering private or
     /// secret keys, i.e., for key transport.
     pub fn key_encipherment(&self) -> bool {
-        self.0.bits() & KeyUsages::KeyEncipherment as u16 == KeyUsages::KeyEncipherment as u16
+        self.0.contains(KeyUsages::KeyEncipherment)
     }
 
     /// The subject public key is used for directly enciphering
     /// raw user data without the use of an intermediate symmetric cipher.
     pub fn data_encipherment(&self) -> bool {
-        self.0.bits() & KeyUsages::DataEncipherment as u16 == KeyUsages::DataEncipherment as u16
+        self.0.contains(KeyUsages::DataEncipherment)
     }
 
     /// The subject public key is used for key agreement
     pub fn key_agreement(&self) -> bool {
-        self.0.bits() & KeyUsages::KeyAgreement as u16 == KeyUsages::KeyAgreement as u16
+        self.0.contains(KeyUsages::KeyAgreement)
     }
 
-    /// The subject public key is used for enciphering private or
-    /// secret keys, i.e., for key transport.
+    /// The subject public key is used for verifying signatures
+    /// on public key certificates.
     pub fn key_cert_sign(&self) -> bool {
-        self.0.bits() & KeyUsages::KeyCertSign as u16 == KeyUsages::KeyCertSign as u16
+        self.0.contains(KeyUsages::KeyCertSign)
     }
 
     /// The subject public key is used for verifying signatures
     /// on certificate revocation lists (e.g., CRLs, delta CRLs,
     /// or ARLs).
     pub fn crl_sign(&self) -> bool {
-        self.0.bits() & KeyUsages::CRLSign as u16 == KeyUsages::CRLSign as u16
+        self.0.contains(KeyUsages::CRLSign)
     }
 
     /// The meaning of the `encipher_only` is undefined when `key_agreement`
@@ -100,7 +111,7 @@ impl KeyUsage {
     /// `key_agreement` also returns true, the subject public key may be
     /// used only for enciphering data while performing key agreement.
     pub fn encipher_only(&self) -> bool {
-        self.0.bits() & KeyUsages::EncipherOnly as u16 == KeyUsages::EncipherOnly as u16
+        self.0.contains(KeyUsages::EncipherOnly)
     }
 
     /// The meaning of the `decipher_only` is undefined when `key_agreement`
@@ -108,19 +119,19 @@ impl KeyUsage {
     /// `key_agreement` also returns true, the subject public key may be
     /// used only for deciphering data while performing key agreement.
     pub fn decipher_only(&self) -> bool {
-        self.0.bits() & KeyUsages::DecipherOnly as u16 == KeyUsages::DecipherOnly as u16
+        self.0.contains(KeyUsages::DecipherOnly)
     }
 }
 
 /// ExtKeyUsageSyntax as defined in [RFC 5280 Section 4.2.1.12].
 ///
 /// Many extended key usage values include:
-/// - [`PKIX_CE_ANYEXTENDEDKEYUSAGE`](constant.PKIX_CE_ANYEXTENDEDKEYUSAGE.html),
-/// - [`PKIX_KP_SERVERAUTH`](constant.PKIX_KP_SERVERAUTH.html),
-/// - [`PKIX_KP_CLIENTAUTH`](constant.PKIX_KP_CLIENTAUTH.html),
-/// - [`PKIX_KP_CODESIGNING`](constant.PKIX_KP_CODESIGNING.html),
-/// - [`PKIX_KP_EMAILPROTECTION`](constant.PKIX_KP_EMAILPROTECTION.html),
-/// - [`PKIX_KP_TIMESTAMPING`](constant.PKIX_KP_TIMESTAMPING.html),
+/// - [`ExtendedKeyUsage::ANY`]
+/// - [`ExtendedKeyUsage::SERVER_AUTH`]
+/// - [`ExtendedKeyUsage::CLIENT_AUTH`]
+/// - [`ExtendedKeyUsage::CODE_SIGNING`]
+/// - [`ExtendedKeyUsage::EMAIL_PROTECTION`]
+/// - [`ExtendedKeyUsage::TIME_STAMPING`]
 ///
 /// ```text
 /// ExtKeyUsageSyntax ::= SEQUENCE SIZE (1..MAX) OF KeyPurposeId
@@ -128,7 +139,7 @@ impl KeyUsage {
 /// ```
 ///
 /// [RFC 5280 Section 4.2.1.12]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.1.12
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct ExtendedKeyUsage(pub Vec<ObjectIdentifier>);
 
 impl AssociatedOid for ExtendedKeyUsage {
@@ -137,6 +148,40 @@ impl AssociatedOid for ExtendedKeyUsage {
 
 impl_newtype!(ExtendedKeyUsage, Vec<ObjectIdentifier>);
 
+impl ExtendedKeyUsage {
+    /// `anyExtendedKeyUsage`: the key may be used for any purpose.
+    pub const ANY: ObjectIdentifier = ANY_EXTENDED_KEY_USAGE;
+
+    /// `id-kp-serverAuth`: TLS WWW server authentication.
+    pub const SERVER_AUTH: ObjectIdentifier = ID_KP_SERVER_AUTH;
+
+    /// `id-kp-clientAuth`: TLS WWW client authentication.
+    pub const CLIENT_AUTH: ObjectIdentifier = ID_KP_CLIENT_AUTH;
+
+    /// `id-kp-codeSigning`: signing of downloadable executable code.
+    pub const CODE_SIGNING: ObjectIdentifier = ID_KP_CODE_SIGNING;
+
+    /// `id-kp-emailProtection`: email protection.
+    pub const EMAIL_PROTECTION: ObjectIdentifier = ID_KP_EMAIL_PROTECTION;
+
+    /// `id-kp-timeStamping`: binding the hash of an object to a time.
+    pub const TIME_STAMPING: ObjectIdentifier = ID_KP_TIME_STAMPING;
+
+    /// Add the given key purpose.
+    pub fn with_purpose(mut self, purpose: ObjectIdentifier) -> Self {
+        self.0.push(purpose);
+        self
+    }
+
+    /// Does this extension list the given key purpose?
+    ///
+    /// Note that this doesn't take [`ExtendedKeyUsage::ANY`] into account:
+    /// callers which honor it need to check for it separately.
+    pub fn contains(&self, purpose: &ObjectIdentifier) -> bool {
+        self.0.contains(purpose)
+    }
+}
+
 /// PrivateKeyUsagePeriod as defined in [RFC 3280 Section 4.2.1.4].
 ///
 /// RFC 5280 states "use of this ISO standard extension is neither deprecated nor recommended for use in the Internet PKI."
@@ -4,7 +4,7 @@ use super::{EdiPartyName, OtherName};
 use crate::name::Name;
 
 use der::asn1::{Ia5String, ObjectIdentifier, OctetString};
-use der::{Choice, ValueOrd};
+use der::{Any, Choice, ValueOrd};
 
 /// GeneralNames as defined in [RFC 5280 Section 4.2.1.6].
 ///
@@ -31,7 +31,8 @@ pub type GeneralNames = alloc::vec::Vec<GeneralName>;
 /// }
 /// ```
 ///
-/// This implementation does not currently support the `x400Address` choice.
+/// The `x400Address` choice is not decoded any further: its value holds the
+/// `[3]`-tagged contents of the `ORAddress` SEQUENCE.
 ///
 /// [RFC 5280 Section 4.2.1.6]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.1.6
 #[derive(Clone, Debug, Eq, PartialEq, Choice, ValueOrd)]
@@ -46,6 +47,9 @@ pub enum GeneralName {
     #[asn1(context_specific = "2", tag_mode = "IMPLICIT")]
     DnsName(Ia5String),
 
+    #[asn1(context_specific = "3", tag_mode = "IMPLICIT", constructed = "true")]
+    X400Address(Any),
+
     #[asn1(context_specific = "4", tag_mode = "EXPLICIT", constructed = "true")]
     DirectoryName(Name),
 
@@ -25,6 +25,8 @@ mod macros;
 
 pub mod anchor;
 pub mod attr;
+pub mod attr_cert;
+pub mod builder;
 pub mod certificate;
 pub mod crl;
 pub mod ext;
@@ -33,6 +35,13 @@ pub mod request;
 pub mod serial_number;
 pub mod time;
 
+mod error;
+
 pub use certificate::{Certificate, PkiPath, TbsCertificate, Version};
 pub use der;
+pub use error::{Error, Result};
 pub use spki;
+
+#[cfg(feature = "verify")]
+#[cfg_attr(docsrs, doc(cfg(feature = "verify")))]
+pub use signature;
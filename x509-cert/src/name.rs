@@ -1,8 +1,12 @@
 //! Name-related definitions as defined in X.501 (and updated by RFC 5280).
 
-use crate::attr::AttributeTypeAndValue;
+use crate::attr::{AttributeType, AttributeTypeAndValue};
 use alloc::vec::Vec;
-use core::fmt;
+use const_oid::db::rfc4519::{
+    COMMON_NAME, COUNTRY_NAME, DOMAIN_COMPONENT, LOCALITY_NAME, ORGANIZATIONAL_UNIT_NAME,
+    ORGANIZATION_NAME, ST,
+};
+use core::{fmt, str::FromStr};
 use der::{asn1::SetOfVec, Decode, Encode};
 
 /// X.501 Name as defined in [RFC 5280 Section 4.1.2.4]. X.501 Name is used to represent distinguished names.
@@ -28,7 +32,9 @@ pub struct RdnSequence(pub Vec<RelativeDistinguishedName>);
 impl RdnSequence {
     /// Converts an RDNSequence string into an encoded RDNSequence
     ///
-    /// This function follows the rules in [RFC 4514].
+    /// This function follows the rules in [RFC 4514]. In particular, the
+    /// RDNs in the string are in reverse order, i.e. the last RDN of the
+    /// RDNSequence comes first.
     ///
     /// [RFC 4514]: https://datatracker.ietf.org/doc/html/rfc4514
     pub fn encode_from_string(s: &str) -> Result<Vec<u8>, der::Error> {
@@ -37,7 +43,7 @@ impl RdnSequence {
             .collect::<Result<Vec<_>, der::Error>>()?;
 
         let mut out = Vec::new();
-        for der in ders.iter() {
+        for der in ders.iter().rev() {
             out.push(RelativeDistinguishedName::from_der(der)?);
         }
 
@@ -45,12 +51,24 @@ impl RdnSequence {
     }
 }
 
-/// Serializes the structure according to the rules in [RFC 4514].
+/// Parses the structure according to the rules in [RFC 4514].
+///
+/// [RFC 4514]: https://datatracker.ietf.org/doc/html/rfc4514
+impl FromStr for RdnSequence {
+    type Err = der::Error;
+
+    fn from_str(s: &str) -> Result<Self, der::Error> {
+        Self::from_der(&Self::encode_from_string(s)?)
+    }
+}
+
+/// Serializes the structure according to the rules in [RFC 4514], starting
+/// with the last RDN of the RDNSequence.
 ///
 /// [RFC 4514]: https://datatracker.ietf.org/doc/html/rfc4514
 impl fmt::Display for RdnSequence {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for (i, atv) in self.0.iter().enumerate() {
+        for (i, atv) in self.0.iter().rev().enumerate() {
             match i {
                 0 => write!(f, "{}", atv)?,
                 _ => write!(f, ",{}", atv)?,
@@ -63,6 +81,114 @@ impl fmt::Display for RdnSequence {
 
 impl_newtype!(RdnSequence, Vec<RelativeDistinguishedName>);
 
+/// Builder for [`Name`]s.
+///
+/// Each attribute is placed in its own [`RelativeDistinguishedName`]. These
+/// are ordered from most to least significant (domain components, country,
+/// state or province, locality, organization, organizational unit, then
+/// common name) regardless of the order in which they were added, followed by
+/// any other attributes. Attributes of the same type keep the order in which
+/// they were added.
+///
+/// Values are encoded using the same string types as
+/// [`RdnSequence::encode_from_string`], i.e. `PrintableString` for the
+/// country, `IA5String` for domain components, and `UTF8String` otherwise.
+/// Any error encountered while adding an attribute is returned by
+/// [`NameBuilder::build`].
+#[derive(Clone, Debug, Default)]
+pub struct NameBuilder {
+    atvs: Vec<AttributeTypeAndValue>,
+    error: Option<der::Error>,
+}
+
+impl NameBuilder {
+    /// Create a new, empty name builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a `domainComponent` (`DC`) attribute.
+    pub fn domain_component(self, value: &str) -> Self {
+        self.attribute(DOMAIN_COMPONENT, value)
+    }
+
+    /// Add a `countryName` (`C`) attribute.
+    pub fn country(self, value: &str) -> Self {
+        self.attribute(COUNTRY_NAME, value)
+    }
+
+    /// Add a `stateOrProvinceName` (`ST`) attribute.
+    pub fn state_or_province(self, value: &str) -> Self {
+        self.attribute(ST, value)
+    }
+
+    /// Add a `localityName` (`L`) attribute.
+    pub fn locality(self, value: &str) -> Self {
+        self.attribute(LOCALITY_NAME, value)
+    }
+
+    /// Add an `organizationName` (`O`) attribute.
+    pub fn organization(self, value: &str) -> Self {
+        self.attribute(ORGANIZATION_NAME, value)
+    }
+
+    /// Add an `organizationalUnitName` (`OU`) attribute.
+    pub fn organizational_unit(self, value: &str) -> Self {
+        self.attribute(ORGANIZATIONAL_UNIT_NAME, value)
+    }
+
+    /// Add a `commonName` (`CN`) attribute.
+    pub fn common_name(self, value: &str) -> Self {
+        self.attribute(COMMON_NAME, value)
+    }
+
+    /// Add an attribute of an arbitrary type with a string value.
+    pub fn attribute(mut self, oid: AttributeType, value: &str) -> Self {
+        match AttributeTypeAndValue::from_str_value(oid, value) {
+            Ok(atv) => self.atvs.push(atv),
+            Err(err) => {
+                self.error.get_or_insert(err);
+            }
+        }
+
+        self
+    }
+
+    /// Build the [`Name`].
+    pub fn build(mut self) -> der::Result<Name> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+
+        // `sort_by_key` is stable, preserving the order of repeated attributes
+        self.atvs.sort_by_key(|atv| significance(&atv.oid));
+
+        self.atvs
+            .into_iter()
+            .map(|atv| {
+                let mut rdn = SetOfVec::new();
+                rdn.add(atv)?;
+                Ok(RelativeDistinguishedName(rdn))
+            })
+            .collect::<der::Result<Vec<_>>>()
+            .map(RdnSequence)
+    }
+}
+
+/// Position of an attribute within a [`Name`] built by [`NameBuilder`].
+fn significance(oid: &AttributeType) -> u8 {
+    match *oid {
+        DOMAIN_COMPONENT => 0,
+        COUNTRY_NAME => 1,
+        ST => 2,
+        LOCALITY_NAME => 3,
+        ORGANIZATION_NAME => 4,
+        ORGANIZATIONAL_UNIT_NAME => 5,
+        COMMON_NAME => 6,
+        _ => 7,
+    }
+}
+
 /// Find the indices of all non-escaped separators.
 fn find(s: &str, b: u8) -> impl '_ + Iterator<Item = usize> {
     (0..s.len())
@@ -14,6 +14,12 @@ use spki::{AlgorithmIdentifierOwned, SubjectPublicKeyInfoOwned};
 #[cfg(feature = "pem")]
 use der::pem::PemLabel;
 
+#[cfg(feature = "verify")]
+use {
+    der::Encode,
+    signature::{SignatureEncoding, Verifier},
+};
+
 /// Version identifier for certification request information.
 ///
 /// (RFC 2986 designates `0` as the only valid version)
@@ -76,6 +82,47 @@ pub struct CertReq {
     pub signature: BitString,
 }
 
+impl CertReq {
+    /// Assemble a [`CertReq`] from a [`CertReqInfo`] and the requester's
+    /// signature over its DER encoding.
+    pub fn new(
+        info: CertReqInfo,
+        algorithm: AlgorithmIdentifierOwned,
+        signature: BitString,
+    ) -> Self {
+        Self {
+            info,
+            algorithm,
+            signature,
+        }
+    }
+
+    /// Verify the self-signature on this request using a `verifier` for the
+    /// public key in [`CertReqInfo::public_key`].
+    ///
+    /// This proves that the requester possesses the corresponding private key.
+    /// The signature is verified over the DER re-encoding of the
+    /// [`CertReqInfo`]. It's up to the caller to pick a `verifier` which
+    /// implements the request's signature `algorithm`.
+    #[cfg(feature = "verify")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "verify")))]
+    pub fn verify<V, S>(&self, verifier: &V) -> crate::Result<()>
+    where
+        V: Verifier<S>,
+        S: SignatureEncoding,
+    {
+        let signature = self
+            .signature
+            .as_bytes()
+            .and_then(|bytes| S::try_from(bytes).ok())
+            .ok_or(crate::Error::SignatureInvalid)?;
+
+        verifier
+            .verify(&self.info.to_vec()?, &signature)
+            .map_err(|_| crate::Error::SignatureInvalid)
+    }
+}
+
 #[cfg(feature = "pem")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
 impl PemLabel for CertReq {
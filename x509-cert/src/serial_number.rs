@@ -2,6 +2,9 @@
 
 use core::fmt::Display;
 
+#[cfg(feature = "rand_core")]
+use {alloc::vec, rand_core::CryptoRngCore};
+
 use der::{
     asn1::Int, asn1::Uint, DecodeValue, EncodeValue, ErrorKind, FixedTag, Header, Length, Reader,
     Result, Tag, ValueOrd, Writer,
@@ -35,6 +38,15 @@ impl SerialNumber {
     /// See notes in `SerialNumber::new` and `SerialNumber::decode_value`.
     const MAX_DECODE_LEN: Length = Length::new(21);
 
+    /// Minimum length in bytes for a [`SerialNumber`] produced by
+    /// [`SerialNumber::generate`].
+    ///
+    /// The CA/Browser Forum Baseline Requirements call for at least 64 bits
+    /// of output from a CSPRNG. Generated serials have their most significant
+    /// bit cleared, so 9 bytes are needed to meet this.
+    #[cfg(feature = "rand_core")]
+    pub const MIN_GENERATE_LEN: usize = 9;
+
     /// Create a new [`SerialNumber`] from a byte slice.
     ///
     /// The byte slice **must** represent a positive integer.
@@ -55,6 +67,37 @@ impl SerialNumber {
         })
     }
 
+    /// Generate a random [`SerialNumber`] of exactly `len` bytes, suitable for
+    /// use with [`CertificateBuilder`][crate::builder::CertificateBuilder].
+    ///
+    /// The most significant bit is cleared so that the serial is a positive
+    /// INTEGER encoded in `len` octets, and the leading byte is never zero.
+    ///
+    /// `len` must be between [`SerialNumber::MIN_GENERATE_LEN`] and
+    /// [`SerialNumber::MAX_LEN`] inclusive.
+    #[cfg(feature = "rand_core")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+    pub fn generate(rng: &mut impl CryptoRngCore, len: usize) -> Result<Self> {
+        if len < Self::MIN_GENERATE_LEN {
+            return Err(Self::TAG.value_error());
+        }
+
+        if Length::try_from(len)? > Self::MAX_LEN {
+            return Err(ErrorKind::Overlength.into());
+        }
+
+        let mut bytes = vec![0u8; len];
+        rng.fill_bytes(&mut bytes);
+
+        // Redraw a zero leading byte rather than shortening the serial
+        while bytes[0] & 0x7F == 0 {
+            rng.fill_bytes(&mut bytes[..1]);
+        }
+
+        bytes[0] &= 0x7F;
+        Self::new(&bytes)
+    }
+
     /// Borrow the inner byte slice which contains the least significant bytes
     /// of a big endian integer value with all leading zeros stripped.
     pub fn as_bytes(&self) -> &[u8] {
@@ -145,6 +188,73 @@ mod tests {
         }
     }
 
+    /// Deterministic RNG which emits `zeros` zero bytes before counting up
+    /// from `next`, for exercising [`SerialNumber::generate`].
+    #[cfg(feature = "rand_core")]
+    struct MockRng {
+        zeros: usize,
+        next: u8,
+    }
+
+    #[cfg(feature = "rand_core")]
+    impl rand_core::RngCore for MockRng {
+        fn next_u32(&mut self) -> u32 {
+            rand_core::impls::next_u32_via_fill(self)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            rand_core::impls::next_u64_via_fill(self)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for byte in dest {
+                if self.zeros > 0 {
+                    self.zeros -= 1;
+                    *byte = 0;
+                } else {
+                    *byte = self.next;
+                    self.next = self.next.wrapping_add(1);
+                }
+            }
+        }
+
+        fn try_fill_bytes(
+            &mut self,
+            dest: &mut [u8],
+        ) -> core::result::Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "rand_core")]
+    impl rand_core::CryptoRng for MockRng {}
+
+    #[test]
+    #[cfg(feature = "rand_core")]
+    fn serial_number_generate() {
+        use der::Encode;
+
+        for zeros in [0, 1, 5, 20] {
+            for len in SerialNumber::MIN_GENERATE_LEN..=20 {
+                let mut rng = MockRng { zeros, next: 0xF0 };
+                let sn = SerialNumber::generate(&mut rng, len).unwrap();
+                let der = sn.to_vec().unwrap();
+
+                // Positive INTEGER of exactly `len` octets without a leading zero
+                assert_eq!(der[0], 0x02);
+                assert_eq!(der[1] as usize, len);
+                assert_ne!(der[2], 0x00);
+                assert!(der[2] < 0x80);
+                assert_eq!(sn.as_bytes().len(), len);
+            }
+        }
+
+        let mut rng = MockRng { zeros: 0, next: 0 };
+        assert!(SerialNumber::generate(&mut rng, SerialNumber::MIN_GENERATE_LEN - 1).is_err());
+        assert!(SerialNumber::generate(&mut rng, 21).is_err());
+    }
+
     #[test]
     fn serial_number_display() {
         {
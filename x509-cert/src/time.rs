@@ -137,4 +137,16 @@ impl Validity {
             not_after: Time::try_from(then)?,
         })
     }
+
+    /// Is the given `time` within this validity period?
+    ///
+    /// Both bounds are inclusive, as required by [RFC 5280 Section 4.1.2.5].
+    /// `UTCTime` and `GeneralizedTime` values are compared by the instant
+    /// they represent, not by their encoding.
+    ///
+    /// [RFC 5280 Section 4.1.2.5]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.1.2.5
+    pub fn is_valid_at(&self, time: DateTime) -> bool {
+        let time = time.unix_duration();
+        self.not_before.to_unix_duration() <= time && time <= self.not_after.to_unix_duration()
+    }
 }
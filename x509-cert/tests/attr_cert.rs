@@ -0,0 +1,97 @@
+//! Attribute certificate tests
+
+use const_oid::db::rfc5912::{ID_ACA_GROUP, ID_AT_ROLE, ID_CE_NO_REV_AVAIL};
+use der::{DateTime, Decode, Encode};
+use hex_literal::hex;
+use x509_cert::attr_cert::{AttCertIssuer, AttributeCertificate, Version};
+use x509_cert::ext::pkix::name::GeneralName;
+
+/// Attribute certificate with a role and a group attribute, issued by the CA
+/// in `crl-v1.der`. There's no OpenSSL tooling for attribute certificates, so
+/// this was assembled with a Python script and signed with the `cryptography`
+/// package. Its structure is:
+///
+/// ```text
+/// holder:     baseCertificateID { issuer: CN=CRL Test CA, serial: 0x99 },
+///             entityName { rfc822Name: alice@example.com }
+/// issuer:     v2Form { issuerName: CN=CRL Test CA,O=RustCrypto,C=US }
+/// serial:     0x0A
+/// validity:   20230101000000Z to 20330101000000Z
+/// attributes: role { roleName: URI:urn:example:role:admin },
+///             group { values: UTF8String "engineering" }
+/// extensions: noRevAvail
+/// ```
+const ATTR_CERT: &[u8] = include_bytes!("examples/attr-cert.der");
+
+#[test]
+fn decode_attr_cert() {
+    let ac = AttributeCertificate::from_der(ATTR_CERT).unwrap();
+    let info = &ac.acinfo;
+
+    assert_eq!(info.version, Version::V2);
+    assert_eq!(info.serial_number.as_bytes(), [0x0A]);
+    assert_eq!(info.signature, ac.signature_algorithm);
+
+    // Holder
+    let base_cert_id = info.holder.base_certificate_id.as_ref().unwrap();
+    assert_eq!(base_cert_id.serial.as_bytes(), [0x00, 0x99]);
+    match &base_cert_id.issuer[..] {
+        [GeneralName::DirectoryName(name)] => {
+            assert_eq!(name.to_string(), "CN=CRL Test CA,O=RustCrypto,C=US")
+        }
+        _ => panic!("unexpected holder issuer"),
+    }
+
+    match &info.holder.entity_name.as_ref().unwrap()[..] {
+        [GeneralName::Rfc822Name(email)] => assert_eq!(email.as_str(), "alice@example.com"),
+        _ => panic!("unexpected holder name"),
+    }
+
+    assert_eq!(info.holder.object_digest_info, None);
+
+    // Issuer
+    match &info.issuer {
+        AttCertIssuer::V2Form(v2) => {
+            assert_eq!(v2.issuer_name, Some(base_cert_id.issuer.clone()));
+            assert_eq!(v2.base_certificate_id, None);
+            assert_eq!(v2.object_digest_info, None);
+        }
+        _ => panic!("expected a v2Form issuer"),
+    }
+
+    // Validity
+    let validity = &info.attr_cert_validity_period;
+    assert!(validity.is_valid_at(DateTime::new(2023, 1, 1, 0, 0, 0).unwrap()));
+    assert!(validity.is_valid_at(DateTime::new(2033, 1, 1, 0, 0, 0).unwrap()));
+    assert!(!validity.is_valid_at(DateTime::new(2033, 1, 1, 0, 0, 1).unwrap()));
+
+    // Attributes
+    assert_eq!(info.attributes.len(), 2);
+
+    let role = info.attribute(ID_AT_ROLE).unwrap();
+    assert_eq!(role.values.len(), 1);
+    assert_eq!(
+        role.values.get(0).unwrap().to_vec().unwrap(),
+        hex!("301AA118861675726E3A6578616D706C653A726F6C653A61646D696E")
+    );
+
+    let group = info.attribute(ID_ACA_GROUP).unwrap();
+    assert_eq!(
+        group.values.get(0).unwrap().to_vec().unwrap(),
+        hex!("300F300D0C0B656E67696E656572696E67")
+    );
+
+    assert!(info.attribute(ID_CE_NO_REV_AVAIL).is_none());
+
+    // Extensions
+    let extensions = info.extensions.as_ref().unwrap();
+    assert_eq!(extensions.len(), 1);
+    assert_eq!(extensions[0].extn_id, ID_CE_NO_REV_AVAIL);
+    assert_eq!(extensions[0].extn_value.as_bytes(), hex!("0500"));
+}
+
+#[test]
+fn reencode_attr_cert() {
+    let ac = AttributeCertificate::from_der(ATTR_CERT).unwrap();
+    assert_eq!(ac.to_vec().unwrap(), ATTR_CERT);
+}
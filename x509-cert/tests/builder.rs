@@ -0,0 +1,131 @@
+//! Certificate builder tests
+
+use der::{Decode, Encode};
+use x509_cert::{
+    builder::{CertReqBuilder, CertificateBuilder},
+    ext::Extensions,
+    name::Name,
+    request::CertReq,
+    Certificate, Version,
+};
+
+/// Self-signed Ed25519 certificate with extensions, generated using:
+///
+/// ```text
+/// openssl genpkey -algorithm ed25519 -out key.pem
+/// openssl req -x509 -new -key key.pem -days 3650 -set_serial 0x1234 \
+///     -subj "/C=US/O=RustCrypto/CN=Certificate Builder Test" \
+///     -addext "keyUsage=critical,keyCertSign" -outform der -out builder-v3.der
+/// ```
+const V3_CERT: &[u8] = include_bytes!("examples/builder-v3.der");
+
+/// Self-signed Ed25519 certificate without extensions, generated using:
+///
+/// ```text
+/// openssl req -new -key key.pem -subj "/CN=Certificate Builder Test V1" -out csr.pem
+/// openssl x509 -req -in csr.pem -key key.pem -days 3650 -set_serial 42 \
+///     -outform der -out builder-v1.der
+/// ```
+const V1_CERT: &[u8] = include_bytes!("examples/builder-v1.der");
+
+/// ECDSA P-256 certification request with a requested `subjectAltName`
+/// extension. See `tests/verify.rs` for how this was generated.
+const P256_CSR: &[u8] = include_bytes!("examples/p256-csr.der");
+
+/// RSA-2048 certification request with four requested extensions.
+const RSA_2048_CSR: &[u8] = include_bytes!("examples/rsa2048-csr.der");
+
+/// Rebuild a self-signed certificate from its parts.
+fn rebuild(cert: &Certificate) -> Certificate {
+    let tbs = &cert.tbs_certificate;
+
+    let tbs_certificate = CertificateBuilder::new(
+        tbs.serial_number.clone(),
+        tbs.validity,
+        tbs.subject.clone(),
+        tbs.subject_public_key_info.clone(),
+    )
+    .extensions(tbs.extensions.clone().unwrap_or_default())
+    .build(cert.signature_algorithm.clone());
+
+    Certificate::new(tbs_certificate, cert.signature.clone())
+}
+
+#[test]
+fn build_self_signed_v3() {
+    let cert = Certificate::from_der(V3_CERT).unwrap();
+    let rebuilt = rebuild(&cert);
+
+    assert_eq!(rebuilt.tbs_certificate.version, Version::V3);
+    assert_eq!(rebuilt.tbs_certificate.issuer, cert.tbs_certificate.subject);
+    assert_eq!(rebuilt.to_vec().unwrap(), V3_CERT);
+}
+
+#[test]
+fn build_self_signed_v1() {
+    let cert = Certificate::from_der(V1_CERT).unwrap();
+    let rebuilt = rebuild(&cert);
+
+    assert_eq!(rebuilt.tbs_certificate.version, Version::V1);
+    assert_eq!(rebuilt.tbs_certificate.extensions, None);
+    assert_eq!(rebuilt.to_vec().unwrap(), V1_CERT);
+}
+
+#[test]
+fn build_with_issuer() {
+    let cert = Certificate::from_der(V1_CERT).unwrap();
+    let tbs = &cert.tbs_certificate;
+    let issuer = Name::from_der(&Name::encode_from_string("CN=Issuer").unwrap()).unwrap();
+
+    let tbs_certificate = CertificateBuilder::new(
+        tbs.serial_number.clone(),
+        tbs.validity,
+        tbs.subject.clone(),
+        tbs.subject_public_key_info.clone(),
+    )
+    .issuer(issuer.clone())
+    .build(cert.signature_algorithm.clone());
+
+    assert_eq!(tbs_certificate.issuer, issuer);
+    assert_eq!(tbs_certificate.subject, tbs.subject);
+    assert_eq!(tbs_certificate.signature, cert.signature_algorithm);
+}
+
+/// Rebuild a certification request from its parts.
+fn rebuild_req(req: &CertReq) -> CertReq {
+    let mut builder = CertReqBuilder::new(req.info.subject.clone(), req.info.public_key.clone());
+
+    if let Some(attribute) = req.info.attributes.get(0) {
+        let extensions: Extensions = attribute.values.get(0).unwrap().decode_as().unwrap();
+        builder = builder.extensions(extensions);
+    }
+
+    CertReq::new(
+        builder.build().unwrap(),
+        req.algorithm.clone(),
+        req.signature.clone(),
+    )
+}
+
+#[test]
+fn build_cert_req() {
+    for der in [P256_CSR, RSA_2048_CSR] {
+        let req = CertReq::from_der(der).unwrap();
+        let rebuilt = rebuild_req(&req);
+
+        assert_eq!(rebuilt, req);
+        assert_eq!(rebuilt.to_vec().unwrap(), der);
+    }
+}
+
+#[test]
+fn build_cert_req_without_extensions() {
+    let req = CertReq::from_der(P256_CSR).unwrap();
+    let info = CertReqBuilder::new(req.info.subject.clone(), req.info.public_key.clone())
+        .build()
+        .unwrap();
+
+    assert!(info.attributes.is_empty());
+    assert_eq!(info.subject, req.info.subject);
+    assert_eq!(info.public_key, req.info.public_key);
+}
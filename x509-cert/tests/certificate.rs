@@ -1,7 +1,10 @@
 //! Certificate tests
 
 use der::{
-    asn1::{BitStringRef, ContextSpecific, ObjectIdentifier, PrintableStringRef, Utf8StringRef},
+    asn1::{
+        BitStringRef, ContextSpecific, ObjectIdentifier, PrintableStringRef, TeletexStringRef,
+        Utf8StringRef,
+    },
     Decode, DecodeValue, Encode, FixedTag, Header, Reader, Tag, Tagged,
 };
 use hex_literal::hex;
@@ -11,7 +14,7 @@ use x509_cert::Certificate;
 use x509_cert::*;
 
 #[cfg(feature = "pem")]
-use der::DecodePem;
+use der::{pem::LineEnding, DecodePem, EncodePem};
 
 // TODO - parse and compare extension values
 const EXTENSIONS: &[(&str, bool)] = &[
@@ -412,3 +415,205 @@ fn decode_cert_negative_serial_number() {
     let reencoded = cert.to_vec().unwrap();
     assert_eq!(der_encoded_cert, reencoded.as_slice());
 }
+
+#[test]
+fn decode_cert_teletex_subject() {
+    // Subject uses TeletexString with Latin-1 encoded umlauts
+    let der_encoded_cert = include_bytes!("examples/teletex-subject.der");
+
+    let cert = Certificate::from_der(der_encoded_cert).unwrap();
+    let subject = &cert.tbs_certificate.subject;
+    assert_eq!(subject.0.len(), 3);
+
+    let cn = subject.0[2].0.get(0).unwrap();
+    assert_eq!(cn.value.tag(), Tag::TeletexString);
+    let cn = TeletexStringRef::try_from(&cn.value).unwrap();
+    assert_eq!(cn.as_bytes(), b"J\xfcrgen M\xfcller");
+    assert_eq!(cn.to_string(), "Jürgen Müller");
+
+    assert_eq!(subject.to_string(), "CN=Jürgen Müller,O=Bücher GmbH,C=DE");
+
+    let reencoded = cert.to_vec().unwrap();
+    assert_eq!(der_encoded_cert, reencoded.as_slice());
+}
+
+#[test]
+#[cfg(feature = "sha2")]
+fn certificate_fingerprint_sha256() {
+    use x509_cert::certificate::FingerprintAlgorithm;
+
+    // openssl x509 -inform der -in amazon.der -noout -fingerprint -sha256
+    let cert = Certificate::from_der(include_bytes!("examples/amazon.der")).unwrap();
+    assert_eq!(
+        cert.fingerprint(FingerprintAlgorithm::Sha256).unwrap(),
+        hex!("5BF3D7E0E6927F773D5106C822C53F6F52C199F7EB1B3B8154B41F2924391C75")
+    );
+    assert_eq!(
+        cert.fingerprint_hex(FingerprintAlgorithm::Sha256).unwrap(),
+        "5B:F3:D7:E0:E6:92:7F:77:3D:51:06:C8:22:C5:3F:6F:52:C1:99:F7:EB:1B:3B:81:54:B4:1F:29:24:39:1C:75"
+    );
+}
+
+#[test]
+#[cfg(feature = "sha1")]
+fn certificate_fingerprint_sha1() {
+    use x509_cert::certificate::FingerprintAlgorithm;
+
+    // openssl x509 -inform der -in amazon.der -noout -fingerprint -sha1
+    let cert = Certificate::from_der(include_bytes!("examples/amazon.der")).unwrap();
+    assert_eq!(
+        cert.fingerprint(FingerprintAlgorithm::Sha1).unwrap(),
+        hex!("08040755C8B6852A5DB945A2B380571111DEFD2D")
+    );
+    assert_eq!(
+        cert.fingerprint_hex(FingerprintAlgorithm::Sha1).unwrap(),
+        "08:04:07:55:C8:B6:85:2A:5D:B9:45:A2:B3:80:57:11:11:DE:FD:2D"
+    );
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn certificate_pem() {
+    let cert = Certificate::from_der(include_bytes!("examples/amazon.der")).unwrap();
+    let pem = cert.to_pem(LineEnding::LF).unwrap();
+    assert!(pem.starts_with("-----BEGIN CERTIFICATE-----\n"));
+    assert!(pem.ends_with("-----END CERTIFICATE-----\n"));
+    assert_eq!(Certificate::from_pem(&pem).unwrap(), cert);
+
+    // Text before the pre-encapsulation boundary is ignored
+    let with_preamble = format!("subject=CN = *.peg.a2z.com\n{}", pem);
+    assert_eq!(Certificate::from_pem(with_preamble).unwrap(), cert);
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn certificate_pem_multi() {
+    let leaf = Certificate::from_der(include_bytes!("examples/amazon.der")).unwrap();
+    let ca = Certificate::from_der(include_bytes!("examples/eca.der")).unwrap();
+
+    let bundle = format!(
+        "# leaf\n{}\n# issuer\r\n{}trailing text\n",
+        leaf.to_pem(LineEnding::LF).unwrap(),
+        ca.to_pem(LineEnding::CRLF).unwrap()
+    );
+    assert_eq!(
+        Certificate::from_pem_multi(&bundle).unwrap(),
+        vec![leaf.clone(), ca]
+    );
+
+    assert!(Certificate::from_pem_multi("").unwrap().is_empty());
+
+    // A truncated trailing certificate is an error
+    let pem = leaf.to_pem(LineEnding::LF).unwrap();
+    let truncated = format!("{}{}", pem, &pem[..100]);
+    assert!(Certificate::from_pem_multi(truncated).is_err());
+}
+
+#[test]
+fn unhandled_critical_extensions() {
+    // Self-signed certificate with an unknown critical extension, generated using:
+    //
+    // openssl req -x509 -new -key key.pem -sha256 -days 3650 -subj "/CN=Critical Extension Test" \
+    //     -addext "basicConstraints=critical,CA:FALSE" \
+    //     -addext "keyUsage=critical,digitalSignature" \
+    //     -addext "1.3.6.1.4.1.55555.1=critical,DER:0500" \
+    //     -addext "1.3.6.1.4.1.55555.2=DER:0500" -outform der -out critical-ext.der
+    let cert = Certificate::from_der(include_bytes!("examples/critical-ext.der")).unwrap();
+    let tbs = &cert.tbs_certificate;
+
+    let unknown_critical = ObjectIdentifier::new_unwrap("1.3.6.1.4.1.55555.1");
+    let unknown_non_critical = ObjectIdentifier::new_unwrap("1.3.6.1.4.1.55555.2");
+    let basic_constraints = ObjectIdentifier::new_unwrap("2.5.29.19");
+    let key_usage = ObjectIdentifier::new_unwrap("2.5.29.15");
+
+    assert_eq!(
+        tbs.unhandled_critical_extensions(&[basic_constraints, key_usage]),
+        vec![unknown_critical]
+    );
+    assert_eq!(
+        tbs.unhandled_critical_extensions(&[]),
+        vec![basic_constraints, key_usage, unknown_critical]
+    );
+    assert!(tbs
+        .unhandled_critical_extensions(&[basic_constraints, key_usage, unknown_critical])
+        .is_empty());
+    assert!(!tbs
+        .unhandled_critical_extensions(&[])
+        .contains(&unknown_non_critical));
+
+    // Certificates without extensions have nothing unhandled
+    let v1 = Certificate::from_der(include_bytes!("examples/builder-v1.der")).unwrap();
+    assert!(v1
+        .tbs_certificate
+        .unhandled_critical_extensions(&[])
+        .is_empty());
+}
+
+#[test]
+fn duplicate_extensions() {
+    // Certificate with two `subjectAltName` extensions, issued by the CA in
+    // `crl-v1.der`. OpenSSL refuses to add an extension twice, so this was
+    // generated with an `issuerAltName` extension whose OID was then patched
+    // to `subjectAltName` before re-signing the `TBSCertificate`:
+    //
+    // openssl x509 -req -in csr.pem -CA ca.pem -CAkey ca.key -days 3650 -set_serial 0x5A \
+    //     -extfile <(printf "subjectAltName=DNS:example.com\nissuerAltName=DNS:attacker.example") \
+    //     -sha256 -outform der -out dup-san.der
+    let cert = Certificate::from_der(include_bytes!("examples/dup-san.der")).unwrap();
+    let tbs = &cert.tbs_certificate;
+
+    let subject_alt_name = ObjectIdentifier::new_unwrap("2.5.29.17");
+    let subject_key_id = ObjectIdentifier::new_unwrap("2.5.29.14");
+    let issuer_alt_name = ObjectIdentifier::new_unwrap("2.5.29.18");
+
+    assert_eq!(
+        tbs.check_unique_extensions(),
+        Err(Error::ExtensionDuplicated(subject_alt_name))
+    );
+    assert_eq!(
+        tbs.get_unique(subject_alt_name),
+        Err(Error::ExtensionDuplicated(subject_alt_name))
+    );
+    assert!(tbs.get::<ext::pkix::SubjectAltName>().is_err());
+
+    // Other extensions can still be looked up
+    let ski = tbs.get_unique(subject_key_id).unwrap().unwrap();
+    assert_eq!(ski.extn_id, subject_key_id);
+    assert_eq!(tbs.get_unique(issuer_alt_name), Ok(None));
+
+    // Certificates without duplicates pass
+    let cert = Certificate::from_der(include_bytes!("examples/critical-ext.der")).unwrap();
+    assert_eq!(cert.tbs_certificate.check_unique_extensions(), Ok(()));
+
+    let v1 = Certificate::from_der(include_bytes!("examples/builder-v1.der")).unwrap();
+    assert_eq!(v1.tbs_certificate.check_unique_extensions(), Ok(()));
+    assert_eq!(v1.tbs_certificate.get_unique(subject_alt_name), Ok(None));
+}
+
+#[test]
+fn decode_ber_lenient_printable_string() {
+    // Issued by noncompliant CAs, with characters which aren't permitted in a
+    // `PrintableString` in the issuer's organizationName
+    let mut der = Vec::from(&include_bytes!("examples/amazon.der")[..]);
+    let pos = der.windows(12).position(|w| w == b"DigiCert Inc").unwrap();
+    der[pos..pos + 12].copy_from_slice(b"Dig@Cert_I&c");
+
+    let cert = Certificate::from_ber(&der).unwrap();
+    let organization = cert
+        .tbs_certificate
+        .issuer
+        .0
+        .iter()
+        .flat_map(|rdn| rdn.0.iter())
+        .find(|atv| atv.oid == ObjectIdentifier::new_unwrap("2.5.4.10"))
+        .unwrap();
+    let value = organization.value.to_vec().unwrap();
+
+    assert_eq!(
+        ext::pkix::name::DirectoryString::from_ber(&value).unwrap(),
+        ext::pkix::name::DirectoryString::PrintableString(
+            der::asn1::PrintableString::new_lenient("Dig@Cert_I&c").unwrap()
+        )
+    );
+    assert!(ext::pkix::name::DirectoryString::from_der(&value).is_err());
+}
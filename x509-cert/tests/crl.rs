@@ -1,5 +1,17 @@
-use der::Decode;
-use x509_cert::crl::CertificateList;
+use der::{DateTime, Decode, Encode};
+use x509_cert::{crl::CertificateList, ext::pkix::CrlReason, serial_number::SerialNumber, Version};
+
+/// Version 1 CRL with a single revoked certificate and no extensions,
+/// generated using:
+///
+/// ```text
+/// openssl req -x509 -newkey ec -pkeyopt ec_paramgen_curve:P-256 -nodes \
+///     -keyout ca.key -out ca.pem -subj "/C=US/O=RustCrypto/CN=CRL Test CA"
+/// printf 'R\t300101000000Z\t230101000000Z\t1234\tunknown\t/CN=Revoked\n' > index.txt
+/// openssl ca -config ca.cnf -gencrl -keyfile ca.key -cert ca.pem -crldays 30 \
+///     | openssl crl -outform der -out crl-v1.der
+/// ```
+const CRL_V1: &[u8] = include_bytes!("examples/crl-v1.der");
 
 #[test]
 fn decode_crl() {
@@ -15,3 +27,59 @@ fn decode_crl() {
     assert_eq!(2, crl.tbs_cert_list.crl_extensions.unwrap().len());
     assert_eq!(4, crl.tbs_cert_list.revoked_certificates.unwrap().len());
 }
+
+#[test]
+fn crl_revoked_serials() {
+    let crl = CertificateList::from_der(include_bytes!("examples/GoodCACRL.crl")).unwrap();
+    assert_eq!(crl.tbs_cert_list.version, Version::V2);
+    assert_eq!(
+        crl.tbs_cert_list.issuer.to_string(),
+        "CN=Good CA,O=Test Certificates 2011,C=US"
+    );
+
+    let serials: Vec<_> = crl.revoked_serials().map(|sn| sn.as_bytes()).collect();
+    assert_eq!(serials, [&[0x0E], &[0x0F]]);
+}
+
+#[test]
+fn crl_is_revoked() {
+    let crl = CertificateList::from_der(include_bytes!("examples/GoodCACRL.crl")).unwrap();
+
+    let revoked = crl
+        .is_revoked(&SerialNumber::new(&[0x0F]).unwrap())
+        .unwrap();
+    assert_eq!(revoked.serial_number.as_bytes(), [0x0F]);
+    assert_eq!(
+        revoked.revocation_date.to_date_time(),
+        DateTime::new(2010, 1, 1, 8, 30, 1).unwrap()
+    );
+    assert_eq!(revoked.reason(), Ok(Some(CrlReason::KeyCompromise)));
+
+    assert_eq!(crl.is_revoked(&SerialNumber::new(&[0x01]).unwrap()), None);
+
+    // Entries without extensions have no reason code
+    let crl = CertificateList::from_der(CRL_V1).unwrap();
+    let revoked = crl
+        .is_revoked(&SerialNumber::new(&[0x12, 0x34]).unwrap())
+        .unwrap();
+    assert_eq!(revoked.reason(), Ok(None));
+}
+
+#[test]
+fn decode_crl_v1() {
+    let crl = CertificateList::from_der(CRL_V1).unwrap();
+    let tbs = &crl.tbs_cert_list;
+
+    assert_eq!(tbs.version, Version::V1);
+    assert_eq!(tbs.signature, crl.signature_algorithm);
+    assert!(tbs.next_update.is_some());
+    assert_eq!(tbs.crl_extensions, None);
+
+    let revoked = tbs.revoked_certificates.as_ref().unwrap();
+    assert_eq!(revoked.len(), 1);
+    assert_eq!(revoked[0].serial_number.as_bytes(), [0x12, 0x34]);
+    assert_eq!(revoked[0].crl_entry_extensions, None);
+
+    // The default version is omitted when re-encoding
+    assert_eq!(crl.to_vec().unwrap(), CRL_V1);
+}
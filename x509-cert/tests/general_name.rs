@@ -10,25 +10,27 @@ const DNS_NAME: &[u8] =
     &hex!("8222756E7465726E65686D656E736E616368666F6C67652D696E2D62617965726E2E6465");
 const DIRECTORY_NAME: &[u8] =
     &hex!("A43B3039310B3009060355040613024445310F300D06035504080C0642617965726E31193017060355040A0C104672656973746161742042617965726E");
-// TODO: EdiPartyName
+const X400_ADDRESS: &[u8] = &hex!("A3083006610413025553");
+const EDI_PARTY_NAME: &[u8] = &hex!("A50EA0050C03626172A1050C03666F6F");
 const URI: &[u8] = &hex!(
     "862A687474703A2F2F63726C2E71756F7661646973676C6F62616C2E636F6D2F71767263613267332E63726C"
 );
 const IPADDR: &[u8] = &hex!("87202A02102C000000000000000000000000FFFFFFFF000000000000000000000000");
-// TODO: RegisteredId
+const REGISTERED_ID: &[u8] = &hex!("88032A0304");
 
 const OTHER_NAMES: &[u8] = &hex!("301da01b060560865e0202a0120c105249462d472d32303030343033362d30");
 const RFC822_NAMES: &[u8] = &hex!("30198117456D61696C5F353238343037373733406468732E676F76");
 const DNS_NAMES: &[u8] =
     &hex!("30248222756E7465726E65686D656E736E616368666F6C67652D696E2D62617965726E2E6465");
 const DIRECTORY_NAMES: &[u8] = &hex!("303DA43B3039310B3009060355040613024445310F300D06035504080C0642617965726E31193017060355040A0C104672656973746161742042617965726E");
-// TODO: EdiPartyName
+const X400_ADDRESSES: &[u8] = &hex!("300AA3083006610413025553");
+const EDI_PARTY_NAMES: &[u8] = &hex!("3010A50EA0050C03626172A1050C03666F6F");
 const URIS: &[u8] = &hex!(
     "302C862A687474703A2F2F63726C2E71756F7661646973676C6F62616C2E636F6D2F71767263613267332E63726C"
 );
 const IPADDRS: &[u8] =
     &hex!("302287202A02102C000000000000000000000000FFFFFFFF000000000000000000000000");
-// TODO: RegisteredId
+const REGISTERED_IDS: &[u8] = &hex!("300588032A0304");
 
 #[rstest]
 #[case(1, OTHER_NAME)]
@@ -37,6 +39,9 @@ const IPADDRS: &[u8] =
 #[case(4, DIRECTORY_NAME)]
 #[case(5, URI)]
 #[case(6, IPADDR)]
+#[case(7, X400_ADDRESS)]
+#[case(8, EDI_PARTY_NAME)]
+#[case(9, REGISTERED_ID)]
 fn singular(#[case] idx: usize, #[case] value: &[u8]) {
     let decoded = GeneralName::from_der(value).unwrap();
 
@@ -47,6 +52,9 @@ fn singular(#[case] idx: usize, #[case] value: &[u8]) {
         (4, GeneralName::DirectoryName(..)) => (),
         (5, GeneralName::UniformResourceIdentifier(..)) => (),
         (6, GeneralName::IpAddress(..)) => (),
+        (7, GeneralName::X400Address(..)) => (),
+        (8, GeneralName::EdiPartyName(..)) => (),
+        (9, GeneralName::RegisteredId(..)) => (),
         _ => panic!("unexpected decoded value"),
     }
 
@@ -61,6 +69,9 @@ fn singular(#[case] idx: usize, #[case] value: &[u8]) {
 #[case(4, DIRECTORY_NAMES)]
 #[case(5, URIS)]
 #[case(6, IPADDRS)]
+#[case(7, X400_ADDRESSES)]
+#[case(8, EDI_PARTY_NAMES)]
+#[case(9, REGISTERED_IDS)]
 fn plural(#[case] idx: usize, #[case] value: &[u8]) {
     let decoded = GeneralNames::from_der(value).unwrap();
 
@@ -72,6 +83,9 @@ fn plural(#[case] idx: usize, #[case] value: &[u8]) {
         (4, GeneralName::DirectoryName(..)) => (),
         (5, GeneralName::UniformResourceIdentifier(..)) => (),
         (6, GeneralName::IpAddress(..)) => (),
+        (7, GeneralName::X400Address(..)) => (),
+        (8, GeneralName::EdiPartyName(..)) => (),
+        (9, GeneralName::RegisteredId(..)) => (),
         _ => panic!("unexpected decoded value"),
     }
 
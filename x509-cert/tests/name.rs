@@ -5,7 +5,7 @@ use der::asn1::{Ia5StringRef, OctetStringRef, PrintableStringRef, SetOfVec, Utf8
 use der::{Any, Decode, Encode, Tag, Tagged};
 use hex_literal::hex;
 use x509_cert::attr::AttributeTypeAndValue;
-use x509_cert::name::{Name, RdnSequence, RelativeDistinguishedName};
+use x509_cert::name::{Name, NameBuilder, RdnSequence, RelativeDistinguishedName};
 
 #[test]
 fn decode_name() {
@@ -135,11 +135,10 @@ fn decode_rdn() {
     // fails when caller adds items not in DER lexicographical order
     assert!(from_scratch2.0.add(atav1a.clone()).is_err());
 
-    // allow out-of-order RDNs (see: RustCrypto/formats#625)
-    assert!(RelativeDistinguishedName::from_der(
-        &hex!("311F301106035504030C0A4A4F484E20534D495448300A060355040A0C03313233")[..],
-    )
-    .is_ok());
+    // reject out-of-order RDNs in DER, but allow them in BER (see: RustCrypto/formats#625)
+    let out_of_order = hex!("311F301106035504030C0A4A4F484E20534D495448300A060355040A0C03313233");
+    assert!(RelativeDistinguishedName::from_der(&out_of_order[..]).is_err());
+    assert!(RelativeDistinguishedName::from_ber(&out_of_order[..]).is_ok());
 }
 
 // #[test]
@@ -314,8 +313,9 @@ fn rdns_serde() {
     ];
 
     for (inputs, output, rdns) in values {
+        // RDNs are listed in string order, which is the reverse of the RDNSequence
         let mut brdns = RdnSequence::default();
-        for rdn in rdns.iter() {
+        for rdn in rdns.iter().rev() {
             let sofv = SetOfVec::try_from(rdn.to_vec()).unwrap();
             brdns.0.push(RelativeDistinguishedName::from(sofv));
         }
@@ -343,3 +343,76 @@ fn rdns_serde() {
         }
     }
 }
+
+#[test]
+fn name_builder() {
+    // Generated using:
+    // openssl req -new -subj "/C=US/ST=California/L=San Francisco/O=Example Inc/OU=Engineering/OU=Security/CN=example.com"
+    let expected = hex!(
+        "30818F"
+        "310B3009060355040613025553"
+        "3113301106035504080C0A43616C69666F726E6961"
+        "3116301406035504070C0D53616E204672616E636973636F"
+        "31143012060355040A0C0B4578616D706C6520496E63"
+        "31143012060355040B0C0B456E67696E656572696E67"
+        "3111300F060355040B0C085365637572697479"
+        "3114301206035504030C0B6578616D706C652E636F6D"
+    );
+
+    // Attributes are sorted by significance regardless of insertion order
+    let name = NameBuilder::new()
+        .common_name("example.com")
+        .organization("Example Inc")
+        .organizational_unit("Engineering")
+        .organizational_unit("Security")
+        .locality("San Francisco")
+        .state_or_province("California")
+        .country("US")
+        .build()
+        .unwrap();
+
+    assert_eq!(name.to_vec().unwrap(), expected);
+    assert_eq!(name, Name::from_der(&expected).unwrap());
+
+    // Country names must be a valid `PrintableString`
+    assert!(NameBuilder::new()
+        .common_name("example.com")
+        .country("U*")
+        .build()
+        .is_err());
+}
+
+#[test]
+fn name_display() {
+    let name = NameBuilder::new()
+        .common_name("example.com")
+        .organization("Example Inc")
+        .country("US")
+        .build()
+        .unwrap();
+    assert_eq!(name.to_string(), "CN=example.com,O=Example Inc,C=US");
+    assert_eq!(
+        "CN=example.com,O=Example Inc,C=US".parse::<Name>().unwrap(),
+        name
+    );
+
+    // Special characters, and leading and trailing spaces, are escaped
+    let name = NameBuilder::new()
+        .country("US")
+        .organization("Example, Inc.")
+        .organizational_unit(" R+D ")
+        .common_name("#1 <example>")
+        .build()
+        .unwrap();
+    let s = name.to_string();
+    assert_eq!(s, r"CN=\#1 \<example\>,OU=\ R\+D\ ,O=Example\, Inc.,C=US");
+    assert_eq!(s.parse::<Name>().unwrap(), name);
+
+    // Multi-valued RDNs are joined with `+`
+    let name = "CN=example.com+OU=Web,O=Example Inc,C=US"
+        .parse::<Name>()
+        .unwrap();
+    assert_eq!(name.0.len(), 3);
+    assert_eq!(name.0[2].0.len(), 2);
+    assert_eq!(name.to_string(), "OU=Web+CN=example.com,O=Example Inc,C=US");
+}
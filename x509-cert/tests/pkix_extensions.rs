@@ -1218,3 +1218,428 @@ fn decode_idp() {
         err.kind()
     );
 }
+
+#[test]
+fn subject_alt_name_dns_names() {
+    let cert = Certificate::from_der(include_bytes!("examples/amazon.der")).unwrap();
+    let (critical, san) = cert
+        .tbs_certificate
+        .get::<SubjectAltName>()
+        .unwrap()
+        .unwrap();
+    assert!(!critical);
+
+    let dns_names = san.dns_names().collect::<Vec<_>>();
+    assert_eq!(dns_names.len(), 36);
+    assert_eq!(
+        dns_names[..4],
+        [
+            "amazon.co.uk",
+            "uedata.amazon.co.uk",
+            "www.amazon.co.uk",
+            "origin-www.amazon.co.uk"
+        ]
+    );
+    assert_eq!(dns_names[35], "origin2-www.amazon.co.jp");
+    assert_eq!(san.email_addresses().count(), 0);
+    assert_eq!(san.uris().count(), 0);
+}
+
+#[test]
+fn subject_alt_name_builder() {
+    let san = SubjectAltName::default()
+        .with_dns_name("example.com")
+        .unwrap()
+        .with_email_address("admin@example.com")
+        .unwrap()
+        .with_uri("https://example.com/")
+        .unwrap();
+
+    let encoded = san.to_vec().unwrap();
+    assert_eq!(
+        encoded,
+        hex!(
+            "3036"
+            "820B6578616D706C652E636F6D"
+            "811161646D696E406578616D706C652E636F6D"
+            "861468747470733A2F2F6578616D706C652E636F6D2F"
+        )
+    );
+
+    let decoded = SubjectAltName::from_der(&encoded).unwrap();
+    assert_eq!(decoded.dns_names().collect::<Vec<_>>(), ["example.com"]);
+    assert_eq!(
+        decoded.email_addresses().collect::<Vec<_>>(),
+        ["admin@example.com"]
+    );
+    assert_eq!(decoded.uris().collect::<Vec<_>>(), ["https://example.com/"]);
+    assert!(SubjectAltName::default()
+        .with_dns_name("ex\u{e4}mple.com")
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn subject_alt_name_ip_addresses() {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    let v4 = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1));
+    let v6 = IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1));
+    let san = SubjectAltName::default()
+        .with_ip_address(v4)
+        .unwrap()
+        .with_ip_address(v6)
+        .unwrap();
+
+    let encoded = san.to_vec().unwrap();
+    assert_eq!(
+        encoded,
+        hex!("3018 8704C0000201 871020010DB8000000000000000000000001")
+    );
+
+    let decoded = SubjectAltName::from_der(&encoded).unwrap();
+    let ips = decoded
+        .ip_addresses()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(ips, [v4, v6]);
+
+    // iPAddress entries must be 4 or 16 octets long
+    let decoded = SubjectAltName::from_der(&hex!("3007 87050102030405")).unwrap();
+    let err = decoded.ip_addresses().next().unwrap().unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::Length {
+            tag: Tag::ContextSpecific {
+                constructed: false,
+                number: der::TagNumber::N7
+            }
+        }
+    );
+}
+
+#[test]
+fn key_usage_encoding() {
+    // Trailing zero bits are removed as required by DER
+    let cases: &[(KeyUsage, &[u8])] = &[
+        (KeyUsage::default(), &hex!("030100")),
+        (
+            KeyUsage::default().with_usage(KeyUsages::DigitalSignature),
+            &hex!("03020780"),
+        ),
+        (
+            KeyUsage::default().with_usage(KeyUsages::KeyCertSign),
+            &hex!("03020204"),
+        ),
+        (
+            KeyUsage::default()
+                .with_usage(KeyUsages::KeyCertSign)
+                .with_usage(KeyUsages::CRLSign),
+            &hex!("03020106"),
+        ),
+        (
+            KeyUsage::default().with_usage(KeyUsages::DecipherOnly),
+            &hex!("0303070080"),
+        ),
+    ];
+
+    for (key_usage, der) in cases {
+        assert_eq!(&key_usage.to_vec().unwrap(), der);
+        assert_eq!(&KeyUsage::from_der(der).unwrap(), key_usage);
+    }
+}
+
+#[test]
+fn key_usage_accessors() {
+    let ku = KeyUsage::from_der(&hex!("03020204")).unwrap();
+    assert!(ku.key_cert_sign());
+    assert!(!ku.digital_signature());
+    assert!(!ku.crl_sign());
+
+    let ku = KeyUsage::from_der(&hex!("030307A080")).unwrap();
+    assert!(ku.digital_signature());
+    assert!(ku.key_encipherment());
+    assert!(ku.decipher_only());
+    assert!(!ku.non_repudiation());
+    assert!(!ku.data_encipherment());
+    assert!(!ku.key_agreement());
+    assert!(!ku.key_cert_sign());
+    assert!(!ku.crl_sign());
+    assert!(!ku.encipher_only());
+}
+
+#[test]
+fn basic_constraints_encoding() {
+    let cases: &[(BasicConstraints, &[u8])] = &[
+        // CA without a path length constraint
+        (
+            BasicConstraints {
+                ca: true,
+                path_len_constraint: None,
+            },
+            &hex!("30030101FF"),
+        ),
+        // CA with a path length constraint of 0
+        (
+            BasicConstraints {
+                ca: true,
+                path_len_constraint: Some(0),
+            },
+            &hex!("30060101FF020100"),
+        ),
+        // End-entity: `cA` is omitted as it's the DEFAULT value
+        (
+            BasicConstraints {
+                ca: false,
+                path_len_constraint: None,
+            },
+            &hex!("3000"),
+        ),
+    ];
+
+    for (bc, der) in cases {
+        assert_eq!(&bc.to_vec().unwrap(), der);
+        assert_eq!(&BasicConstraints::from_der(der).unwrap(), bc);
+    }
+}
+
+#[test]
+fn extended_key_usage() {
+    let eku = ExtendedKeyUsage::default()
+        .with_purpose(ExtendedKeyUsage::SERVER_AUTH)
+        .with_purpose(ExtendedKeyUsage::CLIENT_AUTH);
+
+    let der = eku.to_vec().unwrap();
+    assert_eq!(der, hex!("3014 06082B06010505070301 06082B06010505070302"));
+
+    let decoded = ExtendedKeyUsage::from_der(&der).unwrap();
+    assert_eq!(decoded, eku);
+    assert!(decoded.contains(&ExtendedKeyUsage::SERVER_AUTH));
+    assert!(decoded.contains(&ExtendedKeyUsage::CLIENT_AUTH));
+    assert!(!decoded.contains(&ExtendedKeyUsage::CODE_SIGNING));
+    assert!(!decoded.contains(&ExtendedKeyUsage::EMAIL_PROTECTION));
+    assert!(!decoded.contains(&ExtendedKeyUsage::TIME_STAMPING));
+    assert!(!decoded.contains(&ExtendedKeyUsage::ANY));
+
+    // Web server certificate
+    let cert = Certificate::from_der(include_bytes!("examples/amazon.der")).unwrap();
+    let (_, eku) = cert
+        .tbs_certificate
+        .get::<ExtendedKeyUsage>()
+        .unwrap()
+        .unwrap();
+    assert!(eku.contains(&ExtendedKeyUsage::SERVER_AUTH));
+    assert!(!eku.contains(&ExtendedKeyUsage::CODE_SIGNING));
+}
+
+#[test]
+#[cfg(feature = "sha1")]
+fn subject_key_identifier_from_spki() {
+    use spki::SubjectPublicKeyInfoRef;
+
+    let cert = Certificate::from_der(include_bytes!("examples/amazon.der")).unwrap();
+    let spki = SubjectPublicKeyInfoRef::from(&cert.tbs_certificate.subject_public_key_info);
+    let ski = SubjectKeyIdentifier::try_from(spki).unwrap();
+
+    assert_eq!(
+        ski.as_ref().as_bytes(),
+        hex!("9545143E3A401E9516F082AC457382586D9BF074")
+    );
+
+    let (critical, expected) = cert
+        .tbs_certificate
+        .get::<SubjectKeyIdentifier>()
+        .unwrap()
+        .unwrap();
+    assert!(!critical);
+    assert_eq!(ski, expected);
+}
+
+#[test]
+fn authority_key_identifier() {
+    // Intermediate CA certificate issued by DoD Root CA 3
+    let cert = Certificate::from_der(include_bytes!(
+        "examples/2049a5b28f104b2c6e1a08546f9cfc0353d6fd30.der"
+    ))
+    .unwrap();
+    let (critical, akid) = cert
+        .tbs_certificate
+        .get::<AuthorityKeyIdentifier>()
+        .unwrap()
+        .unwrap();
+    assert!(!critical);
+    assert_eq!(
+        akid.key_identifier.as_ref().unwrap().as_bytes(),
+        hex!("6C8A94A277B180721D817A16AAF2DCCE66EE45C0")
+    );
+    assert_eq!(akid.authority_cert_issuer, None);
+    assert_eq!(akid.authority_cert_serial_number, None);
+
+    let built = AuthorityKeyIdentifier::default().with_key_identifier(
+        OctetString::new(hex!("6C8A94A277B180721D817A16AAF2DCCE66EE45C0")).unwrap(),
+    );
+    assert_eq!(built, akid);
+    assert_eq!(
+        built.to_vec().unwrap(),
+        hex!("3016 8014 6C8A94A277B180721D817A16AAF2DCCE66EE45C0")
+    );
+
+    // Self-signed certificate using all three fields
+    let cert = Certificate::from_der(include_bytes!(
+        "examples/342cd9d3062da48c346965297f081ebc2ef68fdc.der"
+    ))
+    .unwrap();
+    let (_, akid) = cert
+        .tbs_certificate
+        .get::<AuthorityKeyIdentifier>()
+        .unwrap()
+        .unwrap();
+    let (_, skid) = cert
+        .tbs_certificate
+        .get::<SubjectKeyIdentifier>()
+        .unwrap()
+        .unwrap();
+    assert_eq!(akid.key_identifier.as_ref(), Some(skid.as_ref()));
+
+    let built = AuthorityKeyIdentifier::default()
+        .with_key_identifier(skid.0)
+        .with_authority_cert_issuer(vec![GeneralName::DirectoryName(
+            cert.tbs_certificate.issuer.clone(),
+        )])
+        .with_authority_cert_serial_number(SerialNumber::new(&[0]).unwrap());
+    assert_eq!(built, akid);
+}
+
+#[test]
+fn crl_distribution_points_uris() {
+    let cert = Certificate::from_der(include_bytes!("examples/amazon.der")).unwrap();
+    let (critical, crl_dps) = cert
+        .tbs_certificate
+        .get::<CrlDistributionPoints>()
+        .unwrap()
+        .unwrap();
+    assert!(!critical);
+    assert_eq!(
+        crl_dps.uris().collect::<Vec<_>>(),
+        [
+            "http://crl3.digicert.com/DigiCertGlobalCAG2.crl",
+            "http://crl4.digicert.com/DigiCertGlobalCAG2.crl"
+        ]
+    );
+
+    // Distribution points with LDAP URIs and a directory name
+    let cert = Certificate::from_der(include_bytes!(
+        "examples/288C8BCFEE6B89D110DAE2C9873897BF7FF53382.fake.der"
+    ))
+    .unwrap();
+    let (_, crl_dps) = cert
+        .tbs_certificate
+        .get::<CrlDistributionPoints>()
+        .unwrap()
+        .unwrap();
+    assert_eq!(crl_dps.0.len(), 2);
+    assert_eq!(crl_dps.0[1].uris().count(), 0);
+
+    let uris = crl_dps.uris().collect::<Vec<_>>();
+    assert_eq!(uris.len(), 2);
+    assert!(uris[0].starts_with("ldap://sspdir.managed.entrust.com.test/"));
+    assert_eq!(
+        uris[1],
+        "http://sspweb.managed.entrust.com.test/CRLs/EMSSSPCA1.crl"
+    );
+}
+
+#[test]
+fn authority_info_access() {
+    let cert = Certificate::from_der(include_bytes!("examples/amazon.der")).unwrap();
+    let (critical, aia) = cert
+        .tbs_certificate
+        .get::<AuthorityInfoAccessSyntax>()
+        .unwrap()
+        .unwrap();
+    assert!(!critical);
+    assert_eq!(aia.ocsp().collect::<Vec<_>>(), ["http://ocsp.digicert.com"]);
+    assert_eq!(
+        aia.ca_issuers().collect::<Vec<_>>(),
+        ["http://cacerts.digicert.com/DigiCertGlobalCAG2.crt"]
+    );
+}
+
+#[test]
+fn name_constraints() {
+    use der::asn1::Ia5String;
+    use x509_cert::ext::pkix::constraints::name::GeneralSubtree;
+
+    let example_com = GeneralName::DnsName(Ia5String::new(".example.com").unwrap());
+
+    let nc =
+        NameConstraints::default().with_permitted_subtree(GeneralSubtree::new(example_com.clone()));
+    assert_eq!(
+        nc.to_vec().unwrap(),
+        hex!("3012 A010 300E 820C 2E6578616D706C652E636F6D")
+    );
+
+    // Generated using OpenSSL with:
+    // nameConstraints=critical,permitted;DNS:.example.com,excluded;dirName:C=US,O=Example
+    let der = hex!(
+        "3039"
+        "A010300E820C2E6578616D706C652E636F6D"
+        "A1253023A421301F310B30090603550406130255533110300E060355040A0C074578616D706C65"
+    );
+    let nc = NameConstraints::from_der(&der).unwrap();
+
+    let permitted = nc.permitted_subtrees.as_ref().unwrap();
+    assert_eq!(permitted, &[GeneralSubtree::new(example_com)]);
+
+    let excluded = nc.excluded_subtrees.as_ref().unwrap();
+    assert_eq!(excluded.len(), 1);
+    assert_eq!(excluded[0].minimum, 0);
+    assert_eq!(excluded[0].maximum, None);
+    match &excluded[0].base {
+        GeneralName::DirectoryName(name) => assert_eq!(name.to_string(), "O=Example,C=US"),
+        other => panic!("unexpected name: {:?}", other),
+    }
+
+    assert_eq!(nc.to_vec().unwrap(), der);
+}
+
+#[test]
+fn certificate_policies() {
+    // Domain-validated web server certificate with a CPS qualifier
+    let cert = Certificate::from_der(include_bytes!("examples/amazon.der")).unwrap();
+    let (critical, policies) = cert
+        .tbs_certificate
+        .get::<CertificatePolicies>()
+        .unwrap()
+        .unwrap();
+    assert!(!critical);
+
+    let domain_validated = const_oid::ObjectIdentifier::new_unwrap("2.23.140.1.2.1");
+    assert!(policies.contains(&domain_validated));
+    assert!(!policies.contains(&CertificatePolicies::ANY_POLICY));
+
+    let qualifiers = policies.0[0].policy_qualifiers.as_ref().unwrap();
+    assert_eq!(qualifiers.len(), 1);
+    assert_eq!(qualifiers[0].policy_qualifier_id, ID_QT_CPS);
+    let cps_uri = qualifiers[0]
+        .qualifier
+        .as_ref()
+        .unwrap()
+        .decode_into::<Ia5StringRef<'_>>()
+        .unwrap();
+    assert_eq!(cps_uri.as_str(), "http://www.digicert.com/CPS");
+
+    // Intermediate CA certificate asserting anyPolicy
+    let cert = Certificate::from_der(include_bytes!(
+        "examples/16ee54e48c76eaa1052e09010d8faefee95e5ebb.der"
+    ))
+    .unwrap();
+    let (_, policies) = cert
+        .tbs_certificate
+        .get::<CertificatePolicies>()
+        .unwrap()
+        .unwrap();
+    assert!(policies.contains(&CertificatePolicies::ANY_POLICY));
+    assert!(policies.contains(&domain_validated));
+    assert_eq!(policies.0[0].policy_qualifiers, None);
+}
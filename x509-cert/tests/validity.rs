@@ -1,8 +1,8 @@
 //! Validity tests
 
-use der::{Decode, Encode};
+use der::{DateTime, Decode, Encode};
 use hex_literal::hex;
-use x509_cert::time::Validity;
+use x509_cert::{time::Validity, Certificate, Error};
 
 #[test]
 fn decode_validity() {
@@ -134,3 +134,52 @@ fn encode_validity() {
         &hex!("3020180F32303032303130313132303130305A170D3330313233313038333030305A")[..]
     );
 }
+
+#[test]
+fn validity_is_valid_at() {
+    // UTCTime 01/01/2010 08:30:00 GMT to GeneralizedTime 01/01/2050 12:01:00 GMT
+    let mixed = Validity::from_der(
+        &hex!("3020170D3130303130313038333030305A180F32303530303130313132303130305A")[..],
+    )
+    .unwrap();
+
+    assert!(!mixed.is_valid_at(DateTime::new(2010, 1, 1, 8, 29, 59).unwrap()));
+    assert!(mixed.is_valid_at(DateTime::new(2010, 1, 1, 8, 30, 0).unwrap()));
+    assert!(mixed.is_valid_at(DateTime::new(2030, 6, 1, 0, 0, 0).unwrap()));
+    assert!(mixed.is_valid_at(DateTime::new(2050, 1, 1, 12, 1, 0).unwrap()));
+    assert!(!mixed.is_valid_at(DateTime::new(2050, 1, 1, 12, 1, 1).unwrap()));
+
+    // GeneralizedTime 01/01/2002 12:01:00 GMT to UTCTime 31/12/2030 08:30:00 GMT
+    let mixed = Validity::from_der(
+        &hex!("3020180F32303032303130313132303130305A170D3330313233313038333030305A")[..],
+    )
+    .unwrap();
+
+    assert!(!mixed.is_valid_at(DateTime::new(2002, 1, 1, 12, 0, 59).unwrap()));
+    assert!(mixed.is_valid_at(DateTime::new(2002, 1, 1, 12, 1, 0).unwrap()));
+    assert!(mixed.is_valid_at(DateTime::new(2030, 12, 31, 8, 30, 0).unwrap()));
+    assert!(!mixed.is_valid_at(DateTime::new(2030, 12, 31, 8, 30, 1).unwrap()));
+}
+
+#[test]
+fn certificate_check_validity() {
+    // GoodCACert.crt is valid from 01/01/2010 08:30:00 GMT to 31/12/2030 08:30:00 GMT
+    let cert = Certificate::from_der(include_bytes!("examples/GoodCACert.crt")).unwrap();
+
+    assert_eq!(
+        cert.check_validity(DateTime::new(2010, 1, 1, 8, 29, 59).unwrap()),
+        Err(Error::CertificateNotYetValid)
+    );
+    assert_eq!(
+        cert.check_validity(DateTime::new(2010, 1, 1, 8, 30, 0).unwrap()),
+        Ok(())
+    );
+    assert_eq!(
+        cert.check_validity(DateTime::new(2030, 12, 31, 8, 30, 0).unwrap()),
+        Ok(())
+    );
+    assert_eq!(
+        cert.check_validity(DateTime::new(2030, 12, 31, 8, 30, 1).unwrap()),
+        Err(Error::CertificateExpired)
+    );
+}
@@ -0,0 +1,237 @@
+//! Certificate and certification request signature verification tests
+
+#![cfg(feature = "verify")]
+
+use der::{asn1::BitString, Decode};
+use p384::ecdsa::signature::{hazmat::PrehashVerifier, Verifier};
+use sha2::{Digest, Sha256};
+use spki::SubjectPublicKeyInfoOwned;
+use x509_cert::{name::Name, request::CertReq, serial_number::SerialNumber, Certificate, Error};
+
+/// Self-signed ECDSA P-384 CA certificate, generated using:
+///
+/// ```text
+/// openssl ecparam -name secp384r1 -genkey -noout -out ca.pem
+/// openssl req -x509 -new -key ca.pem -sha384 -days 3650 -subj "/CN=Verify Test CA" \
+///     -addext "basicConstraints=critical,CA:TRUE" -outform der -out verify-ca-p384.der
+/// ```
+const CA_CERT: &[u8] = include_bytes!("examples/verify-ca-p384.der");
+
+/// ECDSA P-256 certificate issued by [`CA_CERT`] with `ecdsa-with-SHA256`,
+/// generated using:
+///
+/// ```text
+/// openssl ecparam -name prime256v1 -genkey -noout -out leaf.pem
+/// openssl req -new -key leaf.pem -subj "/CN=verify.example.com" -out leaf.csr
+/// openssl x509 -req -in leaf.csr -CA verify-ca-p384.der -CAform DER -CAkey ca.pem \
+///     -sha256 -days 3650 -set_serial 7 -outform der -out verify-leaf-p256.der
+/// ```
+const LEAF_CERT: &[u8] = include_bytes!("examples/verify-leaf-p256.der");
+
+/// ECDSA P-256 certification request with `ecdsa-with-SHA256`, generated using:
+///
+/// ```text
+/// openssl ecparam -name prime256v1 -genkey -noout -out key.pem
+/// openssl req -new -key key.pem -sha256 -subj "/C=US/O=RustCrypto/CN=csr.example.com" \
+///     -addext "subjectAltName=DNS:csr.example.com" -outform der -out p256-csr.der
+/// ```
+const P256_CSR: &[u8] = include_bytes!("examples/p256-csr.der");
+
+fn public_key(cert: &Certificate) -> &[u8] {
+    spki_public_key(&cert.tbs_certificate.subject_public_key_info)
+}
+
+fn spki_public_key(spki: &SubjectPublicKeyInfoOwned) -> &[u8] {
+    spki.subject_public_key.as_bytes().unwrap()
+}
+
+fn p256_key(public_key: &[u8]) -> p256::ecdsa::VerifyingKey {
+    p256::ecdsa::VerifyingKey::from_sec1_bytes(public_key).unwrap()
+}
+
+fn p384_key(public_key: &[u8]) -> p384::ecdsa::VerifyingKey {
+    p384::ecdsa::VerifyingKey::from_sec1_bytes(public_key).unwrap()
+}
+
+fn ed25519_key(public_key: &[u8]) -> ed25519_dalek::VerifyingKey {
+    ed25519_dalek::VerifyingKey::from_bytes(public_key.try_into().unwrap()).unwrap()
+}
+
+/// Parse an RSA public key with the in-tree `pkcs1` crate.
+fn rsa_key(public_key: &[u8]) -> rsa::RsaPublicKey {
+    let key = pkcs1::RsaPublicKey::from_der(public_key).unwrap();
+    rsa::RsaPublicKey::new(
+        rsa::BigUint::from_bytes_be(key.modulus.as_bytes()),
+        rsa::BigUint::from_bytes_be(key.public_exponent.as_bytes()),
+    )
+    .unwrap()
+}
+
+/// ECDSA P-384 verifier for `ecdsa-with-SHA256` signatures, which
+/// `p384::ecdsa::VerifyingKey` would otherwise verify with SHA-384.
+struct P384Sha256(p384::ecdsa::VerifyingKey);
+
+impl Verifier<p384::ecdsa::DerSignature> for P384Sha256 {
+    fn verify(
+        &self,
+        msg: &[u8],
+        signature: &p384::ecdsa::DerSignature,
+    ) -> Result<(), p384::ecdsa::Error> {
+        self.0.verify_prehash(&Sha256::digest(msg), signature)
+    }
+}
+
+#[test]
+fn self_signed_ed25519() {
+    let cert = Certificate::from_der(include_bytes!("examples/builder-v3.der")).unwrap();
+    let key = ed25519_key(public_key(&cert));
+    cert.verify_signature::<_, ed25519_dalek::Signature>(&key)
+        .unwrap();
+}
+
+#[test]
+fn self_signed_ecdsa_p384_sha384() {
+    let cert = Certificate::from_der(CA_CERT).unwrap();
+    let key = p384_key(public_key(&cert));
+    cert.verify_signature::<_, p384::ecdsa::DerSignature>(&key)
+        .unwrap();
+}
+
+#[test]
+fn self_signed_rsa_sha256() {
+    let cert = Certificate::from_der(include_bytes!("examples/eca.der")).unwrap();
+    let key = rsa::pkcs1v15::VerifyingKey::<sha2::Sha256>::new(rsa_key(public_key(&cert)));
+    cert.verify_signature::<_, rsa::pkcs1v15::Signature>(&key)
+        .unwrap();
+}
+
+#[test]
+fn self_signed_rsa_sha1() {
+    let cert = Certificate::from_der(include_bytes!("examples/exostar.der")).unwrap();
+    let key = rsa::pkcs1v15::VerifyingKey::<sha1::Sha1>::new(rsa_key(public_key(&cert)));
+    cert.verify_signature::<_, rsa::pkcs1v15::Signature>(&key)
+        .unwrap();
+}
+
+#[test]
+fn issued_ecdsa_p256_sha256() {
+    let ca = Certificate::from_der(CA_CERT).unwrap();
+    let leaf = Certificate::from_der(LEAF_CERT).unwrap();
+    let ca_key = P384Sha256(p384_key(public_key(&ca)));
+    leaf.verify_signature(&ca_key).unwrap();
+
+    // Not self-signed
+    let leaf_key = p256_key(public_key(&leaf));
+    assert_eq!(
+        leaf.verify_signature::<_, p256::ecdsa::DerSignature>(&leaf_key),
+        Err(Error::SignatureInvalid)
+    );
+}
+
+#[test]
+fn wrong_key_type() {
+    // An RSA signature isn't a valid DER-encoded ECDSA signature
+    let rsa = Certificate::from_der(include_bytes!("examples/eca.der")).unwrap();
+    let leaf = Certificate::from_der(LEAF_CERT).unwrap();
+    let key = p256_key(public_key(&leaf));
+
+    assert_eq!(
+        rsa.verify_signature::<_, p256::ecdsa::DerSignature>(&key),
+        Err(Error::SignatureInvalid)
+    );
+}
+
+#[test]
+fn tampered_tbs_certificate() {
+    let ca = Certificate::from_der(CA_CERT).unwrap();
+    let mut leaf = Certificate::from_der(LEAF_CERT).unwrap();
+    leaf.tbs_certificate.serial_number = SerialNumber::new(&[8]).unwrap();
+
+    assert_eq!(
+        leaf.verify_signature(&P384Sha256(p384_key(public_key(&ca)))),
+        Err(Error::SignatureInvalid)
+    );
+}
+
+#[test]
+fn tampered_signature() {
+    let ca = Certificate::from_der(CA_CERT).unwrap();
+    let mut leaf = Certificate::from_der(LEAF_CERT).unwrap();
+    let mut signature = leaf.signature.raw_bytes().to_vec();
+    *signature.last_mut().unwrap() ^= 1;
+    leaf.signature = BitString::from_bytes(&signature).unwrap();
+
+    assert_eq!(
+        leaf.verify_signature(&P384Sha256(p384_key(public_key(&ca)))),
+        Err(Error::SignatureInvalid)
+    );
+}
+
+#[test]
+fn signature_algorithm_mismatch() {
+    let ca = Certificate::from_der(CA_CERT).unwrap();
+    let mut leaf = Certificate::from_der(LEAF_CERT).unwrap();
+    leaf.signature_algorithm = ca.signature_algorithm.clone();
+
+    assert_eq!(
+        leaf.verify_signature(&P384Sha256(p384_key(public_key(&ca)))),
+        Err(Error::AlgorithmMismatch)
+    );
+}
+
+#[test]
+fn cert_req_p256() {
+    let req = CertReq::from_der(P256_CSR).unwrap();
+    assert_eq!(
+        req.info.subject.to_string(),
+        "CN=csr.example.com,O=RustCrypto,C=US"
+    );
+    assert_eq!(
+        req.info.public_key.algorithm.oid,
+        "1.2.840.10045.2.1".parse().unwrap()
+    );
+
+    let key = p256_key(spki_public_key(&req.info.public_key));
+    req.verify::<_, p256::ecdsa::DerSignature>(&key).unwrap();
+}
+
+#[test]
+fn cert_req_rsa() {
+    let req = CertReq::from_der(include_bytes!("examples/rsa2048-csr.der")).unwrap();
+    let key = rsa::pkcs1v15::VerifyingKey::<sha2::Sha256>::new(rsa_key(spki_public_key(
+        &req.info.public_key,
+    )));
+    req.verify::<_, rsa::pkcs1v15::Signature>(&key).unwrap();
+}
+
+#[test]
+fn cert_req_tampered() {
+    let mut req = CertReq::from_der(P256_CSR).unwrap();
+    let key = p256_key(spki_public_key(&req.info.public_key));
+    req.info.subject = "CN=evil.example.com".parse::<Name>().unwrap();
+    assert_eq!(
+        req.verify::<_, p256::ecdsa::DerSignature>(&key),
+        Err(Error::SignatureInvalid)
+    );
+}
+
+#[test]
+fn self_signed() {
+    let ca = Certificate::from_der(CA_CERT).unwrap();
+    assert!(ca.is_self_issued());
+    assert!(ca.is_self_signed::<_, p384::ecdsa::DerSignature>(&p384_key(public_key(&ca))));
+
+    let leaf = Certificate::from_der(LEAF_CERT).unwrap();
+    assert!(!leaf.is_self_issued());
+    assert!(!leaf.is_self_signed::<_, p256::ecdsa::DerSignature>(&p256_key(public_key(&leaf))));
+}
+
+#[test]
+fn self_issued_not_self_signed() {
+    // Subject and issuer still match, but the signature no longer verifies
+    let mut cert = Certificate::from_der(CA_CERT).unwrap();
+    cert.tbs_certificate.serial_number = SerialNumber::new(&[0x01]).unwrap();
+
+    assert!(cert.is_self_issued());
+    assert!(!cert.is_self_signed::<_, p384::ecdsa::DerSignature>(&p384_key(public_key(&cert))));
+}
@@ -1,4 +1,5 @@
 #![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
 #![warn(
     clippy::mod_module_files,
     clippy::unwrap_used,
@@ -13,9 +14,10 @@
 
 extern crate alloc;
 
+use const_oid::db::{rfc5912::ID_SHA_1, rfc6960::ID_PKIX_OCSP_BASIC};
 use der::asn1::{BitStringRef, Ia5StringRef, ObjectIdentifier, OctetStringRef, UintRef};
 use der::asn1::{GeneralizedTime, Null};
-use der::{AnyRef, Choice, Enumerated, Sequence};
+use der::{AnyRef, Choice, Decode, Enumerated, ErrorKind, Sequence};
 use spki::{AlgorithmIdentifierOwned, AlgorithmIdentifierRef};
 use x509_cert::ext::pkix::name::GeneralName;
 use x509_cert::ext::pkix::{AuthorityInfoAccessSyntax, CrlReason};
@@ -28,6 +30,12 @@ use alloc::vec::Vec;
 use core::default::Default;
 use core::option::Option;
 
+#[cfg(feature = "verify")]
+use {
+    der::Encode,
+    x509_cert::signature::{SignatureEncoding, Verifier},
+};
+
 /// OcspNoCheck as defined in [RFC 6960 Section 4.2.2.2.1].
 ///
 /// This extension is identified by the ID_PKIX_OCSP_NOCHECK OID.
@@ -57,6 +65,25 @@ pub struct OcspRequest<'a> {
     pub optional_signature: Option<Signature<'a>>,
 }
 
+impl<'a> OcspRequest<'a> {
+    /// Create an unsigned v1 request for the status of the single certificate
+    /// identified by `cert_id`.
+    pub fn new(cert_id: CertId<'a>) -> Self {
+        Self {
+            tbs_request: TbsRequest {
+                version: Version::V1,
+                requestor_name: None,
+                request_list: alloc::vec![Request {
+                    req_cert: cert_id,
+                    single_request_extensions: None,
+                }],
+                request_extensions: None,
+            },
+            optional_signature: None,
+        }
+    }
+}
+
 /// TBSRequest structure as defined in [RFC 6960 Section 4.1.1].
 ///
 /// ```text
@@ -166,6 +193,40 @@ pub struct CertId<'a> {
     pub serial_number: SerialNumber,
 }
 
+impl<'a> CertId<'a> {
+    /// SHA-1 `AlgorithmIdentifier` with `NULL` parameters, the default
+    /// `hashAlgorithm` for a [`CertId`].
+    pub const SHA1: AlgorithmIdentifierRef<'static> = AlgorithmIdentifierRef {
+        oid: ID_SHA_1,
+        parameters: Some(AnyRef::NULL),
+    };
+
+    /// Create a [`CertId`] from the SHA-1 hashes of the issuer's DER-encoded
+    /// name and of the value of its public key BIT STRING, along with the
+    /// serial number of the certificate being checked.
+    ///
+    /// Use [`CertId::with_hash_algorithm`] if the hashes were computed with a
+    /// different algorithm.
+    pub fn new(
+        issuer_name_hash: &'a [u8],
+        issuer_key_hash: &'a [u8],
+        serial_number: SerialNumber,
+    ) -> der::Result<Self> {
+        Ok(Self {
+            hash_algorithm: Self::SHA1,
+            issuer_name_hash: OctetStringRef::new(issuer_name_hash)?,
+            issuer_key_hash: OctetStringRef::new(issuer_key_hash)?,
+            serial_number,
+        })
+    }
+
+    /// Set the algorithm used to compute the issuer name and key hashes.
+    pub fn with_hash_algorithm(mut self, hash_algorithm: AlgorithmIdentifierRef<'a>) -> Self {
+        self.hash_algorithm = hash_algorithm;
+        self
+    }
+}
+
 /// OCSPResponse structure as defined in [RFC 6960 Section 4.2.1].
 ///
 /// ```text
@@ -184,6 +245,27 @@ pub struct OcspResponse<'a> {
     pub response_bytes: Option<ResponseBytes<'a>>,
 }
 
+impl<'a> OcspResponse<'a> {
+    /// Decode the [`BasicOcspResponse`] carried in the `responseBytes`.
+    ///
+    /// Returns `Ok(None)` if there are no response bytes, which is the case
+    /// for responses whose status isn't [`OcspResponseStatus::Successful`].
+    /// Returns an error if the response is of a type other than
+    /// `id-pkix-ocsp-basic`.
+    pub fn basic(&self) -> der::Result<Option<BasicOcspResponse<'a>>> {
+        match &self.response_bytes {
+            None => Ok(None),
+            Some(bytes) if bytes.response_type == ID_PKIX_OCSP_BASIC => {
+                BasicOcspResponse::from_der(bytes.response.as_bytes()).map(Some)
+            }
+            Some(bytes) => Err(ErrorKind::OidUnknown {
+                oid: bytes.response_type,
+            }
+            .into()),
+        }
+    }
+}
+
 /// OCSPResponseStatus structure as defined in [RFC 6960 Section 4.2.1].
 ///
 /// ```text
@@ -249,6 +331,36 @@ pub struct BasicOcspResponse<'a> {
     pub certs: Option<Vec<AnyRef<'a>>>,
 }
 
+impl<'a> BasicOcspResponse<'a> {
+    /// Verify the signature on this response using a `verifier` for the
+    /// responder's public key.
+    ///
+    /// This only checks the signature. Callers must separately check that the
+    /// responder is the certificate's issuer or is authorized by it to sign
+    /// OCSP responses, as described in [RFC 6960 Section 4.2.2.2]. It's up to
+    /// the caller to pick a `verifier` which implements the response's
+    /// `signatureAlgorithm`.
+    ///
+    /// [RFC 6960 Section 4.2.2.2]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.2.2.2
+    #[cfg(feature = "verify")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "verify")))]
+    pub fn verify<V, S>(&self, verifier: &V) -> x509_cert::Result<()>
+    where
+        V: Verifier<S>,
+        S: SignatureEncoding,
+    {
+        let signature = self
+            .signature
+            .as_bytes()
+            .and_then(|bytes| S::try_from(bytes).ok())
+            .ok_or(x509_cert::Error::SignatureInvalid)?;
+
+        verifier
+            .verify(&self.tbs_response_data.to_vec()?, &signature)
+            .map_err(|_| x509_cert::Error::SignatureInvalid)
+    }
+}
+
 /// ResponseData structure as defined in [RFC 6960 Section 4.2.1].
 ///
 /// ```text
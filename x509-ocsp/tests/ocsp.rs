@@ -1,11 +1,28 @@
-use const_oid::db::rfc6960::ID_PKIX_OCSP_BASIC;
-use der::asn1::{Null, ObjectIdentifier};
+use const_oid::db::{rfc5912::ID_SHA_256, rfc6960::ID_PKIX_OCSP_BASIC};
+use der::asn1::{AnyRef, Null, ObjectIdentifier};
 use der::{Decode, Encode};
 use hex_literal::hex;
+use spki::AlgorithmIdentifierRef;
 use x509_cert::ext::pkix::CrlReason;
+use x509_cert::serial_number::SerialNumber;
+use x509_cert::Certificate;
 use x509_ocsp::Version::V1;
 use x509_ocsp::*;
 
+/// OCSP response from `openssl ocsp` acting as a responder, covering one
+/// certificate of each status, generated using:
+///
+/// ```text
+/// openssl ocsp -issuer ca.pem -serial 0x99 -serial 0x1234 -serial 0x42 -no_nonce \
+///     -reqout req.der
+/// openssl ocsp -index index.txt -rsigner ca.pem -rkey ca.key -CA ca.pem -reqin req.der \
+///     -respout resp.der -ndays 7 -resp_no_certs
+/// ```
+const OCSP_RESP_STATUSES: &[u8] = &hex!("3082020D0A0100A08202063082020206092B0601050507300101048201F3308201EF30820195A13A3038310B300906035504061302555331133011060355040A0C0A5275737443727970746F3114301206035504030C0B43524C2054657374204341180F32303236313031353032343431345A308201443063303B300906052B0E03021A050004143A36BFA302F036912ACB9A8257EFC465450D03DB041492BB7B4C8997688E4E6682FE7BCB379F7B1D9ECC020200998000180F32303236313031353032343431345AA011180F32303236313032323032343431345A3079303B300906052B0E03021A050004143A36BFA302F036912ACB9A8257EFC465450D03DB041492BB7B4C8997688E4E6682FE7BCB379F7B1D9ECC02021234A116180F32303233303130313030303030305AA0030A0101180F32303236313031353032343431345AA011180F32303236313032323032343431345A3062303A300906052B0E03021A050004143A36BFA302F036912ACB9A8257EFC465450D03DB041492BB7B4C8997688E4E6682FE7BCB379F7B1D9ECC0201428200180F32303236313031353032343431345AA011180F32303236313032323032343431345A300A06082A8648CE3D04030203480030450221009287C673973C756981FE271232EFAA3704F25118BD22D8B417A2B21741B773EA02206EA864CF9148F7CD9D7200ECA6D96A8E94CE72D2120BFBEB01B94EBDDDB0C590");
+
+/// Self-signed ECDSA P-256 CA which signed [`OCSP_RESP_STATUSES`].
+const OCSP_RESPONDER: &[u8] = &hex!("308201C53082016BA0030201020214455AFAF8F2281EA570EA567E234BEB7D45671EB3300A06082A8648CE3D0403023038310B300906035504061302555331133011060355040A0C0A5275737443727970746F3114301206035504030C0B43524C2054657374204341301E170D3236313031353032343130335A170D3336313031323032343130335A3038310B300906035504061302555331133011060355040A0C0A5275737443727970746F3114301206035504030C0B43524C20546573742043413059301306072A8648CE3D020106082A8648CE3D03010703420004F26F3BCEBAE5D3EEEC2D5CE7C7EAADAB578254F6AEF5C49B9D63611F064E55E2CC0750CC07A3199933788A14808B6D9900711D6EB4150DCCE28D5694759FDFF5A3533051301D0603551D0E0416041492BB7B4C8997688E4E6682FE7BCB379F7B1D9ECC301F0603551D2304183016801492BB7B4C8997688E4E6682FE7BCB379F7B1D9ECC300F0603551D130101FF040530030101FF300A06082A8648CE3D04030203480030450220568446A6EA3BA2B5ACEA8DA86D3516C725B5262AA71D4DC444478BD77FF5B86E022100BD09F2C7D9919389FBE6F3131F90761A0C4989D74BC8DD59A081B92052C6A32E");
+
 #[test]
 fn decode_ocsp_req_ca_signed() {
     // request generated using openssl via this command:
@@ -49,6 +66,39 @@ fn decode_ocsp_req_ca_signed() {
     assert_eq!(reenc, &hex!("3051304F304D304B3049300906052B0E03021A05000414A87E303106E4E88565CFE952598FA6DA7C00532F0414246E2B2DD06A925151256901AA9A47A689E7402002100E4239AB85E2E6A27C52C6DE9B9078D9")[..])
 }
 
+#[test]
+fn build_ocsp_req_sha1() {
+    // same request as `decode_ocsp_req_ca_signed`
+    let cert_id = CertId::new(
+        &hex!("A87E303106E4E88565CFE952598FA6DA7C00532F"),
+        &hex!("246E2B2DD06A925151256901AA9A47A689E74020"),
+        SerialNumber::new(&hex!("0E4239AB85E2E6A27C52C6DE9B9078D9")).unwrap(),
+    )
+    .unwrap();
+
+    let ocsp_req = OcspRequest::new(cert_id);
+    assert_eq!(ocsp_req.to_vec().unwrap(), &hex!("3051304F304D304B3049300906052B0E03021A05000414A87E303106E4E88565CFE952598FA6DA7C00532F0414246E2B2DD06A925151256901AA9A47A689E7402002100E4239AB85E2E6A27C52C6DE9B9078D9")[..]);
+}
+
+#[test]
+fn build_ocsp_req_sha256() {
+    // request generated using openssl via this command:
+    // openssl ocsp -sha256 -issuer ca.pem -serial 0x1234 -no_nonce -reqout sha256.der
+    let cert_id = CertId::new(
+        &hex!("79C0BAAE4083517638A65AD1F5C8684F4CF7E188EDFD01CABC3C7054DD68FE75"),
+        &hex!("FFF6837D8111D4BA91BAD50E02DC141C9412D6602DF2C261D3622261400FAC46"),
+        SerialNumber::new(&hex!("1234")).unwrap(),
+    )
+    .unwrap()
+    .with_hash_algorithm(AlgorithmIdentifierRef {
+        oid: ID_SHA_256,
+        parameters: Some(AnyRef::NULL),
+    });
+
+    let ocsp_req = OcspRequest::new(cert_id);
+    assert_eq!(ocsp_req.to_vec().unwrap(), &hex!("305F305D305B30593057300D06096086480165030402010500042079C0BAAE4083517638A65AD1F5C8684F4CF7E188EDFD01CABC3C7054DD68FE750420FFF6837D8111D4BA91BAD50E02DC141C9412D6602DF2C261D3622261400FAC4602021234")[..]);
+}
+
 #[test]
 fn decode_ocsp_resp_ca_signed() {
     // response generated using openssl via this command:
@@ -295,3 +345,85 @@ fn decode_ocsp_resp_delegated() {
     let reenc = ocsp_resp.to_vec().unwrap();
     assert_eq!(reenc, &hex!("30820FA10A0100A0820F9A30820F9606092B060105050730010104820F8730820F83308209F1A2160414ADB0A9B2DDE9D444B4DF80F599598E84AC5EC687180F32303232303230333135303233365A308209C4307B303D300906052B0E03021A050004140F0D5890F551D42ACF5431B7F42A321F7B74A4730414771441A65D9526D01DFF953B628CEAB7B55D3B92020401017456A116180F32303231303532303138303635305AA0030A0104180F32303232303230333030333030305AA011180F32303232303231303031333030305A307B303D300906052B0E03021A050004140F0D5890F551D42ACF5431B7F42A321F7B74A4730414771441A65D9526D01DFF953B628CEAB7B55D3B92020401017457A116180F32303231303730373139353830355AA0030A0104180F32303232303230333030333030305AA011180F32303232303231303031333030305A307B303D300906052B0E03021A050004140F0D5890F551D42ACF5431B7F42A321F7B74A4730414771441A65D9526D01DFF953B628CEAB7B55D3B92020401017459A116180F32303231303532303138303634395AA0030A0104180F32303232303230333030333030305AA011180F32303232303231303031333030305A307B303D300906052B0E03021A050004140F0D5890F551D42ACF5431B7F42A321F7B74A4730414771441A65D9526D01DFF953B628CEAB7B55D3B9202040101745AA116180F32303231303132323136303832375AA0030A0104180F32303232303230333030333030305AA011180F32303232303231303031333030305A307B303D300906052B0E03021A050004140F0D5890F551D42ACF5431B7F42A321F7B74A4730414771441A65D9526D01DFF953B628CEAB7B55D3B9202040101745BA116180F32303231303731393138353734355AA0030A0101180F32303232303230333030333030305AA011180F32303232303231303031333030305A307B303D300906052B0E03021A050004140F0D5890F551D42ACF5431B7F42A321F7B74A4730414771441A65D9526D01DFF953B628CEAB7B55D3B9202040101745CA116180F32303231303132323136303832365AA0030A0104180F32303232303230333030333030305AA011180F32303232303231303031333030305A307B303D300906052B0E03021A050004140F0D5890F551D42ACF5431B7F42A321F7B74A4730414771441A65D9526D01DFF953B628CEAB7B55D3B9202040101745EA116180F32303231303432323132323332335AA0030A0104180F32303232303230333030333030305AA011180F32303232303231303031333030305A307B303D300906052B0E03021A050004140F0D5890F551D42ACF5431B7F42A321F7B74A4730414771441A65D9526D01DFF953B628CEAB7B55D3B92020401017460A116180F32303231303731393138353734335AA0030A0101180F32303232303230333030333030305AA011180F32303232303231303031333030305A307B303D300906052B0E03021A050004140F0D5890F551D42ACF5431B7F42A321F7B74A4730414771441A65D9526D01DFF953B628CEAB7B55D3B92020401017462A116180F32303231303932393136353734375AA0030A0103180F32303232303230333030333030305AA011180F32303232303231303031333030305A307B303D300906052B0E03021A050004140F0D5890F551D42ACF5431B7F42A321F7B74A4730414771441A65D9526D01DFF953B628CEAB7B55D3B92020401017464A116180F32303231303432323132323332325AA0030A0104180F32303232303230333030333030305AA011180F32303232303231303031333030305A307B303D300906052B0E03021A050004140F0D5890F551D42ACF5431B7F42A321F7B74A4730414771441A65D9526D01DFF953B628CEAB7B55D3B92020401017467A116180F32303231303932393136353734375AA0030A0103180F32303232303230333030333030305AA011180F32303232303231303031333030305A307B303D300906052B0E03021A050004140F0D5890F551D42ACF5431B7F42A321F7B74A4730414771441A65D9526D01DFF953B628CEAB7B55D3B92020401017477A116180F32303231303730313138313934365AA0030A0103180F32303232303230333030333030305AA011180F32303232303231303031333030305A307B303D300906052B0E03021A050004140F0D5890F551D42ACF5431B7F42A321F7B74A4730414771441A65D9526D01DFF953B628CEAB7B55D3B92020401017479A116180F32303232303130353138313532325AA0030A0103180F32303232303230333030333030305AA011180F32303232303231303031333030305A307B303D300906052B0E03021A050004140F0D5890F551D42ACF5431B7F42A321F7B74A4730414771441A65D9526D01DFF953B628CEAB7B55D3B9202040101747AA116180F32303231313130343131353932395AA0030A0101180F32303232303230333030333030305AA011180F32303232303231303031333030305A307B303D300906052B0E03021A050004140F0D5890F551D42ACF5431B7F42A321F7B74A4730414771441A65D9526D01DFF953B628CEAB7B55D3B9202040101747BA116180F32303231303730313138313934325AA0030A0103180F32303232303230333030333030305AA011180F32303232303231303031333030305A307B303D300906052B0E03021A050004140F0D5890F551D42ACF5431B7F42A321F7B74A4730414771441A65D9526D01DFF953B628CEAB7B55D3B9202040101747CA116180F32303232303130353138313532325AA0030A0103180F32303232303230333030333030305AA011180F32303232303231303031333030305A307B303D300906052B0E03021A050004140F0D5890F551D42ACF5431B7F42A321F7B74A4730414771441A65D9526D01DFF953B628CEAB7B55D3B9202040101747DA116180F32303231303231303136333032315AA0030A0104180F32303232303230333030333030305AA011180F32303232303231303031333030305A307B303D300906052B0E03021A050004140F0D5890F551D42ACF5431B7F42A321F7B74A4730414771441A65D9526D01DFF953B628CEAB7B55D3B9202040101747EA116180F32303231313130343131353932325AA0030A0101180F32303232303230333030333030305AA011180F32303232303231303031333030305A307B303D300906052B0E03021A050004140F0D5890F551D42ACF5431B7F42A321F7B74A4730414771441A65D9526D01DFF953B628CEAB7B55D3B92020401017480A116180F32303231303231303136333032305AA0030A0104180F32303232303230333030333030305AA011180F32303232303231303031333030305A307B303D300906052B0E03021A050004140F0D5890F551D42ACF5431B7F42A321F7B74A4730414771441A65D9526D01DFF953B628CEAB7B55D3B92020401017481A116180F32303231303132323133353031395AA0030A0104180F32303232303230333030333030305AA011180F32303232303231303031333030305A300D06092A864886F70D01010B050003820101008946FF8FF3009FC7265CB687D56960190379BEF6537FAC541A2306ED709B51AACD79B313331E297495AC4E26ABD94030CC407FDCE26CFC0B7A2BE1F226906182863FF1F140D628FA02B1D0BE1A1697E2F9C17AEF5FEC32AA4D120FAF0F58474AC2718964033076286DC358F5E96B08A5C83323CA4CA2B881D8FFA8E0EA3DB9A90863D378A3349EBF189030DA11891C6695950C5B93CBD440D998E67C0137655AC9DA40826B51211AD5985918336939131A4D1BF9E9A2A88CFB3C5059E3D1CE0D9B19F487EC018C60A0279E24270C7090006A4EC728E5C4A1F86829AFA236AF9979165DF1E7F0F5544217864F9C348ABF2B17C2FCD7DDE35838A99BC4A7256821A0820476308204723082046E30820356A0030201020203358762300D06092A864886F70D01010B0500305D310B300906035504061302555331183016060355040A130F552E532E20476F7665726E6D656E74310C300A060355040B1303446F44310C300A060355040B1303504B49311830160603550403130F444F4420454D41494C2043412D3539301E170D3232303132353030303035315A170D3232303331313030303035315A3064310B300906035504061302555331183016060355040A130F552E532E20476F7665726E6D656E74310C300A060355040B1303446F44310C300A060355040B1303504B49311F301D06035504031316444F44204E4950524E4554204F43535020313244203230820122300D06092A864886F70D01010105000382010F003082010A0282010100BF8A691DDB447562EFCDCB6C4DED76DDB200D1A188E01A3BA4EEEE1C42A7E5E78C0784C512F5CF64DD1A407A2EBC1F280761C1372351E03AED90C2DA0556066398E555EEE91C92988A62CDA1FD1F4E2E5F6EC6C022821C0D7C8B0E90E7CCF203CE8F102CBF4756D9B8CD09A2F16CF08F1E944311C8CD2120B98F6BCAEA1E60D1841A933815C8936BD20CC984FA1F5BF7AFFFFF2886DE53FDF3AE80E8394E09D441AE615693A78FE0051942B724147595DF049382897E26BBEA4E6902376C494F31141D1693005AA4E8AEBE4FEC342D10BFD90661935FB00D61A1FF7CB3B8FF45E473B3FFDD6D2C692FA8C0DE23373F1A6AD84109E7A9274008C5E94E28CE91850203010001A382012E3082012A301F0603551D23041830168014771441A65D9526D01DFF953B628CEAB7B55D3B92304606082B06010505070101043A3038303606082B06010505073002862A687474703A2F2F63726C2E646973612E6D696C2F7369676E2F444F44454D41494C43415F35392E63657230160603551D250101FF040C300A06082B06010505070309300E0603551D0F0101FF040403020780300F06092B060105050730010504020500301D0603551D0E04160414ADB0A9B2DDE9D444B4DF80F599598E84AC5EC68730670603551D200460305E300B0609608648016502010B24300B0609608648016502010B27300B0609608648016502010B2A300B0609608648016502010B3B300C060A6086480165030201030D300C060A60864801650302010311300C060A60864801650302010327300D06092A864886F70D01010B0500038201010047FE1DF6D439EF205C931F34B8C802B6E6D9C4EFEAFA7228FD07E56DFF17EEC18C250941EB46D5F416A02F2706A951A2A16435C589EE1059BB86EC38C7F07C2C6660C4B25C8918115FFD21A0D9798B860F878122B3781DCA004AFE869B4B88E0B8ABB4C3A255BC034B36ED82E4692AB765667E3036FC4BB2A53EF9B4654D5C8FDA62E1F6B9DFC7382FA9A3BEAC5EB35A105CB6FFD7B33D561DA77B886D17F58F9D6EDC722C9786E7C664598116F9ACB41282FB0C75EF6BA87BAE97249A42DF45FEC6AEB724C03D8EE5FF4FD7753AC0FE50CB6B746544D4A4DB977946D29183295B160538338FA2FB27F6BC84B5CBD658A431A8B87A9E20A57753BC8AB964B486")[..])
 }
+
+#[test]
+fn decode_ocsp_resp_statuses() {
+    let ocsp_resp = OcspResponse::from_der(OCSP_RESP_STATUSES).unwrap();
+    assert_eq!(ocsp_resp.response_status, OcspResponseStatus::Successful);
+
+    let bor = ocsp_resp.basic().unwrap().unwrap();
+    let responder = Certificate::from_der(OCSP_RESPONDER).unwrap();
+    assert_eq!(
+        bor.tbs_response_data.responder_id,
+        ResponderId::ByName(responder.tbs_certificate.subject.clone())
+    );
+    assert_eq!(
+        bor.tbs_response_data
+            .produced_at
+            .to_unix_duration()
+            .as_secs(),
+        1792032254
+    );
+
+    let responses = &bor.tbs_response_data.responses;
+    assert_eq!(responses.len(), 3);
+
+    assert_eq!(responses[0].cert_id.serial_number.as_bytes(), [0x00, 0x99]);
+    assert_eq!(responses[0].cert_status, CertStatus::Good(Null));
+
+    assert_eq!(responses[1].cert_id.serial_number.as_bytes(), [0x12, 0x34]);
+    match &responses[1].cert_status {
+        CertStatus::Revoked(info) => {
+            assert_eq!(
+                info.revocation_time.to_unix_duration().as_secs(),
+                1672531200
+            );
+            assert_eq!(info.revocation_reason, Some(CrlReason::KeyCompromise));
+        }
+        _ => panic!("Expected Revoked and got something else"),
+    }
+
+    assert_eq!(responses[2].cert_id.serial_number.as_bytes(), [0x42]);
+    assert_eq!(responses[2].cert_status, CertStatus::Unknown(Null));
+
+    for sr in responses {
+        assert_eq!(sr.this_update.to_unix_duration().as_secs(), 1792032254);
+        assert_eq!(
+            sr.next_update.unwrap().to_unix_duration().as_secs(),
+            1792637054
+        );
+    }
+}
+
+#[test]
+fn decode_ocsp_resp_unsuccessful() {
+    // tryLater, with no responseBytes
+    let ocsp_resp = OcspResponse::from_der(&hex!("30030A0103")).unwrap();
+    assert_eq!(ocsp_resp.response_status, OcspResponseStatus::TryLater);
+    assert_eq!(ocsp_resp.basic(), Ok(None));
+}
+
+#[test]
+#[cfg(feature = "verify")]
+fn verify_ocsp_resp() {
+    let ocsp_resp = OcspResponse::from_der(OCSP_RESP_STATUSES).unwrap();
+    let bor = ocsp_resp.basic().unwrap().unwrap();
+    let responder = Certificate::from_der(OCSP_RESPONDER).unwrap();
+    let key = p256::ecdsa::VerifyingKey::from_sec1_bytes(
+        responder
+            .tbs_certificate
+            .subject_public_key_info
+            .subject_public_key
+            .raw_bytes(),
+    )
+    .unwrap();
+    bor.verify::<_, p256::ecdsa::DerSignature>(&key).unwrap();
+
+    // A response with modified data no longer verifies
+    let mut tampered = bor.clone();
+    tampered.tbs_response_data.responses[1].cert_status = CertStatus::Good(Null);
+    assert_eq!(
+        tampered.verify::<_, p256::ecdsa::DerSignature>(&key),
+        Err(x509_cert::Error::SignatureInvalid)
+    );
+}